@@ -0,0 +1,529 @@
+# English messages (fallback for every other locale)
+
+## Shared labels
+
+label-hash = Hash
+label-file = File
+label-files = Files
+label-proof = Proof
+label-proofs = Proofs
+label-txid = TXID
+label-network = Network
+label-block = Block
+label-time = Time
+label-explorer = Explorer
+label-confirmations = Confirmations
+label-size = Size
+label-source = Source
+label-url = URL
+label-final-url = Final URL
+label-text = Text
+label-upgrade = Upgrade
+label-compact = Compact
+label-length = Length
+label-qr-code = QR Code
+label-reason = Reason
+label-expected = Expected
+label-got = Got
+label-algorithm = Algorithm
+label-directory = Directory
+label-recipient = Recipient
+label-outputs = Outputs
+label-fee = Fee
+label-change = Change
+label-input = Input
+label-memo = Memo
+label-version = Version
+label-attestations = Attestations
+label-status = Status
+label-on-chain = On-chain
+label-pending-tx = Pending TX
+label-chain-tip = Chain tip
+label-note = Note
+label-filename = File name
+label-report = Report
+label-signature = Signature
+label-alg = Alg
+label-error = Error
+label-file-match = File Match
+label-filter = Filter
+label-path = Path
+label-saved = Saved
+label-to = To
+label-total = Total
+label-verifying = Verifying
+
+size-bytes = { $bytes } bytes
+length-chars = { $count } chars
+amount-zatoshis = { $amount } zatoshis
+header-embeddable-proof = Embeddable Proof
+confirmed-in-block = Confirmed in block { $height }
+
+## stamp
+
+stamp-header-ipfs = Timestamping IPFS Content
+stamp-header-url = Timestamping URL
+stamp-header-stdin = Timestamping Stdin
+stamp-header-file = Timestamping File
+stamp-header-files = Timestamping Files
+stamp-header-text = Timestamping Text
+stamp-header-hash = Timestamping Hash
+stamp-source-cid = CID multihash (nothing downloaded)
+stamp-hashing-stdin = Hashing stdin...
+stamp-hashing-file = Hashing file
+stamp-hashing-files = Hashing files
+stamp-hashing-complete = Hashing complete
+stamp-estimating = Estimating transaction...
+stamp-estimating-batch = Estimating batch transaction...
+stamp-creating = Creating transaction...
+stamp-creating-batch = Creating batch transaction...
+stamp-broadcast = Transaction broadcast
+stamp-no-wait = Not waiting for confirmation - proof will be pending
+stamp-no-wait-batch = Not waiting for confirmation - proofs will be pending
+stamp-pending-saved = Pending proof saved: { $path }
+stamp-proof-saved = Proof saved: { $path }
+stamp-qr-single-input = QR codes are only shown when stamping a single input
+stamp-upgrade-later = run `zots upgrade <proof>` once confirmed
+stamp-dry-run-header = Dry Run
+stamp-dry-run-outputs = { $count } memo output(s), { $amount } zatoshis
+stamp-dry-run-input = { $amount } zatoshis ({ $pool })
+stamp-dry-run-memo = { $length } bytes: magic { $magic }, hash { $hash }, padding { $padding }
+stamp-dry-run-done = Dry run - nothing was proved or broadcast and no proof was saved
+
+## verify
+
+verify-header = Verifying Timestamp
+verify-against-ipfs = Verifying hash against IPFS content...
+verify-against-file = Verifying hash against original file...
+verify-against-text = Verifying hash against original text...
+verify-hash-match = { $kind ->
+    [ipfs] Hash matches original IPFS content
+    [text] Hash matches original text
+   *[file] Hash matches original file
+}
+verify-hash-mismatch = { $kind ->
+    [ipfs] Hash does NOT match original IPFS content!
+    [text] Hash does NOT match original text!
+   *[file] Hash does NOT match original file!
+}
+verify-pending = No attestations found - proof is pending confirmation
+verify-well-formed = PROOF IS WELL-FORMED (offline)
+verify-offline-unchecked = Anchor not checked on-chain; run `zots verify` online to confirm it
+verify-on-chain = Verifying against blockchain...
+verify-too-shallow = ANCHOR TOO SHALLOW
+verify-confirmations-required = { $depth } (required { $required })
+verify-valid = VALID TIMESTAMP (verified on-chain)
+verify-failed = VERIFICATION FAILED
+verify-batch-header = Batch Verification
+verify-hashing-files = Hashing files
+verify-batch-progress = Verifying { $path }...
+verify-column-proof = PROOF
+verify-column-status = STATUS
+verify-column-block = BLOCK
+verify-batch-passed = All { $count } proofs verified
+verify-batch-failed = { $failed } of { $total } proofs failed verification
+
+## info
+
+info-header = Proof Information
+info-confirmed = Confirmed
+info-pending = Pending
+info-attestation = Attestation #{ $number }
+
+## upgrade
+
+upgrade-header = Upgrading Proof
+upgrade-already-confirmed = Proof is already confirmed - nothing to upgrade
+upgrade-looking-up = Looking up transaction...
+upgrade-checking-memo = Checking that the transaction memo holds this hash...
+upgrade-done = Proof upgraded: { $path }
+
+## Prompts and progress
+
+prompt-answer-y-n = Please answer y or n
+sync-progress = Syncing wallet
+sync-done = Wallet synced
+confirm-waiting = Waiting for confirmation...
+confirm-mined = Mined in block { $height }, { $confirmations }/{ $target } confirmations...
+confirm-waiting-block = Waiting for confirmation (block { $height }, { $blocks } since broadcast)...
+confirm-done = Transaction confirmed
+confirm-not-yet = Not confirmed yet
+zec-amount = { $zec } ZEC ({ $zatoshis } zatoshis)
+
+## wallet
+
+label-address = Address
+label-amount = Amount
+label-balance = Balance
+label-block-height = Block Height
+label-data-dir = Data Dir
+label-faucet = Faucet
+pool-orchard = Orchard
+pool-sapling = Sapling
+pool-transparent = Transparent
+wallet-sync-header = Syncing Wallet
+wallet-synchronized = Wallet synchronized
+wallet-balance-header = Wallet Balance
+wallet-pool-spendable = { $zec } ZEC spendable
+wallet-pool-pending = , { $zec } ZEC pending
+wallet-transparent-warning = Transparent funds cannot pay for timestamps
+wallet-transparent-hint = Move them to the Orchard pool with `zots wallet shield`
+wallet-address-header = Wallet Address
+wallet-fund-hint = Fund this address with testnet ZEC from:
+wallet-addresses-header = Wallet Addresses
+wallet-new-address-header = New Address
+wallet-new-address-done = Funds sent to any wallet address are spendable for timestamps
+wallet-info-header = Wallet Info
+wallet-history-header = Wallet History
+wallet-history-empty = No transactions yet
+wallet-history-pending = pending
+wallet-history-entry = { $txid } ({ $kind ->
+    [timestamp] timestamp
+    [sent] sent
+   *[received] received
+})
+wallet-recovered-proof = Recovered proof: { $path }
+wallet-import-header = Import Viewing Key
+wallet-import-done = Watch-only wallet created
+wallet-import-hint = Run `zots wallet sync` to scan from the configured birthday height
+wallet-export-header = Export Viewing Key
+wallet-export-warning = The viewing key reveals all wallet transactions, amounts and memos.
+wallet-export-confirm = Export the viewing key?
+wallet-export-written = Viewing key written to { $path }
+wallet-shield-header = Shielding Funds
+wallet-shield-confirm = Shield these funds?
+wallet-shield-creating = Creating shielding transaction...
+wallet-shield-done = Shielded in block { $height }
+
+## nostr
+
+label-public-key = Public Key
+label-proof-file = Proof File
+label-compact-size = Compact Size
+label-relay = Relay
+label-event-id = Event ID
+label-event-id-hex = Event ID (hex)
+label-view-on-nostr = View on Nostr
+label-relays-success = Relays (success)
+label-fetch-with = Fetch with
+label-tip = Tip
+nostr-publish-header = Publishing Proof to Nostr
+nostr-connecting = Connecting to relays...
+nostr-publishing = Publishing event...
+nostr-published = Proof published to Nostr!
+nostr-relays-failed = Relays (failed): { $count }
+nostr-fetch-header = Fetching Proof from Nostr
+nostr-fetching = Fetching event...
+nostr-not-found = Event not found on any connected relay
+nostr-retrieved = Proof retrieved from Nostr!
+nostr-attestation-block = { $network } block { $height }
+nostr-compact-header = Compact Proof
+nostr-save-tip = Use -o <file.zots> to save the proof to a file
+
+## init
+
+label-config-file = Config File
+label-server = Server
+init-header = zots Setup
+init-mainnet-unsupported = Mainnet is not supported yet
+init-unknown-network = Unknown network '{ $network }'
+init-profile-name = Profile name
+init-replace-profile = Profile '{ $profile }' exists. Replace it?
+init-lightwalletd-server = lightwalletd server
+init-connecting = Connecting to { $url }...
+init-connected = Connected to { $url }
+init-chain-mismatch = Server follows the '{ $chain }' chain, not { $network }
+init-use-anyway = Use it anyway?
+init-unreachable = Could not reach { $url }: { $error }
+init-keep-server = Keep this server anyway?
+init-seed-choice = Generate a new seed or import one (generate/import)
+init-seed-phrase = Seed phrase (24 words)
+init-unknown-choice = Unknown choice '{ $choice }'
+init-birthday-hint = Use the height at which this seed was created; lower heights sync slower
+init-birthday-height = Birthday height
+init-not-a-height = '{ $answer }' is not a block height
+init-data-directory = Data directory
+init-existing-wallet = { $path } already holds a wallet database; remove it if it belongs to another seed
+init-store-keychain = Store the seed in the OS keychain?
+init-stored-keychain = Seed stored in the OS keychain
+init-store-config = Store the seed in the config file (plain text)?
+init-export-seed = Export the seed before running zots:
+init-make-default = Make '{ $profile }' the default profile?
+init-written = Profile '{ $profile }' written to { $path }
+init-seed-env-override = ZOTS_SEED is set and overrides the stored seed
+init-next-steps = Next: `zots wallet sync`, then fund `zots wallet address`
+init-write-down = Write these words down in order and keep them offline:
+init-written-down = Have you written down the seed phrase?
+init-unrecoverable = Without it the wallet cannot be recovered
+
+## export and embed
+
+label-format = Format
+export-header = Exporting Proof
+export-compact-string = compact string
+export-pending = Proof is still pending
+export-done = Exported: { $path }
+import-header = Importing Proof
+import-skipped = Skipped { $count } attestation(s) that are not Zcash attestations on the file digest
+import-pending = Imported proof has no Zcash attestation yet
+embed-header = Embedding Proof
+embed-pending = Proof is still pending; the embedded copy cannot be upgraded later
+embed-done = Stamped copy written: { $path }
+extract-header = Extracting Proof
+extract-match = Proof matches the file's original content
+extract-mismatch = Proof does NOT match the file's original content!
+extract-original-written = Original written: { $path }
+
+## pending
+
+label-upgraded = Upgraded
+label-still-pending = Still pending
+label-failed = Failed
+pending-header = Pending Proofs
+pending-none = No pending proofs
+pending-no-txid = (no txid)
+pending-upgrade-header = Upgrading Pending Proofs
+pending-upgraded = { $path } (block { $height })
+pending-already-confirmed = { $path } (already confirmed)
+pending-still-pending = { $path } still pending
+pending-missing = { $path } no longer exists; untracked
+
+## encode and decode
+
+label-image = Image
+decode-header = Decoding Proof
+decode-pending = Pending proof (no attestations)
+encode-header = Encoding Proof
+encode-json-string = JSON string
+encode-compact-header = Compact Format
+
+## watch and serve
+
+label-interval = Interval
+label-webhooks = Webhooks
+label-listening = Listening
+watch-header = Watching for Changes
+watch-running = Watching (Ctrl-C to stop)...
+watch-stamp-failed = Stamping failed: { $error }; retrying in { $secs }s
+watch-still-pending = { $count } proofs still pending; run `zots confirm <proof>` to complete them
+watch-stopped = Stopped watching
+watch-stamped = Stamped { $count } files in { $txid }
+watch-update-failed = Could not update proofs for { $txid }: { $error }
+watch-check-failed = Confirmation check failed: { $error }
+watch-confirmed = Confirmed { $path } in block { $height }
+serve-header = zots API Server
+serve-no-auth = The API has no authentication; restrict access to trusted clients
+serve-running = Serving (Ctrl-C to stop)...
+serve-stopped = Server stopped
+
+## config
+
+label-profile = Profile
+config-init-header = Config Init
+config-written = Config written to { $path }
+config-seed-hint = Keep the seed in ZOTS_SEED rather than in this file
+config-set-header = Config Set
+config-set-done = Set { $key } in profile '{ $profile }'
+config-seed-plain-text = The seed is stored in plain text (readable only by you); prefer the keychain or ZOTS_SEED
+config-env-override = { $var } is set and overrides this value
+config-show-header = Configuration
+config-not-created = { $path } (not created)
+config-value-source = { $value } ({ $source ->
+    [env] env
+    [file] file
+   *[default] default
+})
+
+## other commands
+
+label-fix = Fix
+label-artifact = Artifact
+label-timestamp = Timestamp
+label-base = Base
+label-merged = Merged
+label-object = Object
+doctor-header = zots Doctor
+doctor-summary = { $checks } check(s), { $failures } failure(s), { $warnings } warning(s)
+doctor-loading-prover = Loading Sapling prover...
+attest-header = In-toto Attestation
+attest-pending = Proof is still pending; the statement carries no attestation yet
+attest-saved = Statement saved: { $path }
+confirm-header = Waiting for Confirmation
+confirm-already-confirmed = Proof is already confirmed
+confirm-still-pending = Still pending
+confirm-timed-out = Transaction still pending after { $secs }s; run `zots confirm` again later
+merge-header = Merging Proofs
+merge-added = { $path } (+{ $count } attestations)
+merge-attestation = block { $height } ({ $txid })
+merge-pending = Merged proof is still pending
+merge-saved = Merged proof saved: { $path }
+
+exit-codes-header = Exit Codes
+fetch-downloading = Downloading
+fetch-downloading-unknown = Downloading...
+
+## TUI
+
+tui-menu-title = Main Menu
+tui-menu-stamp = Stamp a file or hash
+tui-menu-verify = Verify a timestamp proof
+tui-menu-wallet = Wallet management
+tui-menu-history = Proof history
+tui-menu-quit = Quit
+tui-title-stamp = Stamp
+tui-title-verify = Verify
+tui-title-wallet = Wallet
+tui-title-send = Send
+tui-status-title = Status
+tui-status-balance = Balance
+tui-title-history = History ({ $count })
+tui-title-activity = Activity
+# Bracketed keys are clickable: keep them as-is and separate hints with two spaces
+tui-hint-activity = [PgUp/PgDn] [F3] Hide
+tui-hint-stamp = [ESC] Back to menu  [ENTER] Add to queue  [ENTER on empty] Stamp queue  [DEL] Remove last  [F2] Browse
+tui-hint-back = [ESC] Back to menu
+tui-hint-result = [ESC] Back to menu  [C] Copy proof  [T] Copy TXID  [E] Copy explorer link
+tui-hint-sent = [ESC] Back to menu  [T] Copy TXID  [E] Copy explorer link
+tui-hint-stamp-pending = [ESC] Back to menu  [U] Wait and upgrade pending proofs
+tui-hint-verify-file = [ESC] Back to menu  [ENTER] Continue  [F2] Browse
+tui-hint-verify-proof = [ESC] Back to menu  [ENTER] Verify  [F2] Browse
+tui-hint-wallet = [S] Sync  [A] Receive  [N] New address  [T] Send  [ESC] Back to menu
+tui-hint-send-input = [ESC] Back to menu  [ENTER] Continue
+tui-hint-send-confirm = [ESC] Cancel  Type yes + [ENTER] Send
+tui-hint-history = [↑/↓] Select  [ENTER] Details  [V] Re-verify  [U] Upgrade pending  [R] Reload  [ESC] Back
+tui-hint-picker = [↑/↓] Select  [ENTER] Open/Pick  [BACKSPACE] Up a directory  [ESC] Cancel
+
+tui-stamp-prompt = Enter file path or hash to timestamp:
+tui-algorithm-toggle = [Tab] toggle
+tui-syncing = Syncing wallet with blockchain...
+tui-syncing-detail = This may take a moment for initial sync
+tui-broadcasting = Creating and broadcasting transaction...
+tui-broadcasting-detail = Building zk-SNARK proof and sending to network
+tui-waiting-block = Waiting for block confirmation...
+tui-waiting-block-detail = Transaction broadcast - waiting for next block (~75 seconds)
+tui-timestamp-confirmed = TIMESTAMP CONFIRMED
+tui-timestamps-confirmed = { $count } TIMESTAMPS CONFIRMED
+tui-unknown = Unknown
+tui-embeddable-proof = Embeddable proof (copy this):
+tui-toggle-qr = [Q] Toggle QR code
+tui-qr-no-proof = no compact proof available
+tui-qr-failed = failed to render QR code
+
+tui-step = Step { $step }/{ $total }:
+tui-verify-file-prompt = Enter file path or hash to verify:
+tui-verify-proof-prompt = Enter proof file path (.zots):
+tui-verifying-detail = Fetching transaction and decrypting memo
+tui-valid-timestamp = VALID TIMESTAMP
+tui-verified-on-chain = (verified on-chain)
+tui-hash-matches = Hash matches
+tui-hash-mismatch = Hash does NOT match!
+
+tui-scanning = Scanning blocks for transactions
+tui-commands = Commands
+tui-command-sync = Sync wallet
+tui-command-receive = Receive: show address and QR code
+tui-command-new-address = Generate a new diversified address
+tui-command-send = Send funds
+
+tui-history-empty = No proofs found in the current directory or the pending registry
+tui-history-missing = MISSING
+tui-history-confirmed = CONFIRMED
+tui-history-pending = PENDING
+tui-history-block = block { $height }
+tui-picker-empty = (no matching files)
+
+tui-send-address-prompt = Recipient address:
+tui-send-amount-prompt = Amount in ZEC:
+tui-send-memo-prompt = Memo (optional, shielded recipients only):
+tui-send-confirm-prompt = Type 'yes' to send:
+tui-send-check = Check the recipient, amount and fee before sending
+tui-transaction-broadcast = TRANSACTION BROADCAST
+
+tui-queue-hashing = hashing
+tui-queue-waiting = waiting
+tui-queue-broadcast = broadcast
+tui-queue-confirmed = confirmed
+tui-queue-failed = failed
+tui-network-unknown = unknown
+
+tui-status-ready = Ready
+tui-status-ready-sync-failed = Ready (sync failed)
+tui-status-syncing = Syncing wallet...
+tui-status-synced = Synced
+tui-status-looking-up = Looking up transaction...
+tui-status-starting-stamp = Starting stamp operation...
+tui-status-broadcasting = Creating and broadcasting transaction for { $count } hash(es)...
+tui-status-waiting-confirmation = Waiting for confirmation (TXID: { $txid }...)
+tui-status-generating-address = Generating address...
+tui-status-loading-address = Loading address...
+tui-status-computing-fee = Syncing wallet and computing fee...
+tui-status-sending = Building and broadcasting transaction...
+tui-status-confirm-send = Confirm send
+tui-status-sent = Sent (TXID: { $txid }...)
+tui-status-send-failed = Send failed
+
+tui-no-wallet = No wallet configured
+tui-no-wallet-seed = No wallet configured (set ZOTS_SEED)
+tui-wallet-error = Wallet error: { $error }
+tui-account-error = Account init error: { $error }
+tui-sync-done = Wallet synced successfully
+tui-sync-failed = Sync failed: { $error }
+tui-address-new = New diversified address generated
+tui-address-fund = Send TAZ to this address to fund timestamps
+tui-address-failed = Address error: { $error }
+
+tui-using-algorithm = Using { $algorithm }
+tui-enter-stamp-input = Please enter a file path, glob or hash
+tui-not-a-file = Not a file: { $path }
+tui-invalid-hash = Invalid hash: { $error }
+tui-not-file-or-hash = File not found and input is not a valid hash
+tui-still-hashing = Still hashing - try again in a moment
+tui-nothing-to-stamp = Nothing to stamp: every queued item failed
+tui-removed = Removed { $item }
+tui-transaction-failed = Transaction failed: { $error }
+tui-stamp-timed-out =
+    TX broadcast but confirmation timed out: { $error }
+    Pending proofs saved: { $saved }
+tui-save-error = Save error: { $error }
+tui-compact-error = Error generating compact format
+
+tui-enter-verify-input = Please enter a file path or hash to verify
+tui-enter-proof-path = Please enter a proof file path (.zots)
+tui-file-not-found = File not found: { $path }
+tui-load-error = Load error: { $error }
+tui-load-error-path = Load error ({ $path }): { $error }
+tui-invalid-proof = Invalid proof: { $error }
+tui-invalid-proof-hash = Invalid proof hash: { $error }
+tui-invalid-txid = Invalid TXID: { $error }
+tui-hash-error = Hash error: { $error }
+tui-verify-hash-mismatch = Hash does NOT match the provided file/hash!
+tui-verify-pending = Proof is pending (no attestations yet)
+tui-verify-no-wallet = Cannot verify on-chain (no wallet configured)
+tui-verification-error = Verification error: { $error }
+
+tui-proof-pending-upgrade = Proof is pending - upgrade it first [U]
+tui-proof-already-confirmed = Proof is already confirmed
+tui-proof-no-transaction = Proof records no transaction; run `zots upgrade --txid <TXID>`
+tui-lookup-failed = Lookup failed: { $error }
+tui-upgrade-retry = { $error }; try the upgrade again later
+tui-upgrade-done =
+    Confirmed in block { $height } - { $count ->
+        [one] proof upgraded
+       *[other] { $count } proofs upgraded
+    }
+
+tui-enter-recipient = Please enter a recipient address
+tui-invalid-amount = Invalid amount '{ $input }' (use ZEC, e.g. 0.001)
+tui-amount-zero = Amount must be greater than zero
+tui-memo-too-long = Memo is { $bytes } bytes; the limit is { $limit }
+tui-type-yes = Type 'yes' to send, or ESC to cancel
+tui-send-broadcast = Transaction broadcast
+
+tui-copy-proof = proof
+tui-copy-txid = TXID
+tui-copy-explorer-link = explorer link
+tui-copied = Copied { $what } to { $target }
+tui-clipboard-system = clipboard
+tui-clipboard-osc52 = terminal clipboard (OSC 52)
+tui-copy-failed = Copy failed: { $error }
+tui-nothing-to-copy = No { $what } to copy
@@ -0,0 +1,528 @@
+# Mensajes en español
+
+## Etiquetas comunes
+
+label-hash = Hash
+label-file = Archivo
+label-files = Archivos
+label-proof = Prueba
+label-proofs = Pruebas
+label-txid = TXID
+label-network = Red
+label-block = Bloque
+label-time = Hora
+label-explorer = Explorador
+label-confirmations = Confirmaciones
+label-size = Tamaño
+label-source = Origen
+label-url = URL
+label-final-url = URL final
+label-text = Texto
+label-upgrade = Actualizar
+label-compact = Compacta
+label-length = Longitud
+label-qr-code = Código QR
+label-reason = Motivo
+label-expected = Esperado
+label-got = Obtenido
+label-algorithm = Algoritmo
+label-directory = Directorio
+label-recipient = Destinatario
+label-outputs = Salidas
+label-fee = Comisión
+label-change = Cambio
+label-input = Entrada
+label-memo = Memo
+label-version = Versión
+label-attestations = Atestaciones
+label-status = Estado
+label-on-chain = En cadena
+label-pending-tx = TX pendiente
+label-chain-tip = Altura de la cadena
+label-note = Nota
+label-filename = Nombre del archivo
+label-report = Informe
+label-signature = Firma
+label-alg = Alg
+label-error = Error
+label-file-match = Coincidencia
+label-filter = Filtro
+label-path = Ruta
+label-saved = Guardado
+label-to = Para
+label-total = Total
+label-verifying = Verificando
+
+size-bytes = { $bytes } bytes
+length-chars = { $count } caracteres
+amount-zatoshis = { $amount } zatoshis
+header-embeddable-proof = Prueba incrustable
+confirmed-in-block = Confirmada en el bloque { $height }
+
+## stamp
+
+stamp-header-ipfs = Sellando contenido IPFS
+stamp-header-url = Sellando URL
+stamp-header-stdin = Sellando entrada estándar
+stamp-header-file = Sellando archivo
+stamp-header-files = Sellando archivos
+stamp-header-text = Sellando texto
+stamp-header-hash = Sellando hash
+stamp-source-cid = Multihash del CID (no se descargó nada)
+stamp-hashing-stdin = Calculando hash de la entrada estándar...
+stamp-hashing-file = Calculando hash del archivo
+stamp-hashing-files = Calculando hash de los archivos
+stamp-hashing-complete = Hash calculado
+stamp-estimating = Estimando la transacción...
+stamp-estimating-batch = Estimando la transacción por lotes...
+stamp-creating = Creando la transacción...
+stamp-creating-batch = Creando la transacción por lotes...
+stamp-broadcast = Transacción difundida
+stamp-no-wait = Sin esperar confirmación: la prueba quedará pendiente
+stamp-no-wait-batch = Sin esperar confirmación: las pruebas quedarán pendientes
+stamp-pending-saved = Prueba pendiente guardada: { $path }
+stamp-proof-saved = Prueba guardada: { $path }
+stamp-qr-single-input = Los códigos QR solo se muestran al sellar una única entrada
+stamp-upgrade-later = ejecuta `zots upgrade <prueba>` cuando se confirme
+stamp-dry-run-header = Simulación
+stamp-dry-run-outputs = { $count } salida(s) con memo, { $amount } zatoshis
+stamp-dry-run-input = { $amount } zatoshis ({ $pool })
+stamp-dry-run-memo = { $length } bytes: magic { $magic }, hash { $hash }, relleno { $padding }
+stamp-dry-run-done = Simulación: no se generó ni difundió nada y no se guardó ninguna prueba
+
+## verify
+
+verify-header = Verificando sello de tiempo
+verify-against-ipfs = Verificando el hash con el contenido IPFS...
+verify-against-file = Verificando el hash con el archivo original...
+verify-against-text = Verificando el hash con el texto original...
+verify-hash-match = { $kind ->
+    [ipfs] El hash coincide con el contenido IPFS original
+    [text] El hash coincide con el texto original
+   *[file] El hash coincide con el archivo original
+}
+verify-hash-mismatch = { $kind ->
+    [ipfs] ¡El hash NO coincide con el contenido IPFS original!
+    [text] ¡El hash NO coincide con el texto original!
+   *[file] ¡El hash NO coincide con el archivo original!
+}
+verify-pending = No hay atestaciones: la prueba está pendiente de confirmación
+verify-well-formed = LA PRUEBA ESTÁ BIEN FORMADA (sin conexión)
+verify-offline-unchecked = El anclaje no se comprobó en la cadena; ejecuta `zots verify` con conexión para confirmarlo
+verify-on-chain = Verificando en la blockchain...
+verify-too-shallow = ANCLAJE DEMASIADO RECIENTE
+verify-confirmations-required = { $depth } (se requieren { $required })
+verify-valid = SELLO DE TIEMPO VÁLIDO (verificado en la cadena)
+verify-failed = VERIFICACIÓN FALLIDA
+verify-batch-header = Verificación por lotes
+verify-hashing-files = Calculando hash de los archivos
+verify-batch-progress = Verificando { $path }...
+verify-column-proof = PRUEBA
+verify-column-status = ESTADO
+verify-column-block = BLOQUE
+verify-batch-passed = Las { $count } pruebas se verificaron
+verify-batch-failed = { $failed } de { $total } pruebas fallaron la verificación
+
+## info
+
+info-header = Información de la prueba
+info-confirmed = Confirmada
+info-pending = Pendiente
+info-attestation = Atestación n.º { $number }
+
+## upgrade
+
+upgrade-header = Actualizando prueba
+upgrade-already-confirmed = La prueba ya está confirmada: no hay nada que actualizar
+upgrade-looking-up = Buscando la transacción...
+upgrade-checking-memo = Comprobando que el memo de la transacción contiene este hash...
+upgrade-done = Prueba actualizada: { $path }
+
+## Preguntas y progreso
+
+prompt-answer-y-n = Responde y o n
+sync-progress = Sincronizando la cartera
+sync-done = Cartera sincronizada
+confirm-waiting = Esperando confirmación...
+confirm-mined = Minada en el bloque { $height }, { $confirmations }/{ $target } confirmaciones...
+confirm-waiting-block = Esperando confirmación (bloque { $height }, { $blocks } desde la difusión)...
+confirm-done = Transacción confirmada
+confirm-not-yet = Aún sin confirmar
+zec-amount = { $zec } ZEC ({ $zatoshis } zatoshis)
+
+## wallet
+
+label-address = Dirección
+label-amount = Importe
+label-balance = Saldo
+label-block-height = Altura de bloque
+label-data-dir = Directorio de datos
+label-faucet = Faucet
+pool-orchard = Orchard
+pool-sapling = Sapling
+pool-transparent = Transparente
+wallet-sync-header = Sincronizando la cartera
+wallet-synchronized = Cartera sincronizada
+wallet-balance-header = Saldo de la cartera
+wallet-pool-spendable = { $zec } ZEC disponibles
+wallet-pool-pending = , { $zec } ZEC pendientes
+wallet-transparent-warning = Los fondos transparentes no pueden pagar sellos de tiempo
+wallet-transparent-hint = Muévelos al pool Orchard con `zots wallet shield`
+wallet-address-header = Dirección de la cartera
+wallet-fund-hint = Financia esta dirección con ZEC de testnet desde:
+wallet-addresses-header = Direcciones de la cartera
+wallet-new-address-header = Nueva dirección
+wallet-new-address-done = Los fondos enviados a cualquier dirección de la cartera sirven para sellar
+wallet-info-header = Información de la cartera
+wallet-history-header = Historial de la cartera
+wallet-history-empty = Aún no hay transacciones
+wallet-history-pending = pendiente
+wallet-history-entry = { $txid } ({ $kind ->
+    [timestamp] sello de tiempo
+    [sent] enviada
+   *[received] recibida
+})
+wallet-recovered-proof = Prueba recuperada: { $path }
+wallet-import-header = Importar clave de visualización
+wallet-import-done = Cartera de solo lectura creada
+wallet-import-hint = Ejecuta `zots wallet sync` para escanear desde la altura de creación configurada
+wallet-export-header = Exportar clave de visualización
+wallet-export-warning = La clave de visualización revela todas las transacciones, importes y memos de la cartera.
+wallet-export-confirm = ¿Exportar la clave de visualización?
+wallet-export-written = Clave de visualización escrita en { $path }
+wallet-shield-header = Blindando fondos
+wallet-shield-confirm = ¿Blindar estos fondos?
+wallet-shield-creating = Creando la transacción de blindaje...
+wallet-shield-done = Blindados en el bloque { $height }
+
+## nostr
+
+label-public-key = Clave pública
+label-proof-file = Archivo de prueba
+label-compact-size = Tamaño compacto
+label-relay = Relé
+label-event-id = ID del evento
+label-event-id-hex = ID del evento (hex)
+label-view-on-nostr = Ver en Nostr
+label-relays-success = Relés (con éxito)
+label-fetch-with = Recuperar con
+label-tip = Consejo
+nostr-publish-header = Publicando la prueba en Nostr
+nostr-connecting = Conectando con los relés...
+nostr-publishing = Publicando el evento...
+nostr-published = ¡Prueba publicada en Nostr!
+nostr-relays-failed = Relés (fallidos): { $count }
+nostr-fetch-header = Recuperando la prueba de Nostr
+nostr-fetching = Recuperando el evento...
+nostr-not-found = No se encontró el evento en ningún relé conectado
+nostr-retrieved = ¡Prueba recuperada de Nostr!
+nostr-attestation-block = { $network }, bloque { $height }
+nostr-compact-header = Prueba compacta
+nostr-save-tip = Usa -o <archivo.zots> para guardar la prueba en un archivo
+
+## init
+
+label-config-file = Archivo de configuración
+label-server = Servidor
+init-header = Configuración de zots
+init-mainnet-unsupported = Mainnet aún no está soportada
+init-unknown-network = Red desconocida '{ $network }'
+init-profile-name = Nombre del perfil
+init-replace-profile = El perfil '{ $profile }' ya existe. ¿Reemplazarlo?
+init-lightwalletd-server = Servidor lightwalletd
+init-connecting = Conectando con { $url }...
+init-connected = Conectado a { $url }
+init-chain-mismatch = El servidor sigue la cadena '{ $chain }', no { $network }
+init-use-anyway = ¿Usarlo de todos modos?
+init-unreachable = No se pudo contactar con { $url }: { $error }
+init-keep-server = ¿Mantener este servidor de todos modos?
+init-seed-choice = Generar una semilla nueva o importar una (generate/import)
+init-seed-phrase = Frase semilla (24 palabras)
+init-unknown-choice = Opción desconocida '{ $choice }'
+init-birthday-hint = Usa la altura a la que se creó esta semilla; las alturas más bajas sincronizan más despacio
+init-birthday-height = Altura de creación
+init-not-a-height = '{ $answer }' no es una altura de bloque
+init-data-directory = Directorio de datos
+init-existing-wallet = { $path } ya contiene una base de datos de cartera; elimínala si pertenece a otra semilla
+init-store-keychain = ¿Guardar la semilla en el llavero del sistema?
+init-stored-keychain = Semilla guardada en el llavero del sistema
+init-store-config = ¿Guardar la semilla en el archivo de configuración (texto plano)?
+init-export-seed = Exporta la semilla antes de ejecutar zots:
+init-make-default = ¿Hacer de '{ $profile }' el perfil predeterminado?
+init-written = Perfil '{ $profile }' escrito en { $path }
+init-seed-env-override = ZOTS_SEED está definida y prevalece sobre la semilla guardada
+init-next-steps = Siguiente: `zots wallet sync` y después financia `zots wallet address`
+init-write-down = Anota estas palabras en orden y guárdalas sin conexión:
+init-written-down = ¿Has anotado la frase semilla?
+init-unrecoverable = Sin ella la cartera no se puede recuperar
+
+## export and embed
+
+label-format = Formato
+export-header = Exportando prueba
+export-compact-string = cadena compacta
+export-pending = La prueba sigue pendiente
+export-done = Exportada: { $path }
+import-header = Importando prueba
+import-skipped = Se omitieron { $count } atestación(es) que no son atestaciones de Zcash sobre el resumen del archivo
+import-pending = La prueba importada aún no tiene atestación de Zcash
+embed-header = Incrustando prueba
+embed-pending = La prueba sigue pendiente; la copia incrustada no se podrá actualizar después
+embed-done = Copia sellada escrita: { $path }
+extract-header = Extrayendo prueba
+extract-match = La prueba coincide con el contenido original del archivo
+extract-mismatch = ¡La prueba NO coincide con el contenido original del archivo!
+extract-original-written = Original escrito: { $path }
+
+## pending
+
+label-upgraded = Actualizadas
+label-still-pending = Aún pendientes
+label-failed = Fallidas
+pending-header = Pruebas pendientes
+pending-none = No hay pruebas pendientes
+pending-no-txid = (sin txid)
+pending-upgrade-header = Actualizando pruebas pendientes
+pending-upgraded = { $path } (bloque { $height })
+pending-already-confirmed = { $path } (ya confirmada)
+pending-still-pending = { $path } sigue pendiente
+pending-missing = { $path } ya no existe; se deja de seguir
+
+## encode and decode
+
+label-image = Imagen
+decode-header = Decodificando prueba
+decode-pending = Prueba pendiente (sin atestaciones)
+encode-header = Codificando prueba
+encode-json-string = cadena JSON
+encode-compact-header = Formato compacto
+
+## watch and serve
+
+label-interval = Intervalo
+label-webhooks = Webhooks
+label-listening = Escuchando
+watch-header = Vigilando cambios
+watch-running = Vigilando (Ctrl-C para detener)...
+watch-stamp-failed = Falló el sellado: { $error }; reintentando en { $secs } s
+watch-still-pending = { $count } pruebas siguen pendientes; ejecuta `zots confirm <prueba>` para completarlas
+watch-stopped = Vigilancia detenida
+watch-stamped = { $count } archivos sellados en { $txid }
+watch-update-failed = No se pudieron actualizar las pruebas de { $txid }: { $error }
+watch-check-failed = Falló la comprobación de confirmación: { $error }
+watch-confirmed = { $path } confirmada en el bloque { $height }
+serve-header = Servidor API de zots
+serve-no-auth = La API no tiene autenticación; limita el acceso a clientes de confianza
+serve-running = Sirviendo (Ctrl-C para detener)...
+serve-stopped = Servidor detenido
+
+## config
+
+label-profile = Perfil
+config-init-header = Inicializar configuración
+config-written = Configuración escrita en { $path }
+config-seed-hint = Guarda la semilla en ZOTS_SEED en lugar de en este archivo
+config-set-header = Establecer configuración
+config-set-done = { $key } establecido en el perfil '{ $profile }'
+config-seed-plain-text = La semilla se guarda en texto plano (solo tú puedes leerla); es preferible el llavero o ZOTS_SEED
+config-env-override = { $var } está definida y prevalece sobre este valor
+config-show-header = Configuración
+config-not-created = { $path } (no creado)
+config-value-source = { $value } ({ $source ->
+    [env] entorno
+    [file] archivo
+   *[default] predeterminado
+})
+
+## other commands
+
+label-fix = Solución
+label-artifact = Artefacto
+label-timestamp = Sello de tiempo
+label-base = Base
+label-merged = Fusionada
+label-object = Objeto
+doctor-header = Diagnóstico de zots
+doctor-summary = { $checks } comprobación(es), { $failures } fallo(s), { $warnings } aviso(s)
+doctor-loading-prover = Cargando el prover de Sapling...
+attest-header = Atestación in-toto
+attest-pending = La prueba sigue pendiente; la declaración aún no lleva atestación
+attest-saved = Declaración guardada: { $path }
+confirm-header = Esperando confirmación
+confirm-already-confirmed = La prueba ya está confirmada
+confirm-still-pending = Sigue pendiente
+confirm-timed-out = La transacción sigue pendiente tras { $secs } s; vuelve a ejecutar `zots confirm` más tarde
+merge-header = Fusionando pruebas
+merge-added = { $path } (+{ $count } atestaciones)
+merge-attestation = bloque { $height } ({ $txid })
+merge-pending = La prueba fusionada sigue pendiente
+merge-saved = Prueba fusionada guardada: { $path }
+
+exit-codes-header = Códigos de salida
+fetch-downloading = Descargando
+fetch-downloading-unknown = Descargando...
+
+## TUI
+
+tui-menu-title = Menú principal
+tui-menu-stamp = Sellar un archivo o hash
+tui-menu-verify = Verificar una prueba de sello de tiempo
+tui-menu-wallet = Gestión de la cartera
+tui-menu-history = Historial de pruebas
+tui-menu-quit = Salir
+tui-title-stamp = Sellar
+tui-title-verify = Verificar
+tui-title-wallet = Cartera
+tui-title-send = Enviar
+tui-status-title = Estado
+tui-status-balance = Saldo
+tui-title-history = Historial ({ $count })
+tui-title-activity = Actividad
+tui-hint-activity = [PgUp/PgDn] [F3] Ocultar
+tui-hint-stamp = [ESC] Volver al menú  [ENTER] Añadir a la cola  [ENTER en vacío] Sellar la cola  [DEL] Quitar el último  [F2] Examinar
+tui-hint-back = [ESC] Volver al menú
+tui-hint-result = [ESC] Volver al menú  [C] Copiar prueba  [T] Copiar TXID  [E] Copiar enlace del explorador
+tui-hint-sent = [ESC] Volver al menú  [T] Copiar TXID  [E] Copiar enlace del explorador
+tui-hint-stamp-pending = [ESC] Volver al menú  [U] Esperar y actualizar las pruebas pendientes
+tui-hint-verify-file = [ESC] Volver al menú  [ENTER] Continuar  [F2] Examinar
+tui-hint-verify-proof = [ESC] Volver al menú  [ENTER] Verificar  [F2] Examinar
+tui-hint-wallet = [S] Sincronizar  [A] Recibir  [N] Nueva dirección  [T] Enviar  [ESC] Volver al menú
+tui-hint-send-input = [ESC] Volver al menú  [ENTER] Continuar
+tui-hint-send-confirm = [ESC] Cancelar  Escribe yes + [ENTER] Enviar
+tui-hint-history = [↑/↓] Seleccionar  [ENTER] Detalles  [V] Reverificar  [U] Actualizar pendientes  [R] Recargar  [ESC] Volver
+tui-hint-picker = [↑/↓] Seleccionar  [ENTER] Abrir/Elegir  [BACKSPACE] Subir un directorio  [ESC] Cancelar
+
+tui-stamp-prompt = Introduce la ruta de un archivo o un hash para sellar:
+tui-algorithm-toggle = [Tab] cambiar
+tui-syncing = Sincronizando la cartera con la blockchain...
+tui-syncing-detail = La primera sincronización puede tardar un poco
+tui-broadcasting = Creando y difundiendo la transacción...
+tui-broadcasting-detail = Generando la prueba zk-SNARK y enviándola a la red
+tui-waiting-block = Esperando la confirmación del bloque...
+tui-waiting-block-detail = Transacción difundida - esperando al siguiente bloque (~75 segundos)
+tui-timestamp-confirmed = SELLO DE TIEMPO CONFIRMADO
+tui-timestamps-confirmed = { $count } SELLOS DE TIEMPO CONFIRMADOS
+tui-unknown = Desconocido
+tui-embeddable-proof = Prueba incrustable (cópiala):
+tui-toggle-qr = [Q] Mostrar/ocultar código QR
+tui-qr-no-proof = no hay prueba compacta disponible
+tui-qr-failed = no se pudo generar el código QR
+
+tui-step = Paso { $step }/{ $total }:
+tui-verify-file-prompt = Introduce la ruta de un archivo o un hash para verificar:
+tui-verify-proof-prompt = Introduce la ruta del archivo de prueba (.zots):
+tui-verifying-detail = Obteniendo la transacción y descifrando el memo
+tui-valid-timestamp = SELLO DE TIEMPO VÁLIDO
+tui-verified-on-chain = (verificado en la cadena)
+tui-hash-matches = El hash coincide
+tui-hash-mismatch = ¡El hash NO coincide!
+
+tui-scanning = Escaneando bloques en busca de transacciones
+tui-commands = Comandos
+tui-command-sync = Sincronizar la cartera
+tui-command-receive = Recibir: mostrar la dirección y el código QR
+tui-command-new-address = Generar una nueva dirección diversificada
+tui-command-send = Enviar fondos
+
+tui-history-empty = No se encontraron pruebas en el directorio actual ni en el registro de pendientes
+tui-history-missing = FALTA
+tui-history-confirmed = CONFIRMADA
+tui-history-pending = PENDIENTE
+tui-history-block = bloque { $height }
+tui-picker-empty = (no hay archivos coincidentes)
+
+tui-send-address-prompt = Dirección del destinatario:
+tui-send-amount-prompt = Importe en ZEC:
+tui-send-memo-prompt = Memo (opcional, solo para destinatarios blindados):
+tui-send-confirm-prompt = Escribe 'yes' para enviar:
+tui-send-check = Comprueba el destinatario, el importe y la comisión antes de enviar
+tui-transaction-broadcast = TRANSACCIÓN DIFUNDIDA
+
+tui-queue-hashing = calculando hash
+tui-queue-waiting = en espera
+tui-queue-broadcast = difundido
+tui-queue-confirmed = confirmado
+tui-queue-failed = fallido
+tui-network-unknown = desconocida
+
+tui-status-ready = Listo
+tui-status-ready-sync-failed = Listo (la sincronización falló)
+tui-status-syncing = Sincronizando la cartera...
+tui-status-synced = Sincronizada
+tui-status-looking-up = Buscando la transacción...
+tui-status-starting-stamp = Iniciando el sellado...
+tui-status-broadcasting = Creando y difundiendo la transacción para { $count } hash(es)...
+tui-status-waiting-confirmation = Esperando la confirmación (TXID: { $txid }...)
+tui-status-generating-address = Generando la dirección...
+tui-status-loading-address = Cargando la dirección...
+tui-status-computing-fee = Sincronizando la cartera y calculando la comisión...
+tui-status-sending = Construyendo y difundiendo la transacción...
+tui-status-confirm-send = Confirmar el envío
+tui-status-sent = Enviado (TXID: { $txid }...)
+tui-status-send-failed = El envío falló
+
+tui-no-wallet = No hay ninguna cartera configurada
+tui-no-wallet-seed = No hay ninguna cartera configurada (define ZOTS_SEED)
+tui-wallet-error = Error de la cartera: { $error }
+tui-account-error = Error al inicializar la cuenta: { $error }
+tui-sync-done = Cartera sincronizada correctamente
+tui-sync-failed = La sincronización falló: { $error }
+tui-address-new = Nueva dirección diversificada generada
+tui-address-fund = Envía TAZ a esta dirección para financiar los sellos de tiempo
+tui-address-failed = Error de dirección: { $error }
+
+tui-using-algorithm = Usando { $algorithm }
+tui-enter-stamp-input = Introduce la ruta de un archivo, un patrón glob o un hash
+tui-not-a-file = No es un archivo: { $path }
+tui-invalid-hash = Hash no válido: { $error }
+tui-not-file-or-hash = No se encontró el archivo y la entrada no es un hash válido
+tui-still-hashing = Aún calculando hashes - inténtalo de nuevo en un momento
+tui-nothing-to-stamp = Nada que sellar: todos los elementos de la cola fallaron
+tui-removed = Quitado { $item }
+tui-transaction-failed = La transacción falló: { $error }
+tui-stamp-timed-out =
+    Transacción difundida pero se agotó el tiempo de confirmación: { $error }
+    Pruebas pendientes guardadas: { $saved }
+tui-save-error = Error al guardar: { $error }
+tui-compact-error = Error al generar el formato compacto
+
+tui-enter-verify-input = Introduce la ruta de un archivo o un hash para verificar
+tui-enter-proof-path = Introduce la ruta de un archivo de prueba (.zots)
+tui-file-not-found = Archivo no encontrado: { $path }
+tui-load-error = Error de carga: { $error }
+tui-load-error-path = Error de carga ({ $path }): { $error }
+tui-invalid-proof = Prueba no válida: { $error }
+tui-invalid-proof-hash = Hash de la prueba no válido: { $error }
+tui-invalid-txid = TXID no válido: { $error }
+tui-hash-error = Error de hash: { $error }
+tui-verify-hash-mismatch = ¡El hash NO coincide con el archivo o hash indicado!
+tui-verify-pending = La prueba está pendiente (aún no tiene atestaciones)
+tui-verify-no-wallet = No se puede verificar en la cadena (no hay ninguna cartera configurada)
+tui-verification-error = Error de verificación: { $error }
+
+tui-proof-pending-upgrade = La prueba está pendiente - actualízala primero [U]
+tui-proof-already-confirmed = La prueba ya está confirmada
+tui-proof-no-transaction = La prueba no registra ninguna transacción; ejecuta `zots upgrade --txid <TXID>`
+tui-lookup-failed = La búsqueda falló: { $error }
+tui-upgrade-retry = { $error }; vuelve a intentar la actualización más tarde
+tui-upgrade-done =
+    Confirmada en el bloque { $height } - { $count ->
+        [one] prueba actualizada
+       *[other] { $count } pruebas actualizadas
+    }
+
+tui-enter-recipient = Introduce la dirección del destinatario
+tui-invalid-amount = Importe no válido '{ $input }' (usa ZEC, p. ej. 0.001)
+tui-amount-zero = El importe debe ser mayor que cero
+tui-memo-too-long = El memo ocupa { $bytes } bytes; el límite es { $limit }
+tui-type-yes = Escribe 'yes' para enviar, o ESC para cancelar
+tui-send-broadcast = Transacción difundida
+
+tui-copy-proof = la prueba
+tui-copy-txid = el TXID
+tui-copy-explorer-link = el enlace del explorador
+tui-copied = Copiado { $what } a { $target }
+tui-clipboard-system = el portapapeles
+tui-clipboard-osc52 = el portapapeles del terminal (OSC 52)
+tui-copy-failed = No se pudo copiar: { $error }
+tui-nothing-to-copy = No hay { $what } que copiar
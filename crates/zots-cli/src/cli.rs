@@ -2,10 +2,13 @@
 //!
 //! Defines the command-line interface structure using clap's derive macros.
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use zots_core::HashAlgorithm;
 
+use crate::commands::export::ExportFormat;
+
 /// zOpenTimestamps - Zcash blockchain timestamping CLI
 ///
 /// Timestamp files and hashes on the Zcash blockchain with privacy-preserving
@@ -28,29 +31,60 @@ pub struct Cli {
     #[arg(long, value_enum, default_value_t = LogLevelArg::Info, global = true, value_name = "LEVEL")]
     pub log_level: LogLevelArg,
 
+    /// Also write logs to this file, rotated daily (also `log_file` in the config)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Log level for the log file, independent of --log-level
+    #[arg(long, value_enum, default_value_t = LogLevelArg::Debug, global = true, value_name = "LEVEL")]
+    pub log_file_level: LogLevelArg,
+
+    /// Emit machine-readable JSON on stdout (also `ZOTS_OUTPUT=json`)
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Print only errors; scripts can rely on the exit code (or `--json`)
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Language for human-readable output, e.g. `es` (also `ZOTS_LANG`, else the system locale)
+    #[arg(long, global = true, value_name = "LANG")]
+    pub lang: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
-    /// Timestamp a file or hash on the Zcash blockchain
+    /// Timestamp files or a hash on the Zcash blockchain
+    ///
+    /// Several files (or glob patterns) are stamped together in a single
+    /// transaction, with one .zots proof written next to each file.
     Stamp {
-        /// File to timestamp
-        #[arg(conflicts_with = "hash")]
-        file: Option<PathBuf>,
+        /// Files, glob patterns, an http(s) URL or an ipfs:// CID to timestamp (`-` reads stdin)
+        #[arg(conflicts_with = "hash", value_name = "FILE")]
+        files: Vec<String>,
 
         /// Hash to timestamp (hex string, 40 or 64 chars)
-        #[arg(long, conflicts_with = "file")]
+        #[arg(long, conflicts_with = "files")]
         hash: Option<String>,
 
-        /// Output proof file path (default: <file>.zots or <hash>.zots)
+        /// Timestamp data read from stdin (same as `-`)
+        #[arg(long, conflicts_with_all = ["files", "hash"])]
+        stdin: bool,
+
+        /// Timestamp a text message (line endings normalized, trailing whitespace trimmed)
+        #[arg(long, conflicts_with_all = ["files", "hash", "stdin"])]
+        text: Option<String>,
+
+        /// Output proof file path (default: <file>.zots or <hash>.zots; single input only)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
-        /// Hash algorithm to use (sha256 or blake3)
-        #[arg(long, value_enum, default_value_t = HashAlgorithmArg::Sha256, value_name = "ALGO")]
-        hash_algorithm: HashAlgorithmArg,
+        /// Hash algorithm to use (default: `hash_algorithm` from the config, else sha256)
+        #[arg(long, value_enum, value_name = "ALGO")]
+        hash_algorithm: Option<HashAlgorithmArg>,
 
         /// Display QR code for the compact proof output
         #[arg(long)]
@@ -59,22 +93,101 @@ pub enum Commands {
         /// Don't wait for confirmation (create pending proof)
         #[arg(long)]
         no_wait: bool,
+
+        /// Threads used to hash multiple files (default: one per CPU core)
+        #[arg(short, long, value_name = "N")]
+        jobs: Option<usize>,
+
+        /// Show the fee, inputs, memo and proof paths without proving or broadcasting
+        /// (the wallet is still synced to build the proposal)
+        #[arg(long, conflicts_with_all = ["no_wait", "qr"])]
+        dry_run: bool,
+
+        /// Short note stored in the proof metadata, e.g. what the file is (max 280 chars)
+        #[arg(long, value_name = "TEXT")]
+        note: Option<String>,
+
+        /// Record the file name (not its path) in the proof metadata
+        #[arg(long, conflicts_with_all = ["hash", "stdin", "text"])]
+        filename: bool,
     },
 
     /// Verify a timestamp proof
     Verify {
         /// Proof file (.zots)
-        proof: PathBuf,
+        #[arg(required_unless_present_any = ["batch", "explain_exit_codes"])]
+        proof: Option<PathBuf>,
 
-        /// Original file to verify against (optional)
+        /// Original file or ipfs:// CID to verify against (optional)
         #[arg(short, long)]
         file: Option<PathBuf>,
+
+        /// Original text message to verify against (as passed to `stamp --text`)
+        #[arg(long, conflicts_with = "file")]
+        text: Option<String>,
+
+        /// Fail (exit code 6) unless the attestation has at least N confirmations
+        #[arg(long, value_name = "N")]
+        require_confirmations: Option<u64>,
+
+        /// Check structure and the original file only, without contacting lightwalletd
+        /// (exit code 7: the anchor is left unchecked)
+        #[arg(long, conflicts_with_all = ["require_confirmations", "batch"])]
+        offline: bool,
+
+        /// Also write a verification report (inputs, evidence, verdict) to this JSON file
+        #[arg(long, value_name = "FILE", conflicts_with = "batch")]
+        report: Option<PathBuf>,
+
+        /// Sign the report with an Ed25519 PKCS#8 key; the signature goes to <FILE>.sig
+        #[arg(long, value_name = "KEY_PEM", requires = "report")]
+        sign: Option<PathBuf>,
+
+        /// Verify every .zots proof under a directory against its sibling file
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["proof", "file", "text"])]
+        batch: Option<PathBuf>,
+
+        /// Threads used to hash files in --batch mode (default: one per CPU core)
+        #[arg(short, long, value_name = "N", requires = "batch")]
+        jobs: Option<usize>,
+
+        /// Print the exit codes used by stamp, verify and confirm, then exit
+        #[arg(long, exclusive = true)]
+        explain_exit_codes: bool,
+    },
+
+    /// Complete a pending proof once its transaction is confirmed
+    Upgrade {
+        /// Pending proof file (.zots), updated in place
+        proof: PathBuf,
+
+        /// Transaction ID to look up (default: the one recorded in the proof)
+        #[arg(long)]
+        txid: Option<String>,
+    },
+
+    /// Wait for a pending stamp to confirm and update its proof
+    ///
+    /// Exits with code 2 (pending) if the transaction is still pending when
+    /// the timeout expires.
+    Confirm {
+        /// Pending proof file (.zots) or transaction ID
+        #[arg(value_name = "PROOF_OR_TXID")]
+        target: String,
+
+        /// Seconds to wait before giving up
+        #[arg(long, default_value_t = 900, value_name = "SECS")]
+        timeout: u64,
     },
 
     /// Display proof information
     Info {
         /// Proof file (.zots)
         proof: PathBuf,
+
+        /// Also check each transaction on-chain (presence, block, confirmations)
+        #[arg(long)]
+        online: bool,
     },
 
     /// Encode a .zots proof to compact format (CBOR+Base64)
@@ -90,29 +203,218 @@ pub enum Commands {
     /// Decode a compact proof string to JSON
     Decode {
         /// Compact proof string (zots1...) to decode
-        compact: String,
+        #[arg(required_unless_present = "qr")]
+        compact: Option<String>,
+
+        /// Read the compact proof from QR codes in a PNG or JPEG image
+        #[arg(long, value_name = "IMAGE", conflicts_with = "compact")]
+        qr: Option<PathBuf>,
 
         /// Output file path (default: stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
     },
 
+    /// Write a copy of a PNG, JPEG or PDF with its proof in the metadata (tEXt or XMP)
+    Embed {
+        /// Stamped file
+        file: PathBuf,
+
+        /// Proof of the file (default: <file>.zots)
+        #[arg(short, long)]
+        proof: Option<PathBuf>,
+
+        /// Output path (default: <name>.stamped.<ext>)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Emit an in-toto statement with the artifact's timestamp as predicate
+    Attest {
+        /// Stamped build artifact
+        #[arg(long, value_name = "FILE")]
+        artifact: PathBuf,
+
+        /// Proof of the artifact (default: <artifact>.zots)
+        #[arg(short, long)]
+        proof: Option<PathBuf>,
+
+        /// Subject name in the statement (default: the artifact's file name)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Write the statement to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Convert a proof to OpenTimestamps, raw CBOR or detached JSON
+    Export {
+        /// Proof file (.zots) or compact string to export
+        input: String,
+
+        /// Target format
+        #[arg(short, long, value_enum, default_value_t = ExportFormatArg::Ots)]
+        format: ExportFormatArg,
+
+        /// Output path (default: <proof>.<format>)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Convert an OpenTimestamps, CBOR, JSON or compact proof to a .zots file
+    Import {
+        /// File to import (format detected from its contents)
+        input: PathBuf,
+
+        /// Output proof path (default: <input>.zots)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Recover the proof embedded in a file by `zots embed`
+    Extract {
+        /// File carrying an embedded proof
+        file: PathBuf,
+
+        /// Where to save the proof (default: <file>.zots)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Also write the original file, without the embedded proof
+        #[arg(long, value_name = "PATH")]
+        original: Option<PathBuf>,
+
+        /// Verify the extracted proof on-chain
+        #[arg(long)]
+        verify: bool,
+    },
+
     /// Wallet management commands
     Wallet {
         #[command(subcommand)]
         command: WalletCommands,
     },
 
+    /// Set up zots interactively: seed, network, server and config file
+    ///
+    /// Tests the lightwalletd connection and can keep the seed in the OS
+    /// keychain instead of on disk.
+    Init {
+        /// Profile to create (default: the network name)
+        #[arg(long, value_name = "NAME")]
+        profile: Option<String>,
+
+        /// Replace an existing profile without asking
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Diagnose the setup and suggest fixes
+    ///
+    /// Checks config validity, lightwalletd reachability and version, TLS,
+    /// wallet database integrity, birthday height, free disk space and the
+    /// Sapling prover. Exits non-zero if any check fails.
+    Doctor,
+
+    /// Manage the config file (~/.config/zots/config.toml)
+    ///
+    /// Environment variables still take precedence over values in the file.
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
     /// Nostr protocol integration for proof sharing
     Nostr {
         #[command(subcommand)]
         command: NostrCommands,
     },
 
+    /// Timestamp git commits and tags (proofs stored in `.git/zots/`)
+    Git {
+        #[command(subcommand)]
+        command: GitCommands,
+    },
+
+    /// List and upgrade pending proofs tracked by stamp and watch
+    Pending {
+        #[command(subcommand)]
+        command: PendingCommands,
+    },
+
+    /// Operate on proof files (merge)
+    Proof {
+        #[command(subcommand)]
+        command: ProofCommands,
+    },
+
+    /// Watch directories and stamp new or modified files automatically
+    ///
+    /// Changed files are batched into one transaction per interval and get a
+    /// pending `<file>.zots` proof, completed in place once mined.
+    Watch {
+        /// Directories to watch (recursively)
+        #[arg(required = true, value_name = "DIR")]
+        dirs: Vec<PathBuf>,
+
+        /// Only stamp files matching this glob (repeatable; relative to the watched directory)
+        #[arg(long, value_name = "GLOB")]
+        include: Vec<String>,
+
+        /// Never stamp files matching this glob (repeatable)
+        #[arg(long, value_name = "GLOB")]
+        exclude: Vec<String>,
+
+        /// Seconds between batches (default: one Zcash block)
+        #[arg(long, default_value_t = 75, value_name = "SECS")]
+        interval: u64,
+
+        /// Hash algorithm to use (default: `hash_algorithm` from the config, else sha256)
+        #[arg(long, value_enum, value_name = "ALGO")]
+        hash_algorithm: Option<HashAlgorithmArg>,
+
+        #[command(flatten)]
+        webhooks: WebhookArgs,
+    },
+
+    /// Run an HTTP API for stamping and verifying hashes
+    ///
+    /// Endpoints: `POST /stamp`, `GET /proof/{id}`, `POST /verify`.
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080", value_name = "ADDR")]
+        listen: SocketAddr,
+
+        /// Seconds a submitted hash may wait for others to share its transaction
+        #[arg(long, default_value_t = 60, value_name = "SECS")]
+        max_delay: u64,
+
+        #[command(flatten)]
+        webhooks: WebhookArgs,
+    },
+
     /// Launch interactive TUI mode
     Tui,
 }
 
+/// Webhook options shared by long-running commands
+#[derive(Args)]
+pub struct WebhookArgs {
+    /// POST stamp events (broadcast, confirmed, failed) to this URL (repeatable)
+    #[arg(long = "webhook", value_name = "URL")]
+    pub urls: Vec<String>,
+
+    /// Sign webhook bodies with HMAC-SHA256 using this secret
+    #[arg(
+        long,
+        env = "ZOTS_WEBHOOK_SECRET",
+        value_name = "SECRET",
+        hide_env_values = true
+    )]
+    pub webhook_secret: Option<String>,
+}
+
 /// Hash algorithm option for CLI arguments
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
 pub enum HashAlgorithmArg {
@@ -120,6 +422,26 @@ pub enum HashAlgorithmArg {
     Blake3,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormatArg {
+    /// OpenTimestamps detached timestamp (.ots)
+    Ots,
+    /// Raw CBOR (the compact format without base64)
+    Cbor,
+    /// Detached JSON proof document
+    Json,
+}
+
+impl From<ExportFormatArg> for ExportFormat {
+    fn from(value: ExportFormatArg) -> Self {
+        match value {
+            ExportFormatArg::Ots => ExportFormat::Ots,
+            ExportFormatArg::Cbor => ExportFormat::Cbor,
+            ExportFormatArg::Json => ExportFormat::Json,
+        }
+    }
+}
+
 impl From<HashAlgorithmArg> for HashAlgorithm {
     fn from(value: HashAlgorithmArg) -> Self {
         match value {
@@ -135,13 +457,110 @@ pub enum WalletCommands {
     Sync,
 
     /// Show wallet balance
-    Balance,
+    Balance {
+        /// Show spendable and pending amounts per pool
+        #[arg(long)]
+        breakdown: bool,
+    },
 
     /// Show receiving address
     Address,
 
+    /// List every address generated for the wallet
+    Addresses,
+
+    /// Generate a new diversified receiving address
+    ///
+    /// All diversified addresses belong to the same wallet but cannot be
+    /// linked to each other on-chain, so each funding source can get its own.
+    NewAddress,
+
     /// Show wallet info (height, balance, address)
     Info,
+
+    /// List wallet transactions with their memos
+    ///
+    /// Timestamp transactions are flagged. With --recover, a lost .zots proof
+    /// is rebuilt from the timestamp memos of a past transaction.
+    History {
+        /// Maximum number of transactions to list
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+
+        /// Rebuild proofs for the hashes timestamped by this transaction
+        #[arg(long, value_name = "TXID")]
+        recover: Option<String>,
+
+        /// Output proof file path for --recover (default: <hash>.zots; single hash only)
+        #[arg(short, long, requires = "recover")]
+        output: Option<PathBuf>,
+
+        /// Hash algorithm the recovered hashes were made with (default: from the config)
+        #[arg(long, value_enum, value_name = "ALGO")]
+        hash_algorithm: Option<HashAlgorithmArg>,
+    },
+
+    /// Set up a watch-only wallet from a unified full viewing key
+    ///
+    /// Run without ZOTS_SEED and with an empty data directory. The wallet can
+    /// then sync, show balances and verify proofs, but cannot stamp or send.
+    Import {
+        /// Unified full viewing key (uview...)
+        #[arg(long, value_name = "KEY")]
+        ufvk: String,
+    },
+
+    /// Print the unified full viewing key (UFVK)
+    ///
+    /// The viewing key reveals every wallet transaction and memo but cannot
+    /// spend funds. Use it to provision verification services without the seed.
+    ExportViewingKey {
+        /// Write the key to this file (mode 0600) instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Skip the interactive safety prompt
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Move transparent funds to the Orchard pool
+    Shield {
+        /// Broadcast without waiting for confirmation
+        #[arg(long)]
+        no_wait: bool,
+
+        /// Skip the fee confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Create a config file with a testnet profile
+    Init {
+        /// Overwrite an existing config file
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Print the effective value of a setting
+    Get {
+        /// Setting name (e.g. lightwalletd, network, data_dir, hash_algorithm, explorer)
+        key: String,
+    },
+
+    /// Store a setting in the active profile
+    Set {
+        /// Setting name
+        key: String,
+        /// New value
+        value: String,
+    },
+
+    /// Show the effective configuration and where each value comes from
+    Show,
 }
 
 #[derive(Subcommand)]
@@ -163,6 +582,58 @@ pub enum NostrCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub enum PendingCommands {
+    /// List pending proofs with their age and transaction ID
+    List,
+
+    /// Try to upgrade every pending proof once (suitable for cron)
+    UpgradeAll,
+}
+
+#[derive(Subcommand)]
+pub enum ProofCommands {
+    /// Merge proofs of the same hash into one proof with all attestations
+    Merge {
+        /// Proof files (.zots) for the same document
+        #[arg(required = true, num_args = 2..)]
+        proofs: Vec<PathBuf>,
+
+        /// Output proof file (default: update the first proof in place)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GitCommands {
+    /// Timestamp a commit or tag object
+    Stamp {
+        /// Revision to stamp (commit, branch or tag)
+        #[arg(default_value = "HEAD")]
+        rev: String,
+
+        /// Hash algorithm to use (default: `hash_algorithm` from the config, else sha256)
+        #[arg(long, value_enum, value_name = "ALGO")]
+        hash_algorithm: Option<HashAlgorithmArg>,
+
+        /// Don't wait for confirmation (create pending proof)
+        #[arg(long)]
+        no_wait: bool,
+    },
+
+    /// Verify the stored proof of a commit or tag
+    Verify {
+        /// Revision to verify (commit, branch or tag)
+        #[arg(default_value = "HEAD")]
+        rev: String,
+
+        /// Fail (exit code 6) unless the attestation has at least N confirmations
+        #[arg(long, value_name = "N")]
+        require_confirmations: Option<u64>,
+    },
+}
+
 /// Log level option for CLI
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
 pub enum LogLevelArg {
@@ -0,0 +1,82 @@
+//! Attest command implementation.
+//!
+//! Emits an in-toto Statement whose subject is a stamped artifact and whose
+//! predicate is its zots proof, for attaching to SLSA provenance or signing
+//! with `cosign attest --type <predicate type>`.
+//!
+//! The artifact is hashed and checked against the proof first, so a
+//! statement is never produced for the wrong file.
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use std::path::PathBuf;
+use tracing::info;
+use zots_core::{InTotoStatement, TimestampProof, hash_file_with, hash_to_hex};
+
+use super::stamp::default_output_path;
+
+pub fn run(
+    artifact: PathBuf,
+    proof: Option<PathBuf>,
+    name: Option<String>,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    print_header(&t!("attest-header"));
+
+    let proof_path = proof.unwrap_or_else(|| default_output_path(&artifact));
+    let proof = TimestampProof::load(&proof_path)?;
+
+    info!(
+        "Hashing {} with {}",
+        artifact.display(),
+        proof.hash_algorithm().name()
+    );
+    let hash = hash_file_with(&artifact, proof.hash_algorithm())?;
+    if hash != proof.hash_bytes()? {
+        return Err(anyhow::anyhow!(
+            "{} is not the proof of {} (hash {} != {})",
+            proof_path.display(),
+            artifact.display(),
+            hash_to_hex(&hash),
+            proof.hash
+        ));
+    }
+    if !proof.is_confirmed() {
+        print_warning(&t!("attest-pending"));
+    }
+
+    // Subject names are conventionally the artifact's file name
+    let name = name.unwrap_or_else(|| {
+        artifact
+            .file_name()
+            .unwrap_or(artifact.as_os_str())
+            .to_string_lossy()
+            .into_owned()
+    });
+    let statement = InTotoStatement::new(&name, &proof)?;
+    let statement_json = statement.to_json()?;
+
+    print_info(&t!("label-artifact"), &artifact.display().to_string());
+    print_info(&t!("label-proof"), &proof_path.display().to_string());
+    print_hash(&proof.hash, proof.hash_algorithm().name());
+    if let Some(timestamp) = &statement.predicate.timestamp {
+        print_info(&t!("label-timestamp"), timestamp);
+    }
+
+    let output_display = output.as_ref().map(|p| p.display().to_string());
+    if let Some(output) = &output {
+        std::fs::write(output, &statement_json)?;
+        print_success(&t!("attest-saved", path = output.display().to_string()));
+    } else if !json_output() {
+        print_line("");
+        print_line(&statement_json);
+    }
+
+    print_json(&json!({
+        "statement": statement,
+        "output": output_display,
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Config command implementations.
+//!
+//! Manages `~/.config/zots/config.toml` (or `ZOTS_CONFIG`):
+//! - `init` - Write a starter file with a testnet profile
+//! - `get` - Print the effective value of one setting
+//! - `set` - Store a setting in the active profile
+//! - `show` - Print every effective setting and where it comes from
+//!
+//! Environment variables always take precedence over the file, so `get` and
+//! `show` report the value commands will actually use.
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use zots_zcash::{CONFIG_KEYS, ConfigFile, ProfileConfig, ZcashConfig};
+
+pub fn init(force: bool) -> anyhow::Result<()> {
+    print_header(&t!("config-init-header"));
+
+    let path = ConfigFile::default_path();
+    if path.exists() && !force {
+        return Err(anyhow::anyhow!(
+            "{} already exists; pass --force to overwrite it",
+            path.display()
+        ));
+    }
+
+    let file = ConfigFile::starter();
+    file.save(&path)?;
+    print_success(&t!("config-written", path = path.display().to_string()));
+    print_status(&t!("config-seed-hint"));
+
+    print_json(&json!({ "path": path.display().to_string() }));
+
+    Ok(())
+}
+
+pub fn get(key: &str) -> anyhow::Result<()> {
+    // Rejects unknown keys with the list of valid ones
+    ProfileConfig::default().get(key)?;
+
+    let config = ZcashConfig::load(None)?;
+    let value = config
+        .settings()
+        .into_iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| value)
+        .unwrap_or_default();
+
+    print_line(&value);
+    print_json(&json!({ "key": key, "value": value }));
+
+    Ok(())
+}
+
+pub fn set(key: &str, value: &str) -> anyhow::Result<()> {
+    print_header(&t!("config-set-header"));
+
+    let path = ConfigFile::default_path();
+    let mut file = ConfigFile::read(&path)?.unwrap_or_default();
+    let profile_name = file.active_profile_name(None);
+    file.profiles
+        .entry(profile_name.clone())
+        .or_default()
+        .set(key, value)?;
+    if file.default_profile.is_none() {
+        file.default_profile = Some(profile_name.clone());
+    }
+    file.save(&path)?;
+
+    print_success(&t!(
+        "config-set-done",
+        key = key,
+        profile = profile_name.as_str()
+    ));
+    if key == "seed" {
+        print_warning(&t!("config-seed-plain-text"));
+    }
+    if let Some((_, var)) = CONFIG_KEYS.iter().find(|(name, _)| *name == key)
+        && std::env::var(var).is_ok()
+    {
+        print_warning(&t!("config-env-override", var = *var));
+    }
+
+    print_json(&json!({ "profile": profile_name, "key": key, "path": path.display().to_string() }));
+
+    Ok(())
+}
+
+pub fn show() -> anyhow::Result<()> {
+    print_header(&t!("config-show-header"));
+
+    let path = ConfigFile::default_path();
+    let file = ConfigFile::read(&path)?;
+    let profile_name = file.as_ref().map(|f| f.active_profile_name(None));
+    let profile = file
+        .as_ref()
+        .zip(profile_name.as_ref())
+        .and_then(|(file, name)| file.profiles.get(name));
+    let config = ZcashConfig::load(None)?;
+
+    print_info(
+        &t!("label-config-file"),
+        &match &file {
+            Some(_) => path.display().to_string(),
+            None => t!("config-not-created", path = path.display().to_string()),
+        },
+    );
+    if let Some(name) = &profile_name {
+        print_info(&t!("label-profile"), name);
+    }
+    print_line("");
+
+    let mut settings = Vec::new();
+    for (key, value) in config.settings() {
+        let var = CONFIG_KEYS
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, var)| *var);
+        let source = if var.is_some_and(|var| std::env::var(var).is_ok()) {
+            "env"
+        } else if profile.is_some_and(|p| p.get(key).ok().flatten().is_some()) {
+            "file"
+        } else {
+            "default"
+        };
+        print_info(
+            key,
+            &t!(
+                "config-value-source",
+                value = value.as_str(),
+                source = source
+            ),
+        );
+        settings.push(json!({ "key": key, "value": value, "source": source }));
+    }
+
+    print_json(&json!({
+        "path": path.display().to_string(),
+        "profile": profile_name,
+        "settings": settings,
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Confirm command implementation.
+//!
+//! Follow-up to `stamp --no-wait`: polls the blockchain until the recorded
+//! transaction is mined, then writes the attestation into the proof. Exits
+//! with [`exit::PENDING`] if the transaction is still pending when the
+//! timeout expires.
+
+use crate::exit;
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tracing::info;
+use zots_core::TimestampProof;
+use zots_zcash::{ConfirmationWaitPolicy, ZcashConfig, ZotsWallet};
+
+use super::upgrade::attach_confirmation;
+
+pub async fn run(target: String, timeout_secs: u64) -> anyhow::Result<()> {
+    print_header(&t!("confirm-header"));
+
+    // The target is either a pending proof file or a bare transaction ID
+    let proof_path = PathBuf::from(&target);
+    let (mut proof, txid) = if proof_path.is_file() {
+        let proof = TimestampProof::load(&proof_path)?;
+        print_info(&t!("label-proof"), &proof_path.display().to_string());
+        if proof.is_confirmed() {
+            print_success(&t!("confirm-already-confirmed"));
+            print_json(&json!({
+                "status": "confirmed",
+                "proof_path": proof_path.display().to_string(),
+                "attestation": proof.attestations.first().map(attestation_json),
+            }));
+            return Ok(());
+        }
+        let txid = proof.pending_txid.clone().ok_or_else(|| {
+            anyhow::anyhow!("Proof does not record a transaction ID; use `zots upgrade --txid`")
+        })?;
+        (Some(proof), txid)
+    } else if target.len() == 64 && target.chars().all(|c| c.is_ascii_hexdigit()) {
+        (None, target)
+    } else {
+        return Err(anyhow::anyhow!(
+            "{target} is neither a proof file nor a transaction ID"
+        ));
+    };
+    print_info(&t!("label-txid"), &txid);
+
+    let config = ZcashConfig::load(None)?;
+    let network = config.network;
+    let registry = config.pending_store_path();
+    let mut wallet = ZotsWallet::new(config).await?;
+
+    let timeout = Duration::from_secs(timeout_secs);
+    let poll_interval = ConfirmationWaitPolicy::default().poll_interval;
+    let deadline = Instant::now() + timeout;
+    info!(
+        "Polling for confirmation of {} (timeout {}s)",
+        txid, timeout_secs
+    );
+
+    let pb = spinner(&t!("confirm-waiting"));
+    let confirmation = loop {
+        if let Some(confirmation) = wallet.fetch_confirmation(&txid).await? {
+            break confirmation;
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            pb.abandon_with_message(t!("confirm-still-pending"));
+            print_warning(&t!("confirm-timed-out", secs = timeout_secs));
+            print_json(&json!({
+                "status": "pending",
+                "txid": txid,
+                "timeout_secs": timeout_secs,
+            }));
+            return Err(exit::Status(exit::PENDING).into());
+        }
+        tokio::time::sleep(poll_interval.min(remaining)).await;
+    };
+    pb.finish_with_message(t!("info-confirmed"));
+    print_success(&t!(
+        "confirmed-in-block",
+        height = confirmation.block_height
+    ));
+
+    let mut result = json!({
+        "status": "confirmed",
+        "txid": txid,
+        "block_height": confirmation.block_height,
+        "block_time": confirmation.block_time,
+    });
+    if let Some(proof) = proof.as_mut() {
+        let attestation =
+            attach_confirmation(proof, &proof_path, &registry, network, &txid, &confirmation)?;
+        print_success(&t!("upgrade-done", path = proof_path.display().to_string()));
+        result["proof_path"] = json!(proof_path.display().to_string());
+        result["attestation"] = attestation_json(&attestation);
+    }
+    print_json(&result);
+
+    Ok(())
+}
@@ -2,13 +2,37 @@
 //!
 //! Converts a compact proof string (zots1...) back to human-readable JSON.
 //! Useful for inspecting embedded proofs or converting to .zots files.
+//!
+//! With `--qr <image>` the compact string is read from QR codes in a PNG or
+//! JPEG image (e.g. a photo of `zots stamp --qr` output). When no single code
+//! holds a whole proof, the codes are joined in reading order, top to bottom
+//! and left to right, so a proof split across several codes also decodes.
 
+use crate::i18n::t;
 use crate::output::*;
-use std::path::PathBuf;
+use serde_json::json;
+use std::path::{Path, PathBuf};
 use zots_core::TimestampProof;
 
-pub fn run(compact: String, output: Option<PathBuf>) -> anyhow::Result<()> {
-    print_header("Decoding Proof");
+pub fn run(
+    compact: Option<String>,
+    qr: Option<PathBuf>,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    print_header(&t!("decode-header"));
+
+    let compact = match (compact, qr) {
+        (Some(compact), _) => compact,
+        (None, Some(image)) => {
+            print_info(&t!("label-image"), &image.display().to_string());
+            decode_qr_image(&image)?
+        }
+        (None, None) => {
+            return Err(anyhow::anyhow!(
+                "Either a compact proof or --qr <image> is required"
+            ));
+        }
+    };
 
     // Decode from compact format
     let proof = TimestampProof::from_compact(&compact)?;
@@ -16,30 +40,84 @@ pub fn run(compact: String, output: Option<PathBuf>) -> anyhow::Result<()> {
     // Serialize to JSON
     let json = proof.serialize()?;
 
+    let output_path_display = output.as_ref().map(|p| p.display().to_string());
     if let Some(output_path) = output {
         // Save to file
         proof.save(&output_path)?;
-        print_success(&format!("Proof saved: {}", output_path.display()));
+        print_success(&t!(
+            "stamp-proof-saved",
+            path = output_path.display().to_string()
+        ));
     } else {
-        // Print to stdout
-        println!();
-        println!("{json}");
+        // Print to stdout (the JSON result already contains the proof)
+        if !json_output() {
+            print_line("");
+            print_line(&json);
+        }
     }
 
-    println!();
-    print_info("Hash", &proof.hash);
-    print_info("Algorithm", proof.hash_algorithm().name());
-    print_info("Attestations", &proof.attestations.len().to_string());
+    print_line("");
+    print_info(&t!("label-hash"), &proof.hash);
+    print_info(&t!("label-algorithm"), proof.hash_algorithm().name());
+    print_info(
+        &t!("label-attestations"),
+        &proof.attestations.len().to_string(),
+    );
 
     if !proof.attestations.is_empty() {
         let att = &proof.attestations[0];
-        print_info("Network", &att.network.to_string());
-        print_info("Block", &att.block_height.to_string());
-        print_info("Time", &att.timestamp().to_rfc3339());
-        print_info("TXID", att.txid_hex());
+        print_info(&t!("label-network"), &att.network.to_string());
+        print_info(&t!("label-block"), &att.block_height.to_string());
+        print_info(&t!("label-time"), &att.timestamp().to_rfc3339());
+        print_info(&t!("label-txid"), att.txid_hex());
     } else {
-        print_warning("Pending proof (no attestations)");
+        print_warning(&t!("decode-pending"));
     }
 
+    print_json(&json!({
+        "proof": serde_json::from_str::<serde_json::Value>(&json)?,
+        "output": output_path_display,
+    }));
+
     Ok(())
 }
+
+/// Read a compact proof from the QR codes in an image
+fn decode_qr_image(path: &Path) -> anyhow::Result<String> {
+    let image = image::open(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read image {}: {e}", path.display()))?
+        .to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare(image);
+
+    // Decoded contents keyed by the top-left corner of each code
+    let mut codes: Vec<((i32, i32), String)> = prepared
+        .detect_grids()
+        .into_iter()
+        .filter_map(|grid| {
+            let corner = grid.bounds[0];
+            grid.decode()
+                .ok()
+                .map(|(_, content)| ((corner.y, corner.x), content))
+        })
+        .collect();
+    if codes.is_empty() {
+        return Err(anyhow::anyhow!("No QR code found in {}", path.display()));
+    }
+
+    if let Some((_, compact)) = codes
+        .iter()
+        .find(|(_, content)| TimestampProof::from_compact(content).is_ok())
+    {
+        return Ok(compact.trim().to_string());
+    }
+
+    codes.sort_by_key(|(corner, _)| *corner);
+    let joined: String = codes.iter().map(|(_, content)| content.trim()).collect();
+    if TimestampProof::from_compact(&joined).is_ok() {
+        return Ok(joined);
+    }
+    Err(anyhow::anyhow!(
+        "QR codes in {} do not contain a zots proof",
+        path.display()
+    ))
+}
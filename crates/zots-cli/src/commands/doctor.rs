@@ -0,0 +1,391 @@
+//! Doctor command implementation.
+//!
+//! Runs a series of environment checks and prints a fix for each problem:
+//!
+//! 1. Configuration loads (config file, profile, environment)
+//! 2. TLS is used for lightwalletd
+//! 3. lightwalletd (and fallbacks) reachable, on the configured network
+//! 4. Wallet birthday is sane for the network and chain tip
+//! 5. Wallet database passes SQLite's integrity check
+//! 6. Enough free disk space in the data directory
+//! 7. The configured Sapling prover loads
+//!
+//! Exits non-zero when any check fails; warnings alone do not fail.
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::{Value, json};
+use std::path::Path;
+use std::time::Duration;
+use zots_core::Network;
+use zots_zcash::{
+    ConfigFile, LightwalletdInfo, MAINNET_SAPLING_ACTIVATION_HEIGHT, Prover, ProverBackend,
+    TESTNET_SAPLING_ACTIVATION_HEIGHT, ZcashConfig, check_wallet_db, probe_lightwalletd,
+};
+
+/// Give up on an unresponsive lightwalletd after this long
+const PROBE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Warn below this much free space in the data directory (wallet DB and block cache)
+const DISK_WARN_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Fail below this much free space
+const DISK_FAIL_BYTES: u64 = 100 * 1024 * 1024;
+
+/// A birthday this far below the tip makes the first sync slow
+const SLOW_SYNC_BLOCKS: u64 = 500_000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    Warn,
+    Fail,
+}
+
+/// Collected check results
+#[derive(Default)]
+struct Report {
+    checks: Vec<Value>,
+    failures: usize,
+    warnings: usize,
+}
+
+impl Report {
+    fn record(&mut self, name: &str, status: Status, detail: &str, fix: Option<&str>) {
+        match status {
+            Status::Ok => print_success(&format!("{name}: {detail}")),
+            Status::Warn => {
+                self.warnings += 1;
+                print_warning(&format!("{name}: {detail}"));
+            }
+            Status::Fail => {
+                self.failures += 1;
+                print_error(&format!("{name}: {detail}"));
+            }
+        }
+        if let Some(fix) = fix {
+            print_info(&format!("  {}", t!("label-fix")), fix);
+        }
+        self.checks.push(json!({
+            "check": name,
+            "status": match status {
+                Status::Ok => "ok",
+                Status::Warn => "warn",
+                Status::Fail => "fail",
+            },
+            "detail": detail,
+            "fix": fix,
+        }));
+    }
+
+    fn ok(&mut self, name: &str, detail: &str) {
+        self.record(name, Status::Ok, detail, None);
+    }
+
+    fn warn(&mut self, name: &str, detail: &str, fix: &str) {
+        self.record(name, Status::Warn, detail, Some(fix));
+    }
+
+    fn fail(&mut self, name: &str, detail: &str, fix: &str) {
+        self.record(name, Status::Fail, detail, Some(fix));
+    }
+}
+
+pub async fn run() -> anyhow::Result<()> {
+    print_header(&t!("doctor-header"));
+    let mut report = Report::default();
+
+    // 1. Configuration
+    let config = check_config(&mut report);
+
+    if let Some(config) = &config {
+        // 2-3. TLS and lightwalletd
+        check_tls(&mut report, &config.lightwalletd_url);
+        let info = check_lightwalletd(&mut report, config).await;
+
+        // 4. Birthday
+        check_birthday(&mut report, config, info.as_ref().map(|i| i.block_height));
+
+        // 5. Wallet database
+        check_wallet(&mut report, &config.wallet_db_path());
+
+        // 6. Disk space
+        check_disk(&mut report, &config.data_dir);
+
+        // 7. Prover
+        check_prover(&mut report, &config.prover).await;
+    }
+
+    print_line("");
+    let summary = t!(
+        "doctor-summary",
+        checks = report.checks.len(),
+        failures = report.failures,
+        warnings = report.warnings
+    );
+    if report.failures == 0 {
+        print_success(&summary);
+    } else {
+        print_error(&summary);
+    }
+
+    print_json(&json!({
+        "healthy": report.failures == 0,
+        "failures": report.failures,
+        "warnings": report.warnings,
+        "checks": report.checks,
+    }));
+
+    if report.failures > 0 {
+        return Err(anyhow::anyhow!("{} check(s) failed", report.failures));
+    }
+    Ok(())
+}
+
+fn check_config(report: &mut Report) -> Option<ZcashConfig> {
+    let path = ConfigFile::default_path();
+    if let Err(e) = ConfigFile::read(&path) {
+        report.fail(
+            "Config file",
+            &format!("{}: {e:#}", path.display()),
+            "Fix the TOML syntax, or move the file aside and run `zots init`",
+        );
+        return None;
+    }
+
+    match ZcashConfig::load(None) {
+        Ok(config) => {
+            report.ok(
+                "Config",
+                &format!(
+                    "{} via {}, data in {}",
+                    config.network,
+                    config.lightwalletd_url,
+                    config.data_dir.display()
+                ),
+            );
+            Some(config)
+        }
+        Err(e) => {
+            report.fail(
+                "Config",
+                &format!("{e:#}"),
+                "Run `zots init`, or set ZOTS_SEED (see `zots config show`)",
+            );
+            None
+        }
+    }
+}
+
+fn check_tls(report: &mut Report, url: &str) {
+    if url.starts_with("https://") {
+        report.ok(
+            "TLS",
+            "lightwalletd connection uses TLS (system root certificates)",
+        );
+    } else {
+        report.warn(
+            "TLS",
+            &format!("{url} is not encrypted"),
+            "Use an https:// lightwalletd URL (ZOTS_LIGHTWALLETD or `zots config set lightwalletd`)",
+        );
+    }
+}
+
+async fn check_lightwalletd(report: &mut Report, config: &ZcashConfig) -> Option<LightwalletdInfo> {
+    let pb = spinner(&t!(
+        "init-connecting",
+        url = config.lightwalletd_url.as_str()
+    ));
+    let probe = probe(&config.lightwalletd_url).await;
+    pb.finish_and_clear();
+
+    let info = match probe {
+        Ok(info) if info.network() != Some(config.network) => {
+            report.fail(
+                "lightwalletd",
+                &format!(
+                    "{} follows the '{}' chain, not {}",
+                    config.lightwalletd_url, info.chain_name, config.network
+                ),
+                &format!("Point ZOTS_LIGHTWALLETD at a {} server", config.network),
+            );
+            Some(info)
+        }
+        Ok(info) => {
+            report.ok(
+                "lightwalletd",
+                &format!(
+                    "{} ({}), chain tip {}",
+                    config.lightwalletd_url, info.version, info.block_height
+                ),
+            );
+            Some(info)
+        }
+        Err(e) => {
+            let message = format!("{e:#}");
+            let fix = if is_tls_error(&message) {
+                "The TLS handshake failed: install your system CA certificates (e.g. the `ca-certificates` package) or check for an intercepting proxy"
+            } else {
+                "Check the URL and your network connection, or switch servers with ZOTS_LIGHTWALLETD"
+            };
+            report.fail(
+                "lightwalletd",
+                &format!("{} unreachable: {message}", config.lightwalletd_url),
+                fix,
+            );
+            None
+        }
+    };
+
+    for url in &config.lightwalletd_fallbacks {
+        match probe(url).await {
+            Ok(fallback) => report.ok(
+                "Fallback",
+                &format!(
+                    "{url} ({}), chain tip {}",
+                    fallback.version, fallback.block_height
+                ),
+            ),
+            Err(e) => report.warn(
+                "Fallback",
+                &format!("{url} unreachable: {e:#}"),
+                "Remove the fallback server or fix its URL",
+            ),
+        }
+    }
+
+    info
+}
+
+async fn probe(url: &str) -> anyhow::Result<LightwalletdInfo> {
+    tokio::time::timeout(PROBE_TIMEOUT, probe_lightwalletd(url))
+        .await
+        .map_err(|_| anyhow::anyhow!("timed out after {}s", PROBE_TIMEOUT.as_secs()))?
+}
+
+fn is_tls_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["certificate", "tls", "handshake", "unknownissuer"]
+        .iter()
+        .any(|needle| message.contains(needle))
+}
+
+fn check_birthday(report: &mut Report, config: &ZcashConfig, tip: Option<u64>) {
+    let birthday = config.birthday_height;
+    let activation = match config.network {
+        Network::Mainnet => MAINNET_SAPLING_ACTIVATION_HEIGHT,
+        Network::Testnet => TESTNET_SAPLING_ACTIVATION_HEIGHT,
+    };
+
+    if birthday < activation {
+        report.warn(
+            "Birthday",
+            &format!("{birthday} is below Sapling activation ({activation})"),
+            &format!("Set ZOTS_BIRTHDAY_HEIGHT to at least {activation}"),
+        );
+        return;
+    }
+    match tip {
+        Some(tip) if birthday > tip => report.fail(
+            "Birthday",
+            &format!("{birthday} is above the chain tip ({tip}); nothing would be scanned"),
+            "Set ZOTS_BIRTHDAY_HEIGHT to the height at which the seed was created",
+        ),
+        Some(tip) if tip - birthday > SLOW_SYNC_BLOCKS => report.warn(
+            "Birthday",
+            &format!(
+                "{birthday} is {} blocks behind the tip; the first sync will be slow",
+                tip - birthday
+            ),
+            "If the seed is newer, raise ZOTS_BIRTHDAY_HEIGHT (only affects new wallet databases)",
+        ),
+        Some(tip) => report.ok("Birthday", &format!("{birthday} (tip {tip})")),
+        None => report.ok("Birthday", &format!("{birthday} (chain tip unknown)")),
+    }
+}
+
+fn check_wallet(report: &mut Report, db_path: &Path) {
+    if !db_path.exists() {
+        report.warn(
+            "Wallet DB",
+            &format!("{} does not exist yet", db_path.display()),
+            "Run `zots wallet sync` to create it",
+        );
+        return;
+    }
+    match check_wallet_db(db_path) {
+        Ok(()) => report.ok("Wallet DB", &format!("{} is intact", db_path.display())),
+        Err(e) => report.fail(
+            "Wallet DB",
+            &format!("{}: {e:#}", db_path.display()),
+            "Move the database aside and run `zots wallet sync` to rebuild it from the seed",
+        ),
+    }
+}
+
+fn check_disk(report: &mut Report, data_dir: &Path) {
+    // The data directory may not exist yet; measure the filesystem it will live on
+    let Some(existing) = data_dir.ancestors().find(|dir| dir.exists()) else {
+        report.warn(
+            "Disk space",
+            &format!("No existing parent for {}", data_dir.display()),
+            "Check ZOTS_DATA_DIR",
+        );
+        return;
+    };
+    let available = match fs2::available_space(existing) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            report.warn(
+                "Disk space",
+                &format!("Could not measure {}: {e}", existing.display()),
+                "Check that the data directory is readable",
+            );
+            return;
+        }
+    };
+
+    let detail = format!(
+        "{} MiB free in {}",
+        available / (1024 * 1024),
+        existing.display()
+    );
+    if available < DISK_FAIL_BYTES {
+        report.fail(
+            "Disk space",
+            &detail,
+            "Free up space or point ZOTS_DATA_DIR at a larger disk",
+        );
+    } else if available < DISK_WARN_BYTES {
+        report.warn(
+            "Disk space",
+            &detail,
+            "Sync needs room for the wallet database and block cache; free up space",
+        );
+    } else {
+        report.ok("Disk space", &detail);
+    }
+}
+
+async fn check_prover(report: &mut Report, backend: &ProverBackend) {
+    let pb = spinner(&t!("doctor-loading-prover"));
+    let loaded = Prover::load(backend).await;
+    pb.finish_and_clear();
+
+    match loaded {
+        Ok(_) => report.ok("Prover", &format!("{backend} prover ready")),
+        Err(e) => {
+            let fix = match backend {
+                ProverBackend::Local => {
+                    "Download the Sapling parameters (e.g. `zcash-fetch-params`) or set ZOTS_PROVER=bundled"
+                }
+                ProverBackend::Remote(_) => {
+                    "Check that the prover service is running and reachable, or set ZOTS_PROVER=bundled"
+                }
+                ProverBackend::Bundled => "Reinstall zots; the bundled parameters failed to load",
+            };
+            report.fail("Prover", &format!("{backend}: {e:#}"), fix);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Embed and extract command implementations.
+//!
+//! `embed` writes a copy of a stamped PNG, JPEG or PDF with its compact
+//! proof in the file's metadata (a PNG `tEXt` chunk, or XMP for JPEG and
+//! PDF); the original is left untouched. `extract` recovers the proof from
+//! such a copy, checks it against the reconstructed original bytes, and can
+//! hand it to the on-chain verifier. A proof that does not match exits with
+//! [`exit::HASH_MISMATCH`].
+
+use crate::exit;
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tracing::info;
+use zots_core::{TimestampProof, embed_proof, extract_proof, hash_bytes_with, hash_to_hex};
+
+pub fn embed(file: PathBuf, proof: Option<PathBuf>, output: Option<PathBuf>) -> anyhow::Result<()> {
+    print_header(&t!("embed-header"));
+
+    let proof_path = proof.unwrap_or_else(|| append_extension(&file, "zots"));
+    let proof = TimestampProof::load(&proof_path)?;
+    let data = std::fs::read(&file)?;
+
+    let hash = hash_bytes_with(&data, proof.hash_algorithm());
+    if hash != proof.hash_bytes()? {
+        return Err(anyhow::anyhow!(
+            "{} is not the proof of {} (hash {} != {})",
+            proof_path.display(),
+            file.display(),
+            hash_to_hex(&hash),
+            proof.hash
+        ));
+    }
+    if !proof.is_confirmed() {
+        print_warning(&t!("embed-pending"));
+    }
+
+    info!("Embedding {} into {}", proof_path.display(), file.display());
+    let stamped = embed_proof(&data, &proof)?;
+    let output = output.unwrap_or_else(|| stamped_path(&file));
+    std::fs::write(&output, &stamped)?;
+
+    print_info(&t!("label-file"), &file.display().to_string());
+    print_info(&t!("label-proof"), &proof_path.display().to_string());
+    print_success(&t!("embed-done", path = output.display().to_string()));
+
+    print_json(&json!({
+        "file": file.display().to_string(),
+        "proof_path": proof_path.display().to_string(),
+        "output": output.display().to_string(),
+        "hash": proof.hash,
+    }));
+
+    Ok(())
+}
+
+pub async fn extract(
+    file: PathBuf,
+    output: Option<PathBuf>,
+    original: Option<PathBuf>,
+    verify: bool,
+) -> anyhow::Result<()> {
+    print_header(&t!("extract-header"));
+
+    let data = std::fs::read(&file)?;
+    let embedded = extract_proof(&data)?
+        .ok_or_else(|| anyhow::anyhow!("{} carries no embedded proof", file.display()))?;
+    let proof = embedded.proof;
+    print_info(&t!("label-file"), &file.display().to_string());
+    print_info(&t!("label-format"), embedded.format.name());
+    print_hash(&proof.hash, proof.hash_algorithm().name());
+
+    let matches =
+        hash_bytes_with(&embedded.original, proof.hash_algorithm()) == proof.hash_bytes()?;
+    if matches {
+        print_success(&t!("extract-match"));
+    } else {
+        print_error(&t!("extract-mismatch"));
+    }
+
+    let output = output.unwrap_or_else(|| append_extension(&file, "zots"));
+    proof.save(&output)?;
+    print_success(&t!(
+        "stamp-proof-saved",
+        path = output.display().to_string()
+    ));
+    if let Some(original) = &original {
+        std::fs::write(original, &embedded.original)?;
+        print_success(&t!(
+            "extract-original-written",
+            path = original.display().to_string()
+        ));
+    }
+
+    if !verify || !matches {
+        print_json(&json!({
+            "file": file.display().to_string(),
+            "format": embedded.format.name(),
+            "hash": proof.hash,
+            "hash_matches": matches,
+            "proof_path": output.display().to_string(),
+            "original": original.map(|p| p.display().to_string()),
+        }));
+        if !matches {
+            return Err(exit::Status(exit::HASH_MISMATCH).into());
+        }
+        return Ok(());
+    }
+
+    print_line("");
+    super::verify::run(output, None, None, None, false, None).await
+}
+
+/// `path` with `.ext` appended to the full file name
+fn append_extension(path: &Path, ext: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{ext}"));
+    PathBuf::from(name)
+}
+
+/// Default copy name: `report.pdf` -> `report.stamped.pdf`
+fn stamped_path(file: &Path) -> PathBuf {
+    let stem = file.file_stem().unwrap_or_default().to_string_lossy();
+    match file.extension() {
+        Some(ext) => file.with_file_name(format!("{stem}.stamped.{}", ext.to_string_lossy())),
+        None => file.with_file_name(format!("{stem}.stamped")),
+    }
+}
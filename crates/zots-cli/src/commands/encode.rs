@@ -8,49 +8,65 @@
 //! - A JSON string
 //! - An existing compact string (for validation)
 
+use crate::i18n::t;
 use crate::output::*;
+use serde_json::json;
 use std::path::PathBuf;
 use zots_core::TimestampProof;
 
 pub fn run(input: String, show_qr: bool) -> anyhow::Result<()> {
-    print_header("Encoding Proof");
+    print_header(&t!("encode-header"));
 
     // Check if input is a file path or already compact format
     let path = PathBuf::from(&input);
     let proof = if path.exists() {
-        print_info("Input", &input);
+        print_info(&t!("label-input"), &input);
         TimestampProof::load(&path)?
     } else if TimestampProof::is_compact_format(&input) {
         // Already compact, just validate and re-encode
-        print_info("Input", "compact string");
+        print_info(&t!("label-input"), &t!("export-compact-string"));
         TimestampProof::from_compact(&input)?
     } else {
         // Try to parse as JSON
-        print_info("Input", "JSON string");
+        print_info(&t!("label-input"), &t!("encode-json-string"));
         TimestampProof::deserialize(&input)?
     };
 
     // Encode to compact format
     let compact = proof.to_compact()?;
 
-    println!();
-    print_header("Compact Format");
-    println!("{compact}");
-    println!();
-    print_info("Length", &format!("{} chars", compact.len()));
+    print_line("");
+    print_header(&t!("encode-compact-header"));
+    print_line(&compact);
+    print_line("");
+    print_info(
+        &t!("label-length"),
+        &t!("length-chars", count = compact.len()),
+    );
     if show_qr {
-        print_qr("QR Code", &compact)?;
+        print_qr(&t!("label-qr-code"), &compact)?;
     }
 
     // Show what's embedded
-    println!();
-    print_info("Hash", &proof.hash);
-    print_info("Algorithm", proof.hash_algorithm().name());
-    print_info("Attestations", &proof.attestations.len().to_string());
+    print_line("");
+    print_info(&t!("label-hash"), &proof.hash);
+    print_info(&t!("label-algorithm"), proof.hash_algorithm().name());
+    print_info(
+        &t!("label-attestations"),
+        &proof.attestations.len().to_string(),
+    );
     if let Some(att) = proof.attestations.first() {
-        print_info("Network", &att.network.to_string());
-        print_info("Block", &att.block_height.to_string());
+        print_info(&t!("label-network"), &att.network.to_string());
+        print_info(&t!("label-block"), &att.block_height.to_string());
     }
 
+    print_json(&json!({
+        "compact": compact,
+        "length": compact.len(),
+        "hash": proof.hash,
+        "hash_algorithm": proof.hash_algorithm().name(),
+        "attestations": proof.attestations.len(),
+    }));
+
     Ok(())
 }
@@ -0,0 +1,162 @@
+//! Export and import command implementations.
+//!
+//! Converts proofs to formats understood by other notarization tooling and
+//! back:
+//!
+//! - `ots`: OpenTimestamps detached timestamp (SHA-256 proofs only; Zcash
+//!   attestations use a custom attestation tag, see [`zots_core::ots`])
+//! - `cbor`: the raw CBOR payload of the compact format
+//! - `json`: the detached JSON proof document, as written to `.zots` files
+//!
+//! `import` detects the format from the file contents and writes a `.zots`
+//! proof. Attestations an OpenTimestamps file carries for other chains or
+//! calendars are skipped and reported.
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use std::path::PathBuf;
+use tracing::info;
+use zots_core::{TimestampProof, from_ots, is_ots, to_ots};
+
+/// Target format of `zots export`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Ots,
+    Cbor,
+    Json,
+}
+
+impl ExportFormat {
+    fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Ots => "ots",
+            ExportFormat::Cbor => "cbor",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+pub fn export(input: String, format: ExportFormat, output: Option<PathBuf>) -> anyhow::Result<()> {
+    print_header(&t!("export-header"));
+
+    let path = PathBuf::from(&input);
+    let (proof, default_output) = if path.exists() {
+        print_info(&t!("label-input"), &input);
+        (
+            TimestampProof::load(&path)?,
+            path.with_extension(format.name()),
+        )
+    } else if TimestampProof::is_compact_format(&input) {
+        print_info(&t!("label-input"), &t!("export-compact-string"));
+        let proof = TimestampProof::from_compact(&input)?;
+        let name = format!("{}.{}", &proof.hash[..16], format.name());
+        (proof, PathBuf::from(name))
+    } else {
+        return Err(anyhow::anyhow!(
+            "{input} is neither a proof file nor a compact proof"
+        ));
+    };
+
+    let data = match format {
+        ExportFormat::Ots => to_ots(&proof)?,
+        ExportFormat::Cbor => proof.to_cbor()?,
+        ExportFormat::Json => proof.serialize()?.into_bytes(),
+    };
+    let output = output.unwrap_or(default_output);
+    info!("Writing {} export to {}", format.name(), output.display());
+    std::fs::write(&output, &data)?;
+
+    print_hash(&proof.hash, proof.hash_algorithm().name());
+    print_info(&t!("label-format"), format.name());
+    print_info(
+        &t!("label-attestations"),
+        &proof.attestations.len().to_string(),
+    );
+    print_info(&t!("label-size"), &t!("size-bytes", bytes = data.len()));
+    if !proof.is_confirmed() {
+        print_warning(&t!("export-pending"));
+    }
+    print_success(&t!("export-done", path = output.display().to_string()));
+
+    print_json(&json!({
+        "format": format.name(),
+        "output": output.display().to_string(),
+        "size": data.len(),
+        "hash": proof.hash,
+        "hash_algorithm": proof.hash_algorithm().name(),
+        "attestations": proof.attestations.len(),
+    }));
+
+    Ok(())
+}
+
+pub fn import(input: PathBuf, output: Option<PathBuf>) -> anyhow::Result<()> {
+    print_header(&t!("import-header"));
+
+    let data = std::fs::read(&input)?;
+    let (proof, format, skipped) = decode_any(&data)?;
+
+    let output = output.unwrap_or_else(|| input.with_extension("zots"));
+    if output == input {
+        return Err(anyhow::anyhow!(
+            "{} is already a .zots file; pass --output to write a copy",
+            input.display()
+        ));
+    }
+    proof.save(&output)?;
+
+    print_info(&t!("label-input"), &input.display().to_string());
+    print_info(&t!("label-format"), format);
+    print_hash(&proof.hash, proof.hash_algorithm().name());
+    print_info(
+        &t!("label-attestations"),
+        &proof.attestations.len().to_string(),
+    );
+    if skipped > 0 {
+        print_warning(&t!("import-skipped", count = skipped));
+    }
+    if !proof.is_confirmed() {
+        print_warning(&t!("import-pending"));
+    }
+    print_success(&t!(
+        "stamp-proof-saved",
+        path = output.display().to_string()
+    ));
+
+    print_json(&json!({
+        "format": format,
+        "input": input.display().to_string(),
+        "output": output.display().to_string(),
+        "hash": proof.hash,
+        "hash_algorithm": proof.hash_algorithm().name(),
+        "attestations": proof.attestations.len(),
+        "skipped_attestations": skipped,
+        "pending_txid": proof.pending_txid,
+    }));
+
+    Ok(())
+}
+
+/// Decode an OpenTimestamps, JSON, compact or raw CBOR proof
+///
+/// Returns the proof, the detected format and the number of skipped
+/// foreign attestations.
+fn decode_any(data: &[u8]) -> anyhow::Result<(TimestampProof, &'static str, usize)> {
+    if is_ots(data) {
+        let import = from_ots(data)?;
+        return Ok((import.proof, "ots", import.skipped_attestations));
+    }
+    if let Ok(text) = std::str::from_utf8(data) {
+        let text = text.trim();
+        if text.starts_with('{') {
+            return Ok((TimestampProof::deserialize(text)?, "json", 0));
+        }
+        if TimestampProof::is_compact_format(text) {
+            return Ok((TimestampProof::from_compact(text)?, "compact", 0));
+        }
+    }
+    TimestampProof::from_cbor(data)
+        .map(|proof| (proof, "cbor", 0))
+        .map_err(|e| anyhow::anyhow!("Unrecognized proof format: {e}"))
+}
@@ -0,0 +1,98 @@
+//! Git integration for timestamping commits and tags.
+//!
+//! The stamped value is the git object id itself (40 hex chars for SHA-1
+//! repositories, re-hashed like `stamp --hash`; 64 for SHA-256 ones), so a
+//! proof covers the commit's tree, parents and message. Proofs live in
+//! `.git/zots/<object id>.zots`, outside the working tree.
+//!
+//! ## Commands
+//!
+//! - `stamp`: Timestamp HEAD or any commit/tag
+//! - `verify`: Check that a stored proof matches the object and is on-chain
+
+use crate::i18n::t;
+use crate::output::*;
+use std::path::PathBuf;
+use std::process::Command;
+use zots_core::{HashAlgorithm, TimestampProof, hash_from_hex_with};
+
+use super::stamp::StampOptions;
+
+pub async fn stamp(
+    rev: String,
+    hash_algorithm: Option<HashAlgorithm>,
+    no_wait: bool,
+) -> anyhow::Result<()> {
+    let oid = resolve_object(&rev)?;
+    let output = proof_path(&oid)?;
+    if let Some(dir) = output.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    print_info(&t!("label-object"), &format!("{rev} ({oid})"));
+
+    super::stamp::run(
+        Vec::new(),
+        Some(oid),
+        false,
+        None,
+        StampOptions {
+            output: Some(output),
+            hash_algorithm,
+            no_wait,
+            ..StampOptions::default()
+        },
+    )
+    .await
+}
+
+pub async fn verify(rev: String, require_confirmations: Option<u64>) -> anyhow::Result<()> {
+    let oid = resolve_object(&rev)?;
+    let path = proof_path(&oid)?;
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "No proof for {rev} ({oid}); create one with `zots git stamp {rev}`"
+        ));
+    }
+
+    // The proof must commit to this object, not just be valid on-chain
+    let proof = TimestampProof::load(&path)?;
+    let expected = hash_from_hex_with(&oid, proof.hash_algorithm())?;
+    if proof.hash_bytes()? != expected {
+        return Err(anyhow::anyhow!(
+            "{} does not belong to {rev} ({oid})",
+            path.display()
+        ));
+    }
+    print_info(&t!("label-object"), &format!("{rev} ({oid})"));
+
+    super::verify::run(path, None, None, require_confirmations, false, None).await
+}
+
+/// Full object id of a revision (`HEAD`, branch, tag or hash)
+///
+/// Annotated tags resolve to the tag object, so the tag message is covered.
+fn resolve_object(rev: &str) -> anyhow::Result<String> {
+    git(&["rev-parse", "--verify", "--end-of-options", rev])
+}
+
+/// Where the proof for `oid` is stored inside the repository's git dir
+fn proof_path(oid: &str) -> anyhow::Result<PathBuf> {
+    let git_dir = PathBuf::from(git(&["rev-parse", "--absolute-git-dir"])?);
+    Ok(git_dir.join("zots").join(format!("{oid}.zots")))
+}
+
+/// Run git and return its trimmed stdout
+fn git(args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -2,50 +2,285 @@
 //!
 //! Displays detailed information about a timestamp proof file, including:
 //! - File hash
+//! - Note and file name recorded at stamp time
 //! - Attestation details (network, txid, block, time)
 //! - Compact embeddable format
+//!
+//! With `--online`, each attestation's transaction is also looked up on
+//! lightwalletd to report whether it is still in the main chain at the
+//! recorded height and how many confirmations it has. The memo is not
+//! decrypted; use `verify` for that. Failing to reach lightwalletd aborts
+//! the command (exit code `network`) rather than reporting transactions as
+//! not found.
 
+use crate::i18n::t;
 use crate::output::*;
+use serde_json::{Value, json};
 use std::path::PathBuf;
-use zots_core::TimestampProof;
+use tracing::debug;
+use zots_core::{FILENAME_KEY, NOTE_KEY, Network, TimestampProof, ZcashAttestation};
+use zots_zcash::{ZcashConfig, ZotsWallet, is_network_error};
 
-pub fn run(proof_path: PathBuf) -> anyhow::Result<()> {
-    print_header("Proof Information");
+pub async fn run(proof_path: PathBuf, online: bool) -> anyhow::Result<()> {
+    print_header(&t!("info-header"));
 
     let proof = TimestampProof::load(&proof_path)?;
+    let mut chain = if online {
+        Some(ChainView::connect().await?)
+    } else {
+        None
+    };
+    let mut statuses = Vec::with_capacity(proof.attestations.len());
 
-    print_info("File", &proof_path.display().to_string());
-    print_info("Version", &proof.version.to_string());
+    print_info(&t!("label-file"), &proof_path.display().to_string());
+    print_info(&t!("label-version"), &proof.version.to_string());
     print_hash(&proof.hash, proof.hash_algorithm().name());
-    print_info("Attestations", &proof.attestations.len().to_string());
     print_info(
-        "Status",
-        if proof.is_confirmed() {
-            "Confirmed"
+        &t!("label-attestations"),
+        &proof.attestations.len().to_string(),
+    );
+    print_info(
+        &t!("label-status"),
+        &if proof.is_confirmed() {
+            t!("info-confirmed")
         } else {
-            "Pending"
+            t!("info-pending")
         },
     );
+    if let Some(note) = proof.note() {
+        print_info(&t!("label-note"), note);
+    }
+    if let Some(filename) = proof.filename() {
+        print_info(&t!("label-filename"), filename);
+    }
+    for (key, value) in &proof.metadata {
+        if key != NOTE_KEY && key != FILENAME_KEY {
+            print_info(key, value);
+        }
+    }
 
     if !proof.attestations.is_empty() {
         for (i, att) in proof.attestations.iter().enumerate() {
-            println!();
-            println!("  {} Attestation #{}", "─".repeat(3), i + 1);
-            print_info("  Network", &att.network.to_string());
-            print_info("  TXID", att.txid_hex());
-            print_info("  Block", &att.block_height.to_string());
-            print_info("  Time", &att.timestamp().to_rfc3339());
-            print_link("  Explorer", &att.explorer_link());
+            print_line("");
+            print_line(&format!(
+                "  {} {}",
+                "─".repeat(3),
+                t!("info-attestation", number = i + 1)
+            ));
+            print_info(
+                &format!("  {}", t!("label-network")),
+                &att.network.to_string(),
+            );
+            print_info(&format!("  {}", t!("label-txid")), att.txid_hex());
+            print_info(
+                &format!("  {}", t!("label-block")),
+                &att.block_height.to_string(),
+            );
+            print_info(
+                &format!("  {}", t!("label-time")),
+                &att.timestamp().to_rfc3339(),
+            );
+            print_link(&format!("  {}", t!("label-explorer")), &explorer_link(att));
+            if let Some(chain) = chain.as_mut() {
+                let status = chain.check(att).await?;
+                print_info(&format!("  {}", t!("label-on-chain")), &status.describe());
+                statuses.push(status);
+            }
         }
     }
 
+    // A pending proof may have confirmed since it was written
+    let mut pending_status = None;
+    if let (Some(chain), Some(txid)) = (chain.as_mut(), &proof.pending_txid) {
+        let status = chain.check_pending(txid).await?;
+        print_line("");
+        print_info(&t!("label-pending-tx"), &status.describe());
+        if status.state == TxState::Confirmed {
+            print_info(
+                &t!("label-upgrade"),
+                &format!("zots upgrade {}", proof_path.display()),
+            );
+        }
+        pending_status = Some(status);
+    }
+
     // Show compact format for embedding
-    println!();
-    print_header("Embeddable Proof");
+    print_line("");
+    print_header(&t!("header-embeddable-proof"));
     let compact = proof.to_compact()?;
-    println!("{compact}");
-    println!();
-    print_info("Length", &format!("{} chars", compact.len()));
+    print_line(&compact);
+    print_line("");
+    print_info(
+        &t!("label-length"),
+        &t!("length-chars", count = compact.len()),
+    );
+
+    let attestations: Vec<Value> = proof
+        .attestations
+        .iter()
+        .enumerate()
+        .map(|(i, att)| {
+            let mut value = attestation_json(att);
+            if let Some(status) = statuses.get(i) {
+                value["online"] = status.to_json();
+            }
+            value
+        })
+        .collect();
+    let mut result = json!({
+        "file": proof_path.display().to_string(),
+        "version": proof.version,
+        "hash": proof.hash,
+        "hash_algorithm": proof.hash_algorithm().name(),
+        "status": if proof.is_confirmed() { "confirmed" } else { "pending" },
+        "pending_txid": proof.pending_txid,
+        "metadata": proof.metadata,
+        "attestations": attestations,
+        "compact": compact,
+    });
+    if let Some(chain) = &chain {
+        result["tip_height"] = json!(chain.tip);
+    }
+    if let Some(status) = &pending_status {
+        result["pending_online"] = status.to_json();
+    }
+    print_json(&result);
 
     Ok(())
 }
+
+/// Where lightwalletd places a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TxState {
+    /// Mined at the expected height (any height for a pending txid)
+    Confirmed,
+    /// Mined at another height than the proof records
+    Moved,
+    /// In the mempool or only on a fork
+    Unmined,
+    /// lightwalletd does not know the transaction
+    NotFound,
+    /// Attestation for another network than the wallet's; not looked up
+    OtherNetwork,
+}
+
+impl TxState {
+    /// Name used in JSON output
+    fn name(self) -> &'static str {
+        match self {
+            Self::Confirmed => "confirmed",
+            Self::Moved => "moved",
+            Self::Unmined => "unmined",
+            Self::NotFound => "not_found",
+            Self::OtherNetwork => "other_network",
+        }
+    }
+}
+
+/// Chain state of one transaction as seen by lightwalletd
+struct TxStatus {
+    state: TxState,
+    block_height: Option<u32>,
+    confirmations: Option<u64>,
+    /// lightwalletd's answer when the transaction was not found
+    error: Option<String>,
+}
+
+impl TxStatus {
+    fn new(state: TxState) -> Self {
+        Self {
+            state,
+            block_height: None,
+            confirmations: None,
+            error: None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match (self.state, self.block_height, self.confirmations) {
+            (TxState::Confirmed, Some(height), Some(depth)) => {
+                format!("present in block {height} ({depth} confirmations)")
+            }
+            (TxState::Moved, Some(height), Some(depth)) => {
+                format!("now in block {height} ({depth} confirmations) - chain reorganized")
+            }
+            (TxState::Unmined, ..) => "not in the main chain (mempool or fork)".to_string(),
+            (TxState::OtherNetwork, ..) => {
+                "skipped (wallet is configured for another network)".to_string()
+            }
+            _ => format!(
+                "not found ({})",
+                self.error.as_deref().unwrap_or("unknown transaction")
+            ),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "state": self.state.name(),
+            "block_height": self.block_height,
+            "confirmations": self.confirmations,
+            "error": self.error,
+        })
+    }
+}
+
+/// lightwalletd connection and chain tip used by `--online`
+struct ChainView {
+    wallet: ZotsWallet,
+    network: Network,
+    tip: u64,
+}
+
+impl ChainView {
+    async fn connect() -> anyhow::Result<Self> {
+        let config = ZcashConfig::load(None)?;
+        let network = config.network;
+        let mut wallet = ZotsWallet::new(config).await?;
+        let tip = wallet.get_block_height().await?;
+        print_info(&t!("label-chain-tip"), &tip.to_string());
+        Ok(Self {
+            wallet,
+            network,
+            tip,
+        })
+    }
+
+    /// Look up an attestation's transaction and compare with the recorded block
+    async fn check(&mut self, att: &ZcashAttestation) -> anyhow::Result<TxStatus> {
+        if att.network != self.network {
+            return Ok(TxStatus::new(TxState::OtherNetwork));
+        }
+        let mut status = self.check_pending(att.txid_hex()).await?;
+        if status.state == TxState::Confirmed && status.block_height != Some(att.block_height) {
+            status.state = TxState::Moved;
+        }
+        Ok(status)
+    }
+
+    /// Look up a transaction without an expected block
+    ///
+    /// Transport errors are returned; any other lookup error means
+    /// lightwalletd does not know the transaction.
+    async fn check_pending(&mut self, txid: &str) -> anyhow::Result<TxStatus> {
+        match self.wallet.fetch_confirmation(txid).await {
+            Ok(Some(confirmation)) => Ok(TxStatus {
+                state: TxState::Confirmed,
+                block_height: Some(confirmation.block_height),
+                confirmations: Some(
+                    (self.tip + 1).saturating_sub(u64::from(confirmation.block_height)),
+                ),
+                error: None,
+            }),
+            Ok(None) => Ok(TxStatus::new(TxState::Unmined)),
+            Err(e) if is_network_error(&e) => Err(e),
+            Err(e) => {
+                debug!("Lookup of {} failed: {:#}", txid, e);
+                Ok(TxStatus {
+                    error: Some(e.to_string()),
+                    ..TxStatus::new(TxState::NotFound)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Init command implementation.
+//!
+//! Interactive first-run setup. Walks through:
+//!
+//! 1. Network (testnet only for now) and profile name
+//! 2. lightwalletd server, tested with a live connection
+//! 3. Generating a new seed phrase or importing an existing one
+//! 4. Wallet birthday height (the chain tip for a new seed)
+//! 5. Where the seed is kept: the OS keychain (recommended), the config
+//!    file, or nowhere (the user exports `ZOTS_SEED` themselves)
+//!
+//! The result is written as a profile in the config file, which becomes the
+//! default profile if there is none yet (otherwise the user is asked).
+
+use crate::i18n::t;
+use crate::output::*;
+use bip0039::{Count, English, Mnemonic};
+use serde_json::json;
+use std::path::PathBuf;
+use zots_core::Network;
+use zots_zcash::{
+    ConfigFile, DEFAULT_BIRTHDAY_HEIGHT, DEFAULT_LIGHTWALLETD_URL,
+    DEFAULT_MAINNET_LIGHTWALLETD_URL, MAINNET_SAPLING_ACTIVATION_HEIGHT, ProfileConfig,
+    TESTNET_FAUCET_URL, default_data_dir, delete_seed, probe_lightwalletd, store_seed,
+};
+
+pub async fn run(profile: Option<String>, force: bool) -> anyhow::Result<()> {
+    print_header(&t!("init-header"));
+
+    let path = ConfigFile::default_path();
+    let mut file = ConfigFile::read(&path)?.unwrap_or_default();
+    print_info(&t!("label-config-file"), &path.display().to_string());
+    print_line("");
+
+    // Network and profile: the wallet is built on testnet consensus
+    // parameters, so mainnet is refused until it follows the profile
+    let network = loop {
+        match prompt_input(&t!("label-network"), Some("testnet"))?
+            .to_lowercase()
+            .as_str()
+        {
+            "testnet" | "test" => break Network::Testnet,
+            "mainnet" | "main" => print_warning(&t!("init-mainnet-unsupported")),
+            other => print_warning(&t!("init-unknown-network", network = other)),
+        }
+    };
+
+    let profile_name = match profile {
+        Some(name) => name,
+        None => prompt_input(&t!("init-profile-name"), Some(&network.to_string()))?,
+    };
+    let previous = file.profiles.get(&profile_name).cloned();
+    if previous.is_some()
+        && !force
+        && !prompt_yes_no(
+            &t!("init-replace-profile", profile = profile_name.as_str()),
+            false,
+        )?
+    {
+        return Err(anyhow::anyhow!("Setup cancelled"));
+    }
+
+    // lightwalletd
+    let default_server = match network {
+        Network::Testnet => DEFAULT_LIGHTWALLETD_URL,
+        Network::Mainnet => DEFAULT_MAINNET_LIGHTWALLETD_URL,
+    };
+    let (lightwalletd, tip_height) = loop {
+        let url = prompt_input(&t!("init-lightwalletd-server"), Some(default_server))?;
+        let pb = spinner(&t!("init-connecting", url = url.as_str()));
+        let probe = probe_lightwalletd(&url).await;
+        pb.finish_and_clear();
+        match probe {
+            Ok(info) => {
+                print_success(&t!("init-connected", url = url.as_str()));
+                print_info(&t!("label-server"), &info.version);
+                print_info(&t!("label-chain-tip"), &info.block_height.to_string());
+                if info.network() != Some(network) {
+                    print_warning(&t!(
+                        "init-chain-mismatch",
+                        chain = info.chain_name.as_str(),
+                        network = network.to_string()
+                    ));
+                    if !prompt_yes_no(&t!("init-use-anyway"), false)? {
+                        continue;
+                    }
+                }
+                break (url, Some(info.block_height));
+            }
+            Err(e) => {
+                print_error(&t!(
+                    "init-unreachable",
+                    url = url.as_str(),
+                    error = format!("{e:#}")
+                ));
+                if prompt_yes_no(&t!("init-keep-server"), false)? {
+                    break (url, None);
+                }
+            }
+        }
+    };
+    print_line("");
+
+    // Seed
+    let (seed_phrase, generated) = loop {
+        match prompt_input(&t!("init-seed-choice"), Some("generate"))?
+            .to_lowercase()
+            .as_str()
+        {
+            "generate" | "g" => break (generate_seed()?, true),
+            "import" | "i" => {
+                let phrase = prompt_secret(&t!("init-seed-phrase"))?;
+                match check_seed(&phrase) {
+                    Ok(phrase) => break (phrase, false),
+                    Err(e) => print_error(&format!("{e:#}")),
+                }
+            }
+            other => print_warning(&t!("init-unknown-choice", choice = other)),
+        }
+    };
+    print_line("");
+
+    // Birthday: a fresh seed cannot have received funds before the current tip
+    let default_birthday = match (generated, tip_height, network) {
+        (true, Some(tip), _) => tip,
+        (_, _, Network::Testnet) => DEFAULT_BIRTHDAY_HEIGHT,
+        (_, _, Network::Mainnet) => MAINNET_SAPLING_ACTIVATION_HEIGHT,
+    };
+    if !generated {
+        print_status(&t!("init-birthday-hint"));
+    }
+    let birthday_height = loop {
+        let answer = prompt_input(
+            &t!("init-birthday-height"),
+            Some(&default_birthday.to_string()),
+        )?;
+        match answer.parse::<u64>() {
+            Ok(height) => break height,
+            Err(_) => print_warning(&t!("init-not-a-height", answer = answer.as_str())),
+        }
+    };
+
+    let data_dir = match network {
+        Network::Testnet => default_data_dir(),
+        Network::Mainnet => default_data_dir().join("mainnet"),
+    };
+    let data_dir = PathBuf::from(prompt_input(
+        &t!("init-data-directory"),
+        Some(&data_dir.display().to_string()),
+    )?);
+    if data_dir.join("wallet.db").exists() {
+        print_warning(&t!(
+            "init-existing-wallet",
+            path = data_dir.display().to_string()
+        ));
+    }
+    print_line("");
+
+    // Seed storage
+    let mut profile = ProfileConfig {
+        birthday_height: Some(birthday_height),
+        lightwalletd: Some(lightwalletd.clone()),
+        network: Some(network.to_string()),
+        data_dir: Some(data_dir.clone()),
+        hash_algorithm: Some("sha256".to_string()),
+        ..ProfileConfig::default()
+    };
+    let mut seed_storage = "env";
+    if prompt_yes_no(&t!("init-store-keychain"), true)? {
+        match store_seed(&profile_name, &seed_phrase) {
+            Ok(()) => {
+                profile.keychain = Some(true);
+                seed_storage = "keychain";
+                print_success(&t!("init-stored-keychain"));
+            }
+            Err(e) => print_error(&format!("{e:#}")),
+        }
+    }
+    if seed_storage == "env" {
+        if prompt_yes_no(&t!("init-store-config"), false)? {
+            profile.seed = Some(seed_phrase.clone());
+            seed_storage = "config";
+        } else {
+            print_status(&t!("init-export-seed"));
+            print_line("  export ZOTS_SEED=\"<your 24 words>\"");
+        }
+    }
+    // Don't leave the replaced profile's seed behind in the keychain
+    if seed_storage != "keychain"
+        && previous.is_some_and(|p| p.keychain == Some(true))
+        && let Err(e) = delete_seed(&profile_name)
+    {
+        print_warning(&format!("{e:#}"));
+    }
+
+    // Write the profile
+    file.profiles.insert(profile_name.clone(), profile);
+    if file.default_profile.is_none()
+        || (file.default_profile.as_deref() != Some(profile_name.as_str())
+            && prompt_yes_no(
+                &t!("init-make-default", profile = profile_name.as_str()),
+                true,
+            )?)
+    {
+        file.default_profile = Some(profile_name.clone());
+    }
+    file.save(&path)?;
+
+    print_line("");
+    print_success(&t!(
+        "init-written",
+        profile = profile_name.as_str(),
+        path = path.display().to_string()
+    ));
+    if std::env::var("ZOTS_SEED").is_ok() {
+        print_warning(&t!("init-seed-env-override"));
+    }
+    print_status(&t!("init-next-steps"));
+    if network == Network::Testnet {
+        print_link(&t!("label-faucet"), TESTNET_FAUCET_URL);
+    }
+
+    print_json(&json!({
+        "path": path.display().to_string(),
+        "profile": profile_name,
+        "default_profile": file.default_profile,
+        "network": network.to_string(),
+        "lightwalletd": lightwalletd,
+        "birthday_height": birthday_height,
+        "data_dir": data_dir.display().to_string(),
+        "seed_storage": seed_storage,
+        "seed_generated": generated,
+    }));
+
+    Ok(())
+}
+
+/// Generate a 24-word seed and have the user acknowledge backing it up
+fn generate_seed() -> anyhow::Result<String> {
+    let mnemonic = Mnemonic::<English>::generate(Count::Words24);
+    let phrase = mnemonic.phrase().to_string();
+
+    print_line("");
+    print_warning(&t!("init-write-down"));
+    print_line("");
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    for (row, chunk) in words.chunks(4).enumerate() {
+        let line: Vec<String> = chunk
+            .iter()
+            .enumerate()
+            .map(|(i, word)| format!("{:>2}. {word:<10}", row * 4 + i + 1))
+            .collect();
+        print_line(&format!("  {}", line.join(" ")));
+    }
+    print_line("");
+
+    while !prompt_yes_no(&t!("init-written-down"), false)? {
+        print_warning(&t!("init-unrecoverable"));
+    }
+    Ok(phrase)
+}
+
+/// Normalize an imported seed phrase and check its BIP-39 checksum
+fn check_seed(phrase: &str) -> anyhow::Result<String> {
+    let phrase = phrase
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let words = phrase.split(' ').count();
+    if words != 24 {
+        return Err(anyhow::anyhow!("Seed phrase must be 24 words, got {words}"));
+    }
+    Mnemonic::<English>::from_phrase(&phrase)
+        .map_err(|e| anyhow::anyhow!("Invalid seed phrase: {e}"))?;
+    Ok(phrase)
+}
@@ -4,16 +4,42 @@
 //!
 //! - [`stamp`] - Timestamp files or hashes on the blockchain
 //! - [`verify`] - Verify timestamp proofs against the blockchain
+//! - [`git`] - Timestamp and verify git commits and tags
+//! - [`attest`] - Emit in-toto statements carrying a proof for supply-chain tooling
 //! - [`info`] - Display proof file information
 //! - [`encode`] - Convert proofs to compact format
 //! - [`decode`] - Convert compact format to JSON
+//! - [`embed`] - Embed proofs in file metadata and extract them
+//! - [`export`] - Convert proofs to and from OpenTimestamps, CBOR and JSON
+//! - [`proof`] - Offline operations on proof files (merge)
+//! - [`upgrade`] - Complete pending proofs once confirmed
+//! - [`confirm`] - Wait for a pending proof's transaction to confirm
+//! - [`pending`] - List and bulk-upgrade tracked pending proofs
+//! - [`watch`] - Stamp files in watched directories as they change
 //! - [`wallet`] - Wallet management operations
+//! - [`init`] - Interactive first-run setup
+//! - [`config`] - Config file management
+//! - [`doctor`] - Setup diagnostics with suggested fixes
 //! - [`nostr`] - Nostr protocol integration for proof sharing
+//! - [`serve`] - HTTP API backed by the stamp queue
 
+pub mod attest;
+pub mod config;
+pub mod confirm;
 pub mod decode;
+pub mod doctor;
+pub mod embed;
 pub mod encode;
+pub mod export;
+pub mod git;
 pub mod info;
+pub mod init;
 pub mod nostr;
+pub mod pending;
+pub mod proof;
+pub mod serve;
 pub mod stamp;
+pub mod upgrade;
 pub mod verify;
 pub mod wallet;
+pub mod watch;
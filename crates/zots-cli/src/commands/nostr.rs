@@ -10,7 +10,7 @@
 //! - `ZOTS_NOSTR_RELAYS`: Comma-separated list of relay URLs
 //!
 //! Optional environment variables:
-//! - `ZOTS_ZCASH_EXPLORER`: Custom Zcash block explorer base URL
+//! - `ZOTS_ZCASH_EXPLORER`: Custom Zcash block explorer base URL (or `explorer` in the config file)
 //! - `ZOTS_NOSTR_NOTE_URL`: Custom Nostr note viewer base URL
 //!
 //! ## Commands
@@ -18,6 +18,7 @@
 //! - `publish`: Publish a proof to Nostr relays
 //! - `fetch`: Retrieve a proof from a Nostr event ID
 
+use crate::i18n::t;
 use crate::output::*;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -28,11 +29,6 @@ use nostr_sdk::prelude::*;
 /// Default Nostr note viewer URL
 const DEFAULT_NOSTR_NOTE_URL: &str = "https://iris.to";
 
-/// Get the Zcash explorer base URL from environment or use default
-fn get_zcash_explorer_url() -> Option<String> {
-    std::env::var("ZOTS_ZCASH_EXPLORER").ok()
-}
-
 /// Get the Nostr note viewer base URL from environment or use default
 fn get_nostr_note_url() -> String {
     std::env::var("ZOTS_NOSTR_NOTE_URL").unwrap_or_else(|_| DEFAULT_NOSTR_NOTE_URL.to_string())
@@ -108,7 +104,6 @@ const ZOTS_HASH_TAG: &str = "zots-hash";
 /// Generate a human-readable description of a timestamp proof.
 fn proof_description(proof: &TimestampProof) -> String {
     let mut desc = String::new();
-    let custom_explorer = get_zcash_explorer_url();
 
     desc.push_str("⏰ zOpenTimestamps Proof\n\n");
 
@@ -128,7 +123,7 @@ fn proof_description(proof: &TimestampProof) -> String {
 
         for (i, att) in proof.attestations.iter().enumerate() {
             let timestamp = att.timestamp();
-            let explorer_link = att.explorer_link_with_base(custom_explorer.as_deref());
+            let explorer_link = explorer_link(att);
             desc.push_str(&format!("🔗 Attestation #{}\n", i + 1));
             desc.push_str(&format!("   Network: {} (Zcash)\n", att.network));
             desc.push_str(&format!("   Block: {}\n", att.block_height));
@@ -154,20 +149,26 @@ fn proof_description(proof: &TimestampProof) -> String {
 /// - Compact proof (zots1...) in a tag for machine parsing
 /// - Hash tag for discoverability
 pub async fn publish(proof_path: PathBuf) -> anyhow::Result<()> {
-    print_header("Publishing Proof to Nostr");
+    print_header(&t!("nostr-publish-header"));
 
     // Load configuration
     let config = NostrConfig::from_env()?;
-    print_info("Public Key", &config.keys.public_key().to_bech32()?);
+    print_info(
+        &t!("label-public-key"),
+        &config.keys.public_key().to_bech32()?,
+    );
 
     // Load proof
-    print_info("Proof File", &proof_path.display().to_string());
+    print_info(&t!("label-proof-file"), &proof_path.display().to_string());
     let proof = TimestampProof::load(&proof_path)?;
     print_hash(&proof.hash, proof.hash_algorithm().name());
 
     // Convert to compact format
     let compact = proof.to_compact()?;
-    print_info("Compact Size", &format!("{} bytes", compact.len()));
+    print_info(
+        &t!("label-compact-size"),
+        &t!("size-bytes", bytes = compact.len()),
+    );
 
     // Generate human-readable description
     let description = proof_description(&proof);
@@ -176,10 +177,10 @@ pub async fn publish(proof_path: PathBuf) -> anyhow::Result<()> {
     let client = Client::new(config.keys);
 
     // Add relays
-    print_status("Connecting to relays...");
+    print_status(&t!("nostr-connecting"));
     for relay in &config.relays {
         client.add_relay(relay).await?;
-        print_info("Relay", relay);
+        print_info(&t!("label-relay"), relay);
     }
 
     // Connect to relays
@@ -204,31 +205,34 @@ pub async fn publish(proof_path: PathBuf) -> anyhow::Result<()> {
         ));
 
     // Send the event
-    print_status("Publishing event...");
+    print_status(&t!("nostr-publishing"));
     let output = client.send_event_builder(event).await?;
 
     let note_bech32 = output.id().to_bech32()?;
     let note_link = nostr_note_link(&note_bech32);
 
     println!();
-    print_success("Proof published to Nostr!");
-    print_info("Event ID", &note_bech32);
-    print_info("Event ID (hex)", &output.id().to_hex());
-    print_link("View on Nostr", &note_link);
+    print_success(&t!("nostr-published"));
+    print_info(&t!("label-event-id"), &note_bech32);
+    print_info(&t!("label-event-id-hex"), &output.id().to_hex());
+    print_link(&t!("label-view-on-nostr"), &note_link);
 
     // Show which relays received it
     let success_count = output.success.len();
     let failed_count = output.failed.len();
 
     if success_count > 0 {
-        print_info("Relays (success)", &success_count.to_string());
+        print_info(&t!("label-relays-success"), &success_count.to_string());
     }
     if failed_count > 0 {
-        print_warning(&format!("Relays (failed): {failed_count}"));
+        print_warning(&t!("nostr-relays-failed", count = failed_count));
     }
 
     println!();
-    print_info("Fetch with", &format!("zots nostr fetch {note_bech32}"));
+    print_info(
+        &t!("label-fetch-with"),
+        &format!("zots nostr fetch {note_bech32}"),
+    );
 
     // Disconnect
     client.disconnect().await;
@@ -240,7 +244,7 @@ pub async fn publish(proof_path: PathBuf) -> anyhow::Result<()> {
 ///
 /// Retrieves the event by ID and extracts the compact proof from the tags.
 pub async fn fetch(event_id: String, output: Option<PathBuf>) -> anyhow::Result<()> {
-    print_header("Fetching Proof from Nostr");
+    print_header(&t!("nostr-fetch-header"));
 
     // Load configuration (we need relays to fetch from)
     let config = NostrConfig::from_env()?;
@@ -253,13 +257,13 @@ pub async fn fetch(event_id: String, output: Option<PathBuf>) -> anyhow::Result<
         )
     })?;
 
-    print_info("Event ID", &id.to_bech32()?);
+    print_info(&t!("label-event-id"), &id.to_bech32()?);
 
     // Create client (no signing needed for fetching)
     let client = Client::new(config.keys);
 
     // Add relays
-    print_status("Connecting to relays...");
+    print_status(&t!("nostr-connecting"));
     for relay in &config.relays {
         client.add_relay(relay).await?;
     }
@@ -272,11 +276,11 @@ pub async fn fetch(event_id: String, output: Option<PathBuf>) -> anyhow::Result<
     let filter = Filter::new().id(id);
 
     // Fetch the event
-    print_status("Fetching event...");
+    print_status(&t!("nostr-fetching"));
     let events = client.fetch_events(filter, Duration::from_secs(10)).await?;
 
     if events.is_empty() {
-        print_error("Event not found on any connected relay");
+        print_error(&t!("nostr-not-found"));
         client.disconnect().await;
         anyhow::bail!("Event not found: {event_id}");
     }
@@ -307,20 +311,27 @@ pub async fn fetch(event_id: String, output: Option<PathBuf>) -> anyhow::Result<
     let proof = TimestampProof::from_compact(&compact)?;
 
     println!();
-    print_success("Proof retrieved from Nostr!");
+    print_success(&t!("nostr-retrieved"));
     print_hash(&proof.hash, proof.hash_algorithm().name());
-    print_info("Attestations", &proof.attestations.len().to_string());
+    print_info(
+        &t!("label-attestations"),
+        &proof.attestations.len().to_string(),
+    );
 
     // Show attestation details
     for (i, att) in proof.attestations.iter().enumerate() {
         println!();
         print_info(
-            &format!("Attestation #{}", i + 1),
-            &format!("{} block {}", att.network, att.block_height),
+            &t!("info-attestation", number = i + 1),
+            &t!(
+                "nostr-attestation-block",
+                network = att.network.to_string(),
+                height = att.block_height
+            ),
         );
-        print_info("  TX", &att.txid);
+        print_info(&format!("  {}", t!("label-txid")), &att.txid);
         print_info(
-            "  Time",
+            &format!("  {}", t!("label-time")),
             &att.timestamp().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
         );
     }
@@ -329,13 +340,16 @@ pub async fn fetch(event_id: String, output: Option<PathBuf>) -> anyhow::Result<
     if let Some(output_path) = output {
         proof.save(&output_path)?;
         println!();
-        print_success(&format!("Proof saved to: {}", output_path.display()));
+        print_success(&t!(
+            "stamp-proof-saved",
+            path = output_path.display().to_string()
+        ));
     } else {
         println!();
-        print_header("Compact Proof");
+        print_header(&t!("nostr-compact-header"));
         println!("{compact}");
         println!();
-        print_info("Tip", "Use -o <file.zots> to save the proof to a file");
+        print_info(&t!("label-tip"), &t!("nostr-save-tip"));
     }
 
     // Disconnect
@@ -0,0 +1,244 @@
+//! Pending proof management.
+//!
+//! Pending proofs written by `stamp` and `watch` are recorded in
+//! `<data_dir>/pending.json` and dropped once an upgrade attaches their
+//! attestation.
+//!
+//! ## Commands
+//!
+//! - `list`: Show tracked pending proofs with their age and txid
+//! - `upgrade-all`: Try to complete every tracked proof in one pass (cron-friendly)
+
+use crate::exit;
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+use zots_core::{Network, PendingEntry, PendingStore, TimestampProof};
+use zots_zcash::{ZcashConfig, ZotsWallet, is_network_error};
+
+use super::upgrade::attach_confirmation;
+
+/// Location of the pending registry for a config
+pub(crate) fn store_path(config: &ZcashConfig) -> PathBuf {
+    config.pending_store_path()
+}
+
+/// Apply a change to the pending registry at `registry`
+///
+/// The registry is a convenience: failing to update it only logs a warning,
+/// the proof files themselves are already saved.
+pub(crate) fn record(registry: &Path, update: impl FnOnce(&mut PendingStore)) {
+    if let Err(e) = PendingStore::update(registry, update) {
+        warn!("Could not update the pending proof registry: {:#}", e);
+    }
+}
+
+pub fn list() -> anyhow::Result<()> {
+    print_header(&t!("pending-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let store = PendingStore::load(store_path(&config))?;
+    let now = chrono::Utc::now().timestamp();
+
+    if store.entries().is_empty() {
+        print_success(&t!("pending-none"));
+    }
+    for entry in store.entries() {
+        print_line(&format!(
+            "  {:<8} {}  {}",
+            format_age(now - entry.created_at),
+            entry.txid.clone().unwrap_or_else(|| t!("pending-no-txid")),
+            entry.proof_path.display()
+        ));
+    }
+
+    print_json(&json!({
+        "total": store.entries().len(),
+        "pending": store
+            .entries()
+            .iter()
+            .map(|e| entry_json(e, now))
+            .collect::<Vec<_>>(),
+    }));
+
+    Ok(())
+}
+
+pub async fn upgrade_all() -> anyhow::Result<()> {
+    print_header(&t!("pending-upgrade-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let network = config.network;
+    let store = PendingStore::load(store_path(&config))?;
+    if store.entries().is_empty() {
+        print_success(&t!("pending-none"));
+        print_json(&json!({ "total": 0, "upgraded": 0, "pending": 0, "failed": 0, "results": [] }));
+        return Ok(());
+    }
+
+    let registry = store_path(&config);
+    let mut wallet = ZotsWallet::new(config).await?;
+    let mut results = Vec::with_capacity(store.entries().len());
+    let mut finished = Vec::new();
+    let (mut upgraded, mut still_pending, mut failed) = (0, 0, 0);
+    let mut network_failed = 0;
+
+    for entry in store.entries().to_vec() {
+        let path = &entry.proof_path;
+        let outcome = upgrade_entry(&mut wallet, &registry, &entry, network).await;
+        let (status, block_height, error) = match outcome {
+            Ok(Upgrade::Confirmed(height)) => {
+                upgraded += 1;
+                print_success(&t!(
+                    "pending-upgraded",
+                    path = path.display().to_string(),
+                    height = height
+                ));
+                ("confirmed", Some(height), None)
+            }
+            Ok(Upgrade::AlreadyConfirmed) => {
+                print_success(&t!(
+                    "pending-already-confirmed",
+                    path = path.display().to_string()
+                ));
+                ("confirmed", None, None)
+            }
+            Ok(Upgrade::Pending) => {
+                still_pending += 1;
+                print_status(&t!(
+                    "pending-still-pending",
+                    path = path.display().to_string()
+                ));
+                ("pending", None, None)
+            }
+            Ok(Upgrade::Missing) => {
+                print_warning(&t!("pending-missing", path = path.display().to_string()));
+                ("missing", None, None)
+            }
+            Err(e) => {
+                failed += 1;
+                debug!("{}: {:#}", path.display(), e);
+                print_error(&format!("{}: {e}", path.display()));
+                let status = if is_network_error(&e) {
+                    network_failed += 1;
+                    "network_error"
+                } else {
+                    "error"
+                };
+                (status, None, Some(format!("{e:#}")))
+            }
+        };
+        if matches!(status, "confirmed" | "missing") {
+            finished.push(path.clone());
+        }
+        results.push(json!({
+            "proof_path": path.display().to_string(),
+            "txid": entry.txid,
+            "status": status,
+            "block_height": block_height,
+            "error": error,
+        }));
+    }
+    // Other processes may have tracked new proofs during the pass
+    PendingStore::update(&registry, |store| {
+        for path in &finished {
+            store.untrack(path);
+        }
+    })?;
+
+    print_line("");
+    print_info(&t!("label-upgraded"), &upgraded.to_string());
+    print_info(&t!("label-still-pending"), &still_pending.to_string());
+    if failed > 0 {
+        print_info(&t!("label-failed"), &failed.to_string());
+    }
+    info!(
+        "Pending upgrade pass: {} upgraded, {} pending, {} failed",
+        upgraded, still_pending, failed
+    );
+
+    print_json(&json!({
+        "total": results.len(),
+        "upgraded": upgraded,
+        "pending": still_pending,
+        "failed": failed,
+        "results": results,
+    }));
+
+    // Only an unreachable lightwalletd gets the network code, so cron jobs
+    // can tell an outage from a broken proof
+    if failed > 0 {
+        let code = if network_failed == failed {
+            exit::NETWORK
+        } else {
+            exit::FAILURE
+        };
+        return Err(exit::Status(code).into());
+    }
+    Ok(())
+}
+
+/// Result of trying to upgrade one tracked proof
+enum Upgrade {
+    Confirmed(u32),
+    AlreadyConfirmed,
+    Pending,
+    Missing,
+}
+
+async fn upgrade_entry(
+    wallet: &mut ZotsWallet,
+    registry: &Path,
+    entry: &PendingEntry,
+    network: Network,
+) -> anyhow::Result<Upgrade> {
+    if !entry.proof_path.exists() {
+        return Ok(Upgrade::Missing);
+    }
+    let mut proof = TimestampProof::load(&entry.proof_path)?;
+    if proof.is_confirmed() {
+        return Ok(Upgrade::AlreadyConfirmed);
+    }
+    let txid = proof
+        .pending_txid
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("proof does not record a transaction ID"))?;
+
+    match wallet.fetch_confirmation(&txid).await? {
+        Some(confirmation) => {
+            attach_confirmation(
+                &mut proof,
+                &entry.proof_path,
+                registry,
+                network,
+                &txid,
+                &confirmation,
+            )?;
+            Ok(Upgrade::Confirmed(confirmation.block_height))
+        }
+        None => Ok(Upgrade::Pending),
+    }
+}
+
+fn entry_json(entry: &PendingEntry, now: i64) -> Value {
+    json!({
+        "proof_path": entry.proof_path.display().to_string(),
+        "hash": entry.hash,
+        "txid": entry.txid,
+        "created_at": entry.created_at,
+        "age_secs": now - entry.created_at,
+    })
+}
+
+/// Human-readable age: `45s`, `12m`, `3h 5m`, `2d 4h`
+fn format_age(secs: i64) -> String {
+    let secs = secs.max(0);
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
+        _ => format!("{}d {}h", secs / 86400, secs % 86400 / 3600),
+    }
+}
@@ -0,0 +1,76 @@
+//! Proof command implementations.
+//!
+//! Operations on proof files that never touch the blockchain.
+//!
+//! ## Commands
+//!
+//! - `merge`: Combine proofs for the same hash, e.g. when several parties
+//!   each anchored the same document independently
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use std::path::PathBuf;
+use tracing::info;
+use zots_core::TimestampProof;
+
+pub fn merge(proofs: Vec<PathBuf>, output: Option<PathBuf>) -> anyhow::Result<()> {
+    print_header(&t!("merge-header"));
+
+    let (first, rest) = proofs
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("At least one proof is required"))?;
+    let mut merged = TimestampProof::load(first)?;
+    print_hash(&merged.hash, merged.hash_algorithm().name());
+    print_info(&t!("label-base"), &first.display().to_string());
+
+    for path in rest {
+        let proof = TimestampProof::load(path)?;
+        let added = merged
+            .merge(&proof)
+            .map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+        info!("Merged {} ({} new attestations)", path.display(), added);
+        print_info(
+            &t!("label-merged"),
+            &t!(
+                "merge-added",
+                path = path.display().to_string(),
+                count = added
+            ),
+        );
+    }
+
+    // Default to updating the first proof in place, like `upgrade`
+    let output = output.unwrap_or_else(|| first.clone());
+    merged.save(&output)?;
+
+    print_info(
+        &t!("label-attestations"),
+        &merged.attestations.len().to_string(),
+    );
+    for att in &merged.attestations {
+        print_info(
+            &format!("  {}", att.network),
+            &t!(
+                "merge-attestation",
+                height = att.block_height,
+                txid = att.txid_hex()
+            ),
+        );
+    }
+    if !merged.is_confirmed() {
+        print_warning(&t!("merge-pending"));
+    }
+    print_success(&t!("merge-saved", path = output.display().to_string()));
+
+    print_json(&json!({
+        "hash": merged.hash,
+        "hash_algorithm": merged.hash_algorithm().name(),
+        "inputs": proofs.iter().map(|p| p.display().to_string()).collect::<Vec<_>>(),
+        "output": output.display().to_string(),
+        "attestations": merged.attestations.iter().map(attestation_json).collect::<Vec<_>>(),
+        "pending_txid": merged.pending_txid,
+    }));
+
+    Ok(())
+}
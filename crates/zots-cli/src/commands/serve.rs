@@ -0,0 +1,448 @@
+//! Serve command implementation.
+//!
+//! Runs a small HTTP API so other services can timestamp without linking Rust:
+//!
+//! | Endpoint | Body | Response |
+//! |----------|------|----------|
+//! | `POST /stamp` | `{"hash": "<hex>", "hash_algorithm": "sha256"}` | `202` with `id` and `status` |
+//! | `GET /proof/{id}` | - | `200` proof JSON, `202` while pending, `404` if unknown |
+//! | `POST /verify` | `{"proof": <proof JSON or "zots1..." compact>}` | `valid`, `attestation`, `error` |
+//!
+//! Submitted hashes go through a [`StampQueue`], so concurrent requests share
+//! batched transactions. The proof id is `<algorithm>-<hash hex>`, so the same
+//! digest stamped as SHA-256 and BLAKE3 gets two proofs. Each proof is written
+//! to `<data_dir>/proofs/<id>.zots` and tracked in the pending registry before
+//! `POST /stamp` replies, so a stamp interrupted by a restart can still be
+//! completed with `zots pending upgrade-all`. A stamp that fails before its
+//! transaction is broadcast is reported by `GET /proof/{id}` for an hour.
+//!
+//! With `--webhook`, each hash's broadcast, confirmation or failure is also
+//! POSTed to the given URLs (see [`crate::webhook`]).
+//!
+//! There is no authentication: bind to localhost (the default) or put the
+//! server behind a proxy that handles it.
+
+use crate::cli::WebhookArgs;
+use crate::i18n::t;
+use crate::output::*;
+use crate::webhook::{WebhookEvent, Webhooks};
+use axum::extract::{Path as UrlPath, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use zots_core::{Hash256, HashAlgorithm, TimestampProof, hash_from_hex_with, hash_to_hex};
+use zots_zcash::{StampQueue, StampQueueConfig, ZcashConfig, ZotsWallet};
+
+use super::stamp::open_synced_wallet;
+
+/// How long `GET /proof` keeps reporting a failed stamp
+const FAILED_STAMP_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Progress of a hash submitted through `POST /stamp`
+enum StampState {
+    Pending,
+    Failed { error: String, at: Instant },
+}
+
+/// What `POST /stamp` found on disk for a proof id
+enum SavedProof {
+    /// Already confirmed
+    Confirmed,
+    /// Broadcast before a restart; `zots pending upgrade-all` completes it
+    Broadcast(String),
+    /// Newly saved and tracked as pending
+    Queued,
+}
+
+struct ServeState {
+    queue: StampQueue,
+    /// Separate client for `/verify` so lookups never wait behind a batch
+    verifier: tokio::sync::Mutex<ZotsWallet>,
+    proofs_dir: PathBuf,
+    /// Pending registry the served proofs are tracked in
+    registry: PathBuf,
+    default_algorithm: HashAlgorithm,
+    stamps: Mutex<HashMap<String, StampState>>,
+    webhooks: Webhooks,
+}
+
+impl ServeState {
+    fn stamps(&self) -> std::sync::MutexGuard<'_, HashMap<String, StampState>> {
+        self.stamps.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn proof_path(&self, id: &str) -> PathBuf {
+        self.proofs_dir.join(format!("{id}.zots"))
+    }
+
+    /// Record that stamping `id` failed, dropping failures nobody asked about in time
+    fn fail(&self, id: String, error: String) {
+        let mut stamps = self.stamps();
+        stamps.retain(|_, state| match state {
+            StampState::Failed { at, .. } => at.elapsed() < FAILED_STAMP_TTL,
+            StampState::Pending => true,
+        });
+        stamps.insert(
+            id,
+            StampState::Failed {
+                error,
+                at: Instant::now(),
+            },
+        );
+    }
+}
+
+/// Reuse an earlier proof of the hash at `path`, or save and track `proof`
+///
+/// Saved and tracked before `POST /stamp` replies, so an accepted stamp
+/// survives a restart.
+fn save_new_proof(
+    path: &Path,
+    registry: &Path,
+    proof: &TimestampProof,
+) -> anyhow::Result<SavedProof> {
+    if let Ok(existing) = TimestampProof::load(path) {
+        if !existing.attestations.is_empty() {
+            return Ok(SavedProof::Confirmed);
+        }
+        if let Some(txid) = existing.pending_txid {
+            return Ok(SavedProof::Broadcast(txid));
+        }
+    }
+    proof
+        .save(path)
+        .map_err(|e| anyhow::anyhow!("Could not save the pending proof: {e}"))?;
+    super::pending::record(registry, |store| store.track(path, proof));
+    Ok(SavedProof::Queued)
+}
+
+/// Run proof file and registry I/O off the async workers
+///
+/// The registry update can wait several seconds for its lock.
+async fn blocking<T: Send + 'static>(
+    work: impl FnOnce() -> anyhow::Result<T> + Send + 'static,
+) -> anyhow::Result<T> {
+    tokio::task::spawn_blocking(work).await?
+}
+
+/// Proof id of `hash` stamped with `algorithm`
+fn proof_id(algorithm: HashAlgorithm, hash: &Hash256) -> String {
+    format!("{}-{}", algorithm.name(), hash_to_hex(hash))
+}
+
+/// Normalized form of a proof id from a URL, or `None` if it is malformed
+fn parse_proof_id(id: &str) -> Option<String> {
+    let (algorithm, hex) = id.split_once('-')?;
+    let algorithm =
+        serde_json::from_value::<HashAlgorithm>(json!(algorithm.to_lowercase())).ok()?;
+    let hash = hash_from_hex_with(hex, algorithm).ok()?;
+    Some(proof_id(algorithm, &hash))
+}
+
+fn pending_response(id: &str, pending_txid: Option<&str>) -> Response {
+    (
+        StatusCode::ACCEPTED,
+        Json(json!({ "id": id, "status": "pending", "pending_txid": pending_txid })),
+    )
+        .into_response()
+}
+
+pub async fn run(
+    listen: SocketAddr,
+    max_delay_secs: u64,
+    webhooks: WebhookArgs,
+) -> anyhow::Result<()> {
+    let webhooks = Webhooks::new(webhooks.urls, webhooks.webhook_secret)?;
+    print_header(&t!("serve-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let proofs_dir = config.data_dir.join("proofs");
+    std::fs::create_dir_all(&proofs_dir)?;
+    let default_algorithm = config.hash_algorithm;
+    let registry = config.pending_store_path();
+
+    let wallet = open_synced_wallet(&config).await?;
+    let mut verifier = ZotsWallet::new(config).await?;
+    verifier.init_account().await?;
+
+    let queue = StampQueue::spawn(
+        wallet,
+        StampQueueConfig {
+            max_delay: std::time::Duration::from_secs(max_delay_secs),
+            ..StampQueueConfig::default()
+        },
+    );
+    let state = Arc::new(ServeState {
+        queue,
+        verifier: tokio::sync::Mutex::new(verifier),
+        proofs_dir,
+        registry,
+        default_algorithm,
+        stamps: Mutex::new(HashMap::new()),
+        webhooks,
+    });
+
+    let app = Router::new()
+        .route("/stamp", post(stamp))
+        .route("/proof/{id}", get(proof))
+        .route("/verify", post(verify))
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind(listen).await?;
+    print_info(
+        &t!("label-listening"),
+        &format!("http://{}", listener.local_addr()?),
+    );
+    print_info(&t!("label-proofs"), &state.proofs_dir.display().to_string());
+    if !state.webhooks.is_empty() {
+        print_info(&t!("label-webhooks"), &state.webhooks.len().to_string());
+    }
+    if !listen.ip().is_loopback() {
+        print_warning(&t!("serve-no-auth"));
+    }
+    print_status(&t!("serve-running"));
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+
+    print_success(&t!("serve-stopped"));
+    Ok(())
+}
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, Json(json!({ "error": message.into() }))).into_response()
+}
+
+/// `POST /stamp`: queue a hash and return its proof id
+async fn stamp(State(state): State<Arc<ServeState>>, Json(body): Json<Value>) -> Response {
+    let Some(hex) = body.get("hash").and_then(Value::as_str) else {
+        return error_response(StatusCode::BAD_REQUEST, "missing \"hash\"");
+    };
+    let algorithm = match body.get("hash_algorithm") {
+        None => state.default_algorithm,
+        Some(value) => match serde_json::from_value::<HashAlgorithm>(value.clone()) {
+            Ok(algorithm) => algorithm,
+            Err(_) => {
+                return error_response(
+                    StatusCode::BAD_REQUEST,
+                    "hash_algorithm must be \"sha256\" or \"blake3\"",
+                );
+            }
+        },
+    };
+    let hash = match hash_from_hex_with(hex, algorithm) {
+        Ok(hash) => hash,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, e.to_string()),
+    };
+    let id = proof_id(algorithm, &hash);
+    let path = state.proof_path(&id);
+
+    // Claim the id first so concurrent requests for it share one stamp
+    {
+        let mut stamps = state.stamps();
+        if matches!(stamps.get(&id), Some(StampState::Pending)) {
+            return pending_response(&id, None);
+        }
+        stamps.insert(id.clone(), StampState::Pending);
+    }
+
+    let proof = TimestampProof::new_with_algorithm(hash, algorithm);
+    let saved = {
+        let (path, registry, proof) = (path.clone(), state.registry.clone(), proof.clone());
+        blocking(move || save_new_proof(&path, &registry, &proof)).await
+    };
+    match saved {
+        Ok(SavedProof::Queued) => {}
+        Ok(SavedProof::Confirmed) => {
+            state.stamps().remove(&id);
+            return (
+                StatusCode::OK,
+                Json(json!({ "id": id, "status": "confirmed" })),
+            )
+                .into_response();
+        }
+        Ok(SavedProof::Broadcast(txid)) => {
+            state.stamps().remove(&id);
+            return pending_response(&id, Some(&txid));
+        }
+        Err(e) => {
+            state.stamps().remove(&id);
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("{e:#}"));
+        }
+    }
+
+    info!("Queued hash {} for stamping", id);
+    let mut pending = state.queue.submit(hash);
+    let task_state = state.clone();
+    let task_id = id.clone();
+    tokio::spawn(async move {
+        let webhooks = &task_state.webhooks;
+        let registry = task_state.registry.clone();
+        let mut proof = proof;
+        if let Some(txid) = pending.broadcast().await {
+            proof.set_pending_txid(txid.clone());
+            let (path, registry, proof) = (path.clone(), registry.clone(), proof.clone());
+            let saved = blocking(move || {
+                proof.save(&path)?;
+                super::pending::record(&registry, |store| store.track(&path, &proof));
+                Ok(())
+            })
+            .await;
+            if let Err(e) = saved {
+                warn!("Could not save pending proof {}: {:#}", task_id, e);
+            }
+            webhooks.send(
+                WebhookEvent::Broadcast,
+                json!({ "id": task_id, "hash_algorithm": algorithm.name(), "txid": txid }),
+            );
+        }
+        let outcome = match pending.await {
+            Ok(attestation) => {
+                proof.add_attestation(attestation.clone());
+                let (path, registry, proof) = (path.clone(), registry.clone(), proof.clone());
+                blocking(move || {
+                    proof.save(&path)?;
+                    super::pending::record(&registry, |store| {
+                        store.untrack(&path);
+                    });
+                    Ok(attestation)
+                })
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        match outcome {
+            Ok(attestation) => {
+                info!("Proof {} confirmed", task_id);
+                webhooks.send(
+                    WebhookEvent::Confirmed,
+                    json!({ "id": task_id, "attestation": attestation_json(&attestation) }),
+                );
+                task_state.stamps().remove(&task_id);
+            }
+            Err(e) => {
+                warn!("Stamping {} failed: {:#}", task_id, e);
+                webhooks.send(
+                    WebhookEvent::Failed,
+                    json!({ "id": task_id, "error": format!("{e:#}") }),
+                );
+                if proof.pending_txid.is_some() {
+                    // The transaction is out; the saved proof stays pending
+                    task_state.stamps().remove(&task_id);
+                } else {
+                    let removed = blocking(move || {
+                        let _ = std::fs::remove_file(&path);
+                        super::pending::record(&registry, |store| {
+                            store.untrack(&path);
+                        });
+                        Ok(())
+                    })
+                    .await;
+                    if let Err(remove_err) = removed {
+                        warn!(
+                            "Could not remove failed proof {}: {:#}",
+                            task_id, remove_err
+                        );
+                    }
+                    task_state.fail(task_id, format!("{e:#}"));
+                }
+            }
+        }
+    });
+
+    pending_response(&id, None)
+}
+
+/// `GET /proof/{id}`: the confirmed proof, or the stamp's current state
+async fn proof(State(state): State<Arc<ServeState>>, UrlPath(id): UrlPath<String>) -> Response {
+    let Some(id) = parse_proof_id(&id) else {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "id must be <algorithm>-<64-char hex hash>",
+        );
+    };
+
+    match TimestampProof::load(state.proof_path(&id)) {
+        Ok(proof) if !proof.attestations.is_empty() => match serde_json::to_value(&proof) {
+            Ok(value) => (StatusCode::OK, Json(value)).into_response(),
+            Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+        },
+        Ok(proof) => pending_response(&id, proof.pending_txid.as_deref()),
+        Err(_) => match state.stamps().get(&id) {
+            Some(StampState::Pending) => pending_response(&id, None),
+            Some(StampState::Failed { error, .. }) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "id": id, "status": "failed", "error": error })),
+            )
+                .into_response(),
+            None => error_response(StatusCode::NOT_FOUND, "unknown proof id"),
+        },
+    }
+}
+
+/// `POST /verify`: check a proof's first attestation on-chain
+async fn verify(State(state): State<Arc<ServeState>>, Json(body): Json<Value>) -> Response {
+    let parsed = match body.get("proof") {
+        Some(Value::String(compact)) => TimestampProof::from_compact(compact),
+        Some(value @ Value::Object(_)) => TimestampProof::deserialize(&value.to_string()),
+        _ => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "\"proof\" must be a proof object or a compact zots1 string",
+            );
+        }
+    };
+    let proof = match parsed {
+        Ok(proof) => proof,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, e.to_string()),
+    };
+
+    let Some(att) = proof.attestations.first() else {
+        return (
+            StatusCode::OK,
+            Json(json!({
+                "status": "pending",
+                "valid": false,
+                "hash": proof.hash,
+                "pending_txid": proof.pending_txid,
+            })),
+        )
+            .into_response();
+    };
+
+    let result = match (proof.hash_bytes(), att.txid_bytes()) {
+        (Ok(hash), Ok(txid)) => {
+            let mut verifier = state.verifier.lock().await;
+            verifier
+                .verify_timestamp_tx(&txid, &hash, Some(att.block_height))
+                .await
+        }
+        (Err(e), _) | (_, Err(e)) => return error_response(StatusCode::BAD_REQUEST, e.to_string()),
+    };
+    match result {
+        Ok(result) => (
+            StatusCode::OK,
+            Json(json!({
+                "status": if result.valid { "valid" } else { "invalid" },
+                "valid": result.valid,
+                "hash": proof.hash,
+                "hash_algorithm": proof.hash_algorithm().name(),
+                "attestation": attestation_json(att),
+                "error": result.error,
+            })),
+        )
+            .into_response(),
+        Err(e) => error_response(StatusCode::BAD_GATEWAY, format!("{e:#}")),
+    }
+}
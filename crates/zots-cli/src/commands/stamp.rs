@@ -4,64 +4,264 @@
 //! encoded in the memo field. The resulting proof can be used to verify that
 //! the data existed at the time the transaction was confirmed.
 //!
+//! Multiple files are stamped in one transaction carrying one memo output per
+//! file, so the fee is paid once and every file gets its own proof. They are
+//! hashed in parallel on `--jobs` threads (default: one per core).
+//!
+//! If the transaction is not mined before the wait gives up, pending proofs
+//! are saved and the command exits with [`exit::PENDING`].
+//!
+//! An `http://` or `https://` input is downloaded and hashed as it streams
+//! in; the final URL, ETag and byte count are recorded in the proof metadata.
+//! An `ipfs://` CID is stamped from its SHA-256/BLAKE3 digest, or fetched
+//! through the configured gateway when that does not match the algorithm;
+//! the CID is recorded either way.
+//!
+//! `--note` attaches a short free-form note to every proof's metadata and
+//! `--filename` records the stamped file's name (not its path), so a proof
+//! found years later explains what it covers.
+//!
+//! `--dry-run` stops after the transaction proposal: it reports the fee,
+//! selected inputs, memo layout and proof paths without proving, spending or
+//! writing proofs. The wallet is still synced first, so the proposal uses
+//! current notes; that sync updates the local wallet database as any other
+//! command would.
+//!
 //! ## Warning
 //!
 //! This command sends a real blockchain transaction. Only use on testnet.
 
+use crate::exit;
+use crate::fetch::{gateway_url, hash_ipfs, hash_url, is_remote, is_url};
+use crate::i18n::t;
 use crate::output::*;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::path::PathBuf;
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tracing::{debug, info};
 use zots_core::{
-    HashAlgorithm, TimestampProof, ZcashAttestation, hash_file_with, hash_from_hex_with,
-    hash_to_hex,
+    Hash256, HashAlgorithm, NOTE_KEY, TimestampProof, ZcashAttestation, hash_file_with,
+    hash_file_with_progress, hash_from_hex_with, hash_reader_with, hash_text_with, hash_to_hex,
+    normalize_text, validate_note,
 };
-use zots_zcash::{ZcashConfig, ZotsWallet};
+use zots_zcash::{ConfirmationResult, DryRunResult, ZcashConfig, ZotsWallet};
+
+/// How `stamp` hashes its inputs and writes their proofs
+#[derive(Default)]
+pub struct StampOptions {
+    /// Proof path (single input only)
+    pub output: Option<PathBuf>,
+    /// Hash algorithm (default: `hash_algorithm` from the config)
+    pub hash_algorithm: Option<HashAlgorithm>,
+    /// Show a QR code of the compact proof
+    pub show_qr: bool,
+    /// Save a pending proof instead of waiting for confirmation
+    pub no_wait: bool,
+    /// Threads used to hash multiple files (default: available cores)
+    pub jobs: Option<usize>,
+    /// Estimate the transaction and stop before proving or broadcasting
+    pub dry_run: bool,
+    /// Free-form note recorded in each proof's metadata
+    pub note: Option<String>,
+    /// Record the stamped file's name in each proof's metadata
+    pub record_filename: bool,
+}
+
+/// Descriptive metadata added to every proof of one stamp
+#[derive(Default)]
+struct ProofLabels {
+    /// Validated, trimmed note
+    note: Option<String>,
+    record_filename: bool,
+}
+
+impl ProofLabels {
+    fn apply(&self, proof: &mut TimestampProof, file: Option<&Path>) {
+        if let Some(note) = &self.note {
+            proof.set_metadata(NOTE_KEY, note.as_str());
+        }
+        if self.record_filename
+            && let Some(file) = file
+        {
+            proof.set_filename(file);
+        }
+    }
+}
 
 pub async fn run(
-    file: Option<PathBuf>,
+    files: Vec<String>,
     hash: Option<String>,
-    output: Option<PathBuf>,
-    hash_algorithm: HashAlgorithm,
-    show_qr: bool,
-    no_wait: bool,
+    stdin: bool,
+    text: Option<String>,
+    options: StampOptions,
 ) -> anyhow::Result<()> {
+    let StampOptions {
+        output,
+        hash_algorithm,
+        show_qr,
+        no_wait,
+        jobs,
+        dry_run,
+        note,
+        record_filename,
+    } = options;
     info!("Starting stamp operation");
+    // Checked up front so a bad note fails before anything is hashed or synced
+    let labels = ProofLabels {
+        note: note
+            .as_deref()
+            .map(validate_note)
+            .transpose()?
+            .map(str::to_string),
+        record_filename,
+    };
+    let config = ZcashConfig::load(None)?;
+    let requested_algorithm = hash_algorithm;
+    let mut hash_algorithm = hash_algorithm.unwrap_or(config.hash_algorithm);
     debug!("Selected hash algorithm: {}", hash_algorithm.name());
 
+    // `-` stands for stdin and must be the only input
+    let stdin = stdin || files.iter().any(|f| f == "-");
+    if stdin && files.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "stdin (`-`) cannot be combined with other files"
+        ));
+    }
+    // A URL or IPFS CID is resolved remotely rather than read from disk
+    let remote = if files.iter().any(|f| is_remote(f)) {
+        if files.len() > 1 {
+            return Err(anyhow::anyhow!(
+                "A URL or ipfs:// CID must be the only input to `zots stamp`"
+            ));
+        }
+        files.first().cloned()
+    } else {
+        None
+    };
+    let files = if stdin || remote.is_some() {
+        Vec::new()
+    } else {
+        expand_paths(&files)?
+    };
+    if files.len() > 1 {
+        if output.is_some() {
+            return Err(anyhow::anyhow!(
+                "--output can only be used when stamping a single file"
+            ));
+        }
+        let jobs = resolve_jobs(jobs);
+        let mode = if dry_run {
+            BatchMode::DryRun
+        } else if no_wait {
+            BatchMode::NoWait
+        } else {
+            BatchMode::Wait
+        };
+        return run_batch(config, files, hash_algorithm, &labels, show_qr, mode, jobs).await;
+    }
+    let file = files.into_iter().next();
+    let stamped_file = file.clone();
+
     // Determine hash to timestamp
-    let (hash_bytes, output_path) = if let Some(file_path) = file {
-        print_header("Timestamping File");
-        info!("Hashing file {}", file_path.display());
+    let input_file = file
+        .as_ref()
+        .map(|p| p.display().to_string())
+        .or_else(|| remote.clone());
+    let mut metadata = BTreeMap::new();
+    let (hash_bytes, output_path) = if let Some(cid) = remote.as_deref().filter(|r| !is_url(r)) {
+        print_header(&t!("stamp-header-ipfs"));
+
+        let ipfs = hash_ipfs(
+            cid,
+            requested_algorithm,
+            config.hash_algorithm,
+            &config.ipfs_gateway,
+        )
+        .await?;
+        hash_algorithm = ipfs.algorithm;
+        metadata = ipfs.metadata(&gateway_url(&config.ipfs_gateway, &ipfs.cid));
+        let output = output.unwrap_or_else(|| PathBuf::from(format!("{}.zots", ipfs.cid)));
 
-        let pb = ProgressBar::new_spinner();
-        pb.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.green} {msg}")
-                .unwrap(),
+        print_info("CID", &ipfs.cid.to_string());
+        match &ipfs.fetched {
+            None => print_info(&t!("label-source"), &t!("stamp-source-cid")),
+            Some(fetched) => {
+                print_info(&t!("label-source"), &fetched.final_url);
+                print_info(
+                    &t!("label-size"),
+                    &t!("size-bytes", bytes = fetched.content_length),
+                );
+            }
+        }
+        print_hash(&hash_to_hex(&ipfs.hash), hash_algorithm.name());
+
+        (ipfs.hash, output)
+    } else if let Some(url) = remote {
+        print_header(&t!("stamp-header-url"));
+
+        let remote = hash_url(&url, hash_algorithm).await?;
+        metadata = remote.metadata(&url);
+        let output = output.unwrap_or_else(|| remote.default_output_path());
+
+        print_info(&t!("label-url"), &url);
+        if remote.final_url != url {
+            print_info(&t!("label-final-url"), &remote.final_url);
+        }
+        if let Some(etag) = &remote.etag {
+            print_info("ETag", etag);
+        }
+        print_info(
+            &t!("label-size"),
+            &t!("size-bytes", bytes = remote.content_length),
         );
-        pb.set_message("Hashing file...");
+        print_hash(&hash_to_hex(&remote.hash), hash_algorithm.name());
+
+        (remote.hash, output)
+    } else if stdin {
+        print_header(&t!("stamp-header-stdin"));
+        info!("Hashing stdin");
 
-        let hash = hash_file_with(&file_path, hash_algorithm)?;
-        pb.finish_with_message("Hashing complete");
+        let pb = spinner(&t!("stamp-hashing-stdin"));
+        let hash = hash_reader_with(std::io::stdin().lock(), hash_algorithm)?;
+        pb.finish_with_message(t!("stamp-hashing-complete"));
+
+        let hex = hash_to_hex(&hash);
+        let output = output.unwrap_or_else(|| PathBuf::from(format!("{}.zots", &hex[..16])));
+        print_hash(&hex, hash_algorithm.name());
+
+        (hash, output)
+    } else if let Some(file_path) = file {
+        print_header(&t!("stamp-header-file"));
+        info!("Hashing file {}", file_path.display());
+
+        let size = std::fs::metadata(&file_path)?.len();
+        let pb = bytes_bar(size, &t!("stamp-hashing-file"));
+        let hash =
+            hash_file_with_progress(&file_path, hash_algorithm, |done, _| pb.set_position(done))?;
+        pb.finish_with_message(t!("stamp-hashing-complete"));
         debug!("Computed hash: {}", hash_to_hex(&hash));
 
-        let output = output.unwrap_or_else(|| {
-            let mut p = file_path.clone();
-            let new_name = format!(
-                "{}.zots",
-                p.file_name().unwrap_or_default().to_string_lossy()
-            );
-            p.set_file_name(new_name);
-            p
-        });
+        let output = output.unwrap_or_else(|| default_output_path(&file_path));
 
-        print_info("File", &file_path.display().to_string());
+        print_info(&t!("label-file"), &file_path.display().to_string());
         print_hash(&hash_to_hex(&hash), hash_algorithm.name());
 
+        (hash, output)
+    } else if let Some(text) = text {
+        print_header(&t!("stamp-header-text"));
+        info!("Hashing text message");
+
+        let hash = hash_text_with(&text, hash_algorithm);
+        let hex = hash_to_hex(&hash);
+        let output = output.unwrap_or_else(|| PathBuf::from(format!("{}.zots", &hex[..16])));
+
+        print_info(&t!("label-text"), &normalize_text(&text));
+        print_hash(&hex, hash_algorithm.name());
+
         (hash, output)
     } else if let Some(hex) = hash {
-        print_header("Timestamping Hash");
+        print_header(&t!("stamp-header-hash"));
         info!("Using provided hash input");
 
         let hash = hash_from_hex_with(&hex, hash_algorithm)?;
@@ -72,60 +272,99 @@ pub async fn run(
         (hash, output)
     } else {
         return Err(anyhow::anyhow!(
-            "Either a file path or --hash must be provided"
+            "Either a file path, --hash or --text must be provided"
         ));
     };
 
-    // Initialize wallet
-    let config = ZcashConfig::from_env()?;
-    let mut wallet = ZotsWallet::new(config.clone()).await?;
-    info!("Initializing wallet");
-    wallet.init_account().await?;
+    // Initialize and sync wallet
+    let mut wallet = open_synced_wallet(&config).await?;
 
-    // Sync wallet
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.green} {msg}")
-            .unwrap(),
-    );
-    pb.set_message("Syncing wallet...");
-    debug!("Syncing wallet with lightwalletd");
-    wallet.sync().await?;
-    pb.finish_with_message("Wallet synced");
+    if dry_run {
+        let pb = spinner(&t!("stamp-estimating"));
+        let estimate = wallet.create_timestamp_tx_dry_run(&hash_bytes)?;
+        pb.finish_and_clear();
+        let mut proof = TimestampProof::new_with_algorithm(hash_bytes, hash_algorithm);
+        proof.metadata = metadata;
+        labels.apply(&mut proof, stamped_file.as_deref());
+        return print_dry_run(
+            &estimate,
+            hash_algorithm,
+            vec![proof_json(input_file, &proof, &output_path, None)],
+        );
+    }
 
     // Create and broadcast transaction
-    let pb = ProgressBar::new_spinner();
-    pb.set_message("Creating transaction...");
-    info!("Creating timestamp transaction");
+    let pb = spinner(&t!("stamp-creating"));
     let tx_result = wallet.create_timestamp_tx(&hash_bytes).await?;
-    pb.finish_with_message("Transaction broadcast");
+    pb.finish_with_message(t!("stamp-broadcast"));
 
-    print_info("TXID", &tx_result.txid);
+    print_info(&t!("label-txid"), &tx_result.txid);
 
     // Create proof
     let mut proof = TimestampProof::new_with_algorithm(hash_bytes, hash_algorithm);
+    proof.metadata = metadata;
+    labels.apply(&mut proof, stamped_file.as_deref());
 
     if no_wait {
-        print_warning("Not waiting for confirmation - proof will be pending");
+        print_warning(&t!("stamp-no-wait"));
+        proof.set_pending_txid(tx_result.txid.clone());
         let compact = proof.to_compact()?;
         proof.save(&output_path)?;
-        print_success(&format!("Pending proof saved: {}", output_path.display()));
+        super::pending::record(&wallet.config().pending_store_path(), |store| {
+            store.track(&output_path, &proof)
+        });
+        print_success(&t!(
+            "stamp-pending-saved",
+            path = output_path.display().to_string()
+        ));
+        print_info(
+            &t!("label-upgrade"),
+            &format!("zots upgrade {}", output_path.display()),
+        );
 
         // Show compact format for embedding
-        println!();
-        print_info("Compact", &compact);
+        print_line("");
+        print_info(&t!("label-compact"), &compact);
         if show_qr {
-            print_qr("QR Code", &compact)?;
+            print_qr(&t!("label-qr-code"), &compact)?;
         }
+        print_json(&stamp_json(
+            &tx_result.txid,
+            hash_algorithm,
+            None,
+            vec![proof_json(input_file, &proof, &output_path, Some(&compact))],
+        ));
         return Ok(());
     }
 
     // Wait for confirmation
-    let pb = ProgressBar::new_spinner();
-    pb.set_message("Waiting for confirmation...");
-    let confirmation = wallet.wait_confirmation(&tx_result.txid, 10).await?;
-    pb.finish_with_message("Transaction confirmed");
+    let confirmation = match wait_for_confirmation(&mut wallet, &tx_result.txid, 10).await {
+        Ok(confirmation) => confirmation,
+        Err(e) => {
+            // Keep a pending proof so the stamp can be completed later
+            print_warning(&e.to_string());
+            proof.set_pending_txid(tx_result.txid.clone());
+            proof.save(&output_path)?;
+            super::pending::record(&wallet.config().pending_store_path(), |store| {
+                store.track(&output_path, &proof)
+            });
+            print_success(&t!(
+                "stamp-pending-saved",
+                path = output_path.display().to_string()
+            ));
+            print_info(
+                &t!("label-upgrade"),
+                &format!("zots upgrade {}", output_path.display()),
+            );
+            print_json(&stamp_json(
+                &tx_result.txid,
+                hash_algorithm,
+                None,
+                vec![proof_json(input_file, &proof, &output_path, None)],
+            ));
+            return Err(exit::Status(exit::PENDING).into());
+        }
+    };
 
     // Add attestation
     proof.add_attestation(ZcashAttestation::new(
@@ -139,19 +378,415 @@ pub async fn run(
     // Save proof
     proof.save(&output_path)?;
 
-    print_success(&format!("Confirmed in block {}", confirmation.block_height));
-    print_success(&format!("Proof saved: {}", output_path.display()));
+    print_success(&t!(
+        "confirmed-in-block",
+        height = confirmation.block_height
+    ));
+    print_success(&t!(
+        "stamp-proof-saved",
+        path = output_path.display().to_string()
+    ));
 
     // Show compact format for embedding
-    println!();
-    print_header("Embeddable Proof");
+    print_line("");
+    print_header(&t!("header-embeddable-proof"));
     let compact = proof.to_compact()?;
-    println!("{compact}");
-    println!();
-    print_info("Length", &format!("{} chars", compact.len()));
+    print_line(&compact);
+    print_line("");
+    print_info(
+        &t!("label-length"),
+        &t!("length-chars", count = compact.len()),
+    );
     if show_qr {
-        print_qr("QR Code", &compact)?;
+        print_qr(&t!("label-qr-code"), &compact)?;
     }
 
+    print_json(&stamp_json(
+        &tx_result.txid,
+        hash_algorithm,
+        Some(&confirmation),
+        vec![proof_json(input_file, &proof, &output_path, Some(&compact))],
+    ));
+
     Ok(())
 }
+
+/// Stamp several files in one transaction and write a proof for each
+async fn run_batch(
+    config: ZcashConfig,
+    files: Vec<PathBuf>,
+    hash_algorithm: HashAlgorithm,
+    labels: &ProofLabels,
+    show_qr: bool,
+    mode: BatchMode,
+    jobs: usize,
+) -> anyhow::Result<()> {
+    print_header(&t!("stamp-header-files"));
+    info!("Hashing {} files on {} threads", files.len(), jobs);
+
+    let pb = count_bar(files.len() as u64, &t!("stamp-hashing-files"));
+    let inputs: Vec<_> = files
+        .iter()
+        .map(|file_path| (file_path.as_path(), hash_algorithm))
+        .collect();
+    let hashes = hash_files(&inputs, jobs, || pb.inc(1));
+    pb.finish_with_message(t!("stamp-hashing-complete"));
+
+    let mut stamps = Vec::with_capacity(files.len());
+    for (file_path, hash) in files.into_iter().zip(hashes) {
+        let hash = hash.map_err(|e| anyhow::anyhow!("{}: {e}", file_path.display()))?;
+        debug!("{}: {}", file_path.display(), hash_to_hex(&hash));
+        let output = default_output_path(&file_path);
+        stamps.push((file_path, hash, output));
+    }
+
+    for (file_path, hash, _) in &stamps {
+        print_info(&t!("label-file"), &file_path.display().to_string());
+        print_hash(&hash_to_hex(hash), hash_algorithm.name());
+    }
+
+    // Identical files share one memo output
+    let mut seen = HashSet::new();
+    let hashes: Vec<[u8; 32]> = stamps
+        .iter()
+        .map(|(_, hash, _)| *hash)
+        .filter(|hash| seen.insert(*hash))
+        .collect();
+
+    // Initialize and sync wallet
+    let mut wallet = open_synced_wallet(&config).await?;
+
+    if mode == BatchMode::DryRun {
+        let pb = spinner(&t!("stamp-estimating-batch"));
+        let estimate = wallet.create_batch_timestamp_tx_dry_run(&hashes)?;
+        pb.finish_and_clear();
+        let proofs = stamps
+            .iter()
+            .map(|(file_path, hash, output_path)| {
+                let mut proof = TimestampProof::new_with_algorithm(*hash, hash_algorithm);
+                labels.apply(&mut proof, Some(file_path.as_path()));
+                proof_json(
+                    Some(file_path.display().to_string()),
+                    &proof,
+                    output_path,
+                    None,
+                )
+            })
+            .collect();
+        return print_dry_run(&estimate, hash_algorithm, proofs);
+    }
+
+    // Create and broadcast one transaction for all hashes
+    let pb = spinner(&t!("stamp-creating-batch"));
+    let tx_result = wallet.create_batch_timestamp_tx(&hashes).await?;
+    pb.finish_with_message(t!("stamp-broadcast"));
+
+    print_info(&t!("label-txid"), &tx_result.txid);
+    print_info(&t!("label-files"), &stamps.len().to_string());
+    if show_qr {
+        print_warning(&t!("stamp-qr-single-input"));
+    }
+
+    if mode == BatchMode::NoWait {
+        print_warning(&t!("stamp-no-wait-batch"));
+        return save_pending_proofs(
+            &stamps,
+            hash_algorithm,
+            labels,
+            &tx_result.txid,
+            &wallet.config().pending_store_path(),
+        );
+    }
+
+    // Wait for confirmation
+    let confirmation = match wait_for_confirmation(&mut wallet, &tx_result.txid, 10).await {
+        Ok(confirmation) => confirmation,
+        Err(e) => {
+            print_warning(&e.to_string());
+            save_pending_proofs(
+                &stamps,
+                hash_algorithm,
+                labels,
+                &tx_result.txid,
+                &wallet.config().pending_store_path(),
+            )?;
+            return Err(exit::Status(exit::PENDING).into());
+        }
+    };
+    print_success(&t!(
+        "confirmed-in-block",
+        height = confirmation.block_height
+    ));
+
+    let mut proofs = Vec::with_capacity(stamps.len());
+    for (file_path, hash, output_path) in &stamps {
+        let mut proof = TimestampProof::new_with_algorithm(*hash, hash_algorithm);
+        labels.apply(&mut proof, Some(file_path.as_path()));
+        proof.add_attestation(ZcashAttestation::new(
+            config.network,
+            tx_result.txid_bytes,
+            confirmation.block_height,
+            confirmation.block_time,
+            0,
+        ));
+        proof.save(output_path)?;
+        print_success(&t!(
+            "stamp-proof-saved",
+            path = output_path.display().to_string()
+        ));
+        proofs.push(proof_json(
+            Some(file_path.display().to_string()),
+            &proof,
+            output_path,
+            None,
+        ));
+    }
+
+    print_json(&stamp_json(
+        &tx_result.txid,
+        hash_algorithm,
+        Some(&confirmation),
+        proofs,
+    ));
+
+    Ok(())
+}
+
+/// What a multi-file stamp does after hashing
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BatchMode {
+    /// Broadcast and wait for confirmation
+    Wait,
+    /// Broadcast and save pending proofs
+    NoWait,
+    /// Only estimate the transaction
+    DryRun,
+}
+
+/// Print what a stamp would broadcast; nothing is proved, spent or saved
+fn print_dry_run(
+    estimate: &DryRunResult,
+    hash_algorithm: HashAlgorithm,
+    proofs: Vec<Value>,
+) -> anyhow::Result<()> {
+    let layout = &estimate.memo_layout;
+    print_header(&t!("stamp-dry-run-header"));
+    print_info(&t!("label-recipient"), &estimate.recipient);
+    print_info(
+        &t!("label-outputs"),
+        &t!(
+            "stamp-dry-run-outputs",
+            count = estimate.outputs,
+            amount = estimate.amount
+        ),
+    );
+    print_info(
+        &t!("label-fee"),
+        &t!("amount-zatoshis", amount = estimate.fee),
+    );
+    print_info(
+        &t!("label-change"),
+        &t!("amount-zatoshis", amount = estimate.change),
+    );
+    for input in &estimate.inputs {
+        print_info(
+            &t!("label-input"),
+            &t!(
+                "stamp-dry-run-input",
+                amount = input.value,
+                pool = input.pool
+            ),
+        );
+    }
+    print_info(
+        &t!("label-memo"),
+        &t!(
+            "stamp-dry-run-memo",
+            length = estimate.memo.len(),
+            magic = format!("{:?}", layout.magic),
+            hash = format!("{:?}", layout.hash),
+            padding = format!("{:?}", layout.padding)
+        ),
+    );
+    for proof in &proofs {
+        if let Some(path) = proof["proof_path"].as_str() {
+            print_info(&t!("label-proof"), path);
+        }
+    }
+    print_warning(&t!("stamp-dry-run-done"));
+
+    print_json(&json!({
+        "status": "dry_run",
+        "hash_algorithm": hash_algorithm.name(),
+        "fee_zatoshis": estimate.fee,
+        "amount_zatoshis": estimate.amount,
+        "outputs": estimate.outputs,
+        "change_zatoshis": estimate.change,
+        "recipient": estimate.recipient,
+        "inputs": estimate
+            .inputs
+            .iter()
+            .map(|input| json!({ "pool": input.pool, "value_zatoshis": input.value }))
+            .collect::<Vec<_>>(),
+        "memo_length": estimate.memo.len(),
+        "proofs": proofs,
+    }));
+    Ok(())
+}
+
+/// Save a pending proof per file, recording the txid for `zots upgrade`
+/// and tracking it in the pending registry at `registry`
+fn save_pending_proofs(
+    stamps: &[(PathBuf, [u8; 32], PathBuf)],
+    hash_algorithm: HashAlgorithm,
+    labels: &ProofLabels,
+    txid: &str,
+    registry: &Path,
+) -> anyhow::Result<()> {
+    let mut proofs = Vec::with_capacity(stamps.len());
+    for (file_path, hash, output_path) in stamps {
+        let mut proof = TimestampProof::new_with_algorithm(*hash, hash_algorithm);
+        labels.apply(&mut proof, Some(file_path.as_path()));
+        proof.set_pending_txid(txid);
+        proof.save(output_path)?;
+        super::pending::record(registry, |store| store.track(output_path, &proof));
+        print_success(&t!(
+            "stamp-pending-saved",
+            path = output_path.display().to_string()
+        ));
+        proofs.push(proof_json(
+            Some(file_path.display().to_string()),
+            &proof,
+            output_path,
+            None,
+        ));
+    }
+    print_info(&t!("label-upgrade"), &t!("stamp-upgrade-later"));
+    print_json(&stamp_json(txid, hash_algorithm, None, proofs));
+    Ok(())
+}
+
+/// JSON result of a stamp: `status` is "confirmed" or "pending"
+fn stamp_json(
+    txid: &str,
+    hash_algorithm: HashAlgorithm,
+    confirmation: Option<&ConfirmationResult>,
+    proofs: Vec<Value>,
+) -> Value {
+    json!({
+        "status": if confirmation.is_some() { "confirmed" } else { "pending" },
+        "txid": txid,
+        "hash_algorithm": hash_algorithm.name(),
+        "block_height": confirmation.map(|c| c.block_height),
+        "block_time": confirmation.map(|c| c.block_time),
+        "proofs": proofs,
+    })
+}
+
+/// JSON entry for one written proof
+fn proof_json(
+    file: Option<String>,
+    proof: &TimestampProof,
+    output_path: &Path,
+    compact: Option<&str>,
+) -> Value {
+    json!({
+        "file": file,
+        "hash": proof.hash,
+        "proof_path": output_path.display().to_string(),
+        "compact": compact,
+    })
+}
+
+/// Number of hashing threads: `jobs` if given, else one per available core
+pub(crate) fn resolve_jobs(jobs: Option<usize>) -> usize {
+    jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()))
+        .max(1)
+}
+
+/// Hash files on up to `jobs` threads, returning results in input order
+///
+/// `on_done` runs on the worker thread after each file.
+pub(crate) fn hash_files(
+    inputs: &[(&Path, HashAlgorithm)],
+    jobs: usize,
+    on_done: impl Fn() + Sync,
+) -> Vec<zots_core::Result<Hash256>> {
+    let next = AtomicUsize::new(0);
+    let workers = jobs.clamp(1, inputs.len().max(1));
+    let mut results: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&(path, algorithm)) = inputs.get(index) else {
+                            break done;
+                        };
+                        done.push((index, hash_file_with(path, algorithm)));
+                        on_done();
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("hashing thread panicked"))
+            .collect()
+    });
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, hash)| hash).collect()
+}
+
+/// Create the wallet, initialize the account and sync with lightwalletd
+pub(crate) async fn open_synced_wallet(config: &ZcashConfig) -> anyhow::Result<ZotsWallet> {
+    let mut wallet = ZotsWallet::new(config.clone()).await?;
+    wallet.ensure_can_spend()?;
+    info!("Initializing wallet");
+    wallet.init_account().await?;
+
+    debug!("Syncing wallet with lightwalletd");
+    sync_wallet(&mut wallet).await?;
+
+    Ok(wallet)
+}
+
+/// Proof path next to the stamped file (`<file>.zots`)
+pub(crate) fn default_output_path(file_path: &Path) -> PathBuf {
+    let mut p = file_path.to_path_buf();
+    let new_name = format!(
+        "{}.zots",
+        p.file_name().unwrap_or_default().to_string_lossy()
+    );
+    p.set_file_name(new_name);
+    p
+}
+
+/// Expand glob patterns into a de-duplicated file list
+///
+/// Arguments naming an existing path are used as-is; others containing glob
+/// characters are expanded (for shells that do not expand them).
+pub(crate) fn expand_paths(args: &[String]) -> anyhow::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for arg in args {
+        let path = PathBuf::from(arg);
+        if path.exists() || !arg.contains(['*', '?', '[']) {
+            paths.push(path);
+            continue;
+        }
+        let mut matches: Vec<PathBuf> = glob::glob(arg)
+            .map_err(|e| anyhow::anyhow!("Invalid pattern '{arg}': {e}"))?
+            .filter_map(Result::ok)
+            .filter(|p| p.is_file())
+            .collect();
+        if matches.is_empty() {
+            return Err(anyhow::anyhow!("No files match '{arg}'"));
+        }
+        matches.sort();
+        paths.extend(matches);
+    }
+    let mut seen = HashSet::new();
+    paths.retain(|p| seen.insert(p.clone()));
+    Ok(paths)
+}
@@ -0,0 +1,131 @@
+//! Upgrade command implementation.
+//!
+//! Completes a pending proof (from `stamp --no-wait` or a confirmation
+//! timeout) by looking up its transaction on the blockchain and appending the
+//! confirmed attestation in place. A transaction passed with `--txid` must
+//! carry the proof's hash in its memo before it is attached.
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tracing::info;
+use zots_core::{Network, TimestampProof, ZcashAttestation};
+use zots_zcash::{ConfirmationResult, ZcashConfig, ZotsWallet};
+
+pub async fn run(proof_path: PathBuf, txid: Option<String>) -> anyhow::Result<()> {
+    print_header(&t!("upgrade-header"));
+    info!("Upgrading proof {}", proof_path.display());
+
+    let mut proof = TimestampProof::load(&proof_path)?;
+    print_info(&t!("label-proof"), &proof_path.display().to_string());
+    print_hash(&proof.hash, proof.hash_algorithm().name());
+
+    if proof.is_confirmed() && txid.is_none() {
+        print_success(&t!("upgrade-already-confirmed"));
+        print_json(&json!({
+            "status": "confirmed",
+            "upgraded": false,
+            "proof_path": proof_path.display().to_string(),
+            "attestation": proof.attestations.first().map(attestation_json),
+        }));
+        return Ok(());
+    }
+
+    let explicit_txid = txid.is_some();
+    let txid = txid.or_else(|| proof.pending_txid.clone()).ok_or_else(|| {
+        anyhow::anyhow!("Proof does not record a transaction ID; pass it with --txid")
+    })?;
+    print_info(&t!("label-txid"), &txid);
+
+    let config = ZcashConfig::load(None)?;
+    let network = config.network;
+    let registry = config.pending_store_path();
+    let mut wallet = ZotsWallet::new(config).await?;
+
+    print_status(&t!("upgrade-looking-up"));
+    let confirmation = wallet.fetch_confirmation(&txid).await?.ok_or_else(|| {
+        anyhow::anyhow!("Transaction {txid} is not confirmed yet; try again later")
+    })?;
+
+    // A transaction named on the command line may have nothing to do with this proof
+    if explicit_txid {
+        print_status(&t!("upgrade-checking-memo"));
+        wallet.init_account().await?;
+        let txid_bytes = confirmed_attestation(network, &txid, &confirmation).txid_bytes()?;
+        let result = wallet
+            .verify_timestamp_tx(
+                &txid_bytes,
+                &proof.hash_bytes()?,
+                Some(confirmation.block_height),
+            )
+            .await?;
+        if !result.valid {
+            return Err(anyhow::anyhow!(
+                "Transaction {txid} does not timestamp this proof's hash{}",
+                result.error.map(|e| format!(": {e}")).unwrap_or_default()
+            ));
+        }
+    }
+
+    let attestation = attach_confirmation(
+        &mut proof,
+        &proof_path,
+        &registry,
+        network,
+        &txid,
+        &confirmation,
+    )?;
+
+    print_success(&t!(
+        "confirmed-in-block",
+        height = confirmation.block_height
+    ));
+    print_success(&t!("upgrade-done", path = proof_path.display().to_string()));
+
+    print_json(&json!({
+        "status": "confirmed",
+        "upgraded": true,
+        "proof_path": proof_path.display().to_string(),
+        "attestation": attestation_json(&attestation),
+    }));
+
+    Ok(())
+}
+
+/// Append the attestation for a confirmed transaction and save the proof in place
+///
+/// The proof is also dropped from the pending registry at `registry`.
+pub fn attach_confirmation(
+    proof: &mut TimestampProof,
+    proof_path: &Path,
+    registry: &Path,
+    network: Network,
+    txid: &str,
+    confirmation: &ConfirmationResult,
+) -> anyhow::Result<ZcashAttestation> {
+    let attestation = confirmed_attestation(network, txid, confirmation);
+    // Rejects malformed txids before the proof is rewritten
+    attestation.txid_bytes()?;
+    proof.add_attestation(attestation.clone());
+    proof.save(proof_path)?;
+    super::pending::record(registry, |store| {
+        store.untrack(proof_path);
+    });
+    Ok(attestation)
+}
+
+/// Attestation recording that `txid` was mined as described by `confirmation`
+fn confirmed_attestation(
+    network: Network,
+    txid: &str,
+    confirmation: &ConfirmationResult,
+) -> ZcashAttestation {
+    ZcashAttestation {
+        network,
+        txid: txid.to_lowercase(),
+        block_height: confirmation.block_height,
+        block_time: confirmation.block_time,
+        memo_offset: 0,
+    }
+}
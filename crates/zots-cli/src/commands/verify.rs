@@ -2,70 +2,211 @@
 //!
 //! Verifies a timestamp proof by:
 //! 1. Loading the proof file
-//! 2. Optionally verifying the hash matches an original file
+//! 2. Optionally verifying the hash matches an original file, `ipfs://` CID
+//!    or text
 //! 3. Fetching the transaction from the blockchain
 //! 4. Decrypting the memo and verifying it contains the expected hash
 //!
 //! This provides cryptographic proof that the data existed at the block time.
+//!
+//! Outcomes map to the codes in [`crate::exit`]: pending proofs, hash
+//! mismatches and invalid anchors each exit with their own code.
+//!
+//! `--require-confirmations N` additionally fails, with exit code
+//! [`exit::TOO_SHALLOW`], when the attestation block is fewer than `N` blocks
+//! deep at the current tip.
+//!
+//! `--offline` stops after step 2, checking only that the proof is
+//! well-formed and matches the original. Proofs carry no block headers or
+//! merkle paths, so the anchor itself still needs an online verification.
+//!
+//! `--report <file>` also writes a verification report (inputs, attestation,
+//! chain evidence, verdict) for hand-off, signed with `--sign <key.pem>`;
+//! see [`crate::report`].
+//!
+//! `--batch <dir>` runs the same checks for every `.zots` proof under a
+//! directory, pairing each with its sibling file (`report.pdf.zots` with
+//! `report.pdf`), and exits with the code of the most serious failure.
+//! Sibling files are hashed in parallel on `--jobs` threads before the
+//! on-chain checks. A failure (including a lightwalletd error) only marks
+//! its own entry, and the summary table lists each entry's error; entries
+//! that failed on a transport error are `network_error` and exit with
+//! [`exit::NETWORK`].
 
+use crate::exit;
+use crate::fetch::hash_ipfs;
+use crate::i18n::t;
 use crate::output::*;
+use crate::report::VerificationReport;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
 use std::path::PathBuf;
-use tracing::{debug, info};
-use zots_core::{TimestampProof, hash_file_with, hash_to_hex};
-use zots_zcash::{ZcashConfig, ZotsWallet};
+use tracing::{debug, info, warn};
+use zots_core::{
+    Cid, Hash256, TimestampProof, find_proofs_with, hash_file_with, hash_text_with, hash_to_hex,
+};
+use zots_zcash::{ZcashConfig, ZotsWallet, is_network_error};
 
-pub async fn run(proof_path: PathBuf, file: Option<PathBuf>) -> anyhow::Result<()> {
-    print_header("Verifying Timestamp");
+use super::stamp::{hash_files, resolve_jobs};
+
+/// Where to write a `--report`, and the key to sign it with
+pub struct ReportOptions {
+    pub path: PathBuf,
+    pub sign: Option<PathBuf>,
+}
+
+pub async fn run(
+    proof_path: PathBuf,
+    file: Option<PathBuf>,
+    text: Option<String>,
+    require_confirmations: Option<u64>,
+    offline: bool,
+    report: Option<ReportOptions>,
+) -> anyhow::Result<()> {
+    print_header(&t!("verify-header"));
     info!("Starting verification for proof {}", proof_path.display());
 
     // Load proof
     let proof = TimestampProof::load(&proof_path)?;
-    print_info("Proof", &proof_path.display().to_string());
+    if offline {
+        proof.validate()?;
+    }
+    print_info(&t!("label-proof"), &proof_path.display().to_string());
     print_hash(&proof.hash, proof.hash_algorithm().name());
 
     // Get hash bytes for comparison
     let proof_hash_bytes = proof.hash_bytes()?;
     let algorithm = proof.hash_algorithm();
 
-    // Verify against original file if provided
-    if let Some(file_path) = file {
-        print_status("Verifying hash against original file...");
+    let mut evidence = VerificationReport {
+        inputs: json!({
+            "proof": proof_path.display().to_string(),
+            "proof_sha256": hex::encode(Sha256::digest(std::fs::read(&proof_path)?)),
+            "original": null,
+            "required_confirmations": require_confirmations,
+            "offline": offline,
+        }),
+        proof: json!({
+            "hash": proof.hash,
+            "hash_algorithm": algorithm.name(),
+            "metadata": proof.metadata,
+        }),
+        ..Default::default()
+    };
+
+    // Verify against the original file or text if provided
+    let ipfs_input = file
+        .as_ref()
+        .and_then(|f| f.to_str())
+        .filter(|f| Cid::is_ipfs_uri(f))
+        .map(str::to_string);
+    let original = if let Some(cid) = ipfs_input {
+        print_status(&t!("verify-against-ipfs"));
+        let config = ZcashConfig::load(None)?;
+        let ipfs = hash_ipfs(&cid, Some(algorithm), algorithm, &config.ipfs_gateway).await?;
+        Some(("ipfs", Some(cid), ipfs.hash))
+    } else if let Some(file_path) = file {
+        print_status(&t!("verify-against-file"));
         info!(
             "Hashing original file {} with {}",
             file_path.display(),
             algorithm.name()
         );
-        let file_hash = hash_file_with(&file_path, algorithm)?;
+        let hash = hash_file_with(&file_path, algorithm)?;
+        Some(("file", Some(file_path.display().to_string()), hash))
+    } else if let Some(text) = text {
+        print_status(&t!("verify-against-text"));
+        info!("Hashing original text with {}", algorithm.name());
+        Some(("text", None, hash_text_with(&text, algorithm)))
+    } else {
+        None
+    };
 
-        if file_hash == proof_hash_bytes {
-            print_success("Hash matches original file");
+    if let Some((kind, source, original_hash)) = original {
+        evidence.inputs["original"] = json!({
+            "kind": kind,
+            "source": source,
+            "hash": hash_to_hex(&original_hash),
+            "matches": original_hash == proof_hash_bytes,
+        });
+        if original_hash == proof_hash_bytes {
+            print_success(&t!("verify-hash-match", kind = kind));
         } else {
-            print_error("Hash does NOT match original file!");
-            print_info("Expected", &proof.hash);
-            print_info("Got", &hash_to_hex(&file_hash));
-            print_info("Algorithm", algorithm.name());
-            return Ok(());
+            print_error(&t!("verify-hash-mismatch", kind = kind));
+            print_info(&t!("label-expected"), &proof.hash);
+            print_info(&t!("label-got"), &hash_to_hex(&original_hash));
+            print_info(&t!("label-algorithm"), algorithm.name());
+            finish(
+                report.as_ref(),
+                &evidence,
+                json!({
+                    "status": "hash_mismatch",
+                    "valid": false,
+                    "hash": proof.hash,
+                    "file_hash": hash_to_hex(&original_hash),
+                    "hash_algorithm": algorithm.name(),
+                }),
+            )?;
+            return Err(exit::Status(exit::HASH_MISMATCH).into());
         }
     }
 
     // Check attestations
     if proof.attestations.is_empty() {
-        print_warning("No attestations found - proof is pending confirmation");
-        return Ok(());
+        print_warning(&t!("verify-pending"));
+        finish(
+            report.as_ref(),
+            &evidence,
+            json!({
+                "status": "pending",
+                "valid": false,
+                "hash": proof.hash,
+                "hash_algorithm": algorithm.name(),
+                "pending_txid": proof.pending_txid,
+            }),
+        )?;
+        return Err(exit::Status(exit::PENDING).into());
     }
 
     let att = &proof.attestations[0];
+    evidence.attestation = Some(attestation_json(att));
+
+    if offline {
+        print_line("");
+        print_success(&t!("verify-well-formed"));
+        print_info(&t!("label-network"), &att.network.to_string());
+        print_info(&t!("label-block"), &att.block_height.to_string());
+        print_info(&t!("label-time"), &att.timestamp().to_rfc3339());
+        print_info(&t!("label-txid"), att.txid_hex());
+        print_warning(&t!("verify-offline-unchecked"));
+        // Without block headers the anchor is unproven, so this is not a pass
+        finish(
+            report.as_ref(),
+            &evidence,
+            json!({
+                "status": "unchecked",
+                "valid": false,
+                "offline": true,
+                "anchor_verified": false,
+                "hash": proof.hash,
+                "hash_algorithm": algorithm.name(),
+                "attestation": attestation_json(att),
+            }),
+        )?;
+        return Err(exit::Status(exit::UNCHECKED).into());
+    }
 
     // Verify against the blockchain by fetching the transaction
     // and checking the memo contains the expected hash
-    print_status("Verifying against blockchain...");
+    print_status(&t!("verify-on-chain"));
     info!(
         "Fetching transaction {} on {} for verification",
         att.txid_hex(),
         att.network
     );
 
-    let config = ZcashConfig::from_env()?;
+    let config = ZcashConfig::load(None)?;
+    let lightwalletd = config.lightwalletd_url.clone();
     let mut wallet = ZotsWallet::new(config).await?;
     wallet.init_account().await?;
 
@@ -76,23 +217,358 @@ pub async fn run(proof_path: PathBuf, file: Option<PathBuf>) -> anyhow::Result<(
         .verify_timestamp_tx(&txid_bytes, &proof_hash_bytes, Some(att.block_height))
         .await?;
 
-    if result.valid {
-        println!();
-        print_success("VALID TIMESTAMP (verified on-chain)");
-        print_info("Network", &att.network.to_string());
-        print_info("Block", &att.block_height.to_string());
-        print_info("Time", &att.timestamp().to_rfc3339());
-        print_info("TXID", att.txid_hex());
-        print_link("Explorer", &att.explorer_link());
+    // Reports record the depth even when none is required
+    let tip = if result.valid && (require_confirmations.is_some() || report.is_some()) {
+        Some(wallet.get_block_height().await?)
     } else {
-        println!();
-        print_error("VERIFICATION FAILED");
-        if let Some(error) = result.error {
+        None
+    };
+    let confirmations = tip.map(|tip| confirmations_at(att.block_height, tip));
+    evidence.chain = Some(json!({
+        "lightwalletd": lightwalletd,
+        "txid": att.txid_hex(),
+        "block_height": att.block_height,
+        "memo_hash": result.memo_hash.as_ref().map(hash_to_hex),
+        "chain_tip": tip,
+        "confirmations": confirmations,
+        "checked_at": chrono::Utc::now().to_rfc3339(),
+    }));
+    let too_shallow = confirmations
+        .zip(require_confirmations)
+        .is_some_and(|(depth, required)| depth < required);
+
+    if too_shallow {
+        print_line("");
+        print_error(&t!("verify-too-shallow"));
+        print_info(
+            &t!("label-confirmations"),
+            &t!(
+                "verify-confirmations-required",
+                depth = confirmations.unwrap_or_default(),
+                required = require_confirmations.unwrap_or_default()
+            ),
+        );
+        print_info(&t!("label-block"), &att.block_height.to_string());
+        print_info(&t!("label-txid"), att.txid_hex());
+    } else if result.valid {
+        print_line("");
+        print_success(&t!("verify-valid"));
+        print_info(&t!("label-network"), &att.network.to_string());
+        print_info(&t!("label-block"), &att.block_height.to_string());
+        if let Some(depth) = confirmations {
+            print_info(&t!("label-confirmations"), &depth.to_string());
+        }
+        print_info(&t!("label-time"), &att.timestamp().to_rfc3339());
+        print_info(&t!("label-txid"), att.txid_hex());
+        print_link(&t!("label-explorer"), &explorer_link(att));
+    } else {
+        print_line("");
+        print_error(&t!("verify-failed"));
+        if let Some(error) = &result.error {
             debug!("Verification error detail: {}", error);
-            print_info("Reason", &error);
+            print_info(&t!("label-reason"), error);
+        }
+        print_info(&t!("label-txid"), att.txid_hex());
+    }
+
+    finish(
+        report.as_ref(),
+        &evidence,
+        json!({
+            "status": if too_shallow {
+                "too_shallow"
+            } else if result.valid {
+                "valid"
+            } else {
+                "invalid"
+            },
+            "valid": result.valid && !too_shallow,
+            "hash": proof.hash,
+            "hash_algorithm": algorithm.name(),
+            "attestation": attestation_json(att),
+            "confirmations": confirmations,
+            "required_confirmations": require_confirmations,
+            "error": result.error,
+        }),
+    )?;
+
+    if !result.valid {
+        return Err(exit::Status(exit::INVALID).into());
+    }
+    if too_shallow {
+        return Err(exit::Status(exit::TOO_SHALLOW).into());
+    }
+    Ok(())
+}
+
+/// Print the JSON result and write the `--report`, if one was requested
+fn finish(
+    report: Option<&ReportOptions>,
+    evidence: &VerificationReport,
+    verdict: Value,
+) -> anyhow::Result<()> {
+    if let Some(options) = report {
+        let signature = evidence.write(&verdict, &options.path, options.sign.as_deref())?;
+        print_info(&t!("label-report"), &options.path.display().to_string());
+        if let Some(signature) = signature {
+            print_info(&t!("label-signature"), &signature.display().to_string());
+        }
+    }
+    print_json(&verdict);
+    Ok(())
+}
+
+/// Number of confirmations of a block at `height` when the chain tip is `tip`
+fn confirmations_at(height: u32, tip: u64) -> u64 {
+    (tip + 1).saturating_sub(u64::from(height))
+}
+
+/// Outcome of one proof in a batch verification
+struct BatchEntry {
+    proof: PathBuf,
+    file: Option<PathBuf>,
+    status: &'static str,
+    block_height: Option<u32>,
+    error: Option<String>,
+}
+
+impl BatchEntry {
+    fn passed(&self) -> bool {
+        self.status == "valid"
+    }
+}
+
+pub async fn run_batch(
+    dir: PathBuf,
+    require_confirmations: Option<u64>,
+    jobs: Option<usize>,
+) -> anyhow::Result<()> {
+    print_header(&t!("verify-batch-header"));
+    info!("Verifying proofs under {}", dir.display());
+
+    let proofs = find_proofs_with(&dir, |path, e| {
+        warn!("Skipping {}: {e}", path.display());
+    })?;
+    if proofs.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No .zots proofs found in {}",
+            dir.display()
+        ));
+    }
+    print_info(&t!("label-directory"), &dir.display().to_string());
+    print_info(&t!("label-proofs"), &proofs.len().to_string());
+
+    let mut entries: Vec<BatchEntry> = proofs
+        .into_iter()
+        .map(|proof_path| {
+            let sibling = proof_path.with_extension("");
+            BatchEntry {
+                file: sibling.is_file().then_some(sibling),
+                proof: proof_path,
+                status: "valid",
+                block_height: None,
+                error: None,
+            }
+        })
+        .collect();
+    let file_hashes = hash_siblings(&entries, resolve_jobs(jobs));
+
+    // Opened on first use so a directory of pending proofs needs no wallet
+    let mut wallet: Option<ZotsWallet> = None;
+    let mut tip = None;
+    for (entry, file_hash) in entries.iter_mut().zip(file_hashes) {
+        print_status(&t!(
+            "verify-batch-progress",
+            path = entry.proof.display().to_string()
+        ));
+        if let Err(e) = verify_entry(entry, file_hash, &mut wallet).await {
+            entry.status = error_status(&e);
+            entry.error = Some(format!("{e:#}"));
+        } else if let Some(required) = require_confirmations
+            && let Some(height) = entry.block_height
+            && entry.passed()
+            && let Some(wallet) = wallet.as_mut()
+        {
+            // A failed tip lookup fails this entry only; the next one retries it
+            let tip = match tip {
+                Some(tip) => tip,
+                None => match wallet.get_block_height().await {
+                    Ok(height) => *tip.insert(height),
+                    Err(e) => {
+                        entry.status = error_status(&e);
+                        entry.error = Some(format!("Could not get the chain tip: {e:#}"));
+                        continue;
+                    }
+                },
+            };
+            let depth = confirmations_at(height, tip);
+            if depth < required {
+                entry.status = "too_shallow";
+                entry.error = Some(format!("{depth} confirmations, {required} required"));
+            }
         }
-        print_info("TXID", att.txid_hex());
     }
 
+    print_line("");
+    print_line(&format!(
+        "{:<48} {:<14} {:>9}",
+        t!("verify-column-proof"),
+        t!("verify-column-status"),
+        t!("verify-column-block")
+    ));
+    for entry in &entries {
+        let block = entry
+            .block_height
+            .map(|h| h.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        print_line(&format!(
+            "{:<48} {:<14} {:>9}",
+            entry.proof.display(),
+            entry.status,
+            block
+        ));
+        if let Some(error) = &entry.error {
+            print_line(&format!("    {error}"));
+        }
+    }
+
+    let failed = entries.iter().filter(|e| !e.passed()).count();
+    print_line("");
+    if failed == 0 {
+        print_success(&t!("verify-batch-passed", count = entries.len()));
+    } else {
+        print_error(&t!(
+            "verify-batch-failed",
+            failed = failed,
+            total = entries.len()
+        ));
+    }
+
+    print_json(&json!({
+        "total": entries.len(),
+        "valid": entries.len() - failed,
+        "failed": failed,
+        "results": entries
+            .iter()
+            .map(|e| json!({
+                "proof": e.proof.display().to_string(),
+                "file": e.file.as_ref().map(|f| f.display().to_string()),
+                "status": e.status,
+                "valid": e.passed(),
+                "block_height": e.block_height,
+                "error": e.error,
+            }))
+            .collect::<Vec<_>>(),
+    }));
+
+    // Exit with the most serious failure in the batch
+    let worst = [
+        "error",
+        "invalid",
+        "hash_mismatch",
+        "network_error",
+        "pending",
+        "too_shallow",
+    ]
+    .into_iter()
+    .find(|status| entries.iter().any(|e| e.status == *status));
+    if let Some(status) = worst {
+        return Err(exit::Status(status_exit_code(status)).into());
+    }
+    Ok(())
+}
+
+/// Batch entry status for an entry that could not be checked
+fn error_status(error: &anyhow::Error) -> &'static str {
+    if is_network_error(error) {
+        "network_error"
+    } else {
+        "error"
+    }
+}
+
+/// Exit code for a batch entry status
+fn status_exit_code(status: &str) -> i32 {
+    match status {
+        "pending" => exit::PENDING,
+        "hash_mismatch" => exit::HASH_MISMATCH,
+        "network_error" => exit::NETWORK,
+        "invalid" => exit::INVALID,
+        "too_shallow" => exit::TOO_SHALLOW,
+        _ => exit::FAILURE,
+    }
+}
+
+/// Hash every entry's sibling file, each with its proof's algorithm
+///
+/// Entries without a sibling, or whose proof cannot be read (reported later
+/// by [`verify_entry`]), get `None`.
+fn hash_siblings(entries: &[BatchEntry], jobs: usize) -> Vec<Option<zots_core::Result<Hash256>>> {
+    let inputs: Vec<_> = entries
+        .iter()
+        .map(|entry| {
+            let file = entry.file.as_deref()?;
+            let proof = TimestampProof::load(&entry.proof).ok()?;
+            Some((file, proof.hash_algorithm()))
+        })
+        .collect();
+    let work: Vec<_> = inputs.iter().flatten().copied().collect();
+
+    let pb = count_bar(work.len() as u64, &t!("verify-hashing-files"));
+    let mut hashes = hash_files(&work, jobs, || pb.inc(1)).into_iter();
+    pb.finish_and_clear();
+
+    inputs
+        .iter()
+        .map(|input| input.and_then(|_| hashes.next()))
+        .collect()
+}
+
+/// Check one proof structurally, against its sibling file, then on-chain
+async fn verify_entry(
+    entry: &mut BatchEntry,
+    file_hash: Option<zots_core::Result<Hash256>>,
+    wallet: &mut Option<ZotsWallet>,
+) -> anyhow::Result<()> {
+    let proof = TimestampProof::load(&entry.proof)?;
+    let proof_hash_bytes = proof.hash_bytes()?;
+
+    if let Some(file_path) = &entry.file {
+        let file_hash = match file_hash {
+            Some(hash) => hash?,
+            None => hash_file_with(file_path, proof.hash_algorithm())?,
+        };
+        if file_hash != proof_hash_bytes {
+            entry.status = "hash_mismatch";
+            return Ok(());
+        }
+    }
+
+    let Some(att) = proof.attestations.first() else {
+        entry.status = "pending";
+        return Ok(());
+    };
+    entry.block_height = Some(att.block_height);
+
+    let wallet = match wallet {
+        Some(wallet) => wallet,
+        None => {
+            let config = ZcashConfig::load(None)?;
+            let mut opened = ZotsWallet::new(config).await?;
+            opened.init_account().await?;
+            wallet.insert(opened)
+        }
+    };
+    let result = wallet
+        .verify_timestamp_tx(
+            &att.txid_bytes()?,
+            &proof_hash_bytes,
+            Some(att.block_height),
+        )
+        .await?;
+    if !result.valid {
+        entry.status = "invalid";
+        entry.error = result.error;
+    }
     Ok(())
 }
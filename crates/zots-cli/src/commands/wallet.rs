@@ -4,96 +4,447 @@
 //! - `sync` - Sync wallet state with the blockchain
 //! - `balance` - Show balance breakdown by pool (Orchard, Sapling, Transparent)
 //! - `address` - Show unified receiving address
+//! - `addresses` - List all generated addresses
+//! - `new-address` - Generate a new diversified address
 //! - `info` - Show comprehensive wallet information
+//! - `history` - List transactions and recover lost proofs
+//! - `shield` - Move transparent funds to the Orchard pool
+//! - `export-viewing-key` - Export the UFVK for watch-only use
+//! - `import` - Set up a watch-only wallet from a UFVK
 
+use crate::i18n::t;
 use crate::output::*;
-use indicatif::{ProgressBar, ProgressStyle};
-use zots_zcash::{ZcashConfig, ZotsWallet};
+use serde_json::json;
+use std::path::PathBuf;
+use tracing::info;
+use zots_core::{HashAlgorithm, Network, TimestampProof, ZcashAttestation, hash_to_hex};
+use zots_zcash::{
+    HistoryEntry, TESTNET_FAUCET_URL, ZcashConfig, ZotsWallet, memo_text, write_private_file,
+};
 
 pub async fn sync() -> anyhow::Result<()> {
-    print_header("Syncing Wallet");
+    print_header(&t!("wallet-sync-header"));
 
-    let config = ZcashConfig::from_env()?;
+    let config = ZcashConfig::load(None)?;
     let mut wallet = ZotsWallet::new(config).await?;
     wallet.init_account().await?;
 
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.green} {msg}")
-            .unwrap(),
-    );
-    pb.set_message("Syncing with blockchain...");
-
-    wallet.sync().await?;
+    sync_wallet(&mut wallet).await?;
+    print_success(&t!("wallet-synchronized"));
 
-    pb.finish_with_message("Sync complete");
-    print_success("Wallet synchronized");
+    print_json(&json!({ "synced": true }));
 
     Ok(())
 }
 
-pub async fn balance() -> anyhow::Result<()> {
-    print_header("Wallet Balance");
+pub async fn balance(breakdown: bool) -> anyhow::Result<()> {
+    print_header(&t!("wallet-balance-header"));
 
-    let config = ZcashConfig::from_env()?;
+    let config = ZcashConfig::load(None)?;
     let mut wallet = ZotsWallet::new(config).await?;
     wallet.init_account().await?;
 
-    print_status("Syncing wallet...");
-    wallet.sync().await?;
+    sync_wallet(&mut wallet).await?;
 
     let balance = wallet.get_balance()?;
     let zec = balance as f64 / 100_000_000.0;
 
-    print_info("Balance", &format!("{zec:.8} ZEC ({balance} zatoshis)"));
+    print_info(
+        &t!("label-balance"),
+        &t!("zec-amount", zec = format!("{zec:.8}"), zatoshis = balance),
+    );
+
+    if !breakdown {
+        print_json(&json!({ "balance_zatoshis": balance }));
+        return Ok(());
+    }
+
+    let pools = wallet.get_balance_breakdown()?;
+    print_line("");
+    for (name, spendable, pending) in [
+        (t!("pool-orchard"), pools.orchard, pools.orchard_pending),
+        (t!("pool-sapling"), pools.sapling, pools.sapling_pending),
+        (
+            t!("pool-transparent"),
+            pools.transparent,
+            pools.transparent_pending,
+        ),
+    ] {
+        let mut line = t!(
+            "wallet-pool-spendable",
+            zec = format!("{:.8}", spendable as f64 / 100_000_000.0)
+        );
+        if pending > 0 {
+            line.push_str(&t!(
+                "wallet-pool-pending",
+                zec = format!("{:.8}", pending as f64 / 100_000_000.0)
+            ));
+        }
+        print_info(&name, &line);
+    }
+
+    // Timestamps are shielded transactions, so transparent funds must be shielded first
+    let transparent = pools.transparent + pools.transparent_pending;
+    if transparent > 0 {
+        print_line("");
+        print_warning(&t!("wallet-transparent-warning"));
+        print_status(&t!("wallet-transparent-hint"));
+    }
+
+    print_json(&json!({
+        "balance_zatoshis": balance,
+        "spendable_zatoshis": pools.spendable(),
+        "pending_zatoshis": pools.pending(),
+        "pools": {
+            "orchard": { "spendable": pools.orchard, "pending": pools.orchard_pending },
+            "sapling": { "spendable": pools.sapling, "pending": pools.sapling_pending },
+            "transparent": { "spendable": pools.transparent, "pending": pools.transparent_pending },
+        },
+        "shield_recommended": transparent > 0,
+    }));
 
     Ok(())
 }
 
 pub async fn address() -> anyhow::Result<()> {
-    print_header("Wallet Address");
+    print_header(&t!("wallet-address-header"));
 
-    let config = ZcashConfig::from_env()?;
+    let config = ZcashConfig::load(None)?;
     let mut wallet = ZotsWallet::new(config).await?;
     wallet.init_account().await?;
 
     let address = wallet.get_address()?;
-    print_info("Address", &address);
+    print_info(&t!("label-address"), &address);
+
+    print_line("");
+    print_status(&t!("wallet-fund-hint"));
+    print_link(&t!("label-faucet"), TESTNET_FAUCET_URL);
+
+    print_json(&json!({ "address": address }));
+
+    Ok(())
+}
+
+pub async fn addresses() -> anyhow::Result<()> {
+    print_header(&t!("wallet-addresses-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let mut wallet = ZotsWallet::new(config).await?;
+    wallet.init_account().await?;
+
+    let addresses = wallet.get_all_addresses()?;
+    for (index, address) in addresses.iter().enumerate() {
+        print_info(&format!("#{index}"), address);
+    }
+
+    print_json(&json!({ "addresses": addresses }));
+
+    Ok(())
+}
+
+pub async fn new_address() -> anyhow::Result<()> {
+    print_header(&t!("wallet-new-address-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let mut wallet = ZotsWallet::new(config).await?;
+    wallet.init_account().await?;
 
-    println!();
-    print_status("Fund this address with testnet ZEC from:");
-    print_link("Faucet", "https://testnet.zecfaucet.com/");
+    let address = wallet.get_new_address()?;
+    print_info(&t!("label-address"), &address);
+    print_success(&t!("wallet-new-address-done"));
+
+    print_json(&json!({ "address": address }));
 
     Ok(())
 }
 
 pub async fn info() -> anyhow::Result<()> {
-    print_header("Wallet Info");
+    print_header(&t!("wallet-info-header"));
 
-    let config = ZcashConfig::from_env()?;
+    let config = ZcashConfig::load(None)?;
     let mut wallet = ZotsWallet::new(config.clone()).await?;
     wallet.init_account().await?;
 
-    print_status("Syncing wallet...");
-    wallet.sync().await?;
+    sync_wallet(&mut wallet).await?;
 
     let height = wallet.get_block_height().await?;
     let balance = wallet.get_balance()?;
     let address = wallet.get_address()?;
 
-    print_info("Network", &config.network.to_string());
+    print_info(&t!("label-network"), &config.network.to_string());
     print_info("Lightwalletd", &config.lightwalletd_url);
-    print_info("Data Dir", &config.data_dir.display().to_string());
-    print_info("Block Height", &height.to_string());
     print_info(
-        "Balance",
-        &format!(
-            "{:.8} ZEC ({balance} zatoshis)",
-            balance as f64 / 100_000_000.0
+        &t!("label-data-dir"),
+        &config.data_dir.display().to_string(),
+    );
+    print_info(&t!("label-block-height"), &height.to_string());
+    print_info(
+        &t!("label-balance"),
+        &t!(
+            "zec-amount",
+            zec = format!("{:.8}", balance as f64 / 100_000_000.0),
+            zatoshis = balance
+        ),
+    );
+    print_info(&t!("label-address"), &address);
+
+    print_json(&json!({
+        "network": config.network.to_string(),
+        "lightwalletd": config.lightwalletd_url,
+        "data_dir": config.data_dir.display().to_string(),
+        "block_height": height,
+        "balance_zatoshis": balance,
+        "address": address,
+    }));
+
+    Ok(())
+}
+
+pub async fn history(
+    limit: usize,
+    recover: Option<String>,
+    output: Option<PathBuf>,
+    hash_algorithm: Option<HashAlgorithm>,
+) -> anyhow::Result<()> {
+    print_header(&t!("wallet-history-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let network = config.network;
+    let hash_algorithm = hash_algorithm.unwrap_or(config.hash_algorithm);
+    let mut wallet = ZotsWallet::new(config).await?;
+    wallet.init_account().await?;
+
+    sync_wallet(&mut wallet).await?;
+
+    if let Some(txid) = recover {
+        let entry = wallet
+            .get_transaction_history(usize::MAX)?
+            .into_iter()
+            .find(|entry| entry.txid.eq_ignore_ascii_case(&txid))
+            .ok_or_else(|| anyhow::anyhow!("Transaction {txid} not found in wallet history"))?;
+        return recover_proofs(&entry, network, hash_algorithm, output);
+    }
+
+    let history = wallet.get_transaction_history(limit)?;
+    if history.is_empty() {
+        print_status(&t!("wallet-history-empty"));
+    }
+    for entry in &history {
+        let height = entry
+            .height
+            .map_or_else(|| t!("wallet-history-pending"), |h| h.to_string());
+        let kind = if entry.is_timestamp() {
+            "timestamp"
+        } else if entry.is_sent {
+            "sent"
+        } else {
+            "received"
+        };
+        print_line("");
+        print_info(
+            &height,
+            &t!(
+                "wallet-history-entry",
+                txid = entry.txid.as_str(),
+                kind = kind
+            ),
+        );
+        print_info(
+            &t!("label-amount"),
+            &t!("amount-zatoshis", amount = format!("{:+}", entry.amount)),
+        );
+        for hash in entry.timestamp_hashes() {
+            print_info(&t!("label-hash"), &hash_to_hex(&hash));
+        }
+        for text in entry.memos.iter().filter_map(|memo| memo_text(memo)) {
+            print_info(&t!("label-memo"), &text);
+        }
+    }
+
+    print_json(&json!({
+        "transactions": history.iter().map(|entry| json!({
+            "txid": entry.txid,
+            "block_height": entry.height,
+            "block_time": entry.block_time,
+            "amount_zatoshis": entry.amount,
+            "is_sent": entry.is_sent,
+            "is_timestamp": entry.is_timestamp(),
+            "hashes": entry.timestamp_hashes().iter().map(hash_to_hex).collect::<Vec<_>>(),
+            "memos": entry.memos.iter().filter_map(|memo| memo_text(memo)).collect::<Vec<_>>(),
+        })).collect::<Vec<_>>(),
+    }));
+
+    Ok(())
+}
+
+/// Rebuild one proof per timestamp memo of a mined transaction
+fn recover_proofs(
+    entry: &HistoryEntry,
+    network: Network,
+    hash_algorithm: HashAlgorithm,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let hashes = entry.timestamp_hashes();
+    if hashes.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Transaction {} carries no timestamp memo",
+            entry.txid
+        ));
+    }
+    if hashes.len() > 1 && output.is_some() {
+        return Err(anyhow::anyhow!(
+            "Transaction timestamps {} hashes; omit --output to write <hash>.zots for each",
+            hashes.len()
+        ));
+    }
+    let (block_height, block_time) = entry.height.zip(entry.block_time).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Transaction {} is not confirmed yet; try again later",
+            entry.txid
+        )
+    })?;
+
+    let mut proofs = Vec::with_capacity(hashes.len());
+    for hash in &hashes {
+        let hex = hash_to_hex(hash);
+        let path = output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("{hex}.zots")));
+        if path.exists() {
+            return Err(anyhow::anyhow!(
+                "{} already exists; refusing to overwrite",
+                path.display()
+            ));
+        }
+
+        let mut proof = TimestampProof::new_with_algorithm(*hash, hash_algorithm);
+        proof.add_attestation(ZcashAttestation {
+            network,
+            txid: entry.txid.to_lowercase(),
+            block_height,
+            block_time,
+            memo_offset: 0,
+        });
+        proof.save(&path)?;
+        print_success(&t!(
+            "wallet-recovered-proof",
+            path = path.display().to_string()
+        ));
+        proofs.push(json!({ "hash": hex, "proof_path": path.display().to_string() }));
+    }
+
+    print_json(&json!({
+        "txid": entry.txid,
+        "block_height": block_height,
+        "proofs": proofs,
+    }));
+
+    Ok(())
+}
+
+pub async fn import(ufvk: String) -> anyhow::Result<()> {
+    print_header(&t!("wallet-import-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let mut wallet = ZotsWallet::new(config).await?;
+    wallet.import_viewing_key(&ufvk).await?;
+
+    print_success(&t!("wallet-import-done"));
+    print_status(&t!("wallet-import-hint"));
+
+    print_json(&json!({ "imported": true, "watch_only": true }));
+
+    Ok(())
+}
+
+pub async fn export_viewing_key(output: Option<PathBuf>, confirm: bool) -> anyhow::Result<()> {
+    print_header(&t!("wallet-export-header"));
+    print_warning(&t!("wallet-export-warning"));
+
+    if !confirm && !prompt_confirm(&t!("wallet-export-confirm"), "--confirm")? {
+        return Err(anyhow::anyhow!("Export cancelled"));
+    }
+
+    let config = ZcashConfig::load(None)?;
+    let wallet = ZotsWallet::new(config).await?;
+    let ufvk = wallet.export_viewing_key()?;
+
+    match &output {
+        Some(path) => {
+            write_private_file(path, &format!("{ufvk}\n"))?;
+            print_success(&t!(
+                "wallet-export-written",
+                path = path.display().to_string()
+            ));
+        }
+        None => print_info("UFVK", &ufvk),
+    }
+
+    print_json(&json!({
+        "ufvk": if output.is_none() { Some(&ufvk) } else { None },
+        "output": output.as_ref().map(|p| p.display().to_string()),
+    }));
+
+    Ok(())
+}
+
+pub async fn shield(no_wait: bool, yes: bool) -> anyhow::Result<()> {
+    print_header(&t!("wallet-shield-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let mut wallet = ZotsWallet::new(config).await?;
+    wallet.ensure_can_spend()?;
+    wallet.init_account().await?;
+
+    sync_wallet(&mut wallet).await?;
+
+    let preview = wallet.preview_shielding()?;
+    print_info(
+        &t!("label-amount"),
+        &t!(
+            "zec-amount",
+            zec = format!("{:.8}", preview.amount as f64 / 100_000_000.0),
+            zatoshis = preview.amount
         ),
     );
-    print_info("Address", &address);
+    print_info(
+        &t!("label-fee"),
+        &t!("amount-zatoshis", amount = preview.fee),
+    );
+
+    if !yes && !prompt_confirm(&t!("wallet-shield-confirm"), "--yes")? {
+        return Err(anyhow::anyhow!("Shielding cancelled"));
+    }
+
+    let pb = spinner(&t!("wallet-shield-creating"));
+    info!("Shielding {} zatoshis", preview.amount);
+    let result = wallet.shield_previewed(&preview).await?;
+    pb.finish_with_message(t!("stamp-broadcast"));
+    print_info(&t!("label-txid"), &result.txid);
+
+    if no_wait {
+        print_json(&json!({
+            "status": "pending",
+            "txid": result.txid,
+            "amount_zatoshis": preview.amount,
+            "fee_zatoshis": preview.fee,
+        }));
+        return Ok(());
+    }
+
+    let confirmation = wait_for_confirmation(&mut wallet, &result.txid, 10).await?;
+    print_success(&t!(
+        "wallet-shield-done",
+        height = confirmation.block_height
+    ));
+
+    print_json(&json!({
+        "status": "confirmed",
+        "txid": result.txid,
+        "amount_zatoshis": preview.amount,
+        "fee_zatoshis": preview.fee,
+        "block_height": confirmation.block_height,
+    }));
 
     Ok(())
 }
@@ -0,0 +1,426 @@
+//! Watch command implementation.
+//!
+//! Long-running mode that stamps files as they appear or change:
+//! 1. Watches the given directories recursively for created/modified files
+//! 2. Filters them through the `--include` / `--exclude` globs
+//! 3. Every interval (one block by default), stamps the queued files in a
+//!    single transaction and writes a pending `<file>.zots` next to each
+//! 4. Upgrades those proofs in place once their transaction is mined
+//!
+//! Files whose proof already records their current hash are skipped, so
+//! rewriting a file with identical content (or the proof writes themselves)
+//! never triggers a new transaction. When a batch cannot be stamped its files
+//! stay queued and are retried with exponential backoff. Stop with Ctrl-C;
+//! a batch that is being stamped (sync, build and broadcast) is finished
+//! first, so a sent transaction always gets its proofs written.
+//!
+//! With `--webhook`, each batch's broadcast, confirmation or failure is also
+//! POSTed to the given URLs (see [`crate::webhook`]).
+
+use crate::cli::WebhookArgs;
+use crate::i18n::t;
+use crate::output::*;
+use crate::webhook::{WebhookEvent, Webhooks};
+use glob::Pattern;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+use zots_core::{HashAlgorithm, Network, TimestampProof, hash_file_with, hash_to_hex};
+use zots_zcash::{ConfirmationResult, ZcashConfig, ZotsWallet};
+
+use super::stamp::{default_output_path, open_synced_wallet};
+use super::upgrade::attach_confirmation;
+
+/// Longest wait between retries of a batch that failed to stamp
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30 * 60);
+
+/// Files waiting to be stamped, held back after a failed attempt
+///
+/// Not to be confused with [`zots_zcash::StampQueue`], which batches hashes
+/// for `serve`; this only tracks which files are due and the retry backoff.
+struct RetryQueue {
+    files: BTreeSet<PathBuf>,
+    /// Delay before the first retry, doubled after every further failure
+    base_delay: Duration,
+    failures: u32,
+    retry_at: Option<Instant>,
+}
+
+impl RetryQueue {
+    fn new(base_delay: Duration) -> Self {
+        Self {
+            files: BTreeSet::new(),
+            base_delay,
+            failures: 0,
+            retry_at: None,
+        }
+    }
+
+    fn push(&mut self, path: PathBuf) {
+        self.files.insert(path);
+    }
+
+    /// Take every queued file, unless the queue is empty or backing off
+    fn take_due(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        if self.files.is_empty() || self.retry_at.is_some_and(|at| now < at) {
+            return None;
+        }
+        Some(std::mem::take(&mut self.files).into_iter().collect())
+    }
+
+    /// Put back the files of a failed attempt; returns the delay before the retry
+    fn failed(&mut self, files: Vec<PathBuf>, now: Instant) -> Duration {
+        self.files.extend(files);
+        self.failures += 1;
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(self.failures - 1))
+            .min(MAX_RETRY_DELAY);
+        self.retry_at = Some(now + delay);
+        delay
+    }
+
+    fn succeeded(&mut self) {
+        self.failures = 0;
+        self.retry_at = None;
+    }
+}
+
+/// A broadcast batch whose proofs are still pending
+struct InFlight {
+    txid: String,
+    proofs: Vec<PathBuf>,
+}
+
+/// Include/exclude globs matched against paths relative to the watched directory
+struct Filter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl Filter {
+    fn new(include: &[String], exclude: &[String]) -> anyhow::Result<Self> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| Pattern::new(p).map_err(|e| anyhow::anyhow!("Invalid pattern '{p}': {e}")))
+                .collect::<anyhow::Result<Vec<_>>>()
+        };
+        Ok(Self {
+            include: compile(include)?,
+            exclude: compile(exclude)?,
+        })
+    }
+
+    fn accepts(&self, relative: &Path) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches_path(relative)))
+            && !self.exclude.iter().any(|p| p.matches_path(relative))
+    }
+}
+
+pub async fn run(
+    dirs: Vec<PathBuf>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    interval_secs: u64,
+    hash_algorithm: Option<HashAlgorithm>,
+    webhooks: WebhookArgs,
+) -> anyhow::Result<()> {
+    let webhooks = Webhooks::new(webhooks.urls, webhooks.webhook_secret)?;
+    print_header(&t!("watch-header"));
+
+    let config = ZcashConfig::load(None)?;
+    let hash_algorithm = hash_algorithm.unwrap_or(config.hash_algorithm);
+    let filter = Filter::new(&include, &exclude)?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = tx.send(event);
+    })?;
+    let mut roots = Vec::with_capacity(dirs.len());
+    for dir in dirs {
+        let root = dir
+            .canonicalize()
+            .map_err(|e| anyhow::anyhow!("Cannot watch {}: {e}", dir.display()))?;
+        watcher.watch(&root, RecursiveMode::Recursive)?;
+        print_info(&t!("label-directory"), &root.display().to_string());
+        roots.push(root);
+    }
+    print_info(&t!("label-interval"), &format!("{interval_secs}s"));
+    print_info(&t!("label-algorithm"), hash_algorithm.name());
+    if !webhooks.is_empty() {
+        print_info(&t!("label-webhooks"), &webhooks.len().to_string());
+    }
+
+    let mut wallet = open_synced_wallet(&config).await?;
+    print_status(&t!("watch-running"));
+
+    let interval = Duration::from_secs(interval_secs.max(1));
+    let mut queued = RetryQueue::new(interval);
+    let mut in_flight: Vec<InFlight> = Vec::new();
+    let mut ticker = tokio::time::interval(interval);
+    ticker.tick().await;
+
+    loop {
+        tokio::select! {
+            Some(event) = rx.recv() => match event {
+                Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+                    for path in event.paths {
+                        if is_candidate(&path, &roots, &filter) {
+                            debug!("Queued {}", path.display());
+                            queued.push(path);
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Watch error: {}", e),
+            },
+            _ = ticker.tick() => {
+                upgrade_in_flight(&mut wallet, config.network, &mut in_flight, &webhooks).await;
+                if let Some(files) = queued.take_due(Instant::now()) {
+                    let paths: Vec<String> = files.iter().map(|f| f.display().to_string()).collect();
+                    match stamp_queued(&mut wallet, files.clone(), hash_algorithm, &webhooks).await {
+                        Ok(batch) => {
+                            queued.succeeded();
+                            in_flight.extend(batch);
+                        }
+                        Err(e) => {
+                            let delay = queued.failed(files, Instant::now());
+                            print_warning(&t!(
+                                "watch-stamp-failed",
+                                error = format!("{e:#}"),
+                                secs = delay.as_secs()
+                            ));
+                            webhooks.send(
+                                WebhookEvent::Failed,
+                                serde_json::json!({ "files": paths, "error": format!("{e:#}") }),
+                            );
+                        }
+                    }
+                }
+            },
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    print_line("");
+    if !in_flight.is_empty() {
+        let pending: usize = in_flight.iter().map(|b| b.proofs.len()).sum();
+        print_warning(&t!("watch-still-pending", count = pending));
+    }
+    print_success(&t!("watch-stopped"));
+    Ok(())
+}
+
+/// Regular, non-proof files under a watched root that pass the filter
+fn is_candidate(path: &Path, roots: &[PathBuf], filter: &Filter) -> bool {
+    if !path.is_file() || path.extension().is_some_and(|ext| ext == "zots") {
+        return false;
+    }
+    roots
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .is_some_and(|relative| filter.accepts(relative))
+}
+
+/// Stamp changed files in one transaction, writing pending proofs
+async fn stamp_queued(
+    wallet: &mut ZotsWallet,
+    files: Vec<PathBuf>,
+    hash_algorithm: HashAlgorithm,
+    webhooks: &Webhooks,
+) -> anyhow::Result<Option<InFlight>> {
+    let mut stamps = Vec::with_capacity(files.len());
+    for file_path in files {
+        // The file may have been removed or renamed since the event
+        let Ok(hash) = hash_file_with(&file_path, hash_algorithm) else {
+            continue;
+        };
+        let output = default_output_path(&file_path);
+        let unchanged = TimestampProof::load(&output).ok().is_some_and(|p| {
+            p.hash_algorithm() == hash_algorithm && p.hash_bytes().ok() == Some(hash)
+        });
+        if unchanged {
+            debug!("{} already stamped", file_path.display());
+            continue;
+        }
+        stamps.push((file_path, hash, output));
+    }
+    if stamps.is_empty() {
+        return Ok(None);
+    }
+
+    let mut seen = HashSet::new();
+    let hashes: Vec<[u8; 32]> = stamps
+        .iter()
+        .map(|(_, hash, _)| *hash)
+        .filter(|hash| seen.insert(*hash))
+        .collect();
+
+    info!("Stamping {} changed files", stamps.len());
+    wallet.sync().await?;
+    let tx_result = wallet.create_batch_timestamp_tx(&hashes).await?;
+    print_success(&t!(
+        "watch-stamped",
+        count = stamps.len(),
+        txid = tx_result.txid.as_str()
+    ));
+
+    let registry = wallet.config().pending_store_path();
+    let mut proofs = Vec::with_capacity(stamps.len());
+    let mut events = Vec::with_capacity(stamps.len());
+    for (file_path, hash, output) in stamps {
+        let mut proof = TimestampProof::new_with_algorithm(hash, hash_algorithm);
+        proof.set_pending_txid(&tx_result.txid);
+        proof.save(&output)?;
+        super::pending::record(&registry, |store| store.track(&output, &proof));
+        print_info(&t!("label-file"), &file_path.display().to_string());
+        print_hash(&hash_to_hex(&hash), hash_algorithm.name());
+        let event = serde_json::json!({
+            "status": "pending",
+            "file": file_path.display().to_string(),
+            "hash": proof.hash,
+            "proof_path": output.display().to_string(),
+            "txid": tx_result.txid,
+        });
+        print_json(&event);
+        events.push(event);
+        proofs.push(output);
+    }
+    webhooks.send(
+        WebhookEvent::Broadcast,
+        serde_json::json!({ "txid": tx_result.txid, "proofs": events }),
+    );
+
+    Ok(Some(InFlight {
+        txid: tx_result.txid,
+        proofs,
+    }))
+}
+
+/// Attach attestations to the proofs of every batch that has been mined
+async fn upgrade_in_flight(
+    wallet: &mut ZotsWallet,
+    network: Network,
+    in_flight: &mut Vec<InFlight>,
+    webhooks: &Webhooks,
+) {
+    for batch in std::mem::take(in_flight) {
+        match wallet.fetch_confirmation(&batch.txid).await {
+            Ok(Some(confirmation)) => match complete_batch(
+                &batch,
+                &wallet.config().pending_store_path(),
+                network,
+                &confirmation,
+            ) {
+                Ok(attestations) => webhooks.send(
+                    WebhookEvent::Confirmed,
+                    serde_json::json!({
+                        "txid": batch.txid,
+                        "block_height": confirmation.block_height,
+                        "block_time": confirmation.block_time,
+                        "proofs": attestations,
+                    }),
+                ),
+                Err(e) => {
+                    print_warning(&t!(
+                        "watch-update-failed",
+                        txid = batch.txid.as_str(),
+                        error = format!("{e:#}")
+                    ));
+                    webhooks.send(
+                        WebhookEvent::Failed,
+                        serde_json::json!({ "txid": batch.txid, "error": format!("{e:#}") }),
+                    );
+                }
+            },
+            Ok(None) => in_flight.push(batch),
+            Err(e) => {
+                print_warning(&t!("watch-check-failed", error = format!("{e:#}")));
+                in_flight.push(batch);
+            }
+        }
+    }
+}
+
+/// Write the attestation into each proof of a mined batch
+///
+/// Returns the JSON event of every proof that was updated.
+fn complete_batch(
+    batch: &InFlight,
+    registry: &Path,
+    network: Network,
+    confirmation: &ConfirmationResult,
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    let mut events = Vec::with_capacity(batch.proofs.len());
+    for proof_path in &batch.proofs {
+        // Skip proofs replaced by a newer stamp of the same file
+        let Ok(mut proof) = TimestampProof::load(proof_path) else {
+            continue;
+        };
+        if proof.pending_txid.as_deref() != Some(batch.txid.as_str()) {
+            continue;
+        }
+        let attestation = attach_confirmation(
+            &mut proof,
+            proof_path,
+            registry,
+            network,
+            &batch.txid,
+            confirmation,
+        )?;
+        print_success(&t!(
+            "watch-confirmed",
+            path = proof_path.display().to_string(),
+            height = confirmation.block_height
+        ));
+        let event = serde_json::json!({
+            "status": "confirmed",
+            "proof_path": proof_path.display().to_string(),
+            "attestation": attestation_json(&attestation),
+        });
+        print_json(&event);
+        events.push(event);
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_queue_backs_off() {
+        let start = Instant::now();
+        let mut queue = RetryQueue::new(Duration::from_secs(10));
+        assert_eq!(queue.take_due(start), None);
+
+        queue.push(PathBuf::from("a"));
+        queue.push(PathBuf::from("b"));
+        let files = queue.take_due(start).unwrap();
+        assert_eq!(files, vec![PathBuf::from("a"), PathBuf::from("b")]);
+        assert_eq!(queue.take_due(start), None);
+
+        // A failed batch keeps its files, merged with newly queued ones
+        queue.push(PathBuf::from("c"));
+        assert_eq!(queue.failed(files, start), Duration::from_secs(10));
+        assert_eq!(queue.take_due(start + Duration::from_secs(9)), None);
+        let files = queue.take_due(start + Duration::from_secs(10)).unwrap();
+        assert_eq!(files.len(), 3);
+
+        // Each further failure doubles the delay, up to the cap
+        assert_eq!(queue.failed(files, start), Duration::from_secs(20));
+        let files = queue.take_due(start + Duration::from_secs(20)).unwrap();
+        for _ in 0..20 {
+            queue.failed(files.clone(), start);
+            queue.take_due(start + MAX_RETRY_DELAY).unwrap();
+        }
+        assert_eq!(queue.failed(files, start), MAX_RETRY_DELAY);
+
+        queue.succeeded();
+        assert_eq!(queue.take_due(start).unwrap().len(), 3);
+    }
+}
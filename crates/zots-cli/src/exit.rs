@@ -0,0 +1,113 @@
+//! Process exit codes
+//!
+//! A stable contract for scripts and CI jobs, shared by `stamp`, `verify`,
+//! `confirm` and `extract`. Print it with `zots verify --explain-exit-codes`.
+
+use crate::i18n::t;
+use crate::output::*;
+use serde_json::json;
+
+/// The command succeeded (for `verify`: the timestamp is valid)
+pub const SUCCESS: i32 = 0;
+/// Unclassified failure: bad input, I/O or wallet errors
+pub const FAILURE: i32 = 1;
+/// The proof is still pending confirmation
+pub const PENDING: i32 = 2;
+/// The original file or text does not match the proof's hash
+pub const HASH_MISMATCH: i32 = 3;
+/// lightwalletd could not be reached
+pub const NETWORK: i32 = 4;
+/// The attestation does not check out on-chain
+pub const INVALID: i32 = 5;
+/// The timestamp is valid but has fewer confirmations than required
+pub const TOO_SHALLOW: i32 = 6;
+/// The proof is well-formed but its anchor was not checked (`verify --offline`)
+pub const UNCHECKED: i32 = 7;
+/// The command line could not be parsed
+pub const USAGE: i32 = 64;
+
+/// Every code with its name and meaning
+pub const EXIT_CODES: &[(i32, &str, &str)] = &[
+    (
+        SUCCESS,
+        "success",
+        "Command succeeded; for verify, the timestamp is valid",
+    ),
+    (
+        FAILURE,
+        "failure",
+        "Unclassified error (bad input, I/O, wallet)",
+    ),
+    (
+        PENDING,
+        "pending",
+        "Proof not confirmed yet (verify of a pending proof, stamp or confirm timed out)",
+    ),
+    (
+        HASH_MISMATCH,
+        "hash_mismatch",
+        "Original file or text does not match the proof",
+    ),
+    (
+        NETWORK,
+        "network",
+        "lightwalletd unreachable or returned a transport error",
+    ),
+    (
+        INVALID,
+        "invalid",
+        "Attestation not found on-chain or memo does not contain the hash",
+    ),
+    (
+        TOO_SHALLOW,
+        "too_shallow",
+        "Valid, but fewer confirmations than --require-confirmations",
+    ),
+    (
+        UNCHECKED,
+        "unchecked",
+        "Proof is well-formed, but --offline did not check its anchor on-chain",
+    ),
+    (USAGE, "usage", "Invalid command-line arguments"),
+];
+
+/// A command outcome that ends the process with a non-zero code
+///
+/// Commands return it as their error once they have reported the outcome
+/// themselves; `main` then exits with the code without printing anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Status(pub i32);
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "exit status {}", self.0)
+    }
+}
+
+impl std::error::Error for Status {}
+
+/// Print the exit code table
+pub fn explain() -> anyhow::Result<()> {
+    print_header(&t!("exit-codes-header"));
+    for (code, name, meaning) in EXIT_CODES {
+        print_line(&format!("  {code:>3}  {name:<14} {meaning}"));
+    }
+    print_json(&json!({
+        "exit_codes": EXIT_CODES
+            .iter()
+            .map(|(code, name, meaning)| json!({ "code": code, "name": name, "meaning": meaning }))
+            .collect::<Vec<_>>(),
+    }));
+    Ok(())
+}
+
+/// Exit code for an error that reached `main`
+pub fn for_error(error: &anyhow::Error) -> i32 {
+    if let Some(status) = error.downcast_ref::<Status>() {
+        status.0
+    } else if zots_zcash::is_network_error(error) {
+        NETWORK
+    } else {
+        FAILURE
+    }
+}
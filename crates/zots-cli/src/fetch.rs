@@ -0,0 +1,183 @@
+//! Hashing remote content over HTTP(S) and IPFS.
+//!
+//! The response body is streamed straight into the hasher, so a large
+//! artifact is never held in memory or written to disk. What was fetched
+//! (final URL after redirects, ETag, byte count) is returned so it can be
+//! recorded in the proof metadata.
+//!
+//! `ipfs://` inputs are stamped from the digest inside the CID when possible
+//! and only fetched through the configured gateway otherwise.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tracing::{debug, info};
+use zots_core::{Cid, Hash256, HashAlgorithm, hash_reader_with_progress, hash_to_hex};
+
+use crate::i18n::t;
+use crate::output::{bytes_bar, spinner};
+
+/// Result of hashing a URL
+pub struct RemoteHash {
+    pub hash: Hash256,
+    /// URL the content was served from, after redirects
+    pub final_url: String,
+    pub etag: Option<String>,
+    /// Number of bytes hashed
+    pub content_length: u64,
+}
+
+impl RemoteHash {
+    /// Provenance entries for [`zots_core::TimestampProof::metadata`]
+    pub fn metadata(&self, requested_url: &str) -> BTreeMap<String, String> {
+        let mut metadata = BTreeMap::new();
+        metadata.insert("source_url".to_string(), requested_url.to_string());
+        if self.final_url != requested_url {
+            metadata.insert("final_url".to_string(), self.final_url.clone());
+        }
+        if let Some(etag) = &self.etag {
+            metadata.insert("etag".to_string(), etag.clone());
+        }
+        metadata.insert(
+            "content_length".to_string(),
+            self.content_length.to_string(),
+        );
+        metadata
+    }
+
+    /// Proof path in the current directory named after the last URL segment
+    /// (`report.pdf.zots`), or after the hash when the URL has none
+    pub fn default_output_path(&self) -> PathBuf {
+        let name = self
+            .final_url
+            .split(['?', '#'])
+            .next()
+            .and_then(|url| url.rsplit('/').next())
+            .filter(|segment| !segment.is_empty() && !segment.contains(':'))
+            .map(str::to_string)
+            .unwrap_or_else(|| hash_to_hex(&self.hash)[..16].to_string());
+        PathBuf::from(format!("{name}.zots"))
+    }
+}
+
+/// Whether a stamp input names an HTTP(S) URL rather than a local file
+pub fn is_url(input: &str) -> bool {
+    input.starts_with("https://") || input.starts_with("http://")
+}
+
+/// Whether a stamp input names remote content (HTTP(S) or IPFS)
+pub fn is_remote(input: &str) -> bool {
+    is_url(input) || Cid::is_ipfs_uri(input)
+}
+
+/// Download `url` and hash the body as it arrives
+///
+/// Runs the blocking HTTP client on a worker thread so the async runtime
+/// is not stalled by a slow server.
+pub async fn hash_url(url: &str, algorithm: HashAlgorithm) -> anyhow::Result<RemoteHash> {
+    let url = url.to_string();
+    tokio::task::spawn_blocking(move || hash_url_blocking(&url, algorithm)).await?
+}
+
+fn hash_url_blocking(url: &str, algorithm: HashAlgorithm) -> anyhow::Result<RemoteHash> {
+    info!("Fetching {}", url);
+    let client = reqwest::blocking::Client::builder()
+        .user_agent(concat!("zots/", env!("CARGO_PKG_VERSION")))
+        .timeout(None)
+        .build()?;
+    let response = client.get(url).send()?.error_for_status()?;
+
+    let final_url = response.url().to_string();
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    debug!("Final URL {}, ETag {:?}", final_url, etag);
+
+    let pb = match response.content_length() {
+        Some(total) => bytes_bar(total, &t!("fetch-downloading")),
+        None => spinner(&t!("fetch-downloading-unknown")),
+    };
+    let mut content_length = 0;
+    let hash = hash_reader_with_progress(response, algorithm, |done| {
+        content_length = done;
+        pb.set_position(done);
+    })?;
+    pb.finish_with_message(t!("stamp-hashing-complete"));
+
+    Ok(RemoteHash {
+        hash,
+        final_url,
+        etag,
+        content_length,
+    })
+}
+
+/// Result of hashing an `ipfs://` input
+pub struct IpfsHash {
+    pub cid: Cid,
+    pub hash: Hash256,
+    pub algorithm: HashAlgorithm,
+    /// Set when the content had to be fetched through the gateway
+    pub fetched: Option<RemoteHash>,
+}
+
+impl IpfsHash {
+    /// Provenance entries for [`zots_core::TimestampProof::metadata`]
+    pub fn metadata(&self, gateway_url: &str) -> BTreeMap<String, String> {
+        let mut metadata = self
+            .fetched
+            .as_ref()
+            .map(|remote| remote.metadata(gateway_url))
+            .unwrap_or_default();
+        metadata.insert("ipfs_cid".to_string(), self.cid.to_string());
+        metadata
+    }
+}
+
+/// Gateway URL serving the content of `cid`
+pub fn gateway_url(gateway: &str, cid: &Cid) -> String {
+    format!("{}/ipfs/{cid}", gateway.trim_end_matches('/'))
+}
+
+/// Hash the content named by an `ipfs://` CID
+///
+/// When the CID names raw bytes with a SHA-256 or BLAKE3 multihash that agrees
+/// with `requested` (or nothing was requested), its digest is used directly
+/// and nothing is downloaded. Otherwise, including every dag-pb CID, the
+/// content is fetched from `gateway` and hashed with `requested`, falling
+/// back to `fallback`.
+pub async fn hash_ipfs(
+    input: &str,
+    requested: Option<HashAlgorithm>,
+    fallback: HashAlgorithm,
+    gateway: &str,
+) -> anyhow::Result<IpfsHash> {
+    let cid = Cid::parse(input)?;
+    if let (Some(hash), Some(algorithm)) = (cid.hash(), cid.hash_algorithm())
+        && requested.is_none_or(|r| r == algorithm)
+    {
+        debug!("Using the {} digest of {}", algorithm.name(), cid);
+        return Ok(IpfsHash {
+            cid,
+            hash,
+            algorithm,
+            fetched: None,
+        });
+    }
+
+    let algorithm = requested.unwrap_or(fallback);
+    info!(
+        "{} is not a raw {} CID; fetching it through {}",
+        cid,
+        algorithm.name(),
+        gateway
+    );
+    let remote = hash_url(&gateway_url(gateway, &cid), algorithm).await?;
+    Ok(IpfsHash {
+        cid,
+        hash: remote.hash,
+        algorithm,
+        fetched: Some(remote),
+    })
+}
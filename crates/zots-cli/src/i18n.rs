@@ -0,0 +1,97 @@
+//! Localized user-facing messages
+//!
+//! Human-readable output is looked up in `locales/<lang>/zots.ftl`, compiled
+//! into the binary and formatted by [`zots_core::i18n`]. The locale comes
+//! from `--lang`, then `ZOTS_LANG`, then the POSIX locale variables.
+//!
+//! JSON output never goes through this module, so its keys and values stay
+//! language-neutral.
+
+use std::sync::OnceLock;
+use zots_core::i18n::{FluentArgs, Locale, Localizer, env_locale, language_code};
+
+/// Supported locales and their messages; the first is the fallback
+const LOCALES: &[Locale] = &[
+    Locale {
+        code: "en",
+        name: "English",
+        source: include_str!("../locales/en/zots.ftl"),
+    },
+    Locale {
+        code: "es",
+        name: "Español",
+        source: include_str!("../locales/es/zots.ftl"),
+    },
+];
+
+static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+
+/// Select the locale for this process
+///
+/// `lang` is the `--lang` option; without it the environment decides.
+/// Only the first call has an effect.
+pub fn init(lang: Option<&str>) {
+    let requested = lang
+        .map(str::to_string)
+        .or_else(|| std::env::var("ZOTS_LANG").ok())
+        .or_else(env_locale);
+    let mut localizer = Localizer::new(LOCALES);
+    localizer.select(requested.as_deref());
+    let _ = LOCALIZER.set(localizer);
+}
+
+/// Whether a locale name like `es_ES.UTF-8` maps to a bundled language
+pub fn is_supported(locale: &str) -> bool {
+    language_code(LOCALES, locale).is_some()
+}
+
+/// Supported language codes, for messages about unsupported ones
+pub fn supported() -> Vec<&'static str> {
+    LOCALES.iter().map(|locale| locale.code).collect()
+}
+
+/// Format the message `id`, falling back to English and then to the id itself
+pub fn tr(id: &str, args: Option<&FluentArgs>) -> String {
+    localizer().format(id, args)
+}
+
+fn localizer() -> &'static Localizer {
+    LOCALIZER.get_or_init(|| Localizer::new(LOCALES))
+}
+
+/// Format a localized message: `t!("id")` or `t!("id", name = value, ...)`
+macro_rules! t {
+    ($id:expr) => {
+        $crate::i18n::tr($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = zots_core::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr($id, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translations_only_use_english_keys() {
+        let localizer = Localizer::new(LOCALES);
+        for locale in &LOCALES[1..] {
+            assert_eq!(localizer.unknown_ids(locale.code), Vec::<&str>::new());
+        }
+    }
+
+    #[test]
+    fn test_fallback() {
+        let mut localizer = Localizer::new(LOCALES);
+        let english = localizer.format("stamp-dry-run-header", None);
+        localizer.select(Some("fr_FR.UTF-8"));
+        assert_eq!(localizer.format("stamp-dry-run-header", None), english);
+        localizer.select(Some("es_ES.UTF-8"));
+        assert_ne!(localizer.format("stamp-dry-run-header", None), english);
+        assert_eq!(localizer.format("no-such-message", None), "no-such-message");
+    }
+}
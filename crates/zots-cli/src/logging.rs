@@ -0,0 +1,80 @@
+//! Diagnostic logging setup
+//!
+//! Logs go to stderr at `--log-level`. With `--log-file` (or the `log_file`
+//! config setting) they are also written to a daily-rotated file at
+//! `--log-file-level`, so unattended `watch` and `serve` runs can be
+//! diagnosed after the fact.
+
+use crate::cli::LogLevelArg;
+use std::path::Path;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{Layer, Registry, fmt};
+
+/// Rotated log files kept before the oldest is deleted
+const MAX_LOG_FILES: usize = 7;
+
+/// Tracing layer writing to the log file
+pub type FileLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+/// Initialize global logging to stderr and, optionally, a log file.
+pub fn init(level: LevelFilter, file: Option<FileLayer>) {
+    // Ignore errors if already initialized (e.g., in tests)
+    let _ = tracing_subscriber::registry()
+        .with(file)
+        .with(
+            fmt::layer()
+                .with_target(false)
+                .with_writer(std::io::stderr)
+                .with_filter(level),
+        )
+        .try_init();
+}
+
+/// Layer appending to `path`, rotated daily
+///
+/// `zots.log` becomes `zots.2026-01-31.log` and so on; the newest
+/// [`MAX_LOG_FILES`] files are kept.
+pub fn file_layer(path: &Path, level: LevelFilter) -> anyhow::Result<FileLayer> {
+    let name = Path::new(
+        path.file_name()
+            .ok_or_else(|| anyhow::anyhow!("Invalid log file path: {}", path.display()))?,
+    );
+    let dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    std::fs::create_dir_all(dir)
+        .map_err(|e| anyhow::anyhow!("Failed to create log directory {}: {e}", dir.display()))?;
+
+    let mut builder = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .max_log_files(MAX_LOG_FILES);
+    if let Some(stem) = name.file_stem() {
+        builder = builder.filename_prefix(stem.to_string_lossy());
+    }
+    if let Some(extension) = name.extension() {
+        builder = builder.filename_suffix(extension.to_string_lossy());
+    }
+    let appender = builder
+        .build(dir)
+        .map_err(|e| anyhow::anyhow!("Failed to open log file {}: {e}", path.display()))?;
+
+    Ok(fmt::layer()
+        .with_ansi(false)
+        .with_writer(appender)
+        .with_filter(level)
+        .boxed())
+}
+
+/// Map a log level option to a tracing filter.
+pub fn level_filter(level: LogLevelArg) -> LevelFilter {
+    match level {
+        LogLevelArg::Error => LevelFilter::ERROR,
+        LogLevelArg::Warn => LevelFilter::WARN,
+        LogLevelArg::Info => LevelFilter::INFO,
+        LogLevelArg::Debug => LevelFilter::DEBUG,
+        LogLevelArg::Trace => LevelFilter::TRACE,
+    }
+}
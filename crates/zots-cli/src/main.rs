@@ -6,11 +6,22 @@
 //!
 //! - `stamp` - Timestamp a file or hash on the Zcash blockchain
 //! - `verify` - Verify a timestamp proof against the blockchain
+//! - `upgrade` - Complete a pending proof once confirmed
+//! - `confirm` - Wait for a pending proof to confirm
 //! - `info` - Display proof information
 //! - `encode` - Convert proof to compact embeddable format
 //! - `decode` - Convert compact format back to JSON
-//! - `wallet` - Wallet management (sync, balance, address)
+//! - `embed` / `extract` - Carry a proof inside PNG, JPEG or PDF metadata
+//! - `attest` - Emit an in-toto statement for supply-chain provenance
+//! - `export` / `import` - Convert proofs to and from OpenTimestamps, CBOR and JSON
+//! - `config` - Manage the config file
+//! - `wallet` - Wallet management (sync, balance, addresses, shield)
 //! - `nostr` - Publish/fetch proofs via Nostr protocol
+//! - `git` - Timestamp and verify git commits and tags
+//! - `pending` - List and bulk-upgrade pending proofs
+//! - `proof` - Merge proofs of the same document
+//! - `watch` - Stamp files in watched directories as they change
+//! - `serve` - HTTP API for stamping and verification
 //! - `tui` - Launch interactive terminal UI
 //!
 //! ## Usage
@@ -19,6 +30,9 @@
 //! # Timestamp a file
 //! zots stamp document.pdf
 //!
+//! # Timestamp remote content without downloading it first
+//! zots stamp https://example.com/report.pdf
+//!
 //! # Verify a proof
 //! zots verify document.pdf.zots
 //!
@@ -32,18 +46,35 @@
 //! zots tui
 //! ```
 //!
+//! ## Exit Codes
+//!
+//! Scripts can branch on the exit code (see [`exit`] or
+//! `zots verify --explain-exit-codes`): 0 success, 1 other failure,
+//! 2 pending, 3 hash mismatch, 4 network error, 5 invalid, 6 too few
+//! confirmations, 7 unchecked (`verify --offline`), 64 usage error.
+//!
 //! ## Security Warning
 //!
 //! This is experimental software. Do not use on mainnet with real funds.
 
 mod cli;
 mod commands;
+mod exit;
+mod fetch;
+mod i18n;
+mod logging;
 mod output;
+mod report;
 mod tui;
+mod webhook;
 
 use clap::Parser;
-use cli::{Cli, Commands, LogLevelArg, NostrCommands, WalletCommands};
-use tracing_subscriber::filter::LevelFilter;
+use cli::{
+    Cli, Commands, ConfigCommands, GitCommands, NostrCommands, PendingCommands, ProofCommands,
+    WalletCommands,
+};
+use logging::level_filter;
+use zots_zcash::ZcashConfig;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -52,29 +83,151 @@ async fn main() -> anyhow::Result<()> {
 
     dotenvy::dotenv().ok();
 
-    let cli = Cli::parse();
-    if !matches!(cli.command, Commands::Tui) {
-        init_logging(cli.log_level);
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        let _ = e.print();
+        std::process::exit(if e.use_stderr() {
+            exit::USAGE
+        } else {
+            exit::SUCCESS
+        });
+    });
+    let log_file = cli
+        .log_file
+        .clone()
+        .or_else(ZcashConfig::log_file_setting)
+        .map(|path| logging::file_layer(&path, level_filter(cli.log_file_level)))
+        .transpose()?;
+    // The TUI installs its own subscriber and adds the log file to it
+    let tui_log_file = if matches!(cli.command, Commands::Tui) {
+        log_file
+    } else {
+        logging::init(level_filter(cli.log_level), log_file);
+        None
+    };
+    output::set_json_output(
+        cli.json
+            || std::env::var("ZOTS_OUTPUT").is_ok_and(|value| value.eq_ignore_ascii_case("json")),
+    );
+    output::set_quiet(cli.quiet);
+    i18n::init(cli.lang.as_deref());
+    if let Some(lang) = cli.lang.as_deref().filter(|lang| !i18n::is_supported(lang)) {
+        output::print_warning(&format!(
+            "Unsupported language '{lang}' (available: {}); using English",
+            i18n::supported().join(", ")
+        ));
     }
 
-    match cli.command {
+    let result = match cli.command {
         Commands::Stamp {
-            file,
+            files,
             hash,
+            stdin,
+            text,
             output,
             hash_algorithm,
             qr,
             no_wait,
-        } => commands::stamp::run(file, hash, output, hash_algorithm.into(), qr, no_wait).await,
-        Commands::Verify { proof, file } => commands::verify::run(proof, file).await,
-        Commands::Info { proof } => commands::info::run(proof),
+            jobs,
+            dry_run,
+            note,
+            filename,
+        } => {
+            let options = commands::stamp::StampOptions {
+                output,
+                hash_algorithm: hash_algorithm.map(Into::into),
+                show_qr: qr,
+                no_wait,
+                jobs,
+                dry_run,
+                note,
+                record_filename: filename,
+            };
+            commands::stamp::run(files, hash, stdin, text, options).await
+        }
+        Commands::Verify {
+            proof,
+            file,
+            text,
+            require_confirmations,
+            offline,
+            report,
+            sign,
+            batch,
+            jobs,
+            explain_exit_codes,
+        } => match (batch, proof) {
+            _ if explain_exit_codes => exit::explain(),
+            (Some(dir), _) => commands::verify::run_batch(dir, require_confirmations, jobs).await,
+            (None, Some(proof)) => {
+                let report = report.map(|path| commands::verify::ReportOptions { path, sign });
+                commands::verify::run(proof, file, text, require_confirmations, offline, report)
+                    .await
+            }
+            (None, None) => Err(anyhow::anyhow!(
+                "Either a proof file or --batch is required"
+            )),
+        },
+        Commands::Upgrade { proof, txid } => commands::upgrade::run(proof, txid).await,
+        Commands::Confirm { target, timeout } => commands::confirm::run(target, timeout).await,
+        Commands::Info { proof, online } => commands::info::run(proof, online).await,
         Commands::Encode { input, qr } => commands::encode::run(input, qr),
-        Commands::Decode { compact, output } => commands::decode::run(compact, output),
+        Commands::Decode {
+            compact,
+            qr,
+            output,
+        } => commands::decode::run(compact, qr, output),
+        Commands::Attest {
+            artifact,
+            proof,
+            name,
+            output,
+        } => commands::attest::run(artifact, proof, name, output),
+        Commands::Export {
+            input,
+            format,
+            output,
+        } => commands::export::export(input, format.into(), output),
+        Commands::Import { input, output } => commands::export::import(input, output),
+        Commands::Embed {
+            file,
+            proof,
+            output,
+        } => commands::embed::embed(file, proof, output),
+        Commands::Extract {
+            file,
+            output,
+            original,
+            verify,
+        } => commands::embed::extract(file, output, original, verify).await,
         Commands::Wallet { command } => match command {
             WalletCommands::Sync => commands::wallet::sync().await,
-            WalletCommands::Balance => commands::wallet::balance().await,
+            WalletCommands::Balance { breakdown } => commands::wallet::balance(breakdown).await,
             WalletCommands::Address => commands::wallet::address().await,
+            WalletCommands::Addresses => commands::wallet::addresses().await,
+            WalletCommands::NewAddress => commands::wallet::new_address().await,
             WalletCommands::Info => commands::wallet::info().await,
+            WalletCommands::History {
+                limit,
+                recover,
+                output,
+                hash_algorithm,
+            } => {
+                commands::wallet::history(limit, recover, output, hash_algorithm.map(Into::into))
+                    .await
+            }
+            WalletCommands::Import { ufvk } => commands::wallet::import(ufvk).await,
+            WalletCommands::ExportViewingKey { output, confirm } => {
+                commands::wallet::export_viewing_key(output, confirm).await
+            }
+            WalletCommands::Shield { no_wait, yes } => commands::wallet::shield(no_wait, yes).await,
+        },
+        Commands::Init { profile, force } => commands::init::run(profile, force).await,
+        Commands::Doctor => commands::doctor::run().await,
+        Commands::Config { command } => match command {
+            ConfigCommands::Init { force } => commands::config::init(force),
+            ConfigCommands::Get { key } => commands::config::get(&key),
+            ConfigCommands::Set { key, value } => commands::config::set(&key, &value),
+            ConfigCommands::Show => commands::config::show(),
         },
         Commands::Nostr { command } => match command {
             NostrCommands::Publish { proof } => commands::nostr::publish(proof).await,
@@ -82,24 +235,61 @@ async fn main() -> anyhow::Result<()> {
                 commands::nostr::fetch(event_id, output).await
             }
         },
-        Commands::Tui => tui::run().await,
-    }
-}
-
-/// Initialize global logging with the desired level.
-fn init_logging(level: LogLevelArg) {
-    let level_filter = match level {
-        LogLevelArg::Error => LevelFilter::ERROR,
-        LogLevelArg::Warn => LevelFilter::WARN,
-        LogLevelArg::Info => LevelFilter::INFO,
-        LogLevelArg::Debug => LevelFilter::DEBUG,
-        LogLevelArg::Trace => LevelFilter::TRACE,
+        Commands::Git { command } => match command {
+            GitCommands::Stamp {
+                rev,
+                hash_algorithm,
+                no_wait,
+            } => commands::git::stamp(rev, hash_algorithm.map(Into::into), no_wait).await,
+            GitCommands::Verify {
+                rev,
+                require_confirmations,
+            } => commands::git::verify(rev, require_confirmations).await,
+        },
+        Commands::Pending { command } => match command {
+            PendingCommands::List => commands::pending::list(),
+            PendingCommands::UpgradeAll => commands::pending::upgrade_all().await,
+        },
+        Commands::Proof { command } => match command {
+            ProofCommands::Merge { proofs, output } => commands::proof::merge(proofs, output),
+        },
+        Commands::Watch {
+            dirs,
+            include,
+            exclude,
+            interval,
+            hash_algorithm,
+            webhooks,
+        } => {
+            commands::watch::run(
+                dirs,
+                include,
+                exclude,
+                interval,
+                hash_algorithm.map(Into::into),
+                webhooks,
+            )
+            .await
+        }
+        Commands::Serve {
+            listen,
+            max_delay,
+            webhooks,
+        } => commands::serve::run(listen, max_delay, webhooks).await,
+        Commands::Tui => tui::run(level_filter(cli.log_level), tui_log_file).await,
     };
 
-    // Ignore errors if already initialized (e.g., in tests)
-    let _ = tracing_subscriber::fmt()
-        .with_max_level(level_filter)
-        .with_target(false)
-        .with_writer(std::io::stderr)
-        .try_init();
+    if let Err(e) = &result {
+        // Commands returning an exit status have already reported their outcome
+        if !e.is::<exit::Status>() {
+            // In JSON mode failures are reported on stdout too, so scripts always get a document
+            if output::json_output() {
+                output::print_json(&serde_json::json!({ "error": format!("{e:#}") }));
+            } else {
+                eprintln!("Error: {e:?}");
+            }
+        }
+        std::process::exit(exit::for_error(e));
+    }
+    Ok(())
 }
@@ -1,59 +1,390 @@
 //! Colored CLI output helpers
+//!
+//! In JSON mode (`--json` or `ZOTS_OUTPUT=json`) the human-readable output is
+//! written to stderr and each command prints a single JSON document to
+//! stdout with [`print_json`].
+//!
+//! In quiet mode (`--quiet`) only errors are printed; JSON documents and the
+//! exit code still carry the result.
+//!
+//! Long operations (hashing, wallet sync, waiting for blocks) show progress
+//! bars on stderr when it is a terminal, and fall back to plain log lines
+//! otherwise.
 
+use crate::i18n::t;
 use colored::*;
+use indicatif::{ProgressBar, ProgressStyle};
 use qrcode::{QrCode, render::unicode};
+use serde_json::{Value, json};
+use std::io::{BufRead, IsTerminal, Write};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tracing::info;
+use zots_core::ZcashAttestation;
+use zots_zcash::{
+    ConfirmationProgress, ConfirmationResult, ConfirmationWaitPolicy, SyncProgress, ZcashConfig,
+    ZotsWallet,
+};
+
+/// Minimum time between progress log lines when no bar is drawn
+const PROGRESS_LOG_INTERVAL: Duration = Duration::from_secs(10);
+
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable JSON output mode
+pub fn set_json_output(enabled: bool) {
+    JSON_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether commands should emit JSON on stdout
+pub fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Enable or disable quiet mode
+pub fn set_quiet(enabled: bool) {
+    QUIET.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether human-readable output is suppressed
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Print a line of human-readable output (to stderr in JSON mode)
+pub fn print_line(text: &str) {
+    if quiet() {
+        return;
+    }
+    if json_output() {
+        eprintln!("{text}");
+    } else {
+        println!("{text}");
+    }
+}
+
+/// Print a command's JSON result to stdout (JSON mode only)
+pub fn print_json(value: &Value) {
+    if json_output() {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+        );
+    }
+}
+
+/// Explorer link for an attestation, honoring `ZOTS_ZCASH_EXPLORER` and the config file
+pub fn explorer_link(att: &ZcashAttestation) -> String {
+    static EXPLORER_URL: OnceLock<Option<String>> = OnceLock::new();
+    let base = EXPLORER_URL.get_or_init(|| {
+        ZcashConfig::load(None)
+            .ok()
+            .and_then(|config| config.explorer_url)
+    });
+    att.explorer_link_with_base(base.as_deref())
+}
+
+/// JSON representation of an attestation
+pub fn attestation_json(att: &ZcashAttestation) -> Value {
+    json!({
+        "network": att.network.to_string(),
+        "txid": att.txid_hex(),
+        "block_height": att.block_height,
+        "block_time": att.block_time,
+        "time": att.timestamp().to_rfc3339(),
+        "explorer_url": explorer_link(att),
+    })
+}
+
+/// Ask the user to type `yes` before continuing
+///
+/// Fails when stdin is not a terminal so scripts have to opt in explicitly
+/// with `flag`.
+pub fn prompt_confirm(question: &str, flag: &str) -> anyhow::Result<bool> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return Err(anyhow::anyhow!(
+            "Refusing to continue without a terminal; pass {flag}"
+        ));
+    }
+    eprint!("{} {question} [type 'yes']: ", "?".yellow().bold());
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    stdin.lock().read_line(&mut answer)?;
+    Ok(answer.trim().eq_ignore_ascii_case("yes"))
+}
+
+/// Ask for a line of input, returning `default` when the answer is empty
+///
+/// Fails when stdin is not a terminal.
+pub fn prompt_input(question: &str, default: Option<&str>) -> anyhow::Result<String> {
+    let answer = match default {
+        Some(default) => read_answer(&format!("{question} [{default}]"))?,
+        None => read_answer(question)?,
+    };
+    Ok(match default {
+        Some(default) if answer.is_empty() => default.to_string(),
+        _ => answer,
+    })
+}
+
+/// Ask for a secret such as a seed phrase without echoing it
+///
+/// Fails when stdin is not a terminal.
+pub fn prompt_secret(question: &str) -> anyhow::Result<String> {
+    if !std::io::stdin().is_terminal() {
+        return Err(anyhow::anyhow!(
+            "This command is interactive and needs a terminal"
+        ));
+    }
+    eprint!("{} {question} (hidden): ", "?".yellow().bold());
+    std::io::stderr().flush()?;
+    crossterm::terminal::enable_raw_mode()?;
+    let answer = read_hidden_line();
+    crossterm::terminal::disable_raw_mode()?;
+    eprintln!();
+    answer
+}
+
+fn read_hidden_line() -> anyhow::Result<String> {
+    use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers, read};
+
+    let mut line = String::new();
+    loop {
+        let Event::Key(key) = read()? else {
+            continue;
+        };
+        if key.kind == KeyEventKind::Release {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => return Ok(line.trim().to_string()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Err(anyhow::anyhow!("Cancelled"));
+            }
+            KeyCode::Char(c) => line.push(c),
+            KeyCode::Backspace => {
+                line.pop();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Ask a yes/no question; an empty answer picks `default`
+pub fn prompt_yes_no(question: &str, default: bool) -> anyhow::Result<bool> {
+    let hint = if default { "Y/n" } else { "y/N" };
+    loop {
+        match read_answer(&format!("{question} [{hint}]"))?
+            .to_lowercase()
+            .as_str()
+        {
+            "" => return Ok(default),
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => print_warning(&t!("prompt-answer-y-n")),
+        }
+    }
+}
+
+fn read_answer(prompt: &str) -> anyhow::Result<String> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return Err(anyhow::anyhow!(
+            "This command is interactive and needs a terminal"
+        ));
+    }
+    eprint!("{} {prompt}: ", "?".yellow().bold());
+    std::io::stderr().flush()?;
+    let mut answer = String::new();
+    stdin.lock().read_line(&mut answer)?;
+    Ok(answer.trim().to_string())
+}
 
 /// Print a header with underline
 pub fn print_header(text: &str) {
-    println!();
-    println!("{}", text.cyan().bold());
-    println!("{}", "─".repeat(text.len()).cyan());
+    print_line("");
+    print_line(&text.cyan().bold().to_string());
+    print_line(&"─".repeat(text.chars().count()).cyan().to_string());
 }
 
 /// Print a success message with checkmark
 pub fn print_success(text: &str) {
-    println!("{} {}", "✓".green().bold(), text.green());
+    print_line(&format!("{} {}", "✓".green().bold(), text.green()));
 }
 
 /// Print an error message with X
+///
+/// Still shown in quiet mode, on stderr.
 pub fn print_error(text: &str) {
-    println!("{} {}", "✗".red().bold(), text.red());
+    let line = format!("{} {}", "✗".red().bold(), text.red());
+    if quiet() {
+        eprintln!("{line}");
+    } else {
+        print_line(&line);
+    }
 }
 
 /// Print a warning message
 pub fn print_warning(text: &str) {
-    println!("{} {}", "!".yellow().bold(), text.yellow());
+    print_line(&format!("{} {}", "!".yellow().bold(), text.yellow()));
 }
 
 /// Print a labeled info line
 pub fn print_info(label: &str, value: &str) {
-    println!("  {}: {}", label.white().bold(), value);
+    print_line(&format!("  {}: {}", label.white().bold(), value));
 }
 
 /// Print a hash in yellow with its algorithm
 pub fn print_hash(hash: &str, algorithm: &str) {
-    println!(
+    print_line(&format!(
         "  {} ({}): {}",
-        "Hash".white().bold(),
+        t!("label-hash").white().bold(),
         algorithm,
         hash.yellow()
-    );
+    ));
 }
 
 /// Print a clickable terminal hyperlink
 pub fn print_link(label: &str, url: &str) {
     // OSC 8 terminal hyperlink escape sequence
-    println!(
+    print_line(&format!(
         "  {}: \x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
         label,
         url,
         url.blue().underline()
-    );
+    ));
 }
 
 /// Print a status line (for progress updates)
 pub fn print_status(text: &str) {
-    println!("  {} {}", "→".cyan(), text);
+    print_line(&format!("  {} {}", "→".cyan(), text));
+}
+
+/// Whether progress bars are drawn (stderr is a terminal and quiet mode is off)
+pub fn progress_enabled() -> bool {
+    !quiet() && std::io::stderr().is_terminal()
+}
+
+/// Spinner for an operation of unknown length
+///
+/// Hidden when progress bars are disabled; the message is logged instead.
+pub fn spinner(message: &str) -> ProgressBar {
+    if !progress_enabled() {
+        info!("{}", message);
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    pb.set_message(message.to_string());
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb
+}
+
+/// Progress bar with ETA over `total` bytes
+pub fn bytes_bar(total: u64, message: &str) -> ProgressBar {
+    progress_bar(
+        total,
+        message,
+        "{msg} [{bar:30.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec}, ETA {eta})",
+    )
+}
+
+/// Progress bar with ETA over `total` items (files, blocks)
+pub fn count_bar(total: u64, message: &str) -> ProgressBar {
+    progress_bar(
+        total,
+        message,
+        "{msg} [{bar:30.cyan/blue}] {pos}/{len} (ETA {eta})",
+    )
+}
+
+fn progress_bar(total: u64, message: &str, template: &str) -> ProgressBar {
+    if !progress_enabled() {
+        info!("{}", message);
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(total);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template(template)
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    pb.set_message(message.to_string());
+    pb
+}
+
+/// Sync the wallet, showing scanned blocks against the tip
+///
+/// Without a terminal, progress is logged every few seconds instead.
+pub async fn sync_wallet(wallet: &mut ZotsWallet) -> anyhow::Result<()> {
+    let pb = count_bar(0, &t!("sync-progress"));
+    let mut last_log = Instant::now();
+    wallet
+        .sync_with_progress(|progress: &SyncProgress| {
+            pb.set_length(progress.total_blocks());
+            pb.set_position(progress.scanned_blocks());
+            if pb.is_hidden() && last_log.elapsed() >= PROGRESS_LOG_INTERVAL {
+                last_log = Instant::now();
+                info!(
+                    "Synced block {} of {}",
+                    progress.scanned_height, progress.tip_height
+                );
+            }
+        })
+        .await?;
+    pb.finish_with_message(t!("sync-done"));
+    Ok(())
+}
+
+/// Wait for a transaction to be mined, showing the chain tip while waiting
+///
+/// Gives up after `max_blocks` blocks, like [`ZotsWallet::wait_confirmation`].
+pub async fn wait_for_confirmation(
+    wallet: &mut ZotsWallet,
+    txid: &str,
+    max_blocks: u32,
+) -> anyhow::Result<ConfirmationResult> {
+    let pb = spinner(&t!("confirm-waiting"));
+    let policy = ConfirmationWaitPolicy {
+        max_blocks,
+        ..ConfirmationWaitPolicy::default()
+    };
+    let result = wallet
+        .wait_confirmation_with(txid, &policy, |progress: &ConfirmationProgress| {
+            let message = match progress.mined_height {
+                Some(height) => t!(
+                    "confirm-mined",
+                    height = height,
+                    confirmations = progress.confirmations,
+                    target = progress.target,
+                ),
+                None => t!(
+                    "confirm-waiting-block",
+                    height = progress.current_height,
+                    blocks = progress
+                        .current_height
+                        .saturating_sub(progress.start_height),
+                ),
+            };
+            if pb.is_hidden() {
+                info!("{}", message);
+            }
+            pb.set_message(message);
+        })
+        .await;
+    match &result {
+        Ok(_) => pb.finish_with_message(t!("confirm-done")),
+        Err(_) => pb.abandon_with_message(t!("confirm-not-yet")),
+    }
+    result
 }
 
 /// Render a QR code as a string for terminal display
@@ -69,9 +400,9 @@ pub fn render_qr(data: &str) -> anyhow::Result<String> {
 
 /// Print a QR code with a label
 pub fn print_qr(label: &str, data: &str) -> anyhow::Result<()> {
-    println!();
+    print_line("");
     print_header(label);
     let qr = render_qr(data)?;
-    println!("{qr}");
+    print_line(&qr);
     Ok(())
 }
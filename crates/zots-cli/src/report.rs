@@ -0,0 +1,216 @@
+//! Signed verification reports for `zots verify --report`.
+//!
+//! A report is a JSON document recording what was checked and the outcome,
+//! for handing a verification to people who will not run zots themselves:
+//!
+//! ```json
+//! {
+//!   "format": "zots-verification-report/1",
+//!   "generated_at": "2026-01-31T12:00:00+00:00",
+//!   "generator": "zots 0.1.1",
+//!   "inputs": {...},
+//!   "proof": {...},
+//!   "attestation": {...},
+//!   "chain": {...},
+//!   "verdict": {...},
+//!   "signer": {"algorithm": "ed25519", "public_key": "-----BEGIN PUBLIC KEY-----..."}
+//! }
+//! ```
+//!
+//! With `--sign key.pem` (an Ed25519 PKCS#8 key, as made by
+//! `openssl genpkey -algorithm ed25519`) the exact bytes of the report are
+//! signed and the raw 64-byte signature is written next to it as
+//! `<report>.sig`. The signer's public key is part of the signed report, and
+//! anyone can check it with stock OpenSSL:
+//!
+//! ```bash
+//! openssl pkeyutl -verify -pubin -inkey operator.pub -rawin \
+//!     -in report.json -sigfile report.json.sig
+//! ```
+
+use ed25519_dalek::pkcs8::{DecodePrivateKey, EncodePublicKey, LineEnding};
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+/// Identifies the report layout; bumped on incompatible changes
+const FORMAT: &str = "zots-verification-report/1";
+
+/// Evidence gathered while verifying, completed by a verdict
+#[derive(Default)]
+pub struct VerificationReport {
+    /// Proof file and original data the proof was checked against
+    pub inputs: Value,
+    /// Hash and metadata carried by the proof
+    pub proof: Value,
+    /// The attestation that was checked, if the proof has one
+    pub attestation: Option<Value>,
+    /// What the chain said: server, tip, memo contents
+    pub chain: Option<Value>,
+}
+
+impl VerificationReport {
+    /// Write the report with `verdict` to `path`, signing it with `key`
+    ///
+    /// Returns the path of the detached signature when signed. A passing
+    /// verdict is only signed together with the chain evidence backing it.
+    pub fn write(
+        &self,
+        verdict: &Value,
+        path: &Path,
+        key: Option<&Path>,
+    ) -> anyhow::Result<Option<PathBuf>> {
+        if key.is_some() && verdict["valid"] == json!(true) && self.chain.is_none() {
+            return Err(anyhow::anyhow!(
+                "Refusing to sign a passing verdict that was not checked on-chain"
+            ));
+        }
+        let signing_key = key.map(load_signing_key).transpose()?;
+        let signer = match &signing_key {
+            Some(signing_key) => Some(signer_json(signing_key)?),
+            None => None,
+        };
+
+        let report = json!({
+            "format": FORMAT,
+            "generated_at": chrono::Utc::now().to_rfc3339(),
+            "generator": concat!("zots ", env!("CARGO_PKG_VERSION")),
+            "inputs": self.inputs,
+            "proof": self.proof,
+            "attestation": self.attestation,
+            "chain": self.chain,
+            "verdict": verdict,
+            "signer": signer,
+        });
+        let mut bytes = serde_json::to_vec_pretty(&report)?;
+        bytes.push(b'\n');
+        std::fs::write(path, &bytes)
+            .map_err(|e| anyhow::anyhow!("Failed to write report {}: {e}", path.display()))?;
+
+        let Some(signing_key) = signing_key else {
+            return Ok(None);
+        };
+        let signature = signing_key.sign(&bytes);
+        let sig_path = signature_path(path);
+        std::fs::write(&sig_path, signature.to_bytes()).map_err(|e| {
+            anyhow::anyhow!("Failed to write signature {}: {e}", sig_path.display())
+        })?;
+        Ok(Some(sig_path))
+    }
+}
+
+/// Detached signature path for a report: `report.json` -> `report.json.sig`
+pub fn signature_path(report: &Path) -> PathBuf {
+    let mut path = report.as_os_str().to_owned();
+    path.push(".sig");
+    PathBuf::from(path)
+}
+
+fn load_signing_key(path: &Path) -> anyhow::Result<SigningKey> {
+    let pem = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read signing key {}: {e}", path.display()))?;
+    SigningKey::from_pkcs8_pem(&pem).map_err(|e| {
+        anyhow::anyhow!(
+            "{} is not an Ed25519 PKCS#8 PEM key ({e}); create one with `openssl genpkey -algorithm ed25519`",
+            path.display()
+        )
+    })
+}
+
+fn signer_json(key: &SigningKey) -> anyhow::Result<Value> {
+    let verifying_key = key.verifying_key();
+    let public_key_pem = verifying_key
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| anyhow::anyhow!("Failed to encode public key: {e}"))?;
+    Ok(json!({
+        "algorithm": "ed25519",
+        "public_key": public_key_pem,
+        "public_key_hex": hex::encode(verifying_key.as_bytes()),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::pkcs8::EncodePrivateKey;
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    fn write_key(dir: &Path) -> PathBuf {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let path = dir.join("operator.pem");
+        std::fs::write(&path, key.to_pkcs8_pem(LineEnding::LF).unwrap().as_bytes()).unwrap();
+        path
+    }
+
+    fn evidence() -> VerificationReport {
+        VerificationReport {
+            inputs: json!({ "proof": "a.zots" }),
+            proof: json!({ "hash": "00" }),
+            attestation: None,
+            chain: Some(json!({ "block_height": 100 })),
+        }
+    }
+
+    /// Check `report` against its detached signature with the embedded public key
+    fn signature_checks(report: &Path) -> bool {
+        let bytes = std::fs::read(report).unwrap();
+        let json: Value = serde_json::from_slice(&bytes).unwrap();
+        let public_key: [u8; 32] = hex::decode(json["signer"]["public_key_hex"].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let signature: [u8; 64] = std::fs::read(signature_path(report))
+            .unwrap()
+            .try_into()
+            .unwrap();
+        VerifyingKey::from_bytes(&public_key)
+            .unwrap()
+            .verify(&bytes, &Signature::from_bytes(&signature))
+            .is_ok()
+    }
+
+    #[test]
+    fn test_signed_report_roundtrip_and_tamper() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let key = write_key(dir);
+        let path = dir.join("report.json");
+
+        let verdict = json!({ "status": "valid", "valid": true });
+        let sig = evidence().write(&verdict, &path, Some(&key)).unwrap();
+        assert_eq!(sig, Some(signature_path(&path)));
+        assert!(signature_checks(&path));
+
+        // Flipping the verdict invalidates the signature
+        let tampered = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("\"valid\": true", "\"valid\": false");
+        std::fs::write(&path, tampered).unwrap();
+        assert!(!signature_checks(&path));
+    }
+
+    #[test]
+    fn test_refuses_to_sign_unchecked_pass() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let key = write_key(dir);
+        let path = dir.join("report.json");
+        let offline = VerificationReport {
+            chain: None,
+            ..evidence()
+        };
+
+        let pass = json!({ "status": "valid", "valid": true });
+        assert!(offline.write(&pass, &path, Some(&key)).is_err());
+        assert!(!path.exists());
+
+        let unchecked = json!({ "status": "unchecked", "valid": false });
+        assert!(
+            offline
+                .write(&unchecked, &path, Some(&key))
+                .unwrap()
+                .is_some()
+        );
+        assert!(signature_checks(&path));
+    }
+}
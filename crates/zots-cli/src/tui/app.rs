@@ -1,24 +1,33 @@
 //! TUI application state
 //!
 //! Manages the state machine for the TUI application, including:
-//! - Navigation between screens (Menu, Stamp, Verify, Wallet)
+//! - Navigation between screens (Menu, Stamp, Verify, Wallet, History)
 //! - Multi-step input flows with progress tracking
 //! - Async operation phases with spinner animation
 //! - Background task execution for non-blocking UI
 
 use anyhow::Result;
 use crossterm::event::KeyCode;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
+use tracing::{error, info};
 use zots_core::{
-    HashAlgorithm, TimestampProof, ZcashAttestation, hash_file_with, hash_from_hex_with,
-    hash_to_hex,
+    HashAlgorithm, PendingStore, TimestampProof, ZcashAttestation, hash_file_with,
+    hash_from_hex_with, hash_to_hex,
 };
-use zots_zcash::{ZcashConfig, ZotsWallet};
+use zots_zcash::{ConfirmationWaitPolicy, SendPreview, ZcashConfig, ZotsWallet};
+
+use super::clipboard;
+use super::log::LogBuffer;
+use super::picker::FilePicker;
+use crate::i18n::t;
 
 /// Spinner frames for animated progress indicator
 const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
+/// Lines moved per PageUp/PageDown in the activity pane
+const LOG_SCROLL_STEP: usize = 5;
+
 /// Current screen/state of the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AppState {
@@ -26,6 +35,27 @@ pub enum AppState {
     Stamp,
     Verify,
     Wallet,
+    History,
+    Send,
+}
+
+/// Current step in the send flow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendStep {
+    /// Enter recipient address
+    Address,
+    /// Enter amount in ZEC
+    Amount,
+    /// Enter optional memo
+    Memo,
+    /// Syncing and computing the fee
+    Previewing,
+    /// Showing amount and fee, waiting for confirmation
+    Confirm,
+    /// Building and broadcasting
+    Sending,
+    /// Finished (sent or failed)
+    Done,
 }
 
 /// Phase of an async operation
@@ -38,7 +68,15 @@ pub enum OperationPhase {
     /// Creating and broadcasting transaction
     Broadcasting,
     /// Waiting for block confirmation
-    WaitingConfirmation { txid: String },
+    WaitingConfirmation {
+        txid: String,
+        /// Latest chain tip seen while polling (0 before the first poll)
+        current_height: u64,
+        /// Confirmations observed so far
+        confirmations: u32,
+        /// Confirmations required before the proof is written
+        target: u32,
+    },
     /// Operation completed successfully
     Complete,
     /// Operation failed
@@ -72,10 +110,19 @@ pub enum TaskMessage {
     Phase(OperationPhase),
     /// Update status message
     Status(String),
-    /// Stamp operation completed successfully
-    StampComplete(StampResult),
+    /// Queued file finished hashing
+    QueueHashed {
+        id: u64,
+        result: Result<[u8; 32], String>,
+    },
+    /// Queued items moved to a new status
+    QueueStatus { ids: Vec<u64>, status: QueueStatus },
+    /// Stamp operation completed successfully (one result per queued item)
+    StampComplete(Vec<StampResult>),
     /// Stamp operation failed
     StampFailed(String),
+    /// Stamp broadcast but not confirmed in time; pending proofs were saved
+    StampPending { txid: String, message: String },
     /// Verify operation completed
     VerifyComplete(VerifyResult),
     /// Verify operation failed
@@ -88,6 +135,20 @@ pub enum TaskMessage {
     InitialSyncComplete { block_height: u64, balance: u64 },
     /// Initial sync failed (silent)
     InitialSyncFailed,
+    /// Receiving address loaded (`new` when freshly diversified)
+    Address { address: String, new: bool },
+    /// Address lookup failed
+    AddressFailed(String),
+    /// Pending proof upgrade finished (message for the user)
+    UpgradeComplete(String),
+    /// Pending proof upgrade failed
+    UpgradeFailed(String),
+    /// Send fee computed, waiting for confirmation
+    SendPreview(SendPreview),
+    /// Send broadcast
+    SendComplete { txid: String, fee: u64 },
+    /// Send preview or broadcast failed
+    SendFailed(String),
 }
 
 /// TUI application state
@@ -122,14 +183,50 @@ pub struct App {
     pub verify_input_kind: Option<VerifyInputKind>,
     /// Stored hash bytes for verify (computed from file or parsed from hex)
     pub verify_hash: Option<[u8; 32]>,
-    /// Stamp result details for display
+    /// Stamp result details for display (single-item stamps)
     pub stamp_result: Option<StampResult>,
+    /// Results of the last batch stamp
+    pub batch_results: Vec<StampResult>,
+    /// Transaction of pending proofs saved by a timed-out stamp
+    pub stamp_pending_txid: Option<String>,
+    /// Files and hashes queued on the Stamp screen
+    pub stamp_queue: Vec<QueueItem>,
+    /// Id assigned to the next queue entry
+    next_queue_id: u64,
     /// Verify result details for display
     pub verify_result: Option<VerifyResult>,
     /// Whether QR overlay is showing
     pub qr_visible: bool,
     /// Cached compact proof for QR rendering
     pub qr_data: Option<String>,
+    /// Receiving address shown on the Wallet screen
+    pub wallet_address: Option<String>,
+    /// Proofs listed on the History screen
+    pub history: Vec<HistoryItem>,
+    /// Selected row on the History screen
+    pub history_selected: usize,
+    /// Whether the selected proof's details are expanded
+    pub history_details: bool,
+    /// Current step on the Send screen
+    pub send_step: SendStep,
+    /// Recipient address on the Send screen
+    pub send_address: String,
+    /// Amount to send in zatoshis
+    pub send_amount: u64,
+    /// Optional memo text for the send
+    pub send_memo: String,
+    /// Fee preview for the pending send
+    pub send_preview: Option<SendPreview>,
+    /// Broadcast send transaction
+    pub send_txid: Option<String>,
+    /// File picker overlay (Stamp and Verify screens)
+    pub picker: Option<FilePicker>,
+    /// Captured log lines for the activity pane
+    pub logs: LogBuffer,
+    /// Whether the activity pane is shown
+    pub log_visible: bool,
+    /// Activity pane scroll position, in lines back from the newest
+    pub log_scroll: usize,
     /// Channel receiver for background task updates
     task_rx: mpsc::Receiver<TaskMessage>,
     /// Channel sender for background tasks (cloned when spawning)
@@ -150,6 +247,67 @@ pub struct StampResult {
     pub compact: String,
 }
 
+/// Status of a queued stamp item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueueStatus {
+    /// File is being hashed
+    Hashing,
+    /// Hashed, waiting for the batch to be stamped
+    Waiting,
+    /// Included in a broadcast transaction
+    Broadcast,
+    /// Transaction confirmed, proof saved
+    Confirmed,
+    /// Hashing or stamping failed
+    Failed(String),
+}
+
+impl QueueStatus {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Hashing => t!("tui-queue-hashing"),
+            Self::Waiting => t!("tui-queue-waiting"),
+            Self::Broadcast => t!("tui-queue-broadcast"),
+            Self::Confirmed => t!("tui-queue-confirmed"),
+            Self::Failed(_) => t!("tui-queue-failed"),
+        }
+    }
+}
+
+/// A file or hash queued on the Stamp screen
+#[derive(Debug, Clone)]
+pub struct QueueItem {
+    pub id: u64,
+    /// File path or hex hash as entered
+    pub label: String,
+    pub hash: Option<[u8; 32]>,
+    /// Where the proof is written
+    pub output_path: PathBuf,
+    pub status: QueueStatus,
+}
+
+/// A proof listed on the History screen
+#[derive(Debug, Clone)]
+pub struct HistoryItem {
+    pub path: PathBuf,
+    pub hash: String,
+    pub algorithm: HashAlgorithm,
+    /// First attestation, if the proof is confirmed
+    pub attestation: Option<ZcashAttestation>,
+    /// Broadcast transaction of a pending proof
+    pub pending_txid: Option<String>,
+    /// Proof file is missing or unreadable (tracked pending entries only)
+    pub error: Option<String>,
+    /// Note recorded at stamp time
+    pub note: Option<String>,
+}
+
+impl HistoryItem {
+    pub fn is_confirmed(&self) -> bool {
+        self.attestation.is_some()
+    }
+}
+
 /// Result of a verification operation
 #[derive(Debug, Clone)]
 pub struct VerifyResult {
@@ -164,12 +322,14 @@ pub struct VerifyResult {
     pub explorer_link: String,
     pub error: Option<String>,
     pub file_hash_matches: Option<bool>,
+    /// Note recorded at stamp time
+    pub note: Option<String>,
 }
 
 impl App {
     /// Create new app instance
-    pub async fn new() -> Result<Self> {
-        let config = ZcashConfig::from_env().ok();
+    pub async fn new(logs: LogBuffer) -> Result<Self> {
+        let config = ZcashConfig::load(None).ok();
 
         // Create channel for background task communication
         let (task_tx, task_rx) = mpsc::channel(32);
@@ -181,9 +341,9 @@ impl App {
             tokio::spawn(async move {
                 run_initial_sync_task(tx, cfg).await;
             });
-            ("Syncing wallet...".to_string(), true)
+            (t!("tui-status-syncing"), true)
         } else {
-            ("No wallet configured (set ZOTS_SEED)".to_string(), false)
+            (t!("tui-no-wallet-seed"), false)
         };
 
         Ok(Self {
@@ -207,9 +367,27 @@ impl App {
             verify_input_kind: None,
             verify_hash: None,
             stamp_result: None,
+            batch_results: Vec::new(),
+            stamp_pending_txid: None,
+            stamp_queue: Vec::new(),
+            next_queue_id: 0,
             verify_result: None,
             qr_visible: false,
             qr_data: None,
+            wallet_address: None,
+            history: Vec::new(),
+            history_selected: 0,
+            history_details: false,
+            send_step: SendStep::Address,
+            send_address: String::new(),
+            send_amount: 0,
+            send_memo: String::new(),
+            send_preview: None,
+            send_txid: None,
+            picker: None,
+            logs,
+            log_visible: false,
+            log_scroll: 0,
             task_rx,
             task_tx,
             task_running,
@@ -230,16 +408,44 @@ impl App {
                     self.operation_phase = phase;
                 }
                 TaskMessage::Status(status) => {
+                    info!("{status}");
                     self.status_message = status;
                 }
-                TaskMessage::StampComplete(result) => {
-                    self.stamp_result = Some(result);
+                TaskMessage::QueueHashed { id, result } => {
+                    let status = match result {
+                        Ok(hash) => {
+                            if let Some(item) = self.stamp_queue.iter_mut().find(|i| i.id == id) {
+                                item.hash = Some(hash);
+                            }
+                            QueueStatus::Waiting
+                        }
+                        Err(e) => QueueStatus::Failed(e),
+                    };
+                    self.set_queue_status(&[id], &status);
+                }
+                TaskMessage::QueueStatus { ids, status } => {
+                    self.set_queue_status(&ids, &status);
+                }
+                TaskMessage::StampComplete(results) => {
+                    // A single result reuses the detail and QR view
+                    self.stamp_result = match results.as_slice() {
+                        [result] => Some(result.clone()),
+                        _ => None,
+                    };
+                    self.batch_results = results;
                     self.operation_phase = OperationPhase::Complete;
                     self.qr_data = self.stamp_result.as_ref().map(|r| r.compact.clone());
                     self.qr_visible = false;
                     self.task_running = false;
                 }
                 TaskMessage::StampFailed(error) => {
+                    error!("Stamp failed: {error}");
+                    let failed = QueueStatus::Failed(error.clone());
+                    for item in &mut self.stamp_queue {
+                        if item.status != QueueStatus::Confirmed {
+                            item.status = failed.clone();
+                        }
+                    }
                     self.result_message = error;
                     self.result_is_error = true;
                     self.operation_phase = OperationPhase::Failed;
@@ -247,6 +453,16 @@ impl App {
                     self.qr_visible = false;
                     self.task_running = false;
                 }
+                TaskMessage::StampPending { txid, message } => {
+                    info!("Pending proofs saved for {txid}");
+                    self.result_message = message;
+                    self.result_is_error = true;
+                    self.stamp_pending_txid = Some(txid);
+                    self.operation_phase = OperationPhase::Failed;
+                    self.qr_data = None;
+                    self.qr_visible = false;
+                    self.task_running = false;
+                }
                 TaskMessage::VerifyComplete(result) => {
                     self.verify_result = Some(result);
                     self.qr_data = self.verify_result.as_ref().map(|r| r.compact.clone());
@@ -265,6 +481,7 @@ impl App {
                     self.task_running = false;
                 }
                 TaskMessage::VerifyFailed(error) => {
+                    error!("Verify failed: {error}");
                     self.result_message = error;
                     self.result_is_error = true;
                     self.operation_phase = OperationPhase::Failed;
@@ -278,14 +495,15 @@ impl App {
                 } => {
                     self.block_height = block_height;
                     self.balance = balance;
-                    self.status_message = "Synced".to_string();
-                    self.result_message = "Wallet synced successfully".to_string();
+                    self.status_message = t!("tui-status-synced");
+                    self.result_message = t!("tui-sync-done");
                     self.result_is_error = false;
                     self.operation_phase = OperationPhase::Complete;
                     self.task_running = false;
                 }
                 TaskMessage::SyncFailed(error) => {
-                    self.result_message = format!("Sync failed: {error}");
+                    error!("Sync failed: {error}");
+                    self.result_message = t!("tui-sync-failed", error = error);
                     self.result_is_error = true;
                     self.operation_phase = OperationPhase::Failed;
                     self.task_running = false;
@@ -297,16 +515,82 @@ impl App {
                     // Silent update - just set values and return to ready state
                     self.block_height = block_height;
                     self.balance = balance;
-                    self.status_message = "Ready".to_string();
+                    self.status_message = t!("tui-status-ready");
                     self.operation_phase = OperationPhase::Input;
                     self.task_running = false;
                 }
                 TaskMessage::InitialSyncFailed => {
                     // Silent failure - just return to ready state
-                    self.status_message = "Ready (sync failed)".to_string();
+                    self.status_message = t!("tui-status-ready-sync-failed");
                     self.operation_phase = OperationPhase::Input;
                     self.task_running = false;
                 }
+                TaskMessage::Address { address, new } => {
+                    self.wallet_address = Some(address);
+                    self.result_message = if new {
+                        t!("tui-address-new")
+                    } else {
+                        t!("tui-address-fund")
+                    };
+                    self.result_is_error = false;
+                    self.status_message = t!("tui-status-ready");
+                    self.operation_phase = OperationPhase::Complete;
+                    self.task_running = false;
+                }
+                TaskMessage::AddressFailed(error) => {
+                    error!("Address error: {error}");
+                    self.result_message = t!("tui-address-failed", error = error);
+                    self.result_is_error = true;
+                    self.status_message = t!("tui-status-ready");
+                    self.operation_phase = OperationPhase::Failed;
+                    self.task_running = false;
+                }
+                TaskMessage::UpgradeComplete(message) => {
+                    if self.stamp_pending_txid.take().is_some() {
+                        for item in &mut self.stamp_queue {
+                            if item.status == QueueStatus::Broadcast {
+                                item.status = QueueStatus::Confirmed;
+                            }
+                        }
+                    }
+                    self.result_message = message;
+                    self.result_is_error = false;
+                    self.status_message = t!("tui-status-ready");
+                    self.operation_phase = OperationPhase::Complete;
+                    self.task_running = false;
+                    self.reload_history();
+                }
+                TaskMessage::SendPreview(preview) => {
+                    self.send_preview = Some(preview);
+                    self.send_step = SendStep::Confirm;
+                    self.status_message = t!("tui-status-confirm-send");
+                    self.task_running = false;
+                }
+                TaskMessage::SendComplete { txid, fee } => {
+                    info!("Sent {} zatoshis (fee {fee}) in {txid}", self.send_amount);
+                    self.result_message = t!("tui-send-broadcast");
+                    self.result_is_error = false;
+                    self.status_message = t!("tui-status-sent", txid = &txid[..12]);
+                    self.send_txid = Some(txid);
+                    self.send_step = SendStep::Done;
+                    self.task_running = false;
+                }
+                TaskMessage::SendFailed(error) => {
+                    error!("Send failed: {error}");
+                    self.result_message = error;
+                    self.result_is_error = true;
+                    self.status_message = t!("tui-status-send-failed");
+                    self.send_step = SendStep::Done;
+                    self.task_running = false;
+                }
+                TaskMessage::UpgradeFailed(error) => {
+                    error!("Upgrade failed: {error}");
+                    self.result_message = error;
+                    self.result_is_error = true;
+                    self.status_message = t!("tui-status-ready");
+                    self.operation_phase = OperationPhase::Failed;
+                    self.task_running = false;
+                }
             }
         }
 
@@ -329,10 +613,219 @@ impl App {
         self.verify_input_kind = None;
         self.verify_hash = None;
         self.stamp_result = None;
+        self.batch_results.clear();
+        self.stamp_queue.clear();
+        self.stamp_pending_txid = None;
         self.verify_result = None;
         self.task_running = false;
         self.qr_visible = false;
         self.qr_data = None;
+        self.history_details = false;
+        self.wallet_address = None;
+        self.send_step = SendStep::Address;
+        self.send_address.clear();
+        self.send_amount = 0;
+        self.send_memo.clear();
+        self.send_preview = None;
+        self.send_txid = None;
+        self.picker = None;
+    }
+
+    /// Handle activity pane keys (F3 toggles, PageUp/PageDown scroll)
+    ///
+    /// Returns whether the key was consumed.
+    pub fn handle_log_input(&mut self, key: KeyCode) -> bool {
+        let max = self.logs.len().saturating_sub(1);
+        match key {
+            KeyCode::F(3) => {
+                // Reopening follows the newest lines again
+                self.log_visible = !self.log_visible;
+                self.log_scroll = 0;
+            }
+            KeyCode::PageUp if self.log_visible => {
+                self.log_scroll = (self.log_scroll + LOG_SCROLL_STEP).min(max);
+            }
+            KeyCode::PageDown if self.log_visible => {
+                self.log_scroll = self.log_scroll.saturating_sub(LOG_SCROLL_STEP);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Open the History screen with a fresh proof list
+    pub fn open_history(&mut self) {
+        self.state = AppState::History;
+        self.reload_history();
+    }
+
+    /// Rescan proofs, keeping the selection in range
+    fn reload_history(&mut self) {
+        self.history = load_history(self.config.as_ref());
+        self.history_selected = self
+            .history_selected
+            .min(self.history.len().saturating_sub(1));
+    }
+
+    /// Currently selected History entry
+    pub fn selected_history(&self) -> Option<&HistoryItem> {
+        self.history.get(self.history_selected)
+    }
+
+    /// Keyboard handling for the History screen
+    fn handle_history_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.history_selected = self.history_selected.saturating_sub(1);
+                self.clear_history_result();
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.history_selected + 1 < self.history.len() {
+                    self.history_selected += 1;
+                }
+                self.clear_history_result();
+            }
+            KeyCode::Enter => self.history_details = !self.history_details,
+            KeyCode::Char('r') | KeyCode::Char('R') if !self.task_running => {
+                self.reload_history();
+                self.clear_history_result();
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') if !self.task_running => {
+                self.start_history_verify();
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') if !self.task_running => {
+                self.start_upgrade_task();
+            }
+            _ => {}
+        }
+    }
+
+    fn clear_history_result(&mut self) {
+        if !self.task_running {
+            self.result_message.clear();
+            self.result_is_error = false;
+            self.verify_result = None;
+            self.operation_phase = OperationPhase::Input;
+        }
+    }
+
+    /// Re-verify the selected confirmed proof on-chain
+    fn start_history_verify(&mut self) {
+        let Some(item) = self.selected_history().cloned() else {
+            return;
+        };
+        self.clear_history_result();
+        self.history_details = true;
+        let Some(att) = item.attestation else {
+            self.result_message = t!("tui-proof-pending-upgrade");
+            self.result_is_error = true;
+            return;
+        };
+        let Some(config) = self.config.clone() else {
+            self.result_message = t!("tui-verify-no-wallet");
+            self.result_is_error = true;
+            return;
+        };
+        let (proof_hash_bytes, txid_bytes) = match (
+            hash_from_hex_with(&item.hash, item.algorithm),
+            att.txid_bytes(),
+        ) {
+            (Ok(hash), Ok(txid)) => (hash, txid),
+            (Err(e), _) | (_, Err(e)) => {
+                self.result_message = t!("tui-invalid-proof", error = e.to_string());
+                self.result_is_error = true;
+                return;
+            }
+        };
+        let compact = TimestampProof::load(&item.path)
+            .and_then(|proof| proof.to_compact())
+            .unwrap_or_default();
+
+        let verify_data = VerifyTaskData {
+            proof_hash: item.hash.clone(),
+            algorithm: item.algorithm,
+            compact,
+            proof_hash_bytes,
+            txid_bytes,
+            block_height: att.block_height,
+            network: att.network.to_string(),
+            timestamp: att.timestamp().to_rfc3339(),
+            txid: att.txid_hex().to_string(),
+            explorer_link: crate::output::explorer_link(&att),
+            file_hash_matches: None,
+        };
+
+        self.task_running = true;
+        self.operation_phase = OperationPhase::Syncing;
+        self.status_message = t!("verify-on-chain");
+        let tx = self.task_tx.clone();
+        tokio::spawn(async move {
+            run_verify_task(tx, config, verify_data).await;
+        });
+    }
+
+    /// Look up the selected pending proof's transaction and complete it
+    fn start_upgrade_task(&mut self) {
+        let Some(item) = self.selected_history().cloned() else {
+            return;
+        };
+        self.clear_history_result();
+        if item.is_confirmed() {
+            self.result_message = t!("tui-proof-already-confirmed");
+            return;
+        }
+        let Some(txid) = item.pending_txid else {
+            self.result_message = t!("tui-proof-no-transaction");
+            self.result_is_error = true;
+            return;
+        };
+        let Some(config) = self.config.clone() else {
+            self.result_message = t!("tui-no-wallet");
+            self.result_is_error = true;
+            return;
+        };
+
+        self.task_running = true;
+        self.operation_phase = OperationPhase::Syncing;
+        self.status_message = t!("tui-status-looking-up");
+        let tx = self.task_tx.clone();
+        tokio::spawn(async move {
+            run_upgrade_task(tx, config, vec![item.path], txid).await;
+        });
+    }
+
+    /// Whether the Stamp screen shows a timed-out stamp whose proofs can be upgraded
+    fn can_upgrade_stamp(&self) -> bool {
+        self.state == AppState::Stamp
+            && self.operation_phase == OperationPhase::Failed
+            && self.stamp_pending_txid.is_some()
+            && !self.task_running
+    }
+
+    /// Wait for the timed-out stamp transaction and upgrade its pending proofs
+    fn start_stamp_upgrade_task(&mut self) {
+        let Some(txid) = self.stamp_pending_txid.clone() else {
+            return;
+        };
+        let Some(config) = self.config.clone() else {
+            return;
+        };
+        let paths: Vec<PathBuf> = self
+            .stamp_queue
+            .iter()
+            .filter(|item| item.status == QueueStatus::Broadcast)
+            .map(|item| item.output_path.clone())
+            .collect();
+
+        self.task_running = true;
+        self.result_message.clear();
+        self.result_is_error = false;
+        self.operation_phase = OperationPhase::Syncing;
+        self.status_message = t!("tui-status-looking-up");
+        let tx = self.task_tx.clone();
+        tokio::spawn(async move {
+            run_upgrade_task(tx, config, paths, txid).await;
+        });
     }
 
     /// Toggle between supported hash algorithms for stamping
@@ -341,13 +834,30 @@ impl App {
             HashAlgorithm::Sha256 => HashAlgorithm::Blake3,
             HashAlgorithm::Blake3 => HashAlgorithm::Sha256,
         };
-        self.result_message = format!("Using {}", self.hash_algorithm.name());
+        self.result_message = t!(
+            "tui-using-algorithm",
+            algorithm = self.hash_algorithm.name()
+        );
         self.result_is_error = false;
     }
 
     /// Handle keyboard input in current state
     pub fn handle_input(&mut self, key: KeyCode) -> Result<()> {
+        if self.state == AppState::History {
+            self.handle_history_input(key);
+            return Ok(());
+        }
+        if self.picker.is_some() {
+            return self.handle_picker_input(key);
+        }
         match key {
+            KeyCode::F(2) => self.open_picker(),
+            KeyCode::Char(c @ ('c' | 'C' | 't' | 'T' | 'e' | 'E')) if self.can_copy() => {
+                self.copy_result(c.to_ascii_lowercase());
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') if self.can_upgrade_stamp() => {
+                self.start_stamp_upgrade_task();
+            }
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 if self.can_toggle_qr() {
                     self.qr_visible = !self.qr_visible;
@@ -358,10 +868,22 @@ impl App {
                 }
             }
             KeyCode::Tab => {
-                if matches!(self.state, AppState::Stamp) && !self.task_running {
+                // The algorithm applies to the whole queue, so lock it once files are hashed
+                if matches!(self.state, AppState::Stamp)
+                    && !self.task_running
+                    && self.stamp_queue.is_empty()
+                {
                     self.toggle_hash_algorithm();
                 }
             }
+            KeyCode::Delete => {
+                if matches!(self.state, AppState::Stamp)
+                    && !self.task_running
+                    && matches!(self.operation_phase, OperationPhase::Input)
+                {
+                    self.remove_last_queue_item();
+                }
+            }
             KeyCode::Char(c) => {
                 // Don't accept input while task is running
                 if !self.task_running {
@@ -381,6 +903,86 @@ impl App {
         Ok(())
     }
 
+    /// Open the file picker when the current screen is waiting for a path
+    fn open_picker(&mut self) {
+        if self.task_running || !matches!(self.operation_phase, OperationPhase::Input) {
+            return;
+        }
+        let extension = match (&self.state, &self.verify_step) {
+            (AppState::Stamp, _) | (AppState::Verify, VerifyStep::FileOrHash) => None,
+            (AppState::Verify, VerifyStep::ProofPath) => Some("zots"),
+            _ => return,
+        };
+        self.picker = Some(FilePicker::open(
+            FilePicker::start_dir(&self.input_buffer),
+            extension,
+        ));
+    }
+
+    /// Handle keys while the file picker is open
+    ///
+    /// A picked file is submitted as if its path had been typed.
+    fn handle_picker_input(&mut self, key: KeyCode) -> Result<()> {
+        let Some(picker) = self.picker.as_mut() else {
+            return Ok(());
+        };
+        match key {
+            KeyCode::Up => picker.up(),
+            KeyCode::Down => picker.down(),
+            KeyCode::Char(c) => picker.push_filter(c),
+            KeyCode::Backspace => picker.backspace(),
+            KeyCode::Enter => {
+                if let Some(path) = picker.enter() {
+                    self.picker = None;
+                    self.input_buffer = path.display().to_string();
+                    self.process_input()?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Click on a picker row: select it, or open/pick it if already selected
+    pub fn click_picker_row(&mut self, index: usize) -> Result<()> {
+        let Some(picker) = self.picker.as_mut() else {
+            return Ok(());
+        };
+        if picker.selected == index {
+            return self.handle_picker_input(KeyCode::Enter);
+        }
+        picker.select(index);
+        Ok(())
+    }
+
+    /// Click on a History row: select it, or toggle its details if already selected
+    pub fn click_history_row(&mut self, index: usize) {
+        if index == self.history_selected {
+            self.history_details = !self.history_details;
+        } else if index < self.history.len() {
+            self.history_selected = index;
+        }
+    }
+
+    /// Mouse wheel over the main panel: move the list selection
+    pub fn scroll(&mut self, up: bool) -> Result<()> {
+        let key = if up { KeyCode::Up } else { KeyCode::Down };
+        if self.picker.is_some() || self.state == AppState::History {
+            self.handle_input(key)?;
+        }
+        Ok(())
+    }
+
+    /// Mouse wheel over the activity pane
+    pub fn scroll_log(&mut self, up: bool) {
+        let key = if up {
+            KeyCode::PageUp
+        } else {
+            KeyCode::PageDown
+        };
+        self.handle_log_input(key);
+    }
+
     fn can_toggle_qr(&self) -> bool {
         matches!(
             self.operation_phase,
@@ -399,124 +1001,394 @@ impl App {
         match self.state {
             AppState::Stamp => {
                 if matches!(self.operation_phase, OperationPhase::Input) {
-                    self.start_stamp_task();
+                    if self.input_buffer.trim().is_empty() {
+                        self.start_queue_task();
+                    } else {
+                        self.enqueue_stamp_input();
+                    }
                 }
             }
             AppState::Verify => {
                 self.process_verify();
             }
-            AppState::Wallet => {
-                if self.input_buffer.to_lowercase() == "s"
-                    || self.input_buffer.to_lowercase() == "sync"
-                {
-                    self.start_sync_task();
+            AppState::Wallet => match self.input_buffer.trim().to_lowercase().as_str() {
+                "s" | "sync" => self.start_sync_task(),
+                "a" | "address" | "r" | "receive" => self.start_address_task(false),
+                "n" | "new" => self.start_address_task(true),
+                "t" | "send" => {
+                    self.reset_state();
+                    self.state = AppState::Send;
                 }
-            }
-            AppState::Menu => {}
+                _ => {}
+            },
+            AppState::Send => self.process_send(),
+            AppState::Menu | AppState::History => {}
         }
         self.input_buffer.clear();
         Ok(())
     }
 
-    /// Start stamp operation as background task
-    fn start_stamp_task(&mut self) {
+    /// Add a file, glob pattern or hex hash to the stamp queue
+    ///
+    /// Files are hashed in the background; hashes are queued directly.
+    fn enqueue_stamp_input(&mut self) {
         let input = self.input_buffer.trim().to_string();
-        if input.is_empty() {
-            self.result_message = "Please enter a file path or hash".to_string();
+        self.result_message.clear();
+        self.result_is_error = false;
+
+        let is_path = PathBuf::from(&input).exists() || input.contains(['*', '?', '[']);
+        if is_path {
+            let paths = match crate::commands::stamp::expand_paths(std::slice::from_ref(&input)) {
+                Ok(paths) => paths,
+                Err(e) => {
+                    self.result_message = e.to_string();
+                    self.result_is_error = true;
+                    return;
+                }
+            };
+            for path in paths {
+                if !path.is_file() {
+                    self.result_message = t!("tui-not-a-file", path = path.display().to_string());
+                    self.result_is_error = true;
+                    continue;
+                }
+                let label = path.display().to_string();
+                if self.stamp_queue.iter().any(|item| item.label == label) {
+                    continue;
+                }
+                let id = self.push_queue_item(
+                    label,
+                    crate::commands::stamp::default_output_path(&path),
+                    None,
+                );
+                let tx = self.task_tx.clone();
+                let algorithm = self.hash_algorithm;
+                tokio::spawn(async move {
+                    let result =
+                        tokio::task::spawn_blocking(move || hash_file_with(&path, algorithm))
+                            .await
+                            .map_err(|e| e.to_string())
+                            .and_then(|hash| hash.map_err(|e| e.to_string()));
+                    let _ = tx.send(TaskMessage::QueueHashed { id, result }).await;
+                });
+            }
+        } else if input.len() >= 40 {
+            match hash_from_hex_with(&input, self.hash_algorithm) {
+                Ok(hash) => {
+                    let output = PathBuf::from(format!("{}.zots", &input[..16]));
+                    self.push_queue_item(input, output, Some(hash));
+                }
+                Err(e) => {
+                    self.result_message = t!("tui-invalid-hash", error = e.to_string());
+                    self.result_is_error = true;
+                }
+            }
+        } else {
+            self.result_message = t!("tui-not-file-or-hash");
+            self.result_is_error = true;
+        }
+    }
+
+    /// Append a queue entry; entries without a hash start in the hashing state
+    fn push_queue_item(
+        &mut self,
+        label: String,
+        output_path: PathBuf,
+        hash: Option<[u8; 32]>,
+    ) -> u64 {
+        let id = self.next_queue_id;
+        self.next_queue_id += 1;
+        self.stamp_queue.push(QueueItem {
+            id,
+            label,
+            output_path,
+            status: if hash.is_some() {
+                QueueStatus::Waiting
+            } else {
+                QueueStatus::Hashing
+            },
+            hash,
+        });
+        id
+    }
+
+    /// Stamp every hashed queue entry in one batched transaction
+    fn start_queue_task(&mut self) {
+        if self.stamp_queue.is_empty() {
+            self.result_message = t!("tui-enter-stamp-input");
+            self.result_is_error = true;
+            return;
+        }
+        if self
+            .stamp_queue
+            .iter()
+            .any(|item| item.status == QueueStatus::Hashing)
+        {
+            self.result_message = t!("tui-still-hashing");
+            self.result_is_error = true;
+            return;
+        }
+        let jobs: Vec<(u64, [u8; 32], PathBuf)> = self
+            .stamp_queue
+            .iter()
+            .filter(|item| item.status == QueueStatus::Waiting)
+            .filter_map(|item| Some((item.id, item.hash?, item.output_path.clone())))
+            .collect();
+        if jobs.is_empty() {
+            self.result_message = t!("tui-nothing-to-stamp");
             self.result_is_error = true;
             return;
         }
 
-        // Check if config is available
+        // Check if config is available
+        let config = match &self.config {
+            Some(c) => c.clone(),
+            None => {
+                self.result_message = t!("tui-no-wallet-seed");
+                self.result_is_error = true;
+                self.operation_phase = OperationPhase::Failed;
+                return;
+            }
+        };
+
+        // Mark as running and update UI
+        self.task_running = true;
+        self.operation_phase = OperationPhase::Syncing;
+        self.status_message = t!("tui-status-starting-stamp");
+        self.result_message.clear();
+        self.result_is_error = false;
+        self.qr_visible = false;
+        self.qr_data = None;
+
+        let tx = self.task_tx.clone();
+        let algorithm = self.hash_algorithm;
+        tokio::spawn(async move {
+            run_stamp_task(tx, config, jobs, algorithm).await;
+        });
+    }
+
+    /// Drop the last queue entry
+    fn remove_last_queue_item(&mut self) {
+        if let Some(item) = self.stamp_queue.pop() {
+            self.result_message = t!("tui-removed", item = item.label.as_str());
+            self.result_is_error = false;
+        }
+    }
+
+    /// Apply a status to the given queue entries
+    fn set_queue_status(&mut self, ids: &[u64], status: &QueueStatus) {
+        for item in &mut self.stamp_queue {
+            if ids.contains(&item.id) {
+                item.status = status.clone();
+            }
+        }
+    }
+
+    /// Start wallet sync as background task
+    fn start_sync_task(&mut self) {
+        let config = match &self.config {
+            Some(c) => c.clone(),
+            None => {
+                self.result_message = t!("tui-no-wallet");
+                self.result_is_error = true;
+                self.operation_phase = OperationPhase::Failed;
+                return;
+            }
+        };
+
+        self.task_running = true;
+        self.operation_phase = OperationPhase::Syncing;
+        self.status_message = t!("tui-status-syncing");
+
+        let tx = self.task_tx.clone();
+
+        tokio::spawn(async move {
+            run_sync_task(tx, config).await;
+        });
+    }
+
+    /// Load the receiving address (or derive a new diversified one) in the background
+    fn start_address_task(&mut self, new: bool) {
         let config = match &self.config {
             Some(c) => c.clone(),
             None => {
-                self.result_message = "No wallet configured (set ZOTS_SEED)".to_string();
+                self.result_message = t!("tui-no-wallet");
                 self.result_is_error = true;
                 self.operation_phase = OperationPhase::Failed;
                 return;
             }
         };
 
-        // Validate input and compute hash (fast, synchronous)
-        let path = PathBuf::from(&input);
-        let (hash_bytes, output_path) = if path.exists() {
-            match hash_file_with(&path, self.hash_algorithm) {
-                Ok(h) => {
-                    let output = PathBuf::from(format!(
-                        "{}.zots",
-                        path.file_name().unwrap_or_default().to_string_lossy()
-                    ));
-                    (h, output)
-                }
-                Err(e) => {
-                    self.result_message = format!("Hash error: {e}");
+        self.task_running = true;
+        self.result_message.clear();
+        self.status_message = if new {
+            t!("tui-status-generating-address")
+        } else {
+            t!("tui-status-loading-address")
+        };
+
+        let tx = self.task_tx.clone();
+        tokio::spawn(async move {
+            run_address_task(tx, config, new).await;
+        });
+    }
+
+    /// Process send input - address, amount, memo, then confirmation
+    fn process_send(&mut self) {
+        let input = self.input_buffer.trim().to_string();
+        self.result_message.clear();
+        self.result_is_error = false;
+
+        match self.send_step {
+            SendStep::Address => {
+                if input.is_empty() {
+                    self.result_message = t!("tui-enter-recipient");
                     self.result_is_error = true;
-                    self.operation_phase = OperationPhase::Failed;
                     return;
                 }
+                self.send_address = input;
+                self.send_step = SendStep::Amount;
             }
-        } else if input.len() >= 40 {
-            match hash_from_hex_with(&input, self.hash_algorithm) {
-                Ok(h) => {
-                    let output = PathBuf::from(format!("{}.zots", &input[..16]));
-                    (h, output)
+            SendStep::Amount => match parse_zec(&input) {
+                Ok(0) => {
+                    self.result_message = t!("tui-amount-zero");
+                    self.result_is_error = true;
+                }
+                Ok(amount) => {
+                    self.send_amount = amount;
+                    self.send_step = SendStep::Memo;
                 }
                 Err(e) => {
-                    self.result_message = format!("Invalid hash: {e}");
+                    self.result_message = e;
+                    self.result_is_error = true;
+                }
+            },
+            SendStep::Memo => {
+                // Keep the memo as typed; only surrounding whitespace is trimmed
+                if input.len() > 512 {
+                    self.result_message = t!("tui-memo-too-long", bytes = input.len(), limit = 512);
                     self.result_is_error = true;
-                    self.operation_phase = OperationPhase::Failed;
                     return;
                 }
+                self.send_memo = input;
+                self.start_send_task(true);
             }
-        } else {
-            self.result_message = "File not found and input is not a valid hash".to_string();
-            self.result_is_error = true;
-            self.operation_phase = OperationPhase::Failed;
-            return;
-        };
-
-        // Mark as running and update UI
-        self.task_running = true;
-        self.operation_phase = OperationPhase::Syncing;
-        self.status_message = "Starting stamp operation...".to_string();
-        self.qr_visible = false;
-        self.qr_data = None;
-
-        // Clone sender for background task
-        let tx = self.task_tx.clone();
-        let network = config.network;
-        let algorithm = self.hash_algorithm;
-
-        // Spawn background task
-        tokio::spawn(async move {
-            run_stamp_task(tx, config, hash_bytes, output_path, network, algorithm).await;
-        });
+            SendStep::Confirm => {
+                if input.eq_ignore_ascii_case("yes") {
+                    self.start_send_task(false);
+                } else {
+                    self.result_message = t!("tui-type-yes");
+                    self.result_is_error = true;
+                }
+            }
+            SendStep::Previewing | SendStep::Sending | SendStep::Done => {}
+        }
     }
 
-    /// Start wallet sync as background task
-    fn start_sync_task(&mut self) {
+    /// Start the send fee preview (`preview`) or the send itself
+    fn start_send_task(&mut self, preview: bool) {
         let config = match &self.config {
             Some(c) => c.clone(),
             None => {
-                self.result_message = "No wallet configured".to_string();
+                self.result_message = t!("tui-no-wallet-seed");
                 self.result_is_error = true;
-                self.operation_phase = OperationPhase::Failed;
+                self.send_step = SendStep::Done;
                 return;
             }
         };
 
         self.task_running = true;
-        self.operation_phase = OperationPhase::Syncing;
-        self.status_message = "Syncing wallet...".to_string();
+        let (step, status) = if preview {
+            (SendStep::Previewing, t!("tui-status-computing-fee"))
+        } else {
+            (SendStep::Sending, t!("tui-status-sending"))
+        };
+        self.send_step = step;
+        self.status_message = status;
 
         let tx = self.task_tx.clone();
-
+        let address = self.send_address.clone();
+        let amount = self.send_amount;
+        let memo = (!self.send_memo.is_empty()).then(|| self.send_memo.as_bytes().to_vec());
+        // The confirmed send must not pay more than the fee the user approved
+        let approved = if preview { None } else { self.send_preview };
         tokio::spawn(async move {
-            run_sync_task(tx, config).await;
+            run_send_task(tx, config, address, amount, memo, approved).await;
         });
     }
 
+    /// Explorer link for the broadcast send transaction
+    pub fn send_explorer_link(&self) -> Option<String> {
+        self.explorer_tx_link(self.send_txid.as_ref()?)
+    }
+
+    /// Explorer link for a transaction on the configured network
+    fn explorer_tx_link(&self, txid: &str) -> Option<String> {
+        let config = self.config.as_ref()?;
+        let base = config
+            .explorer_url
+            .as_deref()
+            .unwrap_or_else(|| config.network.default_explorer_url());
+        Some(format!("{base}/tx/{txid}"))
+    }
+
+    /// Whether the current screen shows a result that can be copied
+    pub fn can_copy(&self) -> bool {
+        if self.task_running {
+            return false;
+        }
+        match self.state {
+            AppState::Stamp => {
+                self.operation_phase == OperationPhase::Complete && !self.batch_results.is_empty()
+            }
+            AppState::Verify => {
+                self.verify_step == VerifyStep::Results && self.verify_result.is_some()
+            }
+            AppState::Send => self.send_txid.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Copy part of the current result: `c` compact proof, `t` txid, `e` explorer link
+    fn copy_result(&mut self, what: char) {
+        let (compact, txid) = match self.state {
+            // A batch copies one compact proof per line
+            AppState::Stamp => (
+                Some(
+                    self.batch_results
+                        .iter()
+                        .map(|r| r.compact.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+                self.batch_results.first().map(|r| r.txid.clone()),
+            ),
+            AppState::Verify => match &self.verify_result {
+                Some(result) => (Some(result.compact.clone()), Some(result.txid.clone())),
+                None => (None, None),
+            },
+            AppState::Send => (None, self.send_txid.clone()),
+            _ => (None, None),
+        };
+        let txid = txid.filter(|txid| !txid.is_empty());
+
+        let (label, text) = match what {
+            'c' => (t!("tui-copy-proof"), compact),
+            't' => (t!("tui-copy-txid"), txid),
+            _ => (
+                t!("tui-copy-explorer-link"),
+                txid.and_then(|txid| self.explorer_tx_link(&txid)),
+            ),
+        };
+        self.status_message = match text.filter(|text| !text.is_empty()) {
+            Some(text) => match clipboard::copy(&text) {
+                Ok(target) => t!("tui-copied", what = label, target = target),
+                Err(e) => t!("tui-copy-failed", error = e.to_string()),
+            },
+            None => t!("tui-nothing-to-copy", what = label),
+        };
+    }
+
     /// Process verify input - multi-step: file/hash, then proof path
     fn process_verify(&mut self) {
         let input = self.input_buffer.trim().to_string();
@@ -524,7 +1396,7 @@ impl App {
         match self.verify_step {
             VerifyStep::FileOrHash => {
                 if input.is_empty() {
-                    self.result_message = "Please enter a file path or hash to verify".to_string();
+                    self.result_message = t!("tui-enter-verify-input");
                     self.result_is_error = true;
                     return;
                 }
@@ -552,13 +1424,12 @@ impl App {
                             self.result_is_error = false;
                         }
                         Err(e) => {
-                            self.result_message = format!("Invalid hash: {e}");
+                            self.result_message = t!("tui-invalid-hash", error = e.to_string());
                             self.result_is_error = true;
                         }
                     }
                 } else {
-                    self.result_message =
-                        "File not found and input is not a valid hash".to_string();
+                    self.result_message = t!("tui-not-file-or-hash");
                     self.result_is_error = true;
                 }
             }
@@ -571,14 +1442,15 @@ impl App {
                 self.qr_data = None;
 
                 if input.is_empty() {
-                    self.result_message = "Please enter a proof file path (.zots)".to_string();
+                    self.result_message = t!("tui-enter-proof-path");
                     self.result_is_error = true;
                     return;
                 }
 
                 let path = PathBuf::from(&input);
                 if !path.exists() {
-                    self.result_message = format!("File not found: {}", path.display());
+                    self.result_message =
+                        t!("tui-file-not-found", path = path.display().to_string());
                     self.result_is_error = true;
                     return;
                 }
@@ -587,7 +1459,7 @@ impl App {
                 let proof = match TimestampProof::load(&path) {
                     Ok(p) => p,
                     Err(e) => {
-                        self.result_message = format!("Load error: {e}");
+                        self.result_message = t!("tui-load-error", error = e.to_string());
                         self.result_is_error = true;
                         return;
                     }
@@ -597,7 +1469,7 @@ impl App {
                 let proof_hash_bytes = match proof.hash_bytes() {
                     Ok(h) => h,
                     Err(e) => {
-                        self.result_message = format!("Invalid proof hash: {e}");
+                        self.result_message = t!("tui-invalid-proof-hash", error = e.to_string());
                         self.result_is_error = true;
                         return;
                     }
@@ -618,7 +1490,7 @@ impl App {
                 let verify_hash = match recomputed_hash {
                     Ok(hash) => hash,
                     Err(e) => {
-                        self.result_message = format!("Hash error: {e}");
+                        self.result_message = t!("tui-hash-error", error = e.to_string());
                         self.result_is_error = true;
                         return;
                     }
@@ -640,8 +1512,9 @@ impl App {
                         timestamp: String::new(),
                         txid: String::new(),
                         explorer_link: String::new(),
-                        error: Some("Hash does NOT match the provided file/hash!".to_string()),
+                        error: Some(t!("tui-verify-hash-mismatch")),
                         file_hash_matches: Some(false),
+                        note: proof.note().map(str::to_string),
                     });
                     self.verify_step = VerifyStep::Results;
                     self.operation_phase = OperationPhase::Failed;
@@ -659,8 +1532,9 @@ impl App {
                         timestamp: String::new(),
                         txid: String::new(),
                         explorer_link: String::new(),
-                        error: Some("Proof is pending (no attestations yet)".to_string()),
+                        error: Some(t!("tui-verify-pending")),
                         file_hash_matches,
+                        note: proof.note().map(str::to_string),
                     });
                     self.verify_step = VerifyStep::Results;
                     self.operation_phase = OperationPhase::Complete;
@@ -682,11 +1556,10 @@ impl App {
                             block_height: att.block_height,
                             timestamp: att.timestamp().to_rfc3339(),
                             txid: att.txid_hex().to_string(),
-                            explorer_link: att.explorer_link(),
-                            error: Some(
-                                "Cannot verify on-chain (no wallet configured)".to_string(),
-                            ),
+                            explorer_link: crate::output::explorer_link(att),
+                            error: Some(t!("tui-verify-no-wallet")),
                             file_hash_matches,
+                            note: proof.note().map(str::to_string),
                         });
                         self.verify_step = VerifyStep::Results;
                         self.operation_phase = OperationPhase::Complete;
@@ -698,7 +1571,7 @@ impl App {
                 let txid_bytes = match att.txid_bytes() {
                     Ok(b) => b,
                     Err(e) => {
-                        self.result_message = format!("Invalid TXID: {e}");
+                        self.result_message = t!("tui-invalid-txid", error = e.to_string());
                         self.result_is_error = true;
                         self.operation_phase = OperationPhase::Failed;
                         return;
@@ -716,15 +1589,16 @@ impl App {
                     network: att.network.to_string(),
                     timestamp: att.timestamp().to_rfc3339(),
                     txid: att.txid_hex().to_string(),
-                    explorer_link: att.explorer_link(),
+                    explorer_link: crate::output::explorer_link(att),
                     file_hash_matches,
+                    note: proof.note().map(str::to_string),
                 };
                 self.qr_data = Some(proof_compact);
 
                 self.task_running = true;
                 self.verify_step = VerifyStep::Verifying;
                 self.operation_phase = OperationPhase::Syncing;
-                self.status_message = "Verifying against blockchain...".to_string();
+                self.status_message = t!("verify-on-chain");
 
                 let tx = self.task_tx.clone();
 
@@ -739,11 +1613,76 @@ impl App {
     }
 
     /// Get network name for display
-    pub fn network_name(&self) -> &str {
+    pub fn network_name(&self) -> String {
         self.config
             .as_ref()
-            .map(|c| c.network.name())
-            .unwrap_or("unknown")
+            .map(|c| c.network.name().to_string())
+            .unwrap_or_else(|| t!("tui-network-unknown"))
+    }
+}
+
+/// Proofs for the History screen
+///
+/// Lists `.zots` files in the current directory plus every pending proof
+/// tracked in the registry, wherever it was saved.
+fn load_history(config: Option<&ZcashConfig>) -> Vec<HistoryItem> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(".")
+        .map(|dir| {
+            dir.flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "zots"))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+
+    let mut items: Vec<HistoryItem> = paths
+        .iter()
+        .filter_map(|path| {
+            TimestampProof::load(path)
+                .ok()
+                .map(|p| history_item(path, p))
+        })
+        .collect();
+
+    let tracked = config
+        .map(crate::commands::pending::store_path)
+        .and_then(|path| PendingStore::load(path).ok());
+    for entry in tracked.iter().flat_map(|store| store.entries()) {
+        let already_listed = items
+            .iter()
+            .any(|item| std::path::absolute(&item.path).is_ok_and(|path| path == entry.proof_path));
+        if already_listed {
+            continue;
+        }
+        items.push(match TimestampProof::load(&entry.proof_path) {
+            Ok(proof) => history_item(&entry.proof_path, proof),
+            Err(e) => HistoryItem {
+                path: entry.proof_path.clone(),
+                hash: entry.hash.clone(),
+                algorithm: HashAlgorithm::default(),
+                attestation: None,
+                pending_txid: entry.txid.clone(),
+                error: Some(e.to_string()),
+                note: None,
+            },
+        });
+    }
+
+    // Pending proofs first: they are the ones needing attention
+    items.sort_by_key(|item| item.is_confirmed());
+    items
+}
+
+fn history_item(path: &Path, proof: TimestampProof) -> HistoryItem {
+    HistoryItem {
+        path: path.to_path_buf(),
+        algorithm: proof.hash_algorithm(),
+        attestation: proof.attestations.first().cloned(),
+        note: proof.note().map(str::to_string),
+        pending_txid: proof.pending_txid,
+        hash: proof.hash,
+        error: None,
     }
 }
 
@@ -760,30 +1699,33 @@ struct VerifyTaskData {
     txid: String,
     explorer_link: String,
     file_hash_matches: Option<bool>,
+    note: Option<String>,
 }
 
-/// Background task for stamp operation
+/// Background task stamping queued hashes in one batched transaction
 async fn run_stamp_task(
     tx: mpsc::Sender<TaskMessage>,
     config: ZcashConfig,
-    hash_bytes: [u8; 32],
-    output_path: PathBuf,
-    network: zots_core::Network,
+    jobs: Vec<(u64, [u8; 32], PathBuf)>,
     hash_algorithm: HashAlgorithm,
 ) {
-    let hash_hex = hash_to_hex(&hash_bytes);
+    let network = config.network;
+    let registry = config.pending_store_path();
+    let ids: Vec<u64> = jobs.iter().map(|(id, _, _)| *id).collect();
+    let hashes: Vec<[u8; 32]> = jobs.iter().map(|(_, hash, _)| *hash).collect();
 
     // Syncing phase
     let _ = tx.send(TaskMessage::Phase(OperationPhase::Syncing)).await;
-    let _ = tx
-        .send(TaskMessage::Status("Syncing wallet...".to_string()))
-        .await;
+    let _ = tx.send(TaskMessage::Status(t!("tui-status-syncing"))).await;
 
     let mut wallet = match ZotsWallet::new(config).await {
         Ok(w) => w,
         Err(e) => {
             let _ = tx
-                .send(TaskMessage::StampFailed(format!("Wallet error: {e}")))
+                .send(TaskMessage::StampFailed(t!(
+                    "tui-wallet-error",
+                    error = e.to_string()
+                )))
                 .await;
             return;
         }
@@ -791,14 +1733,20 @@ async fn run_stamp_task(
 
     if let Err(e) = wallet.init_account().await {
         let _ = tx
-            .send(TaskMessage::StampFailed(format!("Account init error: {e}")))
+            .send(TaskMessage::StampFailed(t!(
+                "tui-account-error",
+                error = e.to_string()
+            )))
             .await;
         return;
     }
 
     if let Err(e) = wallet.sync().await {
         let _ = tx
-            .send(TaskMessage::StampFailed(format!("Sync failed: {e}")))
+            .send(TaskMessage::StampFailed(t!(
+                "tui-sync-failed",
+                error = e.to_string()
+            )))
             .await;
         return;
     }
@@ -808,99 +1756,150 @@ async fn run_stamp_task(
         .send(TaskMessage::Phase(OperationPhase::Broadcasting))
         .await;
     let _ = tx
-        .send(TaskMessage::Status(
-            "Creating and broadcasting transaction...".to_string(),
-        ))
+        .send(TaskMessage::Status(t!(
+            "tui-status-broadcasting",
+            count = hashes.len()
+        )))
         .await;
 
-    let tx_result = match wallet.create_timestamp_tx(&hash_bytes).await {
+    let tx_result = match wallet.create_batch_timestamp_tx(&hashes).await {
         Ok(r) => r,
         Err(e) => {
             let _ = tx
-                .send(TaskMessage::StampFailed(format!("Transaction failed: {e}")))
+                .send(TaskMessage::StampFailed(t!(
+                    "tui-transaction-failed",
+                    error = e.to_string()
+                )))
                 .await;
             return;
         }
     };
 
     let txid = tx_result.txid.clone();
+    let _ = tx
+        .send(TaskMessage::QueueStatus {
+            ids: ids.clone(),
+            status: QueueStatus::Broadcast,
+        })
+        .await;
 
     // Waiting for confirmation phase
+    let policy = ConfirmationWaitPolicy::default();
     let _ = tx
         .send(TaskMessage::Phase(OperationPhase::WaitingConfirmation {
             txid: txid.clone(),
+            current_height: 0,
+            confirmations: 0,
+            target: policy.confirmations,
         }))
         .await;
     let _ = tx
-        .send(TaskMessage::Status(format!(
-            "Waiting for confirmation (TXID: {}...)",
-            &txid[..12]
+        .send(TaskMessage::Status(t!(
+            "tui-status-waiting-confirmation",
+            txid = &txid[..12]
         )))
         .await;
 
-    let confirmation = match wallet.wait_confirmation(&txid, 10).await {
+    let progress_tx = tx.clone();
+    let progress_txid = txid.clone();
+    let confirmation = match wallet
+        .wait_confirmation_with(&txid, &policy, |progress| {
+            let _ = progress_tx.try_send(TaskMessage::Phase(OperationPhase::WaitingConfirmation {
+                txid: progress_txid.clone(),
+                current_height: progress.current_height,
+                confirmations: progress.confirmations,
+                target: progress.target,
+            }));
+        })
+        .await
+    {
         Ok(c) => c,
         Err(e) => {
-            // Save pending proof
-            let proof = TimestampProof::new_with_algorithm(hash_bytes, hash_algorithm);
-            let _ = proof.save(&output_path);
+            // Save pending proofs so they can be upgraded later
+            let mut saved = Vec::new();
+            for (_, hash, output_path) in &jobs {
+                let mut proof = TimestampProof::new_with_algorithm(*hash, hash_algorithm);
+                proof.set_pending_txid(txid.clone());
+                if proof.save(output_path).is_ok() {
+                    crate::commands::pending::record(&registry, |store| {
+                        store.track(output_path, &proof)
+                    });
+                    saved.push(output_path.display().to_string());
+                }
+            }
 
             let _ = tx
-                .send(TaskMessage::StampFailed(format!(
-                    "TX broadcast but confirmation timed out: {e}\nPending proof saved: {}",
-                    output_path.display()
-                )))
+                .send(TaskMessage::StampPending {
+                    txid,
+                    message: t!(
+                        "tui-stamp-timed-out",
+                        error = e.to_string(),
+                        saved = saved.join(", ")
+                    ),
+                })
                 .await;
             return;
         }
     };
 
-    // Create and save proof
-    let mut proof = TimestampProof::new_with_algorithm(hash_bytes, hash_algorithm);
-    proof.add_attestation(ZcashAttestation::new(
-        network,
-        tx_result.txid_bytes,
-        confirmation.block_height,
-        confirmation.block_time,
-        0,
-    ));
-
-    if let Err(e) = proof.save(&output_path) {
-        let _ = tx
-            .send(TaskMessage::StampFailed(format!("Save error: {e}")))
-            .await;
-        return;
-    }
-
-    let compact = proof
-        .to_compact()
-        .unwrap_or_else(|_| "Error generating compact format".to_string());
+    // Create and save proofs
+    let mut results = Vec::with_capacity(jobs.len());
+    for (_, hash, output_path) in &jobs {
+        let mut proof = TimestampProof::new_with_algorithm(*hash, hash_algorithm);
+        proof.add_attestation(ZcashAttestation::new(
+            network,
+            tx_result.txid_bytes,
+            confirmation.block_height,
+            confirmation.block_time,
+            0,
+        ));
+
+        if let Err(e) = proof.save(output_path) {
+            let _ = tx
+                .send(TaskMessage::StampFailed(t!(
+                    "tui-save-error",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
 
-    let _ = tx
-        .send(TaskMessage::StampComplete(StampResult {
-            hash: hash_hex,
+        let compact = proof
+            .to_compact()
+            .unwrap_or_else(|_| t!("tui-compact-error"));
+        results.push(StampResult {
+            hash: hash_to_hex(hash),
             algorithm: hash_algorithm,
-            txid,
+            txid: txid.clone(),
             block_height: confirmation.block_height,
             block_time: confirmation.block_time as u64,
             output_path: output_path.display().to_string(),
             compact,
-        }))
+        });
+    }
+
+    let _ = tx
+        .send(TaskMessage::QueueStatus {
+            ids,
+            status: QueueStatus::Confirmed,
+        })
         .await;
+    let _ = tx.send(TaskMessage::StampComplete(results)).await;
 }
 
 /// Background task for wallet sync (explicit user action)
 async fn run_sync_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig) {
     let _ = tx.send(TaskMessage::Phase(OperationPhase::Syncing)).await;
-    let _ = tx
-        .send(TaskMessage::Status("Syncing wallet...".to_string()))
-        .await;
+    let _ = tx.send(TaskMessage::Status(t!("tui-status-syncing"))).await;
 
     let mut wallet = match ZotsWallet::new(config).await {
         Ok(w) => w,
         Err(e) => {
             let _ = tx
-                .send(TaskMessage::SyncFailed(format!("Wallet error: {e}")))
+                .send(TaskMessage::SyncFailed(t!(
+                    "tui-wallet-error",
+                    error = e.to_string()
+                )))
                 .await;
             return;
         }
@@ -908,7 +1907,10 @@ async fn run_sync_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig) {
 
     if let Err(e) = wallet.init_account().await {
         let _ = tx
-            .send(TaskMessage::SyncFailed(format!("Account init error: {e}")))
+            .send(TaskMessage::SyncFailed(t!(
+                "tui-account-error",
+                error = e.to_string()
+            )))
             .await;
         return;
     }
@@ -929,6 +1931,128 @@ async fn run_sync_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig) {
         .await;
 }
 
+/// Background task fetching the receiving address
+async fn run_address_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig, new: bool) {
+    let mut wallet = match ZotsWallet::new(config).await {
+        Ok(w) => w,
+        Err(e) => {
+            let _ = tx
+                .send(TaskMessage::AddressFailed(t!(
+                    "tui-wallet-error",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
+    };
+
+    if let Err(e) = wallet.init_account().await {
+        let _ = tx
+            .send(TaskMessage::AddressFailed(t!(
+                "tui-account-error",
+                error = e.to_string()
+            )))
+            .await;
+        return;
+    }
+
+    let address = if new {
+        wallet.get_new_address()
+    } else {
+        wallet.get_address()
+    };
+    let message = match address {
+        Ok(address) => TaskMessage::Address { address, new },
+        Err(e) => TaskMessage::AddressFailed(e.to_string()),
+    };
+    let _ = tx.send(message).await;
+}
+
+/// Background task for the Send screen
+///
+/// Syncs and reports the fee when `preview` is set; otherwise broadcasts.
+async fn run_send_task(
+    tx: mpsc::Sender<TaskMessage>,
+    config: ZcashConfig,
+    address: String,
+    amount: u64,
+    memo: Option<Vec<u8>>,
+    approved: Option<SendPreview>,
+) {
+    let mut wallet = match ZotsWallet::new(config).await {
+        Ok(w) => w,
+        Err(e) => {
+            let _ = tx
+                .send(TaskMessage::SendFailed(t!(
+                    "tui-wallet-error",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
+    };
+
+    if let Err(e) = wallet.init_account().await {
+        let _ = tx
+            .send(TaskMessage::SendFailed(t!(
+                "tui-account-error",
+                error = e.to_string()
+            )))
+            .await;
+        return;
+    }
+
+    let message = if let Some(approved) = approved {
+        match wallet.send_previewed(&address, memo, &approved).await {
+            Ok(result) => TaskMessage::SendComplete {
+                txid: result.txid,
+                fee: result.fee,
+            },
+            Err(e) => TaskMessage::SendFailed(e.to_string()),
+        }
+    } else {
+        if let Err(e) = wallet.sync().await {
+            let _ = tx
+                .send(TaskMessage::SendFailed(t!(
+                    "tui-sync-failed",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
+        match wallet.preview_send(&address, amount, memo.as_deref()) {
+            Ok(preview) => TaskMessage::SendPreview(preview),
+            Err(e) => TaskMessage::SendFailed(e.to_string()),
+        }
+    };
+    let _ = tx.send(message).await;
+}
+
+/// Parse a ZEC amount such as `0.0015` into zatoshis
+fn parse_zec(input: &str) -> Result<u64, String> {
+    let invalid = || t!("tui-invalid-amount", input = input);
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || fraction.len() > 8
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| invalid())?
+    };
+    let fraction: u64 = format!("{fraction:0<8}").parse().map_err(|_| invalid())?;
+    whole
+        .checked_mul(100_000_000)
+        .and_then(|zat| zat.checked_add(fraction))
+        .ok_or_else(invalid)
+}
+
 /// Background task for initial wallet sync (silent, at app startup)
 async fn run_initial_sync_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig) {
     let mut wallet = match ZotsWallet::new(config).await {
@@ -963,17 +2087,16 @@ async fn run_initial_sync_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfi
 /// Background task for verify operation
 async fn run_verify_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig, data: VerifyTaskData) {
     let _ = tx.send(TaskMessage::Phase(OperationPhase::Syncing)).await;
-    let _ = tx
-        .send(TaskMessage::Status(
-            "Verifying against blockchain...".to_string(),
-        ))
-        .await;
+    let _ = tx.send(TaskMessage::Status(t!("verify-on-chain"))).await;
 
     let mut wallet = match ZotsWallet::new(config).await {
         Ok(w) => w,
         Err(e) => {
             let _ = tx
-                .send(TaskMessage::VerifyFailed(format!("Wallet error: {e}")))
+                .send(TaskMessage::VerifyFailed(t!(
+                    "tui-wallet-error",
+                    error = e.to_string()
+                )))
                 .await;
             return;
         }
@@ -981,8 +2104,9 @@ async fn run_verify_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig, dat
 
     if let Err(e) = wallet.init_account().await {
         let _ = tx
-            .send(TaskMessage::VerifyFailed(format!(
-                "Account init error: {e}"
+            .send(TaskMessage::VerifyFailed(t!(
+                "tui-account-error",
+                error = e.to_string()
             )))
             .await;
         return;
@@ -1011,6 +2135,7 @@ async fn run_verify_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig, dat
                     explorer_link: data.explorer_link,
                     error: vr.error,
                     file_hash_matches: data.file_hash_matches,
+                    note: data.note,
                 }))
                 .await;
         }
@@ -1026,10 +2151,194 @@ async fn run_verify_task(tx: mpsc::Sender<TaskMessage>, config: ZcashConfig, dat
                     timestamp: data.timestamp,
                     txid: data.txid,
                     explorer_link: data.explorer_link,
-                    error: Some(format!("Verification error: {e}")),
+                    error: Some(t!("tui-verification-error", error = e.to_string())),
                     file_hash_matches: data.file_hash_matches,
+                    note: data.note,
+                }))
+                .await;
+        }
+    }
+}
+
+/// Background task upgrading pending proofs that share one transaction
+///
+/// Looks the transaction up, waits for it to be mined if needed, then
+/// attaches the attestation to every proof file.
+async fn run_upgrade_task(
+    tx: mpsc::Sender<TaskMessage>,
+    config: ZcashConfig,
+    paths: Vec<PathBuf>,
+    txid: String,
+) {
+    let network = config.network;
+    let registry = config.pending_store_path();
+    let mut proofs = Vec::with_capacity(paths.len());
+    for path in paths {
+        match TimestampProof::load(&path) {
+            Ok(proof) => proofs.push((path, proof)),
+            Err(e) => {
+                let _ = tx
+                    .send(TaskMessage::UpgradeFailed(t!(
+                        "tui-load-error-path",
+                        path = path.display().to_string(),
+                        error = e.to_string()
+                    )))
+                    .await;
+                return;
+            }
+        }
+    }
+
+    let mut wallet = match ZotsWallet::new(config).await {
+        Ok(w) => w,
+        Err(e) => {
+            let _ = tx
+                .send(TaskMessage::UpgradeFailed(t!(
+                    "tui-wallet-error",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
+    };
+
+    let confirmation = match wallet.fetch_confirmation(&txid).await {
+        Ok(Some(confirmation)) => confirmation,
+        Ok(None) => {
+            // Not mined yet: follow the chain until it is
+            let policy = ConfirmationWaitPolicy::default();
+            let _ = tx
+                .send(TaskMessage::Phase(OperationPhase::WaitingConfirmation {
+                    txid: txid.clone(),
+                    current_height: 0,
+                    confirmations: 0,
+                    target: policy.confirmations,
                 }))
                 .await;
+            let _ = tx
+                .send(TaskMessage::Status(t!(
+                    "tui-status-waiting-confirmation",
+                    txid = &txid[..txid.len().min(12)]
+                )))
+                .await;
+            if let Err(e) = wallet.init_account().await {
+                let _ = tx
+                    .send(TaskMessage::UpgradeFailed(t!(
+                        "tui-account-error",
+                        error = e.to_string()
+                    )))
+                    .await;
+                return;
+            }
+
+            let progress_tx = tx.clone();
+            let progress_txid = txid.clone();
+            let waited = wallet
+                .wait_confirmation_with(&txid, &policy, |progress| {
+                    let _ = progress_tx.try_send(TaskMessage::Phase(
+                        OperationPhase::WaitingConfirmation {
+                            txid: progress_txid.clone(),
+                            current_height: progress.current_height,
+                            confirmations: progress.confirmations,
+                            target: progress.target,
+                        },
+                    ));
+                })
+                .await;
+            match waited {
+                // Prefer the block's own time from a fresh lookup
+                Ok(confirmation) => wallet
+                    .fetch_confirmation(&txid)
+                    .await
+                    .ok()
+                    .flatten()
+                    .unwrap_or(confirmation),
+                Err(e) => {
+                    let _ = tx
+                        .send(TaskMessage::UpgradeFailed(t!(
+                            "tui-upgrade-retry",
+                            error = e.to_string()
+                        )))
+                        .await;
+                    return;
+                }
+            }
+        }
+        Err(e) => {
+            let _ = tx
+                .send(TaskMessage::UpgradeFailed(t!(
+                    "tui-lookup-failed",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
+    };
+
+    for (path, proof) in &mut proofs {
+        if let Err(e) = crate::commands::upgrade::attach_confirmation(
+            proof,
+            path,
+            &registry,
+            network,
+            &txid,
+            &confirmation,
+        ) {
+            let _ = tx
+                .send(TaskMessage::UpgradeFailed(t!(
+                    "tui-save-error",
+                    error = e.to_string()
+                )))
+                .await;
+            return;
+        }
+    }
+
+    let _ = tx
+        .send(TaskMessage::UpgradeComplete(t!(
+            "tui-upgrade-done",
+            height = confirmation.block_height,
+            count = proofs.len()
+        )))
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_zec() {
+        assert_eq!(parse_zec("0.1"), Ok(10_000_000));
+        assert_eq!(parse_zec("1."), Ok(100_000_000));
+        assert_eq!(parse_zec(".5"), Ok(50_000_000));
+        assert_eq!(parse_zec("0.00000001"), Ok(1));
+        assert_eq!(parse_zec("21"), Ok(2_100_000_000));
+    }
+
+    #[test]
+    fn test_parse_zec_rejects_invalid() {
+        for input in [
+            "",
+            ".",
+            "1e-8",
+            "0.000000001",
+            "-1",
+            "-0.1",
+            "1.2.3",
+            " 1",
+            "abc",
+        ] {
+            assert!(parse_zec(input).is_err(), "{input:?} should be rejected");
         }
     }
+
+    #[test]
+    fn test_parse_zec_rejects_overflow() {
+        // u64::MAX zatoshis is just under 184467440737.1 ZEC
+        assert_eq!(parse_zec("184467440737.09551615"), Ok(u64::MAX));
+        assert!(parse_zec("184467440737.09551616").is_err());
+        assert!(parse_zec("184467440738").is_err());
+        assert!(parse_zec("99999999999999999999").is_err());
+    }
 }
@@ -0,0 +1,47 @@
+//! Clipboard access for copying results
+//!
+//! Uses the system clipboard when one is reachable. Over SSH, or when no
+//! clipboard is available, falls back to an OSC 52 escape sequence, which
+//! asks the local terminal emulator to set its clipboard instead.
+
+use anyhow::Result;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::io::Write;
+use std::sync::Mutex;
+
+use crate::i18n::t;
+
+/// Kept open for the whole session: on X11 the copied text is only
+/// available while its owner is alive.
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+/// Copy `text`, returning where it went
+pub fn copy(text: &str) -> Result<String> {
+    // The remote host's clipboard is of no use to an SSH user
+    let remote =
+        std::env::var_os("SSH_TTY").is_some() || std::env::var_os("SSH_CONNECTION").is_some();
+    if !remote && system_copy(text) {
+        return Ok(t!("tui-clipboard-system"));
+    }
+
+    osc52(text)?;
+    Ok(t!("tui-clipboard-osc52"))
+}
+
+fn system_copy(text: &str) -> bool {
+    let mut clipboard = CLIPBOARD.lock().unwrap_or_else(|e| e.into_inner());
+    if clipboard.is_none() {
+        *clipboard = arboard::Clipboard::new().ok();
+    }
+    clipboard
+        .as_mut()
+        .is_some_and(|clipboard| clipboard.set_text(text).is_ok())
+}
+
+fn osc52(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()?;
+    Ok(())
+}
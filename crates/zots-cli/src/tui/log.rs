@@ -0,0 +1,116 @@
+//! In-memory log capture for the activity pane
+//!
+//! The TUI owns the terminal, so log lines written to stderr would be lost
+//! behind the alternate screen. Instead a tracing layer records events into
+//! a bounded buffer that the UI renders.
+
+use crate::logging::FileLayer;
+use chrono::Local;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::prelude::*;
+
+/// Oldest lines are dropped beyond this many
+const MAX_LINES: usize = 1000;
+
+/// A captured log event
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    /// Local wall-clock time (HH:MM:SS)
+    pub time: String,
+    pub level: Level,
+    pub message: String,
+}
+
+/// Shared, bounded buffer of captured log lines
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+}
+
+impl LogBuffer {
+    fn push(&self, line: LogLine) {
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == MAX_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.lock().map(|l| l.len()).unwrap_or_default()
+    }
+
+    /// Up to `count` lines ending `skip` lines before the newest
+    pub fn tail(&self, count: usize, skip: usize) -> Vec<LogLine> {
+        let lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        let end = lines.len().saturating_sub(skip);
+        let start = end.saturating_sub(count);
+        lines.range(start..end).cloned().collect()
+    }
+}
+
+/// Tracing layer feeding a [`LogBuffer`]
+struct BufferLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for BufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.buffer.push(LogLine {
+            time: Local::now().format("%H:%M:%S").to_string(),
+            level: *event.metadata().level(),
+            message: visitor.message,
+        });
+    }
+}
+
+/// Collects the `message` field followed by any other fields as `key=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let fields = std::mem::take(&mut self.message);
+            let _ = write!(self.message, "{value:?}{fields}");
+        } else {
+            let _ = write!(self.message, " {}={value:?}", field.name());
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.insert_str(0, value);
+        } else {
+            let _ = write!(self.message, " {}={value}", field.name());
+        }
+    }
+}
+
+/// Install the global subscriber capturing events at `level` and above
+///
+/// The optional log file layer keeps its own level.
+pub fn init(level: LevelFilter, file: Option<FileLayer>) -> LogBuffer {
+    let buffer = LogBuffer::default();
+    // Ignore errors if already initialized (e.g., in tests)
+    let _ = tracing_subscriber::registry()
+        .with(file)
+        .with(
+            BufferLayer {
+                buffer: buffer.clone(),
+            }
+            .with_filter(level),
+        )
+        .try_init();
+    buffer
+}
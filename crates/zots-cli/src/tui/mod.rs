@@ -1,47 +1,56 @@
 //! Terminal UI implementation using ratatui
 //!
-//! Wires up the event loop, keyboard handling, and screen rendering
+//! Wires up the event loop, keyboard and mouse handling, and screen rendering
 //! for the cypherpunk TUI. The TUI shares the same environment-based
 //! configuration as the CLI; make sure `ZOTS_*` variables are set
-//! before launching.
+//! before launching. Log output is captured into the activity pane.
 
 mod app;
+mod clipboard;
+mod log;
+mod picker;
 mod ui;
 
+use crate::logging::FileLayer;
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseButton, MouseEvent,
+        MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use ratatui::{Terminal, backend::CrosstermBackend};
+use ratatui::{Terminal, backend::CrosstermBackend, layout::Rect};
 use std::io;
+use tracing_subscriber::filter::LevelFilter;
 
 use app::{App, AppState};
-use ui::draw;
+use ui::{Click, click_target, draw, over_log};
+
+/// Run the TUI application, capturing logs at `log_level` and above
+pub async fn run(log_level: LevelFilter, log_file: Option<FileLayer>) -> Result<()> {
+    let logs = log::init(log_level, log_file);
 
-/// Run the TUI application
-pub async fn run() -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    // Mouse support is optional; terminals without it are keyboard-only
+    let _ = execute!(stdout, EnableMouseCapture);
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app
-    let mut app = App::new().await?;
+    let mut app = App::new(logs).await?;
 
     // Main loop
     let result = run_app(&mut terminal, &mut app).await;
 
     // Restore terminal
     disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    let _ = execute!(terminal.backend_mut(), DisableMouseCapture);
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
     result
@@ -54,25 +63,14 @@ async fn run_app(
     loop {
         terminal.draw(|f| draw(f, app))?;
 
-        if event::poll(std::time::Duration::from_millis(100))?
-            && let Event::Key(key) = event::read()?
-        {
-            match app.state {
-                AppState::Menu => match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-                    KeyCode::Char('s') | KeyCode::Char('S') => app.state = AppState::Stamp,
-                    KeyCode::Char('v') | KeyCode::Char('V') => app.state = AppState::Verify,
-                    KeyCode::Char('w') | KeyCode::Char('W') => app.state = AppState::Wallet,
-                    _ => {}
-                },
-                AppState::Stamp | AppState::Verify | AppState::Wallet => match key.code {
-                    KeyCode::Esc => {
-                        // Only allow ESC if not busy (or always allow to cancel)
-                        app.state = AppState::Menu;
-                        app.reset_state();
-                    }
-                    _ => app.handle_input(key.code)?,
-                },
+        if event::poll(std::time::Duration::from_millis(100))? {
+            let quit = match event::read()? {
+                Event::Key(key) => handle_key(app, key.code)?,
+                Event::Mouse(mouse) => handle_mouse(terminal, app, mouse)?,
+                _ => false,
+            };
+            if quit {
+                return Ok(());
             }
         }
 
@@ -80,3 +78,72 @@ async fn run_app(
         app.tick().await?;
     }
 }
+
+/// Handle a key press; returns whether the app should quit
+fn handle_key(app: &mut App, code: KeyCode) -> Result<bool> {
+    match app.state {
+        // Activity pane keys work on every screen
+        _ if app.handle_log_input(code) => {}
+        AppState::Menu => match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
+            KeyCode::Char('s') | KeyCode::Char('S') => app.state = AppState::Stamp,
+            KeyCode::Char('v') | KeyCode::Char('V') => app.state = AppState::Verify,
+            KeyCode::Char('w') | KeyCode::Char('W') => app.state = AppState::Wallet,
+            KeyCode::Char('h') | KeyCode::Char('H') => app.open_history(),
+            _ => {}
+        },
+        AppState::History if code == KeyCode::Esc && app.history_details => {
+            app.history_details = false;
+        }
+        AppState::Stamp | AppState::Verify if code == KeyCode::Esc && app.picker.is_some() => {
+            app.picker = None;
+        }
+        AppState::Stamp
+        | AppState::Verify
+        | AppState::Wallet
+        | AppState::History
+        | AppState::Send => match code {
+            KeyCode::Esc => {
+                // Only allow ESC if not busy (or always allow to cancel)
+                app.state = AppState::Menu;
+                app.reset_state();
+            }
+            _ => app.handle_input(code)?,
+        },
+    }
+    Ok(false)
+}
+
+/// Handle clicks and the scroll wheel; returns whether the app should quit
+fn handle_mouse(
+    terminal: &Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    mouse: MouseEvent,
+) -> Result<bool> {
+    let size = terminal.size()?;
+    let area = Rect::new(0, 0, size.width, size.height);
+    let (column, row) = (mouse.column, mouse.row);
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => match click_target(area, app, column, row) {
+            // Wallet commands are typed words, so a click submits the letter
+            Some(Click::Key(KeyCode::Char(c))) if app.state == AppState::Wallet => {
+                app.input_buffer = c.to_string();
+                app.handle_input(KeyCode::Enter)?;
+            }
+            Some(Click::Key(code)) => return handle_key(app, code),
+            Some(Click::HistoryRow(index)) => app.click_history_row(index),
+            Some(Click::PickerRow(index)) => app.click_picker_row(index)?,
+            None => {}
+        },
+        MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+            let up = mouse.kind == MouseEventKind::ScrollUp;
+            if over_log(area, app, column, row) {
+                app.scroll_log(up);
+            } else {
+                app.scroll(up)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
@@ -0,0 +1,185 @@
+//! File picker overlay state
+//!
+//! A directory listing with arrow-key navigation and type-to-filter,
+//! used by the Stamp and Verify screens instead of typing full paths.
+
+use std::path::{Path, PathBuf};
+
+/// A row in the picker listing
+#[derive(Debug, Clone)]
+pub struct PickerEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+}
+
+/// File picker state
+#[derive(Debug, Clone)]
+pub struct FilePicker {
+    /// Directory being listed
+    pub dir: PathBuf,
+    /// Case-insensitive name filter typed by the user
+    pub filter: String,
+    /// Only list files with this extension (directories are always listed)
+    pub extension: Option<String>,
+    /// Selected row in the filtered listing
+    pub selected: usize,
+    /// Directory read error, shown instead of the listing
+    pub error: Option<String>,
+    entries: Vec<PickerEntry>,
+}
+
+impl FilePicker {
+    /// Open a picker on `dir`, optionally restricted to one file extension
+    pub fn open(dir: PathBuf, extension: Option<&str>) -> Self {
+        let dir = dir.canonicalize().unwrap_or(dir);
+        let mut picker = Self {
+            dir,
+            filter: String::new(),
+            extension: extension.map(str::to_string),
+            selected: 0,
+            error: None,
+            entries: Vec::new(),
+        };
+        picker.load();
+        picker
+    }
+
+    /// Start directory for a picker given the current text input
+    ///
+    /// Uses the input if it names a directory, its parent if it names a file,
+    /// and the working directory otherwise.
+    pub fn start_dir(input: &str) -> PathBuf {
+        let path = PathBuf::from(input.trim());
+        if !input.trim().is_empty() {
+            if path.is_dir() {
+                return path;
+            }
+            if let Some(parent) = path.parent()
+                && parent.is_dir()
+            {
+                return parent.to_path_buf();
+            }
+        }
+        std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
+    }
+
+    /// Entries matching the current filter, parent directory first
+    pub fn visible(&self) -> Vec<&PickerEntry> {
+        let filter = self.filter.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|entry| {
+                entry.name == ".."
+                    || (filter.starts_with('.') || !entry.name.starts_with('.'))
+                        && entry.name.to_lowercase().contains(&filter)
+            })
+            .collect()
+    }
+
+    /// Select a row of the filtered listing
+    pub fn select(&mut self, index: usize) {
+        if index < self.visible().len() {
+            self.selected = index;
+        }
+    }
+
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        if self.selected + 1 < self.visible().len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn push_filter(&mut self, c: char) {
+        self.filter.push(c);
+        self.selected = 0;
+    }
+
+    /// Remove the last filter character, or go to the parent when empty
+    pub fn backspace(&mut self) {
+        if self.filter.pop().is_none() {
+            self.parent();
+        }
+        self.selected = 0;
+    }
+
+    /// Activate the selected row
+    ///
+    /// Directories are entered; a file is returned as the pick.
+    pub fn enter(&mut self) -> Option<PathBuf> {
+        let entry = self.visible().get(self.selected).map(|e| (*e).clone())?;
+        if entry.is_dir {
+            self.dir = entry.path.canonicalize().unwrap_or(entry.path);
+            self.filter.clear();
+            self.selected = 0;
+            self.load();
+            None
+        } else {
+            Some(entry.path)
+        }
+    }
+
+    fn parent(&mut self) {
+        if let Some(parent) = self.dir.parent() {
+            self.dir = parent.to_path_buf();
+            self.load();
+        }
+    }
+
+    /// Read the directory: `..`, then directories, then matching files
+    fn load(&mut self) {
+        self.entries.clear();
+        self.error = None;
+
+        if let Some(parent) = self.dir.parent() {
+            self.entries.push(PickerEntry {
+                name: "..".to_string(),
+                path: parent.to_path_buf(),
+                is_dir: true,
+            });
+        }
+
+        let read = match std::fs::read_dir(&self.dir) {
+            Ok(read) => read,
+            Err(e) => {
+                self.error = Some(format!("Cannot read {}: {e}", self.dir.display()));
+                return;
+            }
+        };
+
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
+        for entry in read.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if path.is_dir() {
+                dirs.push(PickerEntry {
+                    name,
+                    path,
+                    is_dir: true,
+                });
+            } else if self.matches_extension(&path) {
+                files.push(PickerEntry {
+                    name,
+                    path,
+                    is_dir: false,
+                });
+            }
+        }
+        dirs.sort_by_key(|e| e.name.to_lowercase());
+        files.sort_by_key(|e| e.name.to_lowercase());
+        self.entries.extend(dirs);
+        self.entries.extend(files);
+    }
+
+    fn matches_extension(&self, path: &Path) -> bool {
+        match &self.extension {
+            Some(ext) => path.extension().is_some_and(|e| e == ext.as_str()),
+            None => true,
+        }
+    }
+}
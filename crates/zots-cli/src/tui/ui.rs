@@ -5,18 +5,27 @@
 //! - Dynamic content based on current screen/state
 //! - Progress indicators for async operations
 //! - Detailed result displays for stamp and verify operations
+//! - Proof history list with per-proof details
+//! - Receiving address with a terminal QR code
+//! - File picker overlay for path input
+//! - Collapsible activity pane with captured log output
+//! - Mouse hit-testing for menus, lists and key hints
 
 use chrono::{DateTime, Utc};
+use crossterm::event::KeyCode;
 use qrcode::{QrCode, render::unicode};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 
-use super::app::{App, AppState, OperationPhase, VerifyStep};
+use super::app::{App, AppState, HistoryItem, OperationPhase, QueueStatus, SendStep, VerifyStep};
+use super::picker::FilePicker;
+use crate::i18n::t;
+use tracing::Level;
 
 /// ASCII art header for cypherpunk aesthetic
 const ASCII_HEADER: &str = r#"
@@ -35,8 +44,18 @@ const ASCII_HEADER: &str = r#"
 ╚═══════════════════════════════════════════════════════════════════╝
 "#;
 
-/// Main draw function
-pub fn draw(f: &mut Frame, app: &App) {
+/// Height of the activity pane, including borders
+const LOG_PANE_HEIGHT: u16 = 10;
+
+/// Screen regions, shared by drawing and mouse hit-testing
+struct Areas {
+    header: Rect,
+    main: Rect,
+    log: Option<Rect>,
+    status: Rect,
+}
+
+fn layout(area: Rect, app: &App) -> Areas {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
@@ -45,22 +64,235 @@ pub fn draw(f: &mut Frame, app: &App) {
             Constraint::Min(10),    // Main content
             Constraint::Length(3),  // Status bar
         ])
-        .split(f.area());
+        .split(area);
+
+    // Activity pane takes the bottom of the content area when shown
+    let (main, log) = if app.log_visible {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(6), Constraint::Length(LOG_PANE_HEIGHT)])
+            .split(chunks[1]);
+        (split[0], Some(split[1]))
+    } else {
+        (chunks[1], None)
+    };
+
+    Areas {
+        header: chunks[0],
+        main,
+        log,
+        status: chunks[2],
+    }
+}
+
+/// Main draw function
+pub fn draw(f: &mut Frame, app: &App) {
+    let Areas {
+        header,
+        main,
+        log,
+        status,
+    } = layout(f.area(), app);
 
     // Header
-    let header = Paragraph::new(ASCII_HEADER).style(Style::default().fg(Color::Cyan));
-    f.render_widget(header, chunks[0]);
+    let header_text = Paragraph::new(ASCII_HEADER).style(Style::default().fg(Color::Cyan));
+    f.render_widget(header_text, header);
+
+    if let Some(log) = log {
+        draw_log(f, log, app);
+    }
 
     // Main content based on state
     match app.state {
-        AppState::Menu => draw_menu(f, chunks[1]),
-        AppState::Stamp => draw_stamp(f, chunks[1], app),
-        AppState::Verify => draw_verify(f, chunks[1], app),
-        AppState::Wallet => draw_wallet(f, chunks[1], app),
+        AppState::Menu => draw_menu(f, main),
+        AppState::Stamp => draw_stamp(f, main, app),
+        AppState::Verify => draw_verify(f, main, app),
+        AppState::Wallet => draw_wallet(f, main, app),
+        AppState::History => draw_history(f, main, app),
+        AppState::Send => draw_send(f, main, app),
+    }
+
+    if let Some(ref picker) = app.picker {
+        draw_picker(f, main, app, picker);
     }
 
     // Status bar
-    draw_status_bar(f, chunks[2], app);
+    draw_status_bar(f, status, app);
+}
+
+/// What a mouse click landed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Click {
+    /// A menu entry or key hint; acts like pressing the key
+    Key(KeyCode),
+    /// A row of the History list
+    HistoryRow(usize),
+    /// A row of the file picker
+    PickerRow(usize),
+}
+
+/// Map a click position to the element drawn there
+pub fn click_target(area: Rect, app: &App, column: u16, row: u16) -> Option<Click> {
+    let main = layout(area, app).main;
+    if !contains(main, column, row) {
+        return None;
+    }
+
+    // Key hints sit on the bottom border of the main panel
+    if row == main.bottom() - 1 {
+        let hint = t!(screen_hint(app)?);
+        let offset = usize::from(column.checked_sub(main.x + 1)?);
+        return hint_buttons(&hint)
+            .into_iter()
+            .find(|(range, _)| range.contains(&offset))
+            .map(|(_, key)| Click::Key(key));
+    }
+
+    let line = usize::from(row.checked_sub(main.y + 1)?);
+    if let Some(ref picker) = app.picker {
+        let first = 2 + usize::from(picker.error.is_some());
+        let (offset, rows) = picker_window(picker, main);
+        let index = offset + line.checked_sub(first)?;
+        return (line - first < rows && index < picker.visible().len())
+            .then_some(Click::PickerRow(index));
+    }
+
+    match app.state {
+        AppState::Menu => MENU_KEYS
+            .iter()
+            .find(|(menu_line, _)| *menu_line == line)
+            .map(|(_, key)| Click::Key(*key)),
+        AppState::History => {
+            // Rows below an expanded entry are shifted by its details
+            let selected = app.history_selected;
+            let details = match app.history.get(selected) {
+                Some(item) if app.history_details => history_details(item).len(),
+                _ => 0,
+            };
+            let index = if line <= selected {
+                line
+            } else if line <= selected + details {
+                selected
+            } else {
+                line - details
+            };
+            (index < app.history.len()).then_some(Click::HistoryRow(index))
+        }
+        _ => None,
+    }
+}
+
+/// Whether a position is over the activity pane
+pub fn over_log(area: Rect, app: &App, column: u16, row: u16) -> bool {
+    layout(area, app)
+        .log
+        .is_some_and(|log| contains(log, column, row))
+}
+
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+}
+
+/// Menu lines (inside the border) and the key each one triggers
+const MENU_KEYS: [(usize, KeyCode); 5] = [
+    (1, KeyCode::Char('s')),
+    (3, KeyCode::Char('v')),
+    (5, KeyCode::Char('w')),
+    (7, KeyCode::Char('h')),
+    (9, KeyCode::Char('q')),
+];
+
+const STAMP_HINT: &str = "tui-hint-stamp";
+const BACK_HINT: &str = "tui-hint-back";
+const RESULT_HINT: &str = "tui-hint-result";
+const SENT_HINT: &str = "tui-hint-sent";
+const STAMP_PENDING_HINT: &str = "tui-hint-stamp-pending";
+const VERIFY_FILE_HINT: &str = "tui-hint-verify-file";
+const VERIFY_PROOF_HINT: &str = "tui-hint-verify-proof";
+const WALLET_HINT: &str = "tui-hint-wallet";
+const SEND_INPUT_HINT: &str = "tui-hint-send-input";
+const SEND_CONFIRM_HINT: &str = "tui-hint-send-confirm";
+const HISTORY_HINT: &str = "tui-hint-history";
+const PICKER_HINT: &str = "tui-hint-picker";
+
+/// Message id of the key hint shown on the bottom border of the current panel
+fn screen_hint(app: &App) -> Option<&'static str> {
+    if app.picker.is_some() {
+        return Some(PICKER_HINT);
+    }
+    match app.state {
+        AppState::Menu => None,
+        AppState::Stamp => match app.operation_phase {
+            OperationPhase::Failed if app.stamp_pending_txid.is_some() => Some(STAMP_PENDING_HINT),
+            OperationPhase::Input | OperationPhase::Failed => Some(STAMP_HINT),
+            OperationPhase::Complete if app.can_copy() => Some(RESULT_HINT),
+            OperationPhase::Complete => Some(BACK_HINT),
+            _ => None,
+        },
+        AppState::Verify => match app.verify_step {
+            VerifyStep::FileOrHash => Some(VERIFY_FILE_HINT),
+            VerifyStep::ProofPath => Some(VERIFY_PROOF_HINT),
+            VerifyStep::Verifying => None,
+            VerifyStep::Results if app.can_copy() => Some(RESULT_HINT),
+            VerifyStep::Results => Some(BACK_HINT),
+        },
+        AppState::Wallet => (!app.task_running).then_some(WALLET_HINT),
+        AppState::History => Some(HISTORY_HINT),
+        AppState::Send => match app.send_step {
+            SendStep::Address | SendStep::Amount | SendStep::Memo => Some(SEND_INPUT_HINT),
+            SendStep::Confirm => Some(SEND_CONFIRM_HINT),
+            SendStep::Previewing | SendStep::Sending => None,
+            SendStep::Done if app.can_copy() => Some(SENT_HINT),
+            SendStep::Done => Some(BACK_HINT),
+        },
+    }
+}
+
+/// Clickable `[KEY] label` segments of a hint, as column ranges
+fn hint_buttons(hint: &str) -> Vec<(std::ops::Range<usize>, KeyCode)> {
+    let chars: Vec<char> = hint.chars().collect();
+    let mut buttons = Vec::new();
+    let mut start = 0;
+    while let Some(open) = (start..chars.len()).find(|&i| chars[i] == '[') {
+        let Some(close) = (open..chars.len()).find(|&i| chars[i] == ']') else {
+            break;
+        };
+        // A button runs until the double space before the next hint
+        let end = (close..chars.len())
+            .find(|&i| chars[i] == ' ' && chars.get(i + 1) == Some(&' '))
+            .unwrap_or(chars.len());
+        let label: String = chars[open + 1..close].iter().collect();
+        let key = match label.split(' ').next().unwrap_or_default() {
+            "ESC" => Some(KeyCode::Esc),
+            "ENTER" => Some(KeyCode::Enter),
+            "DEL" => Some(KeyCode::Delete),
+            "BACKSPACE" => Some(KeyCode::Backspace),
+            "F2" => Some(KeyCode::F(2)),
+            key if key.chars().count() == 1 => key
+                .chars()
+                .next()
+                .map(|c| KeyCode::Char(c.to_ascii_lowercase())),
+            _ => None,
+        };
+        if let Some(key) = key {
+            buttons.push((open..end, key));
+        }
+        start = end;
+    }
+    buttons
+}
+
+/// Hint line for a panel's bottom border
+fn hint_line(app: &App) -> Line<'static> {
+    Line::from(Span::styled(
+        screen_hint(app).map(|id| t!(id)).unwrap_or_default(),
+        Style::default().fg(Color::Gray),
+    ))
+}
+
+/// Indented label of a detail line, padded to `width` columns: `  Hash:   `
+fn field_label(id: &str, width: usize) -> String {
+    format!("  {:<width$}", format!("{}: ", t!(id)))
 }
 
 fn draw_menu(f: &mut Frame, area: Rect) {
@@ -73,7 +305,7 @@ fn draw_menu(f: &mut Frame, area: Rect) {
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Stamp a file or hash"),
+            Span::raw(t!("tui-menu-stamp")),
         ]),
         Line::from(""),
         Line::from(vec![
@@ -83,7 +315,7 @@ fn draw_menu(f: &mut Frame, area: Rect) {
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Verify a timestamp proof"),
+            Span::raw(t!("tui-menu-verify")),
         ]),
         Line::from(""),
         Line::from(vec![
@@ -93,7 +325,17 @@ fn draw_menu(f: &mut Frame, area: Rect) {
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Wallet management"),
+            Span::raw(t!("tui-menu-wallet")),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                "  [H] ",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(t!("tui-menu-history")),
         ]),
         Line::from(""),
         Line::from(vec![
@@ -101,32 +343,78 @@ fn draw_menu(f: &mut Frame, area: Rect) {
                 "  [Q] ",
                 Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
             ),
-            Span::raw("Quit"),
+            Span::raw(t!("tui-menu-quit")),
         ]),
     ];
 
     let menu = Paragraph::new(menu_text)
-        .block(Block::default().borders(Borders::ALL).title("Main Menu"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(t!("tui-menu-title")),
+        )
         .wrap(Wrap { trim: false });
     f.render_widget(menu, area);
 }
 
+/// Stamp queue with per-item status
+fn queue_lines(app: &App) -> Vec<Line<'_>> {
+    if app.stamp_queue.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        format!("Queue ({}):", app.stamp_queue.len()),
+        Style::default().fg(Color::White),
+    ))];
+    for item in &app.stamp_queue {
+        let color = match item.status {
+            QueueStatus::Hashing => Color::Yellow,
+            QueueStatus::Waiting => Color::Gray,
+            QueueStatus::Broadcast => Color::Cyan,
+            QueueStatus::Confirmed => Color::Green,
+            QueueStatus::Failed(_) => Color::Red,
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("  {:<10}", item.status.label()),
+                Style::default().fg(color),
+            ),
+            Span::styled(&item.label, Style::default().fg(Color::White)),
+        ];
+        // Failures from the whole batch are already shown below the queue
+        if let QueueStatus::Failed(error) = &item.status
+            && item.hash.is_none()
+        {
+            spans.push(Span::styled(
+                format!("  {error}"),
+                Style::default().fg(Color::Red),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
 fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
     let mut content = vec![];
 
     match &app.operation_phase {
         OperationPhase::Input | OperationPhase::Failed => {
             content.push(Line::from(vec![Span::styled(
-                "Enter file path or hash to timestamp:",
+                t!("tui-stamp-prompt"),
                 Style::default().fg(Color::White),
             )]));
             content.push(Line::from(vec![
-                Span::styled("Algorithm: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!("{}: ", t!("label-algorithm")),
+                    Style::default().fg(Color::Gray),
+                ),
                 Span::styled(
                     app.hash_algorithm.name(),
                     Style::default().fg(Color::Yellow),
                 ),
-                Span::styled("  [Tab] toggle", Style::default().fg(Color::Gray)),
+                Span::styled(t!("tui-algorithm-toggle"), Style::default().fg(Color::Gray)),
             ]));
             content.push(Line::from(""));
             content.push(Line::from(vec![
@@ -135,6 +423,7 @@ fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
                 Span::styled("█", Style::default().fg(Color::Gray)),
             ]));
             content.push(Line::from(""));
+            content.extend(queue_lines(app));
 
             if !app.result_message.is_empty() {
                 // Split result message by newlines for proper display
@@ -150,62 +439,84 @@ fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
                 }
                 content.push(Line::from(""));
             }
-
-            content.push(Line::from(Span::styled(
-                "[ESC] Back to menu  [ENTER] Submit",
-                Style::default().fg(Color::Gray),
-            )));
         }
         OperationPhase::Syncing => {
             content.push(Line::from(vec![
                 Span::styled(app.spinner(), Style::default().fg(Color::Yellow)),
                 Span::raw(" "),
-                Span::styled(
-                    "Syncing wallet with blockchain...",
-                    Style::default().fg(Color::Yellow),
-                ),
+                Span::styled(t!("tui-syncing"), Style::default().fg(Color::Yellow)),
             ]));
             content.push(Line::from(""));
             content.push(Line::from(Span::styled(
-                "This may take a moment for initial sync",
+                t!("tui-syncing-detail"),
                 Style::default().fg(Color::Gray),
             )));
+            content.push(Line::from(""));
+            content.extend(queue_lines(app));
         }
         OperationPhase::Broadcasting => {
             content.push(Line::from(vec![
                 Span::styled(app.spinner(), Style::default().fg(Color::Cyan)),
                 Span::raw(" "),
-                Span::styled(
-                    "Creating and broadcasting transaction...",
-                    Style::default().fg(Color::Cyan),
-                ),
+                Span::styled(t!("tui-broadcasting"), Style::default().fg(Color::Cyan)),
             ]));
             content.push(Line::from(""));
             content.push(Line::from(Span::styled(
-                "Building zk-SNARK proof and sending to network",
+                t!("tui-broadcasting-detail"),
                 Style::default().fg(Color::Gray),
             )));
+            content.push(Line::from(""));
+            content.extend(queue_lines(app));
         }
-        OperationPhase::WaitingConfirmation { txid, .. } => {
+        OperationPhase::WaitingConfirmation {
+            txid,
+            current_height,
+            confirmations,
+            target,
+        } => {
             content.push(Line::from(vec![
                 Span::styled(app.spinner(), Style::default().fg(Color::Magenta)),
                 Span::raw(" "),
-                Span::styled(
-                    "Waiting for block confirmation...",
-                    Style::default().fg(Color::Magenta),
-                ),
+                Span::styled(t!("tui-waiting-block"), Style::default().fg(Color::Magenta)),
             ]));
             content.push(Line::from(""));
             content.push(Line::from(vec![
-                Span::styled("TXID: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!("{}: ", t!("label-txid")),
+                    Style::default().fg(Color::Gray),
+                ),
                 Span::styled(&txid[..24], Style::default().fg(Color::Yellow)),
                 Span::styled("...", Style::default().fg(Color::Gray)),
             ]));
+            content.push(Line::from(vec![
+                Span::styled(
+                    format!("{}: ", t!("label-confirmations")),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(
+                    format!("{confirmations}/{target}"),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]));
+            if *current_height > 0 {
+                content.push(Line::from(vec![
+                    Span::styled(
+                        format!("{}: ", t!("label-chain-tip")),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        current_height.to_string(),
+                        Style::default().fg(Color::White),
+                    ),
+                ]));
+            }
             content.push(Line::from(""));
             content.push(Line::from(Span::styled(
-                "Transaction broadcast - waiting for next block (~75 seconds)",
+                t!("tui-waiting-block-detail"),
                 Style::default().fg(Color::Gray),
             )));
+            content.push(Line::from(""));
+            content.extend(queue_lines(app));
         }
         OperationPhase::Complete => {
             if let Some(ref result) = app.stamp_result {
@@ -218,7 +529,7 @@ fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(
-                        "TIMESTAMP CONFIRMED",
+                        t!("tui-timestamp-confirmed"),
                         Style::default()
                             .fg(Color::Green)
                             .add_modifier(Modifier::BOLD),
@@ -228,21 +539,33 @@ fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
 
                 // Details section
                 content.push(Line::from(vec![
-                    Span::styled("  Hash:   ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        field_label("label-hash", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
                     Span::styled(&result.hash[..32], Style::default().fg(Color::White)),
                     Span::styled("...", Style::default().fg(Color::Gray)),
                 ]));
                 content.push(Line::from(vec![
-                    Span::styled("  Alg:    ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        field_label("label-alg", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
                     Span::styled(result.algorithm.name(), Style::default().fg(Color::White)),
                 ]));
                 content.push(Line::from(vec![
-                    Span::styled("  TXID:   ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        field_label("label-txid", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
                     Span::styled(&result.txid[..32], Style::default().fg(Color::Yellow)),
                     Span::styled("...", Style::default().fg(Color::Gray)),
                 ]));
                 content.push(Line::from(vec![
-                    Span::styled("  Block:  ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        field_label("label-block", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
                     Span::styled(
                         result.block_height.to_string(),
                         Style::default().fg(Color::Cyan),
@@ -251,20 +574,26 @@ fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
                 // Format block time as human-readable
                 let time_str = DateTime::<Utc>::from_timestamp(result.block_time as i64, 0)
                     .map(|dt| dt.to_rfc3339())
-                    .unwrap_or_else(|| "Unknown".to_string());
+                    .unwrap_or_else(|| t!("tui-unknown"));
                 content.push(Line::from(vec![
-                    Span::styled("  Time:   ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        field_label("label-time", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
                     Span::styled(time_str, Style::default().fg(Color::Magenta)),
                 ]));
                 content.push(Line::from(vec![
-                    Span::styled("  Saved:  ", Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        field_label("label-saved", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
                     Span::styled(&result.output_path, Style::default().fg(Color::Green)),
                 ]));
                 content.push(Line::from(""));
 
                 // Compact format section
                 content.push(Line::from(Span::styled(
-                    "  Embeddable proof (copy this):",
+                    format!("  {}", t!("tui-embeddable-proof")),
                     Style::default().fg(Color::Gray),
                 )));
                 content.push(Line::from(""));
@@ -280,37 +609,97 @@ fn draw_stamp(f: &mut Frame, area: Rect, app: &App) {
                 )));
                 content.push(Line::from(""));
                 content.push(Line::from(vec![
-                    Span::styled("  Length: ", Style::default().fg(Color::Gray)),
                     Span::styled(
-                        format!("{} chars", result.compact.len()),
+                        field_label("label-length", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        t!("length-chars", count = result.compact.len()),
                         Style::default().fg(Color::White),
                     ),
                 ]));
                 content.push(Line::from(Span::styled(
-                    "  [Q] Toggle QR code",
+                    format!("  {}", t!("tui-toggle-qr")),
                     Style::default().fg(Color::Gray),
                 )));
                 if app.qr_visible {
                     content.push(Line::from(""));
                     content.push(Line::from(Span::styled(
-                        "  QR Code:",
+                        format!("  {}:", t!("label-qr-code")),
                         Style::default().fg(Color::Gray),
                     )));
                     for line in qr_lines(app.qr_data.as_deref().unwrap_or_default()) {
                         content.push(line);
                     }
                 }
+            } else if let Some(first) = app.batch_results.first() {
+                content.push(Line::from(vec![
+                    Span::styled(
+                        "✓ ",
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        t!("tui-timestamps-confirmed", count = app.batch_results.len()),
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ]));
+                content.push(Line::from(""));
+                content.push(Line::from(vec![
+                    Span::styled(
+                        field_label("label-txid", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(&first.txid[..32], Style::default().fg(Color::Yellow)),
+                    Span::styled("...", Style::default().fg(Color::Gray)),
+                ]));
+                content.push(Line::from(vec![
+                    Span::styled(
+                        field_label("label-block", 8),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        first.block_height.to_string(),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                ]));
+                content.push(Line::from(""));
+                for result in &app.batch_results {
+                    content.push(Line::from(vec![
+                        Span::styled(
+                            field_label("label-saved", 8),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::styled(&result.output_path, Style::default().fg(Color::Green)),
+                    ]));
+                }
+            } else {
+                // Pending proofs upgraded after a timed-out stamp
+                content.push(Line::from(vec![
+                    Span::styled(
+                        "✓ ",
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(&app.result_message, Style::default().fg(Color::Green)),
+                ]));
+                content.push(Line::from(""));
+                content.extend(queue_lines(app));
             }
-            content.push(Line::from(""));
-            content.push(Line::from(Span::styled(
-                "[ESC] Back to menu",
-                Style::default().fg(Color::Gray),
-            )));
         }
     }
 
     let stamp = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL).title(" Stamp "))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", t!("tui-title-stamp")))
+                .title_bottom(hint_line(app)),
+        )
         .wrap(Wrap { trim: false });
     f.render_widget(stamp, area);
 }
@@ -322,9 +711,12 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
         VerifyStep::FileOrHash => {
             // Step 1: Enter file or hash
             content.push(Line::from(vec![
-                Span::styled("Step 1/2: ", Style::default().fg(Color::Cyan)),
                 Span::styled(
-                    "Enter file path or hash to verify:",
+                    format!("{} ", t!("tui-step", step = 1, total = 2)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    t!("tui-verify-file-prompt"),
                     Style::default().fg(Color::White),
                 ),
             ]));
@@ -347,18 +739,16 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                 )));
                 content.push(Line::from(""));
             }
-
-            content.push(Line::from(Span::styled(
-                "[ESC] Back to menu  [ENTER] Continue",
-                Style::default().fg(Color::Gray),
-            )));
         }
         VerifyStep::ProofPath => {
             // Step 2: Enter proof path
             content.push(Line::from(vec![
-                Span::styled("Step 2/2: ", Style::default().fg(Color::Cyan)),
                 Span::styled(
-                    "Enter proof file path (.zots):",
+                    format!("{} ", t!("tui-step", step = 2, total = 2)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    t!("tui-verify-proof-prompt"),
                     Style::default().fg(Color::White),
                 ),
             ]));
@@ -366,7 +756,10 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
 
             // Show what was entered in step 1
             content.push(Line::from(vec![
-                Span::styled("  Verifying: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!("  {}: ", t!("label-verifying")),
+                    Style::default().fg(Color::Gray),
+                ),
                 Span::styled(
                     if app.verify_file_input.len() > 40 {
                         format!("{}...", &app.verify_file_input[..40])
@@ -395,25 +788,17 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                 )));
                 content.push(Line::from(""));
             }
-
-            content.push(Line::from(Span::styled(
-                "[ESC] Back to menu  [ENTER] Verify",
-                Style::default().fg(Color::Gray),
-            )));
         }
         VerifyStep::Verifying => {
             // Verifying in progress
             content.push(Line::from(vec![
                 Span::styled(app.spinner(), Style::default().fg(Color::Cyan)),
                 Span::raw(" "),
-                Span::styled(
-                    "Verifying against blockchain...",
-                    Style::default().fg(Color::Cyan),
-                ),
+                Span::styled(t!("verify-on-chain"), Style::default().fg(Color::Cyan)),
             ]));
             content.push(Line::from(""));
             content.push(Line::from(Span::styled(
-                "Fetching transaction and decrypting memo",
+                t!("tui-verifying-detail"),
                 Style::default().fg(Color::Gray),
             )));
         }
@@ -430,12 +815,15 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                                 .add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
-                            "VALID TIMESTAMP",
+                            t!("tui-valid-timestamp"),
                             Style::default()
                                 .fg(Color::Green)
                                 .add_modifier(Modifier::BOLD),
                         ),
-                        Span::styled(" (verified on-chain)", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            format!(" {}", t!("tui-verified-on-chain")),
+                            Style::default().fg(Color::Gray),
+                        ),
                     ]));
                 } else {
                     // Invalid or error
@@ -445,7 +833,7 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
-                            "VERIFICATION FAILED",
+                            t!("verify-failed"),
                             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
                         ),
                     ]));
@@ -455,11 +843,20 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                 // Show file hash match status
                 if let Some(matches) = result.file_hash_matches {
                     content.push(Line::from(vec![
-                        Span::styled("  File Match: ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            format!("  {}: ", t!("label-file-match")),
+                            Style::default().fg(Color::Gray),
+                        ),
                         if matches {
-                            Span::styled("✓ Hash matches", Style::default().fg(Color::Green))
+                            Span::styled(
+                                format!("✓ {}", t!("tui-hash-matches")),
+                                Style::default().fg(Color::Green),
+                            )
                         } else {
-                            Span::styled("✗ Hash does NOT match!", Style::default().fg(Color::Red))
+                            Span::styled(
+                                format!("✗ {}", t!("tui-hash-mismatch")),
+                                Style::default().fg(Color::Red),
+                            )
                         },
                     ]));
                 }
@@ -467,7 +864,10 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                 // Show details
                 if !result.hash.is_empty() {
                     content.push(Line::from(vec![
-                        Span::styled("  Hash:     ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            field_label("label-hash", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(
                             if result.hash.len() > 32 {
                                 format!("{}...", &result.hash[..32])
@@ -478,21 +878,40 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                         ),
                     ]));
                     content.push(Line::from(vec![
-                        Span::styled("  Alg:      ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            field_label("label-alg", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(result.algorithm.name(), Style::default().fg(Color::White)),
                     ]));
                 }
 
+                if let Some(ref note) = result.note {
+                    content.push(Line::from(vec![
+                        Span::styled(
+                            field_label("label-note", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::styled(note, Style::default().fg(Color::White)),
+                    ]));
+                }
+
                 if !result.network.is_empty() {
                     content.push(Line::from(vec![
-                        Span::styled("  Network:  ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            field_label("label-network", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(&result.network, Style::default().fg(Color::Yellow)),
                     ]));
                 }
 
                 if result.block_height > 0 {
                     content.push(Line::from(vec![
-                        Span::styled("  Block:    ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            field_label("label-block", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(
                             result.block_height.to_string(),
                             Style::default().fg(Color::Cyan),
@@ -502,14 +921,20 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
 
                 if !result.timestamp.is_empty() {
                     content.push(Line::from(vec![
-                        Span::styled("  Time:     ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            field_label("label-time", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(&result.timestamp, Style::default().fg(Color::Magenta)),
                     ]));
                 }
 
                 if !result.txid.is_empty() {
                     content.push(Line::from(vec![
-                        Span::styled("  TXID:     ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            field_label("label-txid", 10),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(
                             if result.txid.len() > 32 {
                                 format!("{}...", &result.txid[..32])
@@ -525,7 +950,10 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                 if let Some(ref error) = result.error {
                     content.push(Line::from(""));
                     content.push(Line::from(vec![
-                        Span::styled("  Note: ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            format!("  {}: ", t!("label-note")),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(
                             error,
                             Style::default().fg(if result.valid {
@@ -541,19 +969,22 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                 if result.valid && !result.explorer_link.is_empty() {
                     content.push(Line::from(""));
                     content.push(Line::from(vec![
-                        Span::styled("  Explorer: ", Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            format!("  {}: ", t!("label-explorer")),
+                            Style::default().fg(Color::Gray),
+                        ),
                         Span::styled(&result.explorer_link, Style::default().fg(Color::Blue)),
                     ]));
                 }
 
                 content.push(Line::from(Span::styled(
-                    "  [Q] Toggle QR code",
+                    format!("  {}", t!("tui-toggle-qr")),
                     Style::default().fg(Color::Gray),
                 )));
                 if app.qr_visible {
                     content.push(Line::from(""));
                     content.push(Line::from(Span::styled(
-                        "  QR Code:",
+                        format!("  {}:", t!("label-qr-code")),
                         Style::default().fg(Color::Gray),
                     )));
                     for line in qr_lines(app.qr_data.as_deref().unwrap_or_default()) {
@@ -561,17 +992,16 @@ fn draw_verify(f: &mut Frame, area: Rect, app: &App) {
                     }
                 }
             }
-
-            content.push(Line::from(""));
-            content.push(Line::from(Span::styled(
-                "[ESC] Back to menu",
-                Style::default().fg(Color::Gray),
-            )));
         }
     }
 
     let verify = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL).title(" Verify "))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", t!("tui-title-verify")))
+                .title_bottom(hint_line(app)),
+        )
         .wrap(Wrap { trim: false });
     f.render_widget(verify, area);
 }
@@ -581,35 +1011,47 @@ fn draw_wallet(f: &mut Frame, area: Rect, app: &App) {
     let mut content = vec![];
 
     // Check if syncing
-    if matches!(app.operation_phase, OperationPhase::Syncing) {
+    if app.task_running && !matches!(app.operation_phase, OperationPhase::Syncing) {
+        content.push(Line::from(vec![
+            Span::styled(app.spinner(), Style::default().fg(Color::Cyan)),
+            Span::raw(" "),
+            Span::styled(&app.status_message, Style::default().fg(Color::Cyan)),
+        ]));
+    } else if matches!(app.operation_phase, OperationPhase::Syncing) {
         content.push(Line::from(vec![
             Span::styled(app.spinner(), Style::default().fg(Color::Yellow)),
             Span::raw(" "),
-            Span::styled(
-                "Syncing wallet with blockchain...",
-                Style::default().fg(Color::Yellow),
-            ),
+            Span::styled(t!("tui-syncing"), Style::default().fg(Color::Yellow)),
         ]));
         content.push(Line::from(""));
         content.push(Line::from(Span::styled(
-            "Scanning blocks for transactions",
+            t!("tui-scanning"),
             Style::default().fg(Color::Gray),
         )));
     } else {
         // Wallet info section
         content.push(Line::from(vec![
-            Span::styled("  Network:      ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                field_label("label-network", 14),
+                Style::default().fg(Color::Gray),
+            ),
             Span::styled(app.network_name(), Style::default().fg(Color::Yellow)),
         ]));
         content.push(Line::from(vec![
-            Span::styled("  Block Height: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                field_label("label-block-height", 14),
+                Style::default().fg(Color::Gray),
+            ),
             Span::styled(
                 app.block_height.to_string(),
                 Style::default().fg(Color::Cyan),
             ),
         ]));
         content.push(Line::from(vec![
-            Span::styled("  Balance:      ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                field_label("label-balance", 14),
+                Style::default().fg(Color::Gray),
+            ),
             Span::styled(
                 format!("{balance_zec:.8} TAZ"),
                 Style::default()
@@ -631,9 +1073,25 @@ fn draw_wallet(f: &mut Frame, area: Rect, app: &App) {
             content.push(Line::from(""));
         }
 
+        // Receive section
+        if let Some(ref address) = app.wallet_address {
+            content.push(Line::from(vec![
+                Span::styled(
+                    field_label("label-address", 14),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(address, Style::default().fg(Color::Yellow)),
+            ]));
+            content.push(Line::from(""));
+            for line in qr_lines(address) {
+                content.push(line);
+            }
+            content.push(Line::from(""));
+        }
+
         // Commands section
         content.push(Line::from(Span::styled(
-            "  Commands:",
+            format!("  {}:", t!("tui-commands")),
             Style::default().fg(Color::White),
         )));
         content.push(Line::from(vec![
@@ -643,51 +1101,496 @@ fn draw_wallet(f: &mut Frame, area: Rect, app: &App) {
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled("Sync wallet", Style::default().fg(Color::Gray)),
+            Span::styled(t!("tui-command-sync"), Style::default().fg(Color::Gray)),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled(
+                "    [A] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(t!("tui-command-receive"), Style::default().fg(Color::Gray)),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled(
+                "    [N] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                t!("tui-command-new-address"),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled(
+                "    [T] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(t!("tui-command-send"), Style::default().fg(Color::Gray)),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled("    > ", Style::default().fg(Color::Green)),
+            Span::raw(&app.input_buffer),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+        ]));
+    }
+
+    let wallet = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", t!("tui-title-wallet")))
+                .title_bottom(hint_line(app)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(wallet, area);
+}
+
+fn draw_history(f: &mut Frame, area: Rect, app: &App) {
+    let mut content = vec![];
+
+    if app.history.is_empty() {
+        content.push(Line::from(Span::styled(
+            format!("  {}", t!("tui-history-empty")),
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    for (index, item) in app.history.iter().enumerate() {
+        let selected = index == app.history_selected;
+        let (status, color) = if item.error.is_some() {
+            (t!("tui-history-missing"), Color::Red)
+        } else if item.is_confirmed() {
+            (t!("tui-history-confirmed"), Color::Green)
+        } else {
+            (t!("tui-history-pending"), Color::Yellow)
+        };
+        let name = item
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| item.path.display().to_string());
+        let block = item
+            .attestation
+            .as_ref()
+            .map(|att| t!("tui-history-block", height = att.block_height))
+            .unwrap_or_default();
+
+        let marker_style = if selected {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        content.push(Line::from(vec![
+            Span::styled(if selected { "> " } else { "  " }, marker_style),
+            Span::styled(format!("{status:<9}"), Style::default().fg(color)),
+            Span::raw("  "),
+            Span::styled(
+                format!("{name:<40}"),
+                if selected {
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                },
+            ),
+            Span::styled(block, Style::default().fg(Color::Cyan)),
+        ]));
+
+        if selected && app.history_details {
+            content.extend(history_details(item));
+        }
+    }
+    content.push(Line::from(""));
+
+    if app.task_running {
+        content.push(Line::from(vec![
+            Span::styled(app.spinner(), Style::default().fg(Color::Cyan)),
+            Span::raw(" "),
+            Span::styled(&app.status_message, Style::default().fg(Color::Cyan)),
         ]));
         content.push(Line::from(""));
+    } else if let Some(ref result) = app.verify_result {
+        let (text, color) = if result.valid {
+            (format!("✓ {}", t!("verify-valid")), Color::Green)
+        } else {
+            (format!("✗ {}", t!("verify-failed")), Color::Red)
+        };
+        content.push(Line::from(Span::styled(
+            format!("  {text}"),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )));
+        if let Some(ref error) = result.error {
+            content.push(Line::from(Span::styled(
+                format!("  {error}"),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        content.push(Line::from(""));
+    } else if !app.result_message.is_empty() {
+        content.push(Line::from(Span::styled(
+            format!("  {}", app.result_message),
+            Style::default().fg(if app.result_is_error {
+                Color::Red
+            } else {
+                Color::Green
+            }),
+        )));
+        content.push(Line::from(""));
+    }
+
+    let title = format!(" {} ", t!("tui-title-history", count = app.history.len()));
+    let history = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_bottom(hint_line(app)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(history, area);
+}
+
+/// Detail lines shown under the selected History entry
+fn history_details(item: &HistoryItem) -> Vec<Line<'static>> {
+    let field = |id: &str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(
+                format!("    {}", field_label(id, 9)),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+
+    let mut lines = vec![
+        field("label-path", item.path.display().to_string(), Color::White),
+        field("label-hash", item.hash.clone(), Color::White),
+        field("label-alg", item.algorithm.name().to_string(), Color::White),
+    ];
+    if let Some(ref note) = item.note {
+        lines.push(field("label-note", note.clone(), Color::White));
+    }
+    if let Some(ref att) = item.attestation {
+        lines.push(field(
+            "label-network",
+            att.network.to_string(),
+            Color::Yellow,
+        ));
+        lines.push(field(
+            "label-block",
+            att.block_height.to_string(),
+            Color::Cyan,
+        ));
+        lines.push(field(
+            "label-time",
+            att.timestamp().to_rfc3339(),
+            Color::Magenta,
+        ));
+        lines.push(field(
+            "label-txid",
+            att.txid_hex().to_string(),
+            Color::Yellow,
+        ));
+        lines.push(field(
+            "label-explorer",
+            crate::output::explorer_link(att),
+            Color::Blue,
+        ));
+    } else if let Some(ref txid) = item.pending_txid {
+        lines.push(field("label-txid", txid.clone(), Color::Yellow));
+    }
+    if let Some(ref error) = item.error {
+        lines.push(field("label-error", error.clone(), Color::Red));
+    }
+    lines
+}
+
+/// First listed picker entry and the number of rows, keeping the selection in view
+fn picker_window(picker: &FilePicker, area: Rect) -> (usize, usize) {
+    // Borders, filter line and blank line (plus an error line, if any)
+    let rows = (area.height as usize)
+        .saturating_sub(4 + usize::from(picker.error.is_some()))
+        .max(1);
+    (picker.selected.saturating_sub(rows - 1), rows)
+}
+
+/// File picker drawn over the main content area
+fn draw_picker(f: &mut Frame, area: Rect, app: &App, picker: &FilePicker) {
+    let mut content = vec![Line::from(vec![
+        Span::styled(
+            format!("{}: ", t!("label-filter")),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::raw(&picker.filter),
+        Span::styled("█", Style::default().fg(Color::Gray)),
+    ])];
+    content.push(Line::from(""));
+
+    let (offset, rows) = picker_window(picker, area);
+    if let Some(ref error) = picker.error {
         content.push(Line::from(Span::styled(
-            "  [ESC] Back to menu",
+            error,
+            Style::default().fg(Color::Red),
+        )));
+    }
+    let entries = picker.visible();
+    if entries.is_empty() && picker.error.is_none() {
+        content.push(Line::from(Span::styled(
+            format!("  {}", t!("tui-picker-empty")),
             Style::default().fg(Color::Gray),
         )));
     }
+    for (i, entry) in entries.iter().enumerate().skip(offset).take(rows) {
+        let selected = i == picker.selected;
+        let name = if entry.is_dir {
+            format!("{}/", entry.name)
+        } else {
+            entry.name.clone()
+        };
+        let mut style = Style::default().fg(if entry.is_dir {
+            Color::Cyan
+        } else {
+            Color::White
+        });
+        if selected {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        content.push(Line::from(vec![
+            Span::styled(
+                if selected { "> " } else { "  " },
+                Style::default().fg(Color::Green),
+            ),
+            Span::styled(name, style),
+        ]));
+    }
+
+    let title = match picker.extension {
+        Some(ref ext) => format!(" {} (*.{ext}) ", picker.dir.display()),
+        None => format!(" {} ", picker.dir.display()),
+    };
+    f.render_widget(Clear, area);
+    let list = Paragraph::new(content).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_bottom(hint_line(app))
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    f.render_widget(list, area);
+}
+
+fn draw_send(f: &mut Frame, area: Rect, app: &App) {
+    let zec = |zat: u64| format!("{:.8} ZEC", zat as f64 / 100_000_000.0);
+    let field = |id: &str, value: String, color: Color| {
+        Line::from(vec![
+            Span::styled(field_label(id, 11), Style::default().fg(Color::Gray)),
+            Span::styled(value, Style::default().fg(color)),
+        ])
+    };
+    let mut content = vec![];
+
+    // Values entered so far
+    let step = app.send_step;
+    if step != SendStep::Address {
+        content.push(field("label-to", app.send_address.clone(), Color::Yellow));
+    }
+    if !matches!(step, SendStep::Address | SendStep::Amount) {
+        content.push(field("label-amount", zec(app.send_amount), Color::Green));
+        if !app.send_memo.is_empty() {
+            content.push(field("label-memo", app.send_memo.clone(), Color::White));
+        }
+    }
+    if let Some(preview) = app.send_preview {
+        content.push(field("label-fee", zec(preview.fee), Color::Cyan));
+        content.push(field(
+            "label-total",
+            zec(preview.amount + preview.fee),
+            Color::Magenta,
+        ));
+    }
+    if !content.is_empty() {
+        content.push(Line::from(""));
+    }
+
+    let prompt = match step {
+        SendStep::Address => Some(t!("tui-send-address-prompt")),
+        SendStep::Amount => Some(t!("tui-send-amount-prompt")),
+        SendStep::Memo => Some(t!("tui-send-memo-prompt")),
+        SendStep::Confirm => Some(t!("tui-send-confirm-prompt")),
+        _ => None,
+    };
+    if let Some(prompt) = prompt {
+        content.push(Line::from(Span::styled(
+            prompt,
+            Style::default().fg(Color::White),
+        )));
+        content.push(Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Green)),
+            Span::raw(&app.input_buffer),
+            Span::styled("█", Style::default().fg(Color::Gray)),
+        ]));
+        content.push(Line::from(""));
+    }
 
-    let wallet = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL).title(" Wallet "))
+    match step {
+        SendStep::Previewing | SendStep::Sending => {
+            content.push(Line::from(vec![
+                Span::styled(app.spinner(), Style::default().fg(Color::Cyan)),
+                Span::raw(" "),
+                Span::styled(&app.status_message, Style::default().fg(Color::Cyan)),
+            ]));
+        }
+        SendStep::Confirm => {
+            content.push(Line::from(Span::styled(
+                t!("tui-send-check"),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        SendStep::Done => {
+            if let Some(ref txid) = app.send_txid {
+                content.push(Line::from(vec![
+                    Span::styled(
+                        "✓ ",
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        t!("tui-transaction-broadcast"),
+                        Style::default()
+                            .fg(Color::Green)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ]));
+                content.push(Line::from(""));
+                content.push(field("label-txid", txid.clone(), Color::Yellow));
+                if let Some(link) = app.send_explorer_link() {
+                    content.push(field("label-explorer", link, Color::Blue));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if app.result_is_error && !app.result_message.is_empty() {
+        content.push(Line::from(""));
+        for line in app.result_message.lines() {
+            content.push(Line::from(Span::styled(
+                line,
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
+    let send = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", t!("tui-title-send")))
+                .title_bottom(hint_line(app)),
+        )
         .wrap(Wrap { trim: false });
-    f.render_widget(wallet, area);
+    f.render_widget(send, area);
+}
+
+/// Activity pane with the newest (or scrolled-back) log lines
+fn draw_log(f: &mut Frame, area: Rect, app: &App) {
+    let rows = area.height.saturating_sub(2) as usize;
+    let content: Vec<Line> = app
+        .logs
+        .tail(rows, app.log_scroll)
+        .into_iter()
+        .map(|line| {
+            let color = match line.level {
+                Level::ERROR => Color::Red,
+                Level::WARN => Color::Yellow,
+                Level::INFO => Color::Green,
+                _ => Color::Gray,
+            };
+            Line::from(vec![
+                Span::styled(format!("{} ", line.time), Style::default().fg(Color::Gray)),
+                Span::styled(format!("{:<5} ", line.level), Style::default().fg(color)),
+                Span::raw(line.message),
+            ])
+        })
+        .collect();
+
+    let title = if app.log_scroll > 0 {
+        format!(
+            " {} (-{}) {} ",
+            t!("tui-title-activity"),
+            app.log_scroll,
+            t!("tui-hint-activity")
+        )
+    } else {
+        format!(" {} {} ", t!("tui-title-activity"), t!("tui-hint-activity"))
+    };
+    let log = Paragraph::new(content).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    f.render_widget(log, area);
 }
 
 fn draw_status_bar(f: &mut Frame, area: Rect, app: &App) {
     let balance_zec = app.balance as f64 / 100_000_000.0;
 
     let status = Paragraph::new(Line::from(vec![
-        Span::styled("Status: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{}: ", t!("label-status")),
+            Style::default().fg(Color::Gray),
+        ),
         Span::styled(&app.status_message, Style::default().fg(Color::Green)),
         Span::raw(" │ "),
-        Span::styled("Block: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{}: ", t!("label-block")),
+            Style::default().fg(Color::Gray),
+        ),
         Span::styled(
             app.block_height.to_string(),
             Style::default().fg(Color::Yellow),
         ),
         Span::raw(" │ "),
-        Span::styled("Balance: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{}: ", t!("tui-status-balance")),
+            Style::default().fg(Color::Gray),
+        ),
         Span::styled(
             format!("{balance_zec:.8} TAZ"),
             Style::default().fg(Color::Green),
         ),
         Span::raw(" │ "),
-        Span::styled("Network: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{}: ", t!("label-network")),
+            Style::default().fg(Color::Gray),
+        ),
         Span::styled(app.network_name(), Style::default().fg(Color::Cyan)),
     ]))
-    .block(Block::default().borders(Borders::ALL).title("Status"));
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(t!("tui-status-title")),
+    );
     f.render_widget(status, area);
 }
 
 fn qr_lines(data: &str) -> Vec<Line<'static>> {
     if data.is_empty() {
         return vec![Line::from(Span::styled(
-            "  (no compact proof available)",
+            format!("  ({})", t!("tui-qr-no-proof")),
             Style::default().fg(Color::Red),
         ))];
     }
@@ -705,7 +1608,7 @@ fn qr_lines(data: &str) -> Vec<Line<'static>> {
                 .collect()
         }
         Err(_) => vec![Line::from(Span::styled(
-            "  (failed to render QR code)",
+            format!("  ({})", t!("tui-qr-failed")),
             Style::default().fg(Color::Red),
         ))],
     }
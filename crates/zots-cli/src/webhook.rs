@@ -0,0 +1,169 @@
+//! Webhook notifications for long-running commands.
+//!
+//! `watch` and `serve` POST a JSON event to every configured URL when a stamp
+//! is broadcast, confirmed or fails:
+//!
+//! ```json
+//! {"event": "stamp.confirmed", "timestamp": 1734293400, "data": {...}}
+//! ```
+//!
+//! With a secret, each request carries `X-Zots-Signature: sha256=<hex>`, the
+//! HMAC-SHA256 of the raw body, so receivers can authenticate it. Deliveries
+//! run in the background and are retried a few times; a receiver that stays
+//! down only produces warnings.
+
+use hmac::{Hmac, Mac};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Attempts per delivery before giving up
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Stamp lifecycle events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEvent {
+    Broadcast,
+    Confirmed,
+    Failed,
+}
+
+impl WebhookEvent {
+    pub fn name(self) -> &'static str {
+        match self {
+            WebhookEvent::Broadcast => "stamp.broadcast",
+            WebhookEvent::Confirmed => "stamp.confirmed",
+            WebhookEvent::Failed => "stamp.failed",
+        }
+    }
+}
+
+/// Webhook targets shared by a long-running command
+#[derive(Clone)]
+pub struct Webhooks {
+    urls: Vec<String>,
+    secret: Option<String>,
+    client: reqwest::Client,
+}
+
+impl Webhooks {
+    pub fn new(urls: Vec<String>, secret: Option<String>) -> anyhow::Result<Self> {
+        for url in &urls {
+            if !crate::fetch::is_url(url) {
+                return Err(anyhow::anyhow!("Webhook URL must be http(s): {url}"));
+            }
+        }
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("zots/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(10))
+            .build()?;
+        Ok(Self {
+            urls,
+            secret,
+            client,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    /// Number of configured URLs
+    pub fn len(&self) -> usize {
+        self.urls.len()
+    }
+
+    /// Deliver `event` to every URL in the background
+    pub fn send(&self, event: WebhookEvent, data: Value) {
+        if self.urls.is_empty() {
+            return;
+        }
+        let body = json!({
+            "event": event.name(),
+            "timestamp": chrono::Utc::now().timestamp(),
+            "data": data,
+        })
+        .to_string();
+        let signature = self.secret.as_deref().map(|secret| sign(secret, &body));
+
+        for url in &self.urls {
+            let request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header("X-Zots-Event", event.name());
+            let request = match &signature {
+                Some(signature) => request.header("X-Zots-Signature", signature),
+                None => request,
+            };
+            let (url, body) = (url.clone(), body.clone());
+            tokio::spawn(async move {
+                for attempt in 1..=MAX_ATTEMPTS {
+                    let Some(request) = request.try_clone() else {
+                        break;
+                    };
+                    match request.body(body.clone()).send().await {
+                        Ok(response) if response.status().is_success() => {
+                            debug!("Webhook {} delivered to {}", event.name(), url);
+                            return;
+                        }
+                        Ok(response) => warn!(
+                            "Webhook {} to {} returned {} (attempt {attempt}/{MAX_ATTEMPTS})",
+                            event.name(),
+                            url,
+                            response.status()
+                        ),
+                        Err(e) => warn!(
+                            "Webhook {} to {} failed: {} (attempt {attempt}/{MAX_ATTEMPTS})",
+                            event.name(),
+                            url,
+                            e
+                        ),
+                    }
+                    if attempt < MAX_ATTEMPTS {
+                        tokio::time::sleep(Duration::from_secs(2u64.pow(attempt))).await;
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// `sha256=<hex HMAC-SHA256 of body>`
+fn sign(secret: &str, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_matches_rfc4231() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_signature_verifies_body() {
+        let body = r#"{"event":"stamp.confirmed","data":{}}"#;
+        let signature = sign("secret", body);
+        let digest = hex::decode(signature.strip_prefix("sha256=").unwrap()).unwrap();
+
+        let verify = |secret: &str, body: &str| {
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+            mac.update(body.as_bytes());
+            mac.verify_slice(&digest).is_ok()
+        };
+        assert!(verify("secret", body));
+        assert!(!verify("other", body));
+        assert!(!verify("secret", &body.replace("confirmed", "failed")));
+    }
+}
@@ -0,0 +1,700 @@
+//! Printable timestamp certificates.
+//!
+//! Renders a proof as a document for people rather than tools: the
+//! stamped file, its hash and algorithm, each attestation's block, time and
+//! transaction, and the compact proof as text and (optionally) a QR code.
+//! HTML output is self-contained; PDF output is A4, continued on further
+//! pages when it does not fit, and uses the standard PDF fonts, so neither
+//! needs anything beyond this crate.
+//!
+//! QR encoding is left to the caller, which passes the finished module
+//! matrix, so the core crate stays free of an image or QR dependency.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use zots_core::{Certificate, TimestampProof};
+//!
+//! let proof = TimestampProof::new([0u8; 32]);
+//! let html = Certificate::new(&proof).to_html().unwrap();
+//! assert!(html.contains(&proof.hash));
+//!
+//! let pdf = Certificate::new(&proof).to_pdf().unwrap();
+//! assert!(pdf.starts_with(b"%PDF-"));
+//! ```
+
+use crate::{Result, TimestampProof};
+use std::fmt::Write;
+
+/// Document title
+const TITLE: &str = "Timestamp Certificate";
+
+/// How a certificate tells the reader to check it
+const VERIFY_HINT: &str = "Verify with `zots verify <file> <proof>`, or import the compact proof below (or scan the QR code) into any zots client.";
+
+/// A4 page size in PDF points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+
+/// Left and right page margin in points
+const MARGIN: f32 = 56.0;
+
+/// Left edge of values in the PDF field table
+const VALUE_X: f32 = 150.0;
+
+/// Characters per line of a wrapped PDF value (Courier 9pt in the value column)
+const VALUE_CHARS: usize = 72;
+
+/// Characters per line of the compact proof (Courier 8pt across the page)
+const COMPACT_CHARS: usize = 88;
+
+/// Side of the QR code in points
+const QR_SIZE: f32 = 144.0;
+
+/// Square QR code as rows of dark (`true`) and light modules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrMatrix {
+    width: usize,
+    modules: Vec<bool>,
+}
+
+impl QrMatrix {
+    /// Matrix of `width` x `width` modules, row by row
+    ///
+    /// Returns `None` if `modules` does not hold exactly `width * width`
+    /// entries.
+    pub fn new(width: usize, modules: Vec<bool>) -> Option<Self> {
+        (width > 0 && modules.len() == width * width).then_some(Self { width, modules })
+    }
+
+    /// Modules per side
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Whether the module at `row`, `column` is dark
+    pub fn is_dark(&self, row: usize, column: usize) -> bool {
+        self.modules[row * self.width + column]
+    }
+}
+
+/// A printable certificate of one proof
+#[derive(Debug, Clone)]
+pub struct Certificate<'a> {
+    proof: &'a TimestampProof,
+    qr: Option<QrMatrix>,
+    explorer_url: Option<String>,
+}
+
+/// One labelled line of the field table
+struct Field {
+    label: String,
+    value: String,
+    style: FieldStyle,
+}
+
+/// How a field value is set
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldStyle {
+    Text,
+    /// Hashes and txids, in a monospace font
+    Hex,
+    /// Explorer URL, a hyperlink in HTML
+    Link,
+}
+
+impl<'a> Certificate<'a> {
+    /// Certificate of `proof`, without a QR code
+    pub fn new(proof: &'a TimestampProof) -> Self {
+        Self {
+            proof,
+            qr: None,
+            explorer_url: None,
+        }
+    }
+
+    /// Include the QR code of the compact proof
+    pub fn with_qr(mut self, qr: QrMatrix) -> Self {
+        self.qr = Some(qr);
+        self
+    }
+
+    /// Link transactions to this explorer instead of the network default
+    pub fn with_explorer_url(mut self, base: impl Into<String>) -> Self {
+        self.explorer_url = Some(base.into());
+        self
+    }
+
+    /// Field table shared by both output formats
+    fn fields(&self) -> Vec<Field> {
+        let field = |label: &str, value: String, style: FieldStyle| Field {
+            label: label.to_string(),
+            value,
+            style,
+        };
+        let proof = self.proof;
+
+        let mut fields = Vec::new();
+        if let Some(name) = proof.filename() {
+            fields.push(field("File", name.to_string(), FieldStyle::Text));
+        }
+        fields.push(field(
+            "Algorithm",
+            proof.hash_algorithm().name().to_string(),
+            FieldStyle::Text,
+        ));
+        fields.push(field("Hash", proof.hash.clone(), FieldStyle::Hex));
+        if let Some(note) = proof.note() {
+            fields.push(field("Note", note.to_string(), FieldStyle::Text));
+        }
+
+        if proof.attestations.is_empty() {
+            fields.push(field(
+                "Status",
+                "Pending - not yet confirmed on-chain".to_string(),
+                FieldStyle::Text,
+            ));
+            if let Some(txid) = &proof.pending_txid {
+                fields.push(field("Transaction", txid.clone(), FieldStyle::Hex));
+            }
+        }
+        let numbered = proof.attestations.len() > 1;
+        for (i, att) in proof.attestations.iter().enumerate() {
+            let label = |name: &str| {
+                if numbered {
+                    format!("{name} #{}", i + 1)
+                } else {
+                    name.to_string()
+                }
+            };
+            fields.push(field(
+                &label("Network"),
+                att.network.to_string(),
+                FieldStyle::Text,
+            ));
+            fields.push(field(
+                &label("Block"),
+                att.block_height.to_string(),
+                FieldStyle::Text,
+            ));
+            fields.push(field(
+                &label("Time"),
+                att.timestamp().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                FieldStyle::Text,
+            ));
+            fields.push(field(
+                &label("Transaction"),
+                att.txid.clone(),
+                FieldStyle::Hex,
+            ));
+            fields.push(field(
+                &label("Explorer"),
+                att.explorer_link_with_base(self.explorer_url.as_deref()),
+                FieldStyle::Link,
+            ));
+        }
+        fields
+    }
+
+    /// Render as a self-contained HTML page, styled for printing
+    pub fn to_html(&self) -> Result<String> {
+        let compact = self.proof.to_compact()?;
+        let mut rows = String::new();
+        for field in self.fields() {
+            let value = escape_html(&field.value);
+            let (class, value) = match field.style {
+                FieldStyle::Text => ("", value),
+                FieldStyle::Hex => (" class=\"mono\"", value),
+                FieldStyle::Link => ("", format!("<a href=\"{value}\">{value}</a>")),
+            };
+            let _ = writeln!(
+                rows,
+                "<tr><th>{}</th><td{class}>{value}</td></tr>",
+                escape_html(&field.label)
+            );
+        }
+        let qr = self.qr.as_ref().map(qr_svg).unwrap_or_default();
+
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{TITLE}</title>
+<style>
+body {{ font-family: Helvetica, Arial, sans-serif; color: #111; max-width: 46rem; margin: 2rem auto; padding: 0 1rem; }}
+h1 {{ margin-bottom: 0.2rem; }}
+.subtitle {{ color: #555; margin-top: 0; }}
+table {{ border-collapse: collapse; width: 100%; margin: 1.5rem 0; }}
+th {{ text-align: left; vertical-align: top; padding: 0.35rem 1rem 0.35rem 0; white-space: nowrap; }}
+td {{ padding: 0.35rem 0; word-break: break-all; }}
+.mono, .compact {{ font-family: "Courier New", monospace; }}
+.compact {{ font-size: 0.8rem; word-break: break-all; background: #f4f4f4; padding: 0.75rem; }}
+.qr svg {{ width: 12rem; height: 12rem; }}
+@media print {{ body {{ margin: 0; }} a {{ color: inherit; text-decoration: none; }} }}
+</style>
+</head>
+<body>
+<h1>{TITLE}</h1>
+<p class="subtitle">zOpenTimestamps - proof of existence anchored in the Zcash blockchain</p>
+<table>
+{rows}</table>
+<p>{hint}</p>
+<div class="qr">{qr}</div>
+<p class="compact">{compact}</p>
+</body>
+</html>
+"#,
+            hint = escape_html(VERIFY_HINT),
+            compact = escape_html(&compact),
+        ))
+    }
+
+    /// Render as an A4 PDF
+    ///
+    /// Uses the standard Helvetica and Courier fonts in WinAnsi encoding,
+    /// which covers Latin-1; other characters are printed as `?`. Content
+    /// that does not fit (a long note, many attestations) continues on a new
+    /// page.
+    pub fn to_pdf(&self) -> Result<Vec<u8>> {
+        let compact = self.proof.to_compact()?;
+        let mut doc = PdfDocument::default();
+
+        let y = doc.line(20.0);
+        doc.text("F2", 22.0, MARGIN, y, TITLE);
+        let y = doc.line(20.0);
+        doc.text(
+            "F1",
+            11.0,
+            MARGIN,
+            y,
+            "zOpenTimestamps - proof of existence anchored in the Zcash blockchain",
+        );
+
+        let mut gap = 36.0;
+        for field in self.fields() {
+            let (font, size) = match field.style {
+                FieldStyle::Hex => ("F3", 9.0),
+                FieldStyle::Text | FieldStyle::Link => ("F1", 10.0),
+            };
+            for (i, line) in wrap(&field.value, VALUE_CHARS).iter().enumerate() {
+                let y = doc.line(if i == 0 { gap } else { 14.0 });
+                if i == 0 {
+                    doc.text("F2", 10.0, MARGIN, y, &field.label);
+                }
+                doc.text(font, size, VALUE_X, y, line);
+            }
+            gap = 18.0;
+        }
+
+        gap += 12.0;
+        for line in wrap(VERIFY_HINT, 100) {
+            let y = doc.line(gap);
+            doc.text("F1", 9.0, MARGIN, y, &line);
+            gap = 12.0;
+        }
+
+        if let Some(qr) = &self.qr {
+            let y = doc.line(gap + 12.0 + QR_SIZE);
+            doc.qr(qr, MARGIN, y, QR_SIZE);
+            gap = 0.0;
+        }
+
+        let y = doc.line(gap + 24.0);
+        doc.text("F2", 10.0, MARGIN, y, "Compact proof");
+        gap = 14.0;
+        for line in wrap(&compact, COMPACT_CHARS) {
+            let y = doc.line(gap);
+            doc.text("F3", 8.0, MARGIN, y, &line);
+            gap = 10.0;
+        }
+
+        Ok(doc.finish())
+    }
+}
+
+/// Drawing operators of a PDF document, one content stream per page
+struct PdfDocument {
+    /// Content of the pages before the current one
+    pages: Vec<String>,
+    content: String,
+    /// Baseline of the last line drawn on the current page
+    y: f32,
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self {
+            pages: Vec::new(),
+            content: String::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+}
+
+impl PdfDocument {
+    /// Move down `height` points and return the new baseline
+    ///
+    /// Starts a new page first when the line would reach the bottom margin.
+    fn line(&mut self, height: f32) -> f32 {
+        if self.y - height < MARGIN {
+            self.pages.push(std::mem::take(&mut self.content));
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+        self.y -= height;
+        self.y
+    }
+
+    /// Draw one line of text with its baseline at (`x`, `y`)
+    fn text(&mut self, font: &str, size: f32, x: f32, y: f32, text: &str) {
+        let _ = writeln!(
+            self.content,
+            "BT /{font} {size} Tf {x:.1} {y:.1} Td ({}) Tj ET",
+            escape_pdf(text)
+        );
+    }
+
+    /// Draw `qr` as a `size` square with its lower-left corner at (`x`, `y`)
+    fn qr(&mut self, qr: &QrMatrix, x: f32, y: f32, size: f32) {
+        let module = size / qr.width() as f32;
+        self.content.push_str("0 g\n");
+        for row in 0..qr.width() {
+            for column in 0..qr.width() {
+                if qr.is_dark(row, column) {
+                    let mx = x + column as f32 * module;
+                    // PDF y grows upwards, QR rows go down
+                    let my = y + size - (row + 1) as f32 * module;
+                    let _ = writeln!(self.content, "{mx:.2} {my:.2} {module:.2} {module:.2} re");
+                }
+            }
+        }
+        self.content.push_str("f\n");
+    }
+
+    /// Assemble the document: catalog, page tree, fonts, then pages and contents
+    fn finish(mut self) -> Vec<u8> {
+        self.pages.push(self.content);
+        // Objects 1-5 are fixed; page `i` is object 6 + 2i, its content 7 + 2i
+        let kids: Vec<String> = (0..self.pages.len())
+            .map(|i| format!("{} 0 R", 6 + 2 * i))
+            .collect();
+        let mut objects = vec![
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                self.pages.len()
+            ),
+            font_object("Helvetica"),
+            font_object("Helvetica-Bold"),
+            font_object("Courier"),
+        ];
+        for (i, content) in self.pages.iter().enumerate() {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R /F3 5 0 R >> >> /Contents {} 0 R >>",
+                7 + 2 * i
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{content}endstream",
+                content.len()
+            ));
+        }
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", i + 1).as_bytes());
+        }
+
+        let xref = pdf.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(trailer, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        );
+        pdf.extend_from_slice(trailer.as_bytes());
+        pdf
+    }
+}
+
+fn font_object(name: &str) -> String {
+    format!("<< /Type /Font /Subtype /Type1 /BaseFont /{name} /Encoding /WinAnsiEncoding >>")
+}
+
+/// Inline SVG of `qr` with a quiet zone, black on white
+fn qr_svg(qr: &QrMatrix) -> String {
+    const QUIET_ZONE: usize = 4;
+    let side = qr.width() + 2 * QUIET_ZONE;
+    let mut path = String::new();
+    for row in 0..qr.width() {
+        for column in 0..qr.width() {
+            if qr.is_dark(row, column) {
+                let _ = write!(
+                    path,
+                    "M{} {}h1v1h-1z",
+                    column + QUIET_ZONE,
+                    row + QUIET_ZONE
+                );
+            }
+        }
+    }
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {side} {side}\" shape-rendering=\"crispEdges\">\
+         <rect width=\"{side}\" height=\"{side}\" fill=\"#fff\"/><path d=\"{path}\" fill=\"#000\"/></svg>"
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape a PDF string literal in WinAnsi encoding
+///
+/// Non-ASCII characters are written as octal escapes so the content stream
+/// stays ASCII; characters WinAnsi lacks become `?`.
+fn escape_pdf(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' '..='~' => escaped.push(c),
+            _ => match win_ansi(c) {
+                Some(byte) => {
+                    let _ = write!(escaped, "\\{byte:03o}");
+                }
+                None => escaped.push('?'),
+            },
+        }
+    }
+    escaped
+}
+
+/// WinAnsi code of a non-ASCII character, if the encoding has it
+fn win_ansi(c: char) -> Option<u8> {
+    let byte = match c {
+        '\u{a0}'..='\u{ff}' => c as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        'ƒ' => 0x83,
+        '„' => 0x84,
+        '…' => 0x85,
+        '†' => 0x86,
+        '‡' => 0x87,
+        'ˆ' => 0x88,
+        '‰' => 0x89,
+        'Š' => 0x8a,
+        '‹' => 0x8b,
+        'Œ' => 0x8c,
+        'Ž' => 0x8e,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '˜' => 0x98,
+        '™' => 0x99,
+        'š' => 0x9a,
+        '›' => 0x9b,
+        'œ' => 0x9c,
+        'ž' => 0x9e,
+        'Ÿ' => 0x9f,
+        _ => return None,
+    };
+    Some(byte)
+}
+
+/// Split `text` into lines of at most `width` characters, at spaces when possible
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        // Words longer than a line (hashes, compact proofs) are cut
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let word: String = word.into_iter().collect();
+        if word.is_empty() {
+            continue;
+        }
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, ZcashAttestation};
+    use std::path::Path;
+
+    fn confirmed_proof() -> TimestampProof {
+        let mut proof = TimestampProof::new([0xab; 32]);
+        proof.set_filename(Path::new("/tmp/report <final>.pdf"));
+        proof.add_attestation(ZcashAttestation::new(
+            Network::Testnet,
+            [0x11; 32],
+            3739654,
+            1734293400,
+            0,
+        ));
+        proof
+    }
+
+    #[test]
+    fn test_qr_matrix_size() {
+        assert!(QrMatrix::new(2, vec![true, false, false, true]).is_some());
+        assert!(QrMatrix::new(2, vec![true, false, false]).is_none());
+        assert!(QrMatrix::new(0, Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_certificate_html() {
+        let proof = confirmed_proof();
+        let qr = QrMatrix::new(2, vec![true, false, false, true]).unwrap();
+        let html = Certificate::new(&proof)
+            .with_qr(qr)
+            .with_explorer_url("https://explorer.example")
+            .to_html()
+            .unwrap();
+
+        assert!(html.contains(&proof.hash));
+        assert!(html.contains(&proof.attestations[0].txid));
+        assert!(html.contains("3739654"));
+        assert!(html.contains("2024-12-15 20:10:00 UTC"));
+        assert!(html.contains(&proof.to_compact().unwrap()));
+        assert!(html.contains("https://explorer.example/tx/"));
+        assert!(html.contains("report &lt;final&gt;.pdf"));
+        assert!(!html.contains("<final>"));
+        // Two dark modules, offset by the quiet zone
+        assert!(html.contains("M4 4h1v1h-1zM5 5h1v1h-1z"));
+    }
+
+    #[test]
+    fn test_certificate_pending() {
+        let mut proof = TimestampProof::new([0xcd; 32]);
+        proof.set_pending_txid("ff".repeat(32));
+        let html = Certificate::new(&proof).to_html().unwrap();
+        assert!(html.contains("Pending"));
+        assert!(html.contains(&"ff".repeat(32)));
+    }
+
+    #[test]
+    fn test_certificate_pdf() {
+        let proof = confirmed_proof();
+        let qr = QrMatrix::new(2, vec![true, false, false, true]).unwrap();
+        let pdf = Certificate::new(&proof).with_qr(qr).to_pdf().unwrap();
+        let text = String::from_utf8(pdf).unwrap();
+
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains(&format!("({})", proof.hash)));
+        assert!(text.contains("(report <final>.pdf)"));
+
+        // startxref points at the xref table
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|n| n.parse().ok())
+            .unwrap();
+        assert!(text[startxref..].starts_with("xref\n0 8\n"));
+
+        // Each xref entry points at its object
+        let entries: Vec<usize> = text[startxref..]
+            .lines()
+            .skip(3)
+            .take(7)
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        for (i, offset) in entries.into_iter().enumerate() {
+            assert!(text[offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+
+        // Stream length matches its content
+        let stream = &text[text.find("stream\n").unwrap() + "stream\n".len()..];
+        let length: usize = text
+            .split("/Length ")
+            .nth(1)
+            .and_then(|tail| tail.split(' ').next())
+            .and_then(|n| n.parse().ok())
+            .unwrap();
+        assert!(stream[length..].starts_with("endstream"));
+        assert!(text.contains("/Count 1 >>"));
+    }
+
+    #[test]
+    fn test_certificate_pdf_continues_on_new_pages() {
+        let mut proof = confirmed_proof();
+        for height in 0..40 {
+            proof.add_attestation(ZcashAttestation::new(
+                Network::Testnet,
+                [height as u8 + 0x20; 32],
+                3739700 + height,
+                1734293400,
+                0,
+            ));
+        }
+        let text = String::from_utf8(Certificate::new(&proof).to_pdf().unwrap()).unwrap();
+
+        let pages = text.matches("/Type /Page /Parent").count();
+        assert!(pages > 1);
+        assert!(text.contains(&format!("/Count {pages} >>")));
+        // Nothing is drawn below the bottom margin
+        for line in text.lines().filter(|line| line.starts_with("BT ")) {
+            let y: f32 = line.split(' ').nth(5).unwrap().parse().unwrap();
+            assert!(y >= MARGIN, "{line}");
+        }
+        // The last attestation still makes it into the document
+        assert!(text.contains("3739739"));
+    }
+
+    #[test]
+    fn test_escape_pdf() {
+        assert_eq!(escape_pdf("a(b)c\\d"), "a\\(b\\)c\\\\d");
+        assert_eq!(escape_pdf("café"), "caf\\351");
+        assert_eq!(escape_pdf("“ok” – 5€"), "\\223ok\\224 \\226 5\\200");
+        assert_eq!(escape_pdf("日本"), "??");
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("", 4), vec![""]);
+    }
+}
@@ -0,0 +1,284 @@
+//! IPFS content identifiers.
+//!
+//! A CID names content by a multihash of it, so a SHA-256 or BLAKE3 CID of
+//! raw bytes (codec `raw`) can be timestamped directly from its digest
+//! without fetching anything.
+//!
+//! Both CID versions are decoded:
+//!
+//! - **CIDv0**: base58btc `Qm...`, always a SHA-256 multihash of a dag-pb node
+//! - **CIDv1**: multibase (`b`/`B` base32, `z` base58btc, `f`/`F` base16)
+//!   of `<version><codec><multihash>`
+//!
+//! For any other codec, such as the dag-pb of files added with the default
+//! UnixFS settings, the digest covers an encoded DAG node rather than the file
+//! bytes, so [`Cid::hash`] declines it and the content has to be fetched.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use zots_core::{Cid, HashAlgorithm};
+//!
+//! let cid = Cid::parse("ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG").unwrap();
+//! assert_eq!(cid.version, 0);
+//! assert_eq!(cid.hash_algorithm(), Some(HashAlgorithm::Sha256));
+//! assert_eq!(cid.hash(), None); // dag-pb: the digest is not of the file bytes
+//! ```
+
+use crate::{Error, Hash256, HashAlgorithm, Result};
+use std::fmt;
+
+/// Multihash code of SHA2-256
+const MULTIHASH_SHA2_256: u64 = 0x12;
+
+/// Multihash code of BLAKE3
+const MULTIHASH_BLAKE3: u64 = 0x1e;
+
+/// Multicodec of dag-pb, the implicit codec of CIDv0
+const CODEC_DAG_PB: u64 = 0x70;
+
+/// Multicodec of raw bytes, whose multihash is the digest of the content itself
+const CODEC_RAW: u64 = 0x55;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// A decoded IPFS content identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cid {
+    /// CID version (0 or 1)
+    pub version: u8,
+    /// Multicodec of the content (e.g. 0x70 dag-pb, 0x55 raw)
+    pub codec: u64,
+    /// Multihash function code (e.g. 0x12 SHA2-256, 0x1e BLAKE3)
+    pub hash_code: u64,
+    /// Hash digest
+    pub digest: Vec<u8>,
+    text: String,
+}
+
+impl Cid {
+    /// Parse a CID, with or without an `ipfs://` or `/ipfs/` prefix
+    pub fn parse(input: &str) -> Result<Self> {
+        let text = input.trim();
+        let text = text
+            .strip_prefix("ipfs://")
+            .or_else(|| text.strip_prefix("/ipfs/"))
+            .unwrap_or(text)
+            .trim_end_matches('/');
+        if text.contains('/') {
+            return Err(invalid("paths inside a CID are not supported"));
+        }
+
+        if text.len() == 46 && text.starts_with("Qm") {
+            let bytes = decode_base58(text)?;
+            let (hash_code, digest) = decode_multihash(&bytes)?;
+            return Ok(Self {
+                version: 0,
+                codec: CODEC_DAG_PB,
+                hash_code,
+                digest,
+                text: text.to_string(),
+            });
+        }
+
+        let mut chars = text.chars();
+        let bytes = match chars.next() {
+            Some('b') => decode_base32(chars.as_str())?,
+            Some('B') => decode_base32(&chars.as_str().to_lowercase())?,
+            Some('z') => decode_base58(chars.as_str())?,
+            Some('f' | 'F') => {
+                hex::decode(chars.as_str()).map_err(|e| invalid(&format!("bad base16: {e}")))?
+            }
+            Some(c) => return Err(invalid(&format!("unsupported multibase prefix '{c}'"))),
+            None => return Err(invalid("empty CID")),
+        };
+
+        let mut rest = bytes.as_slice();
+        let version = read_varint(&mut rest)?;
+        if version != 1 {
+            return Err(invalid(&format!("unsupported CID version {version}")));
+        }
+        let codec = read_varint(&mut rest)?;
+        let (hash_code, digest) = decode_multihash(rest)?;
+        Ok(Self {
+            version: 1,
+            codec,
+            hash_code,
+            digest,
+            text: text.to_string(),
+        })
+    }
+
+    /// Whether `input` is written as an IPFS reference (`ipfs://...`)
+    pub fn is_ipfs_uri(input: &str) -> bool {
+        input.trim().starts_with("ipfs://")
+    }
+
+    /// The zots hash algorithm matching this CID's multihash, if any
+    pub fn hash_algorithm(&self) -> Option<HashAlgorithm> {
+        if self.digest.len() != 32 {
+            return None;
+        }
+        match self.hash_code {
+            MULTIHASH_SHA2_256 => Some(HashAlgorithm::Sha256),
+            MULTIHASH_BLAKE3 => Some(HashAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+
+    /// The digest, if it can be stamped as-is with [`Self::hash_algorithm`]
+    ///
+    /// Only `raw` CIDs qualify: for other codecs the digest is of an encoded
+    /// node, not of the bytes the CID serves.
+    pub fn hash(&self) -> Option<Hash256> {
+        if self.codec != CODEC_RAW {
+            return None;
+        }
+        self.hash_algorithm()?;
+        self.digest.as_slice().try_into().ok()
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidHash(format!("Invalid CID: {reason}"))
+}
+
+/// Split a multihash into its function code and digest
+fn decode_multihash(bytes: &[u8]) -> Result<(u64, Vec<u8>)> {
+    let mut rest = bytes;
+    let code = read_varint(&mut rest)?;
+    let len = read_varint(&mut rest)?;
+    if rest.len() as u64 != len {
+        return Err(invalid(&format!(
+            "multihash declares {len} digest bytes but has {}",
+            rest.len()
+        )));
+    }
+    Ok((code, rest.to_vec()))
+}
+
+/// Read an unsigned LEB128 varint, advancing `bytes`
+fn read_varint(bytes: &mut &[u8]) -> Result<u64> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *bytes = &bytes[i + 1..];
+            return Ok(value);
+        }
+    }
+    Err(invalid("truncated varint"))
+}
+
+fn decode_base58(text: &str) -> Result<Vec<u8>> {
+    // Big-endian base-256 accumulator
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in text.bytes() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| invalid(&format!("bad base58 character '{}'", c as char)))?
+            as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = text.bytes().take_while(|&c| c == b'1').count();
+    let mut out = vec![0u8; zeros];
+    out.extend(bytes);
+    Ok(out)
+}
+
+fn decode_base32(text: &str) -> Result<Vec<u8>> {
+    let mut out = Vec::with_capacity(text.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in text.trim_end_matches('=').bytes() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or_else(|| invalid(&format!("bad base32 character '{}'", c as char)))?
+            as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CIDs of the empty UnixFS directory node
+    const EMPTY_DIR_V0: &str = "QmUNLLsPACCz1vLxQVkXqqLX5R1X345qqfHbsf67hvA3Nn";
+    const EMPTY_DIR_V1: &str = "bafybeiczsscdsbs7ffqz55asqdf3smv6klcw3gofszvwlyarci47bgf354";
+
+    #[test]
+    fn test_cid_v0_and_v1_agree() {
+        let v0 = Cid::parse(EMPTY_DIR_V0).unwrap();
+        let v1 = Cid::parse(&format!("ipfs://{EMPTY_DIR_V1}")).unwrap();
+
+        assert_eq!(v0.version, 0);
+        assert_eq!(v1.version, 1);
+        assert_eq!(v0.codec, CODEC_DAG_PB);
+        assert_eq!(v1.codec, CODEC_DAG_PB);
+        assert_eq!(v0.hash_algorithm(), Some(HashAlgorithm::Sha256));
+        assert_eq!(v0.digest, v1.digest);
+        assert_eq!(v1.to_string(), EMPTY_DIR_V1);
+    }
+
+    #[test]
+    fn test_cid_dag_pb_is_not_stamped_directly() {
+        // A SHA-256 multihash, but of the dag-pb node rather than the content
+        let v0 = Cid::parse(EMPTY_DIR_V0).unwrap();
+        assert_eq!(v0.hash_algorithm(), Some(HashAlgorithm::Sha256));
+        assert_eq!(v0.hash(), None);
+
+        let v1 = Cid::parse(EMPTY_DIR_V1).unwrap();
+        assert_eq!(v1.hash(), None);
+    }
+
+    #[test]
+    fn test_cid_base16_raw_blake3() {
+        let digest = [0xabu8; 32];
+        let text = format!("f01551e20{}", hex::encode(digest));
+        let cid = Cid::parse(&text).unwrap();
+        assert_eq!(cid.codec, CODEC_RAW);
+        assert_eq!(cid.hash_algorithm(), Some(HashAlgorithm::Blake3));
+        assert_eq!(cid.hash(), Some(digest));
+    }
+
+    #[test]
+    fn test_cid_unsupported_hash() {
+        // SHA2-512 multihash: decodes, but cannot be stamped directly
+        let text = format!("f01551340{}", "00".repeat(64));
+        let cid = Cid::parse(&text).unwrap();
+        assert_eq!(cid.hash_algorithm(), None);
+        assert_eq!(cid.hash(), None);
+    }
+
+    #[test]
+    fn test_cid_invalid() {
+        assert!(Cid::parse("").is_err());
+        assert!(Cid::parse("xabc").is_err());
+        assert!(Cid::parse("QmInvalid0OIl000000000000000000000000000000000").is_err());
+        assert!(Cid::parse("f0155122000").is_err());
+        assert!(Cid::parse(&format!("ipfs://{EMPTY_DIR_V1}/index.html")).is_err());
+    }
+}
@@ -0,0 +1,532 @@
+//! Embedding proofs in file metadata.
+//!
+//! Writes the compact proof into a copy of the stamped file so that a single
+//! distributable file carries its own timestamp, and recovers both the proof
+//! and the original (stamped) bytes from such a copy:
+//!
+//! | Format | Carrier | Original bytes |
+//! |--------|---------|----------------|
+//! | PNG | `tEXt` chunk with keyword `zots-proof`, before `IEND` | File without that chunk |
+//! | JPEG | XMP packet in its own `APP1` segment, after any JFIF/Exif headers | File without that segment |
+//! | PDF | XMP metadata stream appended as an incremental update | File truncated to `zots:originalLength` |
+//!
+//! Every carrier is inserted without touching the rest of the file, so the
+//! original is recovered byte for byte and re-hashing it must reproduce the
+//! proof's hash. JPEG proofs are XMP, not EXIF: existing Exif data is left as
+//! it is and the proof is not visible to EXIF-only tools.
+//!
+//! ```rust,no_run
+//! use zots_core::{TimestampProof, embed_proof, extract_proof, hash_bytes};
+//!
+//! let png = std::fs::read("photo.png").unwrap();
+//! let proof = TimestampProof::new(hash_bytes(&png));
+//! let stamped = embed_proof(&png, &proof).unwrap();
+//!
+//! let embedded = extract_proof(&stamped).unwrap().unwrap();
+//! assert_eq!(embedded.original, png);
+//! assert_eq!(embedded.proof.hash, proof.hash);
+//! ```
+
+use crate::{Error, Result, TimestampProof};
+use std::ops::Range;
+
+/// Keyword of the PNG `tEXt` chunk holding the proof
+const PNG_KEYWORD: &str = "zots-proof";
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+/// Identifier that starts an XMP `APP1` payload
+const XMP_APP1_HEADER: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+const XMP_NAMESPACE: &str = "https://github.com/AbdelStark/zopentimestamps/ns/1.0/";
+
+/// File formats that can carry an embedded proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbedFormat {
+    Png,
+    Jpeg,
+    Pdf,
+}
+
+impl EmbedFormat {
+    /// Detect the format from the file's magic bytes
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(&PNG_SIGNATURE) {
+            Some(Self::Png)
+        } else if data.starts_with(&[0xFF, 0xD8]) {
+            Some(Self::Jpeg)
+        } else if data.starts_with(b"%PDF-") {
+            Some(Self::Pdf)
+        } else {
+            None
+        }
+    }
+
+    /// User-facing format name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Png => "PNG",
+            Self::Jpeg => "JPEG",
+            Self::Pdf => "PDF",
+        }
+    }
+}
+
+/// A proof recovered from a file, with the bytes it was stamped over
+#[derive(Debug, Clone)]
+pub struct EmbeddedProof {
+    pub format: EmbedFormat,
+    pub proof: TimestampProof,
+    /// The file as it was before the proof was embedded
+    pub original: Vec<u8>,
+}
+
+/// Return a copy of `data` with `proof` embedded in its metadata
+///
+/// Fails if the format is unsupported or the file already carries a proof.
+pub fn embed_proof(data: &[u8], proof: &TimestampProof) -> Result<Vec<u8>> {
+    let format = EmbedFormat::detect(data).ok_or_else(|| {
+        Error::Embed("unsupported file format (expected PNG, JPEG or PDF)".into())
+    })?;
+    if extract_proof(data)?.is_some() {
+        return Err(Error::Embed("file already carries a proof".into()));
+    }
+    let compact = proof.to_compact()?;
+    match format {
+        EmbedFormat::Png => embed_png(data, &compact),
+        EmbedFormat::Jpeg => embed_jpeg(data, &compact),
+        EmbedFormat::Pdf => embed_pdf(data, &compact),
+    }
+}
+
+/// Recover an embedded proof and the original bytes, if the file has one
+pub fn extract_proof(data: &[u8]) -> Result<Option<EmbeddedProof>> {
+    let Some(format) = EmbedFormat::detect(data) else {
+        return Ok(None);
+    };
+    let found = match format {
+        EmbedFormat::Png => extract_png(data)?,
+        EmbedFormat::Jpeg => extract_jpeg(data)?,
+        EmbedFormat::Pdf => extract_pdf(data)?,
+    };
+    found
+        .map(|(compact, original)| {
+            Ok(EmbeddedProof {
+                format,
+                proof: TimestampProof::from_compact(&compact)?,
+                original,
+            })
+        })
+        .transpose()
+}
+
+/// A PNG chunk or JPEG marker segment located in a file
+struct Segment<K> {
+    start: usize,
+    kind: K,
+    body: Range<usize>,
+    end: usize,
+}
+
+// PNG
+
+fn png_chunks(data: &[u8]) -> Result<Vec<Segment<[u8; 4]>>> {
+    let mut chunks = Vec::new();
+    let mut pos = PNG_SIGNATURE.len();
+    while pos < data.len() {
+        let header = data
+            .get(pos..pos + 8)
+            .ok_or_else(|| Error::Embed("truncated PNG chunk".into()))?;
+        let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let kind = [header[4], header[5], header[6], header[7]];
+        let end = pos + 12 + len;
+        if end > data.len() {
+            return Err(Error::Embed("truncated PNG chunk".into()));
+        }
+        chunks.push(Segment {
+            start: pos,
+            kind,
+            body: pos + 8..pos + 8 + len,
+            end,
+        });
+        pos = end;
+        if &kind == b"IEND" {
+            break;
+        }
+    }
+    Ok(chunks)
+}
+
+fn embed_png(data: &[u8], compact: &str) -> Result<Vec<u8>> {
+    let chunks = png_chunks(data)?;
+    let iend = chunks
+        .iter()
+        .find(|chunk| &chunk.kind == b"IEND")
+        .map(|chunk| chunk.start)
+        .ok_or_else(|| Error::Embed("PNG has no IEND chunk".into()))?;
+
+    let mut body = Vec::with_capacity(PNG_KEYWORD.len() + 1 + compact.len());
+    body.extend_from_slice(PNG_KEYWORD.as_bytes());
+    body.push(0);
+    body.extend_from_slice(compact.as_bytes());
+
+    let mut out = Vec::with_capacity(data.len() + body.len() + 12);
+    out.extend_from_slice(&data[..iend]);
+    out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    let crc_start = out.len();
+    out.extend_from_slice(b"tEXt");
+    out.extend_from_slice(&body);
+    let crc = crc32(&out[crc_start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+    out.extend_from_slice(&data[iend..]);
+    Ok(out)
+}
+
+fn extract_png(data: &[u8]) -> Result<Option<(String, Vec<u8>)>> {
+    for chunk in png_chunks(data)? {
+        if &chunk.kind != b"tEXt" {
+            continue;
+        }
+        let Some(text) = data[chunk.body]
+            .strip_prefix(PNG_KEYWORD.as_bytes())
+            .and_then(|rest| rest.strip_prefix(&[0]))
+        else {
+            continue;
+        };
+        let compact = String::from_utf8_lossy(text).into_owned();
+        let mut original = data[..chunk.start].to_vec();
+        original.extend_from_slice(&data[chunk.end..]);
+        return Ok(Some((compact, original)));
+    }
+    Ok(None)
+}
+
+/// CRC-32 (IEEE) as used by PNG chunks
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+// JPEG
+
+/// Marker segments before the scan data
+fn jpeg_segments(data: &[u8]) -> Result<Vec<Segment<u8>>> {
+    let mut segments = Vec::new();
+    let mut pos = 2;
+    while pos + 4 <= data.len() && data[pos] == 0xFF {
+        let marker = data[pos + 1];
+        // Start of scan: entropy-coded data follows, no more metadata
+        if marker == 0xDA {
+            break;
+        }
+        let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = pos + 2 + len;
+        if len < 2 || end > data.len() {
+            return Err(Error::Embed("truncated JPEG segment".into()));
+        }
+        segments.push(Segment {
+            start: pos,
+            kind: marker,
+            body: pos + 4..end,
+            end,
+        });
+        pos = end;
+    }
+    Ok(segments)
+}
+
+fn embed_jpeg(data: &[u8], compact: &str) -> Result<Vec<u8>> {
+    let segments = jpeg_segments(data)?;
+    // JFIF APP0 must come first and Exif APP1 right after it, so the XMP
+    // segment goes after the leading run of APP0/APP1 segments
+    let insert_at = segments
+        .iter()
+        .take_while(|segment| matches!(segment.kind, 0xE0 | 0xE1))
+        .last()
+        .map_or(2, |segment| segment.end);
+
+    let mut payload = XMP_APP1_HEADER.to_vec();
+    payload.extend_from_slice(xmp_packet(compact, None).as_bytes());
+    let len = u16::try_from(payload.len() + 2)
+        .map_err(|_| Error::Embed("proof too large for a JPEG segment".into()))?;
+
+    let mut out = Vec::with_capacity(data.len() + payload.len() + 4);
+    out.extend_from_slice(&data[..insert_at]);
+    out.extend_from_slice(&[0xFF, 0xE1]);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(&payload);
+    out.extend_from_slice(&data[insert_at..]);
+    Ok(out)
+}
+
+fn extract_jpeg(data: &[u8]) -> Result<Option<(String, Vec<u8>)>> {
+    for segment in jpeg_segments(data)? {
+        if segment.kind != 0xE1 {
+            continue;
+        }
+        let Some(xmp) = data[segment.body].strip_prefix(XMP_APP1_HEADER) else {
+            continue;
+        };
+        let Some((compact, _)) = parse_xmp(&String::from_utf8_lossy(xmp)) else {
+            continue;
+        };
+        let mut original = data[..segment.start].to_vec();
+        original.extend_from_slice(&data[segment.end..]);
+        return Ok(Some((compact, original)));
+    }
+    Ok(None)
+}
+
+// PDF
+
+fn embed_pdf(data: &[u8], compact: &str) -> Result<Vec<u8>> {
+    let prev = rfind(data, b"startxref")
+        .and_then(|i| {
+            String::from_utf8_lossy(&data[i + b"startxref".len()..])
+                .split_whitespace()
+                .next()?
+                .parse::<usize>()
+                .ok()
+        })
+        .filter(|&offset| offset < data.len())
+        .ok_or_else(|| Error::Embed("PDF has no startxref".into()))?;
+
+    // The classic trailer or the xref stream dictionary at `prev`
+    let section = String::from_utf8_lossy(&data[prev..]);
+    let trailer = match section.find("trailer") {
+        Some(i) if section.starts_with("xref") => &section[i..],
+        _ => &section[..],
+    };
+    let end = trailer
+        .find("startxref")
+        .or_else(|| trailer.find("stream"))
+        .unwrap_or(trailer.len());
+    let trailer = &trailer[..end];
+
+    let size: usize = pdf_value(trailer, "/Size")
+        .and_then(|v| v.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok())
+        .ok_or_else(|| Error::Embed("PDF trailer has no /Size".into()))?;
+    let root = pdf_value(trailer, "/Root")
+        .and_then(pdf_reference)
+        .ok_or_else(|| Error::Embed("PDF trailer has no /Root".into()))?;
+    let info = pdf_value(trailer, "/Info").and_then(pdf_reference);
+    let id = pdf_value(trailer, "/ID").and_then(|v| Some(&v[..=v.find(']')?]));
+
+    let xmp = xmp_packet(compact, Some(data.len()));
+    let mut update = String::from("\n");
+    let object_offset = data.len() + update.len();
+    update.push_str(&format!(
+        "{size} 0 obj\n<< /Type /Metadata /Subtype /XML /Length {} >>\nstream\n{xmp}\nendstream\nendobj\n",
+        xmp.len()
+    ));
+    let xref_offset = data.len() + update.len();
+    update.push_str(&format!(
+        "xref\n{size} 1\n{object_offset:010} 00000 n \ntrailer\n<< /Size {} /Root {root}",
+        size + 1
+    ));
+    if let Some(info) = info {
+        update.push_str(&format!(" /Info {info}"));
+    }
+    if let Some(id) = id {
+        update.push_str(&format!(" /ID {id}"));
+    }
+    update.push_str(&format!(
+        " /Prev {prev} >>\nstartxref\n{xref_offset}\n%%EOF\n"
+    ));
+
+    let mut out = data.to_vec();
+    out.extend_from_slice(update.as_bytes());
+    Ok(out)
+}
+
+fn extract_pdf(data: &[u8]) -> Result<Option<(String, Vec<u8>)>> {
+    let namespace = format!("xmlns:zots=\"{XMP_NAMESPACE}\"");
+    let Some(start) = rfind(data, namespace.as_bytes()) else {
+        return Ok(None);
+    };
+    let Some((compact, original_len)) = parse_xmp(&String::from_utf8_lossy(&data[start..])) else {
+        return Ok(None);
+    };
+    let original_len = original_len
+        .filter(|&len| len <= start)
+        .ok_or_else(|| Error::Embed("embedded PDF proof has no valid originalLength".into()))?;
+    Ok(Some((compact, data[..original_len].to_vec())))
+}
+
+/// Byte offset of the last occurrence of `needle`
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+/// Text following `key` in a PDF dictionary
+fn pdf_value<'a>(dict: &'a str, key: &str) -> Option<&'a str> {
+    let i = dict.find(key)?;
+    Some(dict[i + key.len()..].trim_start())
+}
+
+/// `N G R` at the start of `value`
+fn pdf_reference(value: &str) -> Option<String> {
+    let mut parts = value.split_whitespace();
+    let (num, gen_, r) = (parts.next()?, parts.next()?, parts.next()?);
+    (num.parse::<u32>().is_ok() && gen_.parse::<u16>().is_ok() && r.starts_with('R'))
+        .then(|| format!("{num} {gen_} R"))
+}
+
+// XMP
+
+fn xmp_packet(compact: &str, original_len: Option<usize>) -> String {
+    let length_attr = original_len
+        .map(|len| format!(" zots:originalLength=\"{len}\""))
+        .unwrap_or_default();
+    format!(
+        "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\
+         <x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\
+         <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\
+         <rdf:Description rdf:about=\"\" xmlns:zots=\"{XMP_NAMESPACE}\"{length_attr}>\
+         <zots:proof>{compact}</zots:proof>\
+         </rdf:Description></rdf:RDF></x:xmpmeta>\
+         <?xpacket end=\"w\"?>"
+    )
+}
+
+/// Compact proof and original length from a zots XMP packet
+fn parse_xmp(xmp: &str) -> Option<(String, Option<usize>)> {
+    let start = xmp.find("<zots:proof>")? + "<zots:proof>".len();
+    let end = start + xmp[start..].find("</zots:proof>")?;
+    let original_len = xmp.find("zots:originalLength=\"").and_then(|i| {
+        let rest = &xmp[i + "zots:originalLength=\"".len()..];
+        rest[..rest.find('"')?].parse().ok()
+    });
+    Some((xmp[start..end].trim().to_string(), original_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, ZcashAttestation, hash_bytes};
+
+    /// Smallest valid PNG: a 1x1 grayscale image
+    fn minimal_png() -> Vec<u8> {
+        let mut png = PNG_SIGNATURE.to_vec();
+        for (kind, body) in [
+            (&b"IHDR"[..], &[0, 0, 0, 1, 0, 0, 0, 1, 8, 0, 0, 0, 0][..]),
+            (
+                b"IDAT",
+                &[0x78, 0x9C, 0x63, 0x60, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01],
+            ),
+            (b"IEND", &[]),
+        ] {
+            png.extend_from_slice(&(body.len() as u32).to_be_bytes());
+            let start = png.len();
+            png.extend_from_slice(kind);
+            png.extend_from_slice(body);
+            let crc = crc32(&png[start..]);
+            png.extend_from_slice(&crc.to_be_bytes());
+        }
+        png
+    }
+
+    fn proof_for(data: &[u8]) -> TimestampProof {
+        let mut proof = TimestampProof::new(hash_bytes(data));
+        proof.add_attestation(ZcashAttestation::new(
+            Network::Testnet,
+            [0x11; 32],
+            3_700_000,
+            1_750_000_000,
+            0,
+        ));
+        proof
+    }
+
+    fn assert_roundtrip(data: &[u8], format: EmbedFormat) {
+        assert_eq!(EmbedFormat::detect(data), Some(format));
+        let proof = proof_for(data);
+        let stamped = embed_proof(data, &proof).unwrap();
+        assert_ne!(stamped, data);
+
+        let embedded = extract_proof(&stamped).unwrap().unwrap();
+        assert_eq!(embedded.format, format);
+        assert_eq!(embedded.original, data);
+        assert_eq!(embedded.proof.hash, proof.hash);
+        assert_eq!(
+            embedded.proof.attestations[0].txid,
+            proof.attestations[0].txid
+        );
+        assert!(embed_proof(&stamped, &proof).is_err());
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn test_embed_png_roundtrip() {
+        assert_roundtrip(&minimal_png(), EmbedFormat::Png);
+    }
+
+    #[test]
+    fn test_embed_jpeg_roundtrip() {
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+        jpeg.extend_from_slice(b"JFIF\0\x01\x01\0\0\x01\0\x01\0\0");
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+        assert_roundtrip(&jpeg, EmbedFormat::Jpeg);
+
+        // The JFIF header stays first
+        let stamped = embed_proof(&jpeg, &proof_for(&jpeg)).unwrap();
+        assert_eq!(&stamped[..4], &[0xFF, 0xD8, 0xFF, 0xE0]);
+    }
+
+    #[test]
+    fn test_embed_jpeg_keeps_exif_first() {
+        let exif = b"Exif  MM *     ";
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+        jpeg.extend_from_slice(b"JFIF      ");
+        jpeg.extend_from_slice(&[0xFF, 0xE1, 0x00, exif.len() as u8 + 2]);
+        jpeg.extend_from_slice(exif);
+        let headers = jpeg.len();
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+        assert_roundtrip(&jpeg, EmbedFormat::Jpeg);
+
+        let stamped = embed_proof(&jpeg, &proof_for(&jpeg)).unwrap();
+        assert_eq!(&stamped[..headers], &jpeg[..headers]);
+        assert_eq!(&stamped[headers..headers + 2], &[0xFF, 0xE1]);
+        assert!(stamped[headers + 4..].starts_with(XMP_APP1_HEADER));
+
+        // Exif without JFIF also keeps its place after SOI
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1, 0x00, exif.len() as u8 + 2];
+        jpeg.extend_from_slice(exif);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+        let stamped = embed_proof(&jpeg, &proof_for(&jpeg)).unwrap();
+        assert_eq!(&stamped[2..6 + exif.len()], &jpeg[2..6 + exif.len()]);
+        assert_eq!(extract_proof(&stamped).unwrap().unwrap().original, jpeg);
+    }
+
+    #[test]
+    fn test_embed_pdf_roundtrip() {
+        let pdf = b"%PDF-1.4\n1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+2 0 obj\n<< /Type /Pages /Kids [] /Count 0 >>\nendobj\n\
+xref\n0 3\n0000000000 65535 f \n0000000009 00000 n \n0000000058 00000 n \n\
+trailer\n<< /Size 3 /Root 1 0 R >>\nstartxref\n110\n%%EOF\n";
+        assert_roundtrip(pdf, EmbedFormat::Pdf);
+
+        let stamped = embed_proof(pdf, &proof_for(pdf)).unwrap();
+        let text = String::from_utf8_lossy(&stamped);
+        assert!(text.contains("3 0 obj"));
+        assert!(text.contains("/Size 4 /Root 1 0 R /Prev 110"));
+    }
+
+    #[test]
+    fn test_embed_rejects_unknown_format() {
+        let proof = proof_for(b"plain text");
+        assert!(embed_proof(b"plain text", &proof).is_err());
+        assert!(extract_proof(b"plain text").unwrap().is_none());
+    }
+}
@@ -13,6 +13,10 @@ pub enum Error {
     #[error("Invalid proof format: {0}")]
     InvalidProof(String),
 
+    /// Proof metadata rejected (e.g. a note that is too long)
+    #[error("Invalid metadata: {0}")]
+    InvalidMetadata(String),
+
     /// Hash mismatch during verification
     #[error("Hash mismatch: expected {expected}, got {actual}")]
     HashMismatch { expected: String, actual: String },
@@ -29,6 +33,14 @@ pub enum Error {
     #[error("Transaction not found: {0}")]
     TxNotFound(String),
 
+    /// File format cannot carry an embedded proof, or carries a broken one
+    #[error("Embedding error: {0}")]
+    Embed(String),
+
+    /// Pending proof registry unreadable, unwritable or locked
+    #[error("Pending registry error: {0}")]
+    PendingRegistry(String),
+
     /// Network communication error
     #[error("Network error: {0}")]
     Network(String),
@@ -65,10 +65,35 @@ pub fn hash_file(path: impl AsRef<Path>) -> Result<Hash256> {
 
 /// Hash a file with a specific algorithm
 pub fn hash_file_with(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> Result<Hash256> {
+    hash_file_with_progress(path, algorithm, |_, _| {})
+}
+
+/// Hash a file, reporting `(bytes_hashed, file_size)` after every chunk
+pub fn hash_file_with_progress(
+    path: impl AsRef<Path>,
+    algorithm: HashAlgorithm,
+    mut on_progress: impl FnMut(u64, u64),
+) -> Result<Hash256> {
     let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
+    let total = file.metadata()?.len();
+    hash_reader_with_progress(BufReader::new(file), algorithm, |done| {
+        on_progress(done, total)
+    })
+}
 
+/// Hash everything read from `reader` (e.g. stdin) without buffering it in memory
+pub fn hash_reader_with(reader: impl Read, algorithm: HashAlgorithm) -> Result<Hash256> {
+    hash_reader_with_progress(reader, algorithm, |_| {})
+}
+
+/// Hash a reader, reporting the number of bytes hashed so far after every chunk
+pub fn hash_reader_with_progress(
+    mut reader: impl Read,
+    algorithm: HashAlgorithm,
+    mut on_progress: impl FnMut(u64),
+) -> Result<Hash256> {
     let mut buffer = [0u8; 8192];
+    let mut done = 0u64;
 
     match algorithm {
         HashAlgorithm::Sha256 => {
@@ -79,6 +104,8 @@ pub fn hash_file_with(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> Resul
                     break;
                 }
                 hasher.update(&buffer[..bytes_read]);
+                done += bytes_read as u64;
+                on_progress(done);
             }
             Ok(hasher.finalize().into())
         }
@@ -90,12 +117,38 @@ pub fn hash_file_with(path: impl AsRef<Path>, algorithm: HashAlgorithm) -> Resul
                     break;
                 }
                 hasher.update(&buffer[..bytes_read]);
+                done += bytes_read as u64;
+                on_progress(done);
             }
             Ok(*hasher.finalize().as_bytes())
         }
     }
 }
 
+/// Normalize a text message before hashing
+///
+/// Line endings become `\n`, trailing whitespace is removed from every line,
+/// and leading/trailing blank lines are dropped. The same statement typed on
+/// Windows or Unix, with or without a final newline, therefore hashes the same.
+pub fn normalize_text(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |i| i + 1);
+    lines[start..end].join("\n")
+}
+
+/// Hash a text message (UTF-8, after [`normalize_text`]) with a specific algorithm
+pub fn hash_text_with(text: &str, algorithm: HashAlgorithm) -> Hash256 {
+    hash_bytes_with(normalize_text(text).as_bytes(), algorithm)
+}
+
 /// Parse a hex string and return a Hash256
 ///
 /// Accepts:
@@ -157,6 +210,46 @@ mod tests {
         assert_eq!(hex, blake3::hash(data).to_hex().to_string());
     }
 
+    #[test]
+    fn test_hash_reader_matches_bytes() {
+        let data = vec![0x5Au8; 20_000];
+        for algorithm in [HashAlgorithm::Sha256, HashAlgorithm::Blake3] {
+            let hash = hash_reader_with(std::io::Cursor::new(&data), algorithm).unwrap();
+            assert_eq!(hash, hash_bytes_with(&data, algorithm));
+        }
+    }
+
+    #[test]
+    fn test_hash_reader_reports_progress() {
+        let data = vec![0x5Au8; 20_000];
+        let mut reported = Vec::new();
+        let hash =
+            hash_reader_with_progress(std::io::Cursor::new(&data), HashAlgorithm::Sha256, |done| {
+                reported.push(done)
+            })
+            .unwrap();
+        assert_eq!(hash, hash_bytes(&data));
+        assert!(reported.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(reported.last(), Some(&20_000));
+    }
+
+    #[test]
+    fn test_hash_text_normalization() {
+        assert_eq!(
+            normalize_text("\n  hello \r\nworld\t\r\n\n"),
+            "  hello\nworld"
+        );
+        assert_eq!(
+            hash_text_with("hello world\n", HashAlgorithm::Sha256),
+            hash_bytes(b"hello world")
+        );
+        assert_eq!(
+            hash_text_with("a\r\nb", HashAlgorithm::Blake3),
+            hash_text_with("a\nb  \n", HashAlgorithm::Blake3)
+        );
+        assert_eq!(normalize_text(" \r\n "), "");
+    }
+
     #[test]
     fn test_hash_bytes_empty() {
         let data = b"";
@@ -0,0 +1,186 @@
+//! Fluent message catalogs (feature `i18n`).
+//!
+//! The CLI and the desktop app each compile their own `.ftl` files into the
+//! binary and describe them with a [`Locale`] table; this module does the
+//! rest: building the bundles, resolving a locale name such as `es_ES.UTF-8`
+//! to a bundled language, and formatting messages with a fallback to the
+//! first locale in the table, then to the message id itself.
+//!
+//! ```rust
+//! use zots_core::i18n::{Locale, Localizer};
+//!
+//! static LOCALES: &[Locale] = &[
+//!     Locale { code: "en", name: "English", source: "hello = Hello\nbye = Bye\n" },
+//!     Locale { code: "es", name: "Español", source: "hello = Hola\n" },
+//! ];
+//!
+//! let mut localizer = Localizer::new(LOCALES);
+//! localizer.select(Some("es_ES.UTF-8"));
+//! assert_eq!(localizer.format("hello", None), "Hola");
+//! assert_eq!(localizer.format("bye", None), "Bye");
+//! assert_eq!(localizer.format("missing", None), "missing");
+//! ```
+
+use fluent_bundle::FluentResource;
+use fluent_bundle::concurrent::FluentBundle;
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+/// A bundled translation
+#[derive(Debug, Clone, Copy)]
+pub struct Locale {
+    /// Language code, e.g. `es`
+    pub code: &'static str,
+    /// Native name of the language, for language pickers
+    pub name: &'static str,
+    /// Fluent source of its messages
+    pub source: &'static str,
+}
+
+/// Formats messages in a selected locale, falling back to the first one
+pub struct Localizer {
+    locales: &'static [Locale],
+    /// Bundle for the selected locale, `None` when it is the fallback
+    selected: Option<FluentBundle<FluentResource>>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Localizer {
+    /// Localizer for `locales` showing the fallback (first) locale
+    ///
+    /// Panics if `locales` is empty or the fallback messages do not parse.
+    pub fn new(locales: &'static [Locale]) -> Self {
+        Self {
+            locales,
+            selected: None,
+            fallback: bundle(&locales[0]).expect("fallback locale is bundled"),
+        }
+    }
+
+    /// Show messages in the locale named by `locale`
+    ///
+    /// `None` and unsupported locales select the fallback.
+    pub fn select(&mut self, locale: Option<&str>) {
+        self.selected = locale
+            .and_then(|locale| self.language_code(locale))
+            .filter(|code| *code != self.locales[0].code)
+            .and_then(|code| self.locales.iter().find(|l| l.code == code))
+            .and_then(bundle);
+    }
+
+    /// Format the message `id`, falling back to the first locale and then to the id itself
+    pub fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        self.selected
+            .as_ref()
+            .and_then(|bundle| format_message(bundle, id, args))
+            .or_else(|| format_message(&self.fallback, id, args))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Bundled language code for a locale name (see [`language_code`])
+    pub fn language_code(&self, locale: &str) -> Option<&'static str> {
+        language_code(self.locales, locale)
+    }
+
+    /// Message ids of the locale `code` that the fallback does not define
+    ///
+    /// Such messages can never be shown; a non-empty list means a key was
+    /// renamed or removed in the fallback but not in the translation.
+    pub fn unknown_ids(&self, code: &str) -> Vec<&'static str> {
+        self.locales
+            .iter()
+            .filter(|l| l.code == code)
+            .flat_map(|l| message_ids(l.source))
+            .filter(|id| !self.fallback.has_message(id))
+            .collect()
+    }
+}
+
+/// Code of the locale in `locales` for a name like `es`, `es-MX` or `es_ES.UTF-8`
+pub fn language_code(locales: &'static [Locale], locale: &str) -> Option<&'static str> {
+    let language = locale.split(['_', '-', '.', '@']).next()?.to_lowercase();
+    locales
+        .iter()
+        .map(|l| l.code)
+        .find(|code| *code == language)
+}
+
+/// First set POSIX locale variable (`LC_ALL`, `LC_MESSAGES`, then `LANG`)
+pub fn env_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+fn bundle(locale: &Locale) -> Option<FluentBundle<FluentResource>> {
+    let langid: LanguageIdentifier = locale.code.parse().ok()?;
+    let resource = FluentResource::try_new(locale.source.to_string()).ok()?;
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // Bidi isolation marks end up verbatim in terminals and as glyphs in iced
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource).ok()?;
+    Some(bundle)
+}
+
+fn format_message(
+    bundle: &FluentBundle<FluentResource>,
+    id: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    errors.is_empty().then(|| text.into_owned())
+}
+
+/// Ids of the messages defined in a Fluent source (terms and comments skipped)
+fn message_ids(source: &'static str) -> impl Iterator<Item = &'static str> {
+    source
+        .lines()
+        .filter(|line| line.starts_with(|c: char| c.is_ascii_alphabetic()))
+        .filter_map(|line| line.split_once('='))
+        .map(|(id, _)| id.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static LOCALES: &[Locale] = &[
+        Locale {
+            code: "en",
+            name: "English",
+            source: "greeting = Hello, { $name }\nonly-english = Fallback\n",
+        },
+        Locale {
+            code: "es",
+            name: "Español",
+            source: "# comment\ngreeting = Hola, { $name }\nstale-key = Sobra\n",
+        },
+    ];
+
+    #[test]
+    fn test_falls_back_to_first_locale() {
+        let mut localizer = Localizer::new(LOCALES);
+        let mut args = FluentArgs::new();
+        args.set("name", "Ana");
+        assert_eq!(localizer.format("greeting", Some(&args)), "Hello, Ana");
+
+        localizer.select(Some("es_MX.UTF-8"));
+        assert_eq!(localizer.format("greeting", Some(&args)), "Hola, Ana");
+        assert_eq!(localizer.format("only-english", None), "Fallback");
+        assert_eq!(localizer.format("nowhere", None), "nowhere");
+
+        localizer.select(Some("fr_FR"));
+        assert_eq!(localizer.format("greeting", Some(&args)), "Hello, Ana");
+    }
+
+    #[test]
+    fn test_unknown_ids() {
+        let localizer = Localizer::new(LOCALES);
+        assert_eq!(localizer.unknown_ids("es"), vec!["stale-key"]);
+        assert!(localizer.unknown_ids("en").is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+//! in-toto attestation statements.
+//!
+//! Wraps a timestamp proof as the predicate of an [in-toto Statement] whose
+//! subject is the stamped artifact, so supply-chain tooling (SLSA
+//! provenance, `cosign attest`, policy engines) can carry zots proofs next to
+//! other build attestations.
+//!
+//! [in-toto Statement]: https://github.com/in-toto/attestation/blob/main/spec/v1/statement.md
+//!
+//! ## Example
+//!
+//! ```rust
+//! use zots_core::{InTotoStatement, TimestampProof};
+//!
+//! let proof = TimestampProof::new([0u8; 32]);
+//! let statement = InTotoStatement::new("app.tar.gz", &proof).unwrap();
+//! assert_eq!(statement.subject[0].digest["sha256"], proof.hash);
+//! ```
+
+use crate::{Error, HashAlgorithm, Result, TimestampProof};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Statement type of in-toto attestation framework v1
+pub const INTOTO_STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+
+/// Predicate type identifying a zots timestamp predicate
+pub const TIMESTAMP_PREDICATE_TYPE: &str =
+    "https://github.com/AbdelStark/zopentimestamps/predicate/timestamp/v1";
+
+/// An in-toto v1 Statement about one artifact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTotoStatement {
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+    pub subject: Vec<InTotoSubject>,
+    #[serde(rename = "predicateType")]
+    pub predicate_type: String,
+    pub predicate: TimestampPredicate,
+}
+
+/// Artifact the statement is about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InTotoSubject {
+    /// Artifact name, usually its file name
+    pub name: String,
+    /// Digests keyed by algorithm (`sha256` or `blake3`)
+    pub digest: BTreeMap<String, String>,
+}
+
+/// The zots timestamp carried as the statement predicate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimestampPredicate {
+    /// Block time of the earliest attestation (RFC 3339), absent while pending
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// The full proof, as in a `.zots` file
+    pub proof: TimestampProof,
+    /// The proof in compact format
+    pub compact: String,
+}
+
+impl InTotoStatement {
+    /// Build a statement about the artifact `name` from its proof
+    pub fn new(name: impl Into<String>, proof: &TimestampProof) -> Result<Self> {
+        let algorithm = match proof.hash_algorithm() {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Blake3 => "blake3",
+        };
+        let timestamp = proof
+            .attestations
+            .iter()
+            .min_by_key(|att| att.block_height)
+            .map(|att| att.timestamp().to_rfc3339());
+
+        Ok(Self {
+            statement_type: INTOTO_STATEMENT_TYPE.to_string(),
+            subject: vec![InTotoSubject {
+                name: name.into(),
+                digest: BTreeMap::from([(algorithm.to_string(), proof.hash.clone())]),
+            }],
+            predicate_type: TIMESTAMP_PREDICATE_TYPE.to_string(),
+            predicate: TimestampPredicate {
+                timestamp,
+                compact: proof.to_compact()?,
+                proof: proof.clone(),
+            },
+        })
+    }
+
+    /// Serialize the statement to pretty JSON
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| Error::InvalidProof(format!("Statement serialization failed: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, ZcashAttestation};
+
+    #[test]
+    fn test_statement_shape() {
+        let mut proof = TimestampProof::new_with_algorithm([0x42u8; 32], HashAlgorithm::Blake3);
+        proof.add_attestation(ZcashAttestation::new(
+            Network::Testnet,
+            [0x11u8; 32],
+            100,
+            1_700_000_000,
+            0,
+        ));
+
+        let statement = InTotoStatement::new("dist/app.tar.gz", &proof).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&statement.to_json().unwrap()).unwrap();
+
+        assert_eq!(value["_type"], INTOTO_STATEMENT_TYPE);
+        assert_eq!(value["predicateType"], TIMESTAMP_PREDICATE_TYPE);
+        assert_eq!(value["subject"][0]["name"], "dist/app.tar.gz");
+        assert_eq!(value["subject"][0]["digest"]["blake3"], proof.hash);
+        assert_eq!(value["predicate"]["timestamp"], "2023-11-14T22:13:20+00:00");
+        assert_eq!(
+            value["predicate"]["proof"]["attestations"][0]["block_height"],
+            100
+        );
+    }
+}
@@ -10,7 +10,13 @@
 //!
 //! - **Hashing**: SHA-256 (default) and optional BLAKE3 hashing of files and data
 //! - **Proof Format**: JSON and compact CBOR+Base64 serialization
+//! - **Interop**: OpenTimestamps `.ots` export and import, in-toto statements
 //! - **Attestations**: Blockchain attestation records
+//! - **Embedding**: Proofs carried inside PNG, JPEG and PDF metadata
+//! - **Pending Registry**: Proofs awaiting confirmation, for bulk upgrades
+//! - **IPFS**: Decoding CIDs to stamp content-addressed data by its digest
+//! - **Certificates**: Printable HTML and PDF summaries of a proof
+//! - **Localization**: Fluent message catalogs for the apps (feature `i18n`)
 //!
 //! ## Example
 //!
@@ -44,10 +50,24 @@
 //! This is experimental software. Do not use on mainnet with real funds.
 //! The code has not been audited.
 
+pub mod certificate;
+pub mod cid;
+pub mod embed;
 pub mod error;
 pub mod hash;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+pub mod intoto;
+pub mod ots;
 pub mod proof;
+pub mod store;
 
+pub use certificate::*;
+pub use cid::Cid;
+pub use embed::*;
 pub use error::{Error, Result};
 pub use hash::*;
+pub use intoto::*;
+pub use ots::*;
 pub use proof::*;
+pub use store::*;
@@ -0,0 +1,366 @@
+//! OpenTimestamps (`.ots`) interoperability.
+//!
+//! Converts proofs to and from the binary detached timestamp format used by
+//! the OpenTimestamps tools, so zots proofs can travel through tooling that
+//! only understands `.ots` files.
+//!
+//! OpenTimestamps has no Zcash attestation type, so each attestation is
+//! written as an attestation with the tag [`ZCASH_ATTESTATION_TAG`] directly
+//! on the file digest. Standard clients parse these as unknown attestations
+//! and leave them intact. A pending proof's txid is written as a pending
+//! attestation with a `zcash-tx:<txid>` URI.
+//!
+//! Only SHA-256 proofs can be exported: OpenTimestamps has no BLAKE3 file
+//! hash operation. On import, attestations of other kinds (Bitcoin, calendar
+//! servers, anything behind further operations) are skipped and counted.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use zots_core::{TimestampProof, from_ots, to_ots};
+//!
+//! let mut proof = TimestampProof::new([0u8; 32]);
+//! proof.set_pending_txid("ab".repeat(32));
+//! let ots = to_ots(&proof).unwrap();
+//! let imported = from_ots(&ots).unwrap();
+//! assert_eq!(imported.proof.pending_txid, proof.pending_txid);
+//! ```
+
+use crate::{Error, HashAlgorithm, Network, Result, TimestampProof, ZcashAttestation};
+
+/// Magic bytes opening every detached timestamp file
+pub const OTS_MAGIC: &[u8] = b"\x00OpenTimestamps\x00\x00Proof\x00\xbf\x89\xe2\xe8\x84\xe8\x92\x94";
+
+/// Attestation tag used for Zcash attestations
+pub const ZCASH_ATTESTATION_TAG: [u8; 8] = *b"zOTSzec\x01";
+
+/// Detached timestamp file format version
+const OTS_VERSION: u64 = 1;
+
+/// Tag of OpenTimestamps pending (calendar) attestations
+const PENDING_ATTESTATION_TAG: [u8; 8] = [0x83, 0xdf, 0xe3, 0x0d, 0x2e, 0xf9, 0x0c, 0x8e];
+
+/// URI scheme for a pending Zcash transaction
+const PENDING_TXID_SCHEME: &str = "zcash-tx:";
+
+const OP_SHA256: u8 = 0x08;
+const OP_APPEND: u8 = 0xf0;
+const OP_PREPEND: u8 = 0xf1;
+const TAG_ATTESTATION: u8 = 0x00;
+const TAG_FORK: u8 = 0xff;
+
+/// Deepest operation chain accepted on import
+const MAX_DEPTH: usize = 256;
+
+/// A proof read from an OpenTimestamps file
+#[derive(Debug, Clone)]
+pub struct OtsImport {
+    pub proof: TimestampProof,
+    /// Attestations that are not Zcash attestations on the file digest
+    pub skipped_attestations: usize,
+}
+
+/// Encode a SHA-256 proof as an OpenTimestamps detached timestamp file
+pub fn to_ots(proof: &TimestampProof) -> Result<Vec<u8>> {
+    if proof.hash_algorithm() != HashAlgorithm::Sha256 {
+        return Err(Error::InvalidProof(format!(
+            "OpenTimestamps supports SHA-256 proofs only, not {}",
+            proof.hash_algorithm().name()
+        )));
+    }
+
+    let mut attestations = Vec::new();
+    for att in &proof.attestations {
+        let mut payload = vec![match att.network {
+            Network::Mainnet => 0,
+            Network::Testnet => 1,
+        }];
+        payload.extend_from_slice(&att.txid_bytes()?);
+        write_varuint(&mut payload, att.block_height.into());
+        write_varuint(&mut payload, att.block_time.into());
+        write_varuint(&mut payload, att.memo_offset.into());
+        attestations.push((ZCASH_ATTESTATION_TAG, payload));
+    }
+    if let Some(txid) = &proof.pending_txid {
+        let mut payload = Vec::new();
+        write_varbytes(
+            &mut payload,
+            format!("{PENDING_TXID_SCHEME}{txid}").as_bytes(),
+        );
+        attestations.push((PENDING_ATTESTATION_TAG, payload));
+    }
+    if attestations.is_empty() {
+        return Err(Error::InvalidProof(
+            "Proof has no attestation or pending transaction to export".into(),
+        ));
+    }
+
+    let mut out = OTS_MAGIC.to_vec();
+    write_varuint(&mut out, OTS_VERSION);
+    out.push(OP_SHA256);
+    out.extend_from_slice(&proof.hash_bytes()?);
+    let last = attestations.len() - 1;
+    for (i, (tag, payload)) in attestations.iter().enumerate() {
+        if i < last {
+            out.push(TAG_FORK);
+        }
+        out.push(TAG_ATTESTATION);
+        out.extend_from_slice(tag);
+        write_varbytes(&mut out, payload);
+    }
+    Ok(out)
+}
+
+/// Whether `data` starts like an OpenTimestamps file
+pub fn is_ots(data: &[u8]) -> bool {
+    data.starts_with(OTS_MAGIC)
+}
+
+/// Decode an OpenTimestamps detached timestamp file
+pub fn from_ots(data: &[u8]) -> Result<OtsImport> {
+    let mut reader = Reader(
+        data.strip_prefix(OTS_MAGIC)
+            .ok_or_else(|| invalid("missing OpenTimestamps header"))?,
+    );
+    let version = reader.varuint()?;
+    if version != OTS_VERSION {
+        return Err(invalid(&format!("unsupported version {version}")));
+    }
+    if reader.byte()? != OP_SHA256 {
+        return Err(invalid("only SHA-256 file digests can be imported"));
+    }
+    let hash: [u8; 32] = reader.bytes(32)?.try_into().expect("32 bytes");
+
+    let mut import = OtsImport {
+        proof: TimestampProof::new_with_algorithm(hash, HashAlgorithm::Sha256),
+        skipped_attestations: 0,
+    };
+    read_timestamp(&mut reader, &mut import, 0)?;
+    if !reader.0.is_empty() {
+        return Err(invalid("trailing bytes after timestamp"));
+    }
+    import
+        .proof
+        .attestations
+        .sort_by_key(|att| att.block_height);
+    Ok(import)
+}
+
+/// Read a timestamp: forks, then a final attestation or operation
+///
+/// `depth` counts the operations applied to the file digest so far; only
+/// attestations at depth 0 commit to the digest itself.
+fn read_timestamp(reader: &mut Reader, import: &mut OtsImport, depth: usize) -> Result<()> {
+    if depth > MAX_DEPTH {
+        return Err(invalid("operation chain too deep"));
+    }
+    loop {
+        let tag = reader.byte()?;
+        if tag == TAG_FORK {
+            let tag = reader.byte()?;
+            read_item(reader, import, tag, depth)?;
+        } else {
+            return read_item(reader, import, tag, depth);
+        }
+    }
+}
+
+fn read_item(reader: &mut Reader, import: &mut OtsImport, tag: u8, depth: usize) -> Result<()> {
+    match tag {
+        TAG_ATTESTATION => {
+            let kind: [u8; 8] = reader.bytes(8)?.try_into().expect("8 bytes");
+            let payload = reader.varbytes()?;
+            if depth == 0 {
+                read_attestation(kind, payload, import)
+            } else {
+                import.skipped_attestations += 1;
+                Ok(())
+            }
+        }
+        OP_APPEND | OP_PREPEND => {
+            reader.varbytes()?;
+            read_timestamp(reader, import, depth + 1)
+        }
+        // Unary operations: sha1, ripemd160, sha256, keccak256, reverse, hexlify
+        0x02 | 0x03 | 0x08 | 0x67 | 0xf2 | 0xf3 => read_timestamp(reader, import, depth + 1),
+        _ => Err(invalid(&format!("unknown operation 0x{tag:02x}"))),
+    }
+}
+
+fn read_attestation(kind: [u8; 8], payload: &[u8], import: &mut OtsImport) -> Result<()> {
+    let mut payload = Reader(payload);
+    match kind {
+        ZCASH_ATTESTATION_TAG => {
+            let network = match payload.byte()? {
+                0 => Network::Mainnet,
+                1 => Network::Testnet,
+                n => return Err(invalid(&format!("unknown Zcash network {n}"))),
+            };
+            let txid: [u8; 32] = payload.bytes(32)?.try_into().expect("32 bytes");
+            let block_height = narrow(payload.varuint()?)?;
+            let block_time = narrow(payload.varuint()?)?;
+            let memo_offset = narrow(payload.varuint()?)?;
+            import.proof.attestations.push(ZcashAttestation::new(
+                network,
+                txid,
+                block_height,
+                block_time,
+                memo_offset,
+            ));
+        }
+        PENDING_ATTESTATION_TAG => {
+            let uri = String::from_utf8_lossy(payload.varbytes()?).into_owned();
+            match uri.strip_prefix(PENDING_TXID_SCHEME) {
+                Some(txid) => import.proof.set_pending_txid(txid),
+                // A calendar server: useless without the OpenTimestamps client
+                None => import.skipped_attestations += 1,
+            }
+        }
+        _ => import.skipped_attestations += 1,
+    }
+    Ok(())
+}
+
+/// Cursor over an OpenTimestamps byte stream
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(invalid("unexpected end of data"));
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn varuint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(invalid("varuint too long"))
+    }
+
+    fn varbytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.varuint()?;
+        self.bytes(usize::try_from(len).map_err(|_| invalid("length too large"))?)
+    }
+}
+
+fn write_varuint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn write_varbytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_varuint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn narrow<T: TryFrom<u64>>(value: u64) -> Result<T> {
+    T::try_from(value).map_err(|_| invalid(&format!("value {value} out of range")))
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidProof(format!("Invalid OpenTimestamps file: {reason}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ots_roundtrip() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        proof.add_attestation(ZcashAttestation::new(
+            Network::Testnet,
+            [0x11u8; 32],
+            3_739_654,
+            1_734_293_400,
+            0,
+        ));
+        proof.add_attestation(ZcashAttestation::new(
+            Network::Mainnet,
+            [0x22u8; 32],
+            2_800_000,
+            1_734_000_000,
+            32,
+        ));
+        proof.set_pending_txid("33".repeat(32));
+
+        let ots = to_ots(&proof).unwrap();
+        assert!(is_ots(&ots));
+        let imported = from_ots(&ots).unwrap();
+
+        assert_eq!(imported.skipped_attestations, 0);
+        assert_eq!(imported.proof.hash, proof.hash);
+        assert_eq!(imported.proof.pending_txid, proof.pending_txid);
+        assert_eq!(imported.proof.attestations.len(), 2);
+        // Sorted by block height on import
+        assert_eq!(imported.proof.attestations[0].txid, "22".repeat(32));
+        assert_eq!(imported.proof.attestations[1].block_height, 3_739_654);
+        assert_eq!(imported.proof.attestations[0].memo_offset, 32);
+    }
+
+    #[test]
+    fn test_ots_skips_foreign_attestations() {
+        let mut data = OTS_MAGIC.to_vec();
+        data.push(0x01);
+        data.push(OP_SHA256);
+        data.extend_from_slice(&[0x42u8; 32]);
+        // Fork 1: append, sha256, then a Bitcoin attestation
+        data.extend_from_slice(&[TAG_FORK, OP_APPEND, 0x02, 0xaa, 0xbb, OP_SHA256]);
+        data.extend_from_slice(&[
+            TAG_ATTESTATION,
+            0x05,
+            0x88,
+            0x96,
+            0x0d,
+            0x73,
+            0xd7,
+            0x19,
+            0x01,
+        ]);
+        data.extend_from_slice(&[0x01, 0x0a]);
+        // Fork 2: a calendar pending attestation on the digest
+        data.push(TAG_ATTESTATION);
+        data.extend_from_slice(&PENDING_ATTESTATION_TAG);
+        let uri = b"https://alice.btc.calendar.opentimestamps.org";
+        data.push((uri.len() + 1) as u8);
+        data.push(uri.len() as u8);
+        data.extend_from_slice(uri);
+
+        let imported = from_ots(&data).unwrap();
+        assert_eq!(imported.skipped_attestations, 2);
+        assert!(imported.proof.attestations.is_empty());
+        assert!(imported.proof.pending_txid.is_none());
+    }
+
+    #[test]
+    fn test_ots_rejects_invalid() {
+        let blake3 = TimestampProof::new_with_algorithm([0u8; 32], HashAlgorithm::Blake3);
+        assert!(to_ots(&blake3).is_err());
+        assert!(to_ots(&TimestampProof::new([0u8; 32])).is_err());
+
+        assert!(from_ots(b"not a timestamp").is_err());
+        let mut truncated = OTS_MAGIC.to_vec();
+        truncated.extend_from_slice(&[0x01, OP_SHA256, 0x00]);
+        assert!(from_ots(&truncated).is_err());
+    }
+}
@@ -48,6 +48,7 @@ use crate::{Error, Hash256, HashAlgorithm, Result};
 use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Prefix for compact CBOR+Base64 encoded proofs
 pub const COMPACT_PREFIX: &str = "zots1";
@@ -59,6 +60,44 @@ pub const ZOTS_MAGIC: [u8; 8] = [0x00, 0x7A, 0x4F, 0x54, 0x53, 0x00, 0x00, 0x01]
 /// Current proof format version
 pub const PROOF_VERSION: u8 = 1;
 
+/// Metadata key for the free-form note attached at stamp time
+pub const NOTE_KEY: &str = "note";
+
+/// Metadata key for the name of the stamped file
+pub const FILENAME_KEY: &str = "filename";
+
+/// Longest note accepted by [`TimestampProof::set_note`], in characters
+pub const MAX_NOTE_CHARS: usize = 280;
+
+/// Time of the Zcash mainnet genesis block; no attestation can predate it
+const ZCASH_GENESIS_TIME: u32 = 1_477_641_360;
+
+/// Block times may run ahead of the clock by up to 90 minutes; allow some slack
+const MAX_FUTURE_BLOCK_SECS: i64 = 2 * 60 * 60;
+
+/// Check a proof note, returning it trimmed
+///
+/// A note must be non-empty, at most [`MAX_NOTE_CHARS`] characters and free
+/// of control characters other than newlines.
+pub fn validate_note(note: &str) -> Result<&str> {
+    let note = note.trim();
+    if note.is_empty() {
+        return Err(Error::InvalidMetadata("note is empty".into()));
+    }
+    let chars = note.chars().count();
+    if chars > MAX_NOTE_CHARS {
+        return Err(Error::InvalidMetadata(format!(
+            "note is {chars} characters, at most {MAX_NOTE_CHARS} allowed"
+        )));
+    }
+    if note.chars().any(|c| c.is_control() && c != '\n') {
+        return Err(Error::InvalidMetadata(
+            "note contains control characters".into(),
+        ));
+    }
+    Ok(note)
+}
+
 /// Network type (mainnet or testnet)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -177,6 +216,16 @@ pub struct TimestampProof {
     pub hash_algorithm: HashAlgorithm,
     /// List of blockchain attestations
     pub attestations: Vec<ZcashAttestation>,
+    /// Broadcast transaction awaiting confirmation (display hex)
+    ///
+    /// Recorded for pending proofs so they can be upgraded once mined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_txid: Option<String>,
+    /// Provenance of the timestamped data, e.g. `source_url` or `etag`
+    ///
+    /// Informational only: verification never depends on it.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl TimestampProof {
@@ -192,6 +241,8 @@ impl TimestampProof {
             hash: hex::encode(hash),
             hash_algorithm: algorithm,
             attestations: Vec::new(),
+            pending_txid: None,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -213,8 +264,115 @@ impl TimestampProof {
     }
 
     /// Add an attestation to the proof
+    ///
+    /// Clears the pending txid if the attestation is for that transaction.
+    /// An attestation for a transaction the proof already records is ignored.
     pub fn add_attestation(&mut self, att: ZcashAttestation) {
-        self.attestations.push(att);
+        if self
+            .pending_txid
+            .as_deref()
+            .is_some_and(|txid| txid.eq_ignore_ascii_case(&att.txid))
+        {
+            self.pending_txid = None;
+        }
+        let known = self
+            .attestations
+            .iter()
+            .any(|a| a.network == att.network && a.txid.eq_ignore_ascii_case(&att.txid));
+        if !known {
+            self.attestations.push(att);
+        }
+    }
+
+    /// Record the broadcast transaction of a pending proof
+    pub fn set_pending_txid(&mut self, txid: impl Into<String>) {
+        self.pending_txid = Some(txid.into());
+    }
+
+    /// Record a provenance entry in the proof metadata
+    pub fn set_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.metadata.insert(key.into(), value.into());
+    }
+
+    /// Attach a short note explaining what the proof covers
+    ///
+    /// See [`validate_note`] for the accepted notes.
+    pub fn set_note(&mut self, note: &str) -> Result<()> {
+        let note = validate_note(note)?;
+        self.set_metadata(NOTE_KEY, note);
+        Ok(())
+    }
+
+    /// Note attached at stamp time, if any
+    pub fn note(&self) -> Option<&str> {
+        self.metadata.get(NOTE_KEY).map(String::as_str)
+    }
+
+    /// Record the name (not the path) of the stamped file
+    pub fn set_filename(&mut self, path: &std::path::Path) {
+        if let Some(name) = path.file_name() {
+            self.set_metadata(FILENAME_KEY, name.to_string_lossy());
+        }
+    }
+
+    /// Name of the stamped file, if recorded
+    pub fn filename(&self) -> Option<&str> {
+        self.metadata.get(FILENAME_KEY).map(String::as_str)
+    }
+
+    /// Merge the attestations of another proof for the same hash
+    ///
+    /// Attestations already present (same network and txid) are skipped and
+    /// the result is ordered by block height, so the earliest anchor comes
+    /// first. A pending txid is kept only while no attestation covers it;
+    /// metadata keys missing from this proof are copied over.
+    /// Returns the number of attestations added.
+    pub fn merge(&mut self, other: &TimestampProof) -> Result<usize> {
+        if self.hash_algorithm != other.hash_algorithm {
+            return Err(Error::InvalidProof(format!(
+                "Cannot merge a {} proof into a {} proof",
+                other.hash_algorithm.name(),
+                self.hash_algorithm.name()
+            )));
+        }
+        if self.hash_bytes()? != other.hash_bytes()? {
+            return Err(Error::HashMismatch {
+                expected: self.hash.clone(),
+                actual: other.hash.clone(),
+            });
+        }
+
+        let mut added = 0;
+        for att in &other.attestations {
+            let known = self
+                .attestations
+                .iter()
+                .any(|a| a.network == att.network && a.txid.eq_ignore_ascii_case(&att.txid));
+            if !known {
+                self.attestations.push(att.clone());
+                added += 1;
+            }
+        }
+        self.attestations.sort_by_key(|a| a.block_height);
+
+        if self.pending_txid.is_none() {
+            self.pending_txid = other.pending_txid.clone();
+        }
+        for (key, value) in &other.metadata {
+            self.metadata
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        if let Some(txid) = &self.pending_txid
+            && self
+                .attestations
+                .iter()
+                .any(|a| a.txid.eq_ignore_ascii_case(txid))
+        {
+            self.pending_txid = None;
+        }
+
+        Ok(added)
     }
 
     /// Check if the proof has any confirmed attestations
@@ -251,6 +409,93 @@ impl TimestampProof {
         Ok(proof)
     }
 
+    /// Check the proof is well-formed without contacting the network
+    ///
+    /// Beyond what [`Self::deserialize`] enforces, rejects attestations at
+    /// height zero or dated before the Zcash genesis block, and a malformed
+    /// pending txid.
+    pub fn validate(&self) -> Result<()> {
+        if self.version != PROOF_VERSION {
+            return Err(Error::InvalidProof(format!(
+                "Unsupported version: {}",
+                self.version
+            )));
+        }
+        let _ = self.hash_bytes()?;
+
+        for att in &self.attestations {
+            let _ = att.txid_bytes()?;
+            if att.block_height == 0 {
+                return Err(Error::InvalidProof(format!(
+                    "Attestation {} has no block height",
+                    att.txid
+                )));
+            }
+            if att.block_time < ZCASH_GENESIS_TIME {
+                return Err(Error::InvalidProof(format!(
+                    "Attestation {} predates the Zcash genesis block",
+                    att.txid
+                )));
+            }
+        }
+
+        if let Some(txid) = &self.pending_txid {
+            let bytes = hex::decode(txid)
+                .map_err(|e| Error::InvalidProof(format!("Invalid pending txid hex: {e}")))?;
+            if bytes.len() != 32 {
+                return Err(Error::InvalidProof("Pending txid must be 32 bytes".into()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Non-fatal issues worth flagging when displaying the proof
+    ///
+    /// Includes the [`Self::validate`] error, if any, plus pending proofs
+    /// without a txid to upgrade from, testnet anchors, block times in the
+    /// future and repeated attestations. An empty list means nothing looks
+    /// off; it does not mean the anchor was checked on-chain.
+    pub fn lint(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Err(e) = self.validate() {
+            warnings.push(e.to_string());
+        }
+
+        if self.attestations.is_empty() {
+            if self.pending_txid.is_none() {
+                warnings.push(
+                    "Pending proof without a transaction id; it cannot be upgraded".to_string(),
+                );
+            } else {
+                warnings.push("Pending: not anchored in a block yet".to_string());
+            }
+        }
+
+        let now = Utc::now().timestamp();
+        for (i, att) in self.attestations.iter().enumerate() {
+            if att.network == Network::Testnet {
+                warnings.push(format!(
+                    "Attestation {} is on testnet, which has no real-world value",
+                    att.txid
+                ));
+            }
+            if i64::from(att.block_time) > now + MAX_FUTURE_BLOCK_SECS {
+                warnings.push(format!(
+                    "Attestation {} has a block time in the future",
+                    att.txid
+                ));
+            }
+            let repeated = self.attestations[..i]
+                .iter()
+                .any(|other| other.network == att.network && other.txid == att.txid);
+            if repeated {
+                warnings.push(format!("Attestation {} is listed more than once", att.txid));
+            }
+        }
+        warnings
+    }
+
     /// Save the proof to a file
     pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
         let json = self.serialize()?;
@@ -264,16 +509,43 @@ impl TimestampProof {
         Self::deserialize(&data)
     }
 
+    /// Encode the proof as raw CBOR (the payload of the compact format)
+    pub fn to_cbor(&self) -> Result<Vec<u8>> {
+        let mut cbor_data = Vec::new();
+        ciborium::into_writer(self, &mut cbor_data)
+            .map_err(|e| Error::InvalidProof(format!("CBOR encoding failed: {e}")))?;
+        Ok(cbor_data)
+    }
+
+    /// Decode a proof from raw CBOR
+    pub fn from_cbor(cbor_data: &[u8]) -> Result<Self> {
+        let proof: Self = ciborium::from_reader(cbor_data)
+            .map_err(|e| Error::InvalidProof(format!("CBOR decode failed: {e}")))?;
+
+        if proof.version != PROOF_VERSION {
+            return Err(Error::InvalidProof(format!(
+                "Unsupported version: {}",
+                proof.version
+            )));
+        }
+
+        // Validate hash is valid hex
+        let _ = proof.hash_bytes()?;
+
+        // Validate all txids are valid hex
+        for att in &proof.attestations {
+            let _ = att.txid_bytes()?;
+        }
+
+        Ok(proof)
+    }
+
     /// Encode the proof to compact CBOR+Base64 format
     ///
     /// Returns a string like "zots1..." that can be embedded in files,
     /// EXIF metadata, git commit messages, etc.
     pub fn to_compact(&self) -> Result<String> {
-        let mut cbor_data = Vec::new();
-        ciborium::into_writer(self, &mut cbor_data)
-            .map_err(|e| Error::InvalidProof(format!("CBOR encoding failed: {e}")))?;
-
-        let encoded = URL_SAFE_NO_PAD.encode(&cbor_data);
+        let encoded = URL_SAFE_NO_PAD.encode(self.to_cbor()?);
         Ok(format!("{COMPACT_PREFIX}{encoded}"))
     }
 
@@ -294,25 +566,7 @@ impl TimestampProof {
             .decode(encoded)
             .map_err(|e| Error::InvalidProof(format!("Base64 decode failed: {e}")))?;
 
-        let proof: Self = ciborium::from_reader(&cbor_data[..])
-            .map_err(|e| Error::InvalidProof(format!("CBOR decode failed: {e}")))?;
-
-        if proof.version != PROOF_VERSION {
-            return Err(Error::InvalidProof(format!(
-                "Unsupported version: {}",
-                proof.version
-            )));
-        }
-
-        // Validate hash is valid hex
-        let _ = proof.hash_bytes()?;
-
-        // Validate all txids are valid hex
-        for att in &proof.attestations {
-            let _ = att.txid_bytes()?;
-        }
-
-        Ok(proof)
+        Self::from_cbor(&cbor_data)
     }
 
     /// Check if a string is a valid compact proof format
@@ -563,6 +817,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cbor_roundtrip() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        proof.set_pending_txid("11".repeat(32));
+        let cbor = proof.to_cbor().unwrap();
+        let decoded = TimestampProof::from_cbor(&cbor).unwrap();
+        assert_eq!(decoded.pending_txid, proof.pending_txid);
+        assert!(TimestampProof::from_cbor(b"{}").is_err());
+    }
+
     #[test]
     fn test_compact_invalid_prefix() {
         assert!(TimestampProof::from_compact("invalid").is_err());
@@ -594,4 +858,158 @@ mod tests {
         assert_eq!(decoded.hash, proof.hash);
         assert!(decoded.attestations.is_empty());
     }
+
+    #[test]
+    fn test_proof_validate() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        proof.add_attestation(ZcashAttestation::new(
+            Network::Testnet,
+            [0x11u8; 32],
+            100,
+            1_700_000_000,
+            0,
+        ));
+        assert!(proof.validate().is_ok());
+
+        let mut zero_height = proof.clone();
+        zero_height.attestations[0].block_height = 0;
+        assert!(zero_height.validate().is_err());
+
+        let mut too_early = proof.clone();
+        too_early.attestations[0].block_time = 1_000;
+        assert!(too_early.validate().is_err());
+
+        let mut bad_pending = TimestampProof::new([0x42u8; 32]);
+        bad_pending.set_pending_txid("abcd");
+        assert!(bad_pending.validate().is_err());
+    }
+
+    #[test]
+    fn test_proof_lint() {
+        let attestation =
+            ZcashAttestation::new(Network::Mainnet, [0x11u8; 32], 100, 1_700_000_000, 0);
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        proof.add_attestation(attestation.clone());
+        assert!(proof.lint().is_empty());
+
+        let mut testnet = proof.clone();
+        testnet.attestations[0].network = Network::Testnet;
+        assert_eq!(testnet.lint().len(), 1);
+
+        let mut future = proof.clone();
+        future.attestations[0].block_time = u32::MAX;
+        assert_eq!(future.lint().len(), 1);
+
+        let mut repeated = proof.clone();
+        repeated.attestations.push(attestation.clone());
+        assert_eq!(repeated.lint().len(), 1);
+
+        assert_eq!(TimestampProof::new([0x42u8; 32]).lint().len(), 1);
+        let mut pending = TimestampProof::new([0x42u8; 32]);
+        pending.set_pending_txid(attestation.txid);
+        assert_eq!(pending.lint().len(), 1);
+    }
+
+    #[test]
+    fn test_proof_merge() {
+        let first = ZcashAttestation::new(Network::Testnet, [0x11u8; 32], 200, 1_700_000_000, 0);
+        let second = ZcashAttestation::new(Network::Testnet, [0x22u8; 32], 100, 1_699_000_000, 0);
+
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        proof.add_attestation(first.clone());
+        let mut other = TimestampProof::new([0x42u8; 32]);
+        other.add_attestation(first);
+        other.add_attestation(second.clone());
+
+        assert_eq!(proof.merge(&other).unwrap(), 1);
+        assert_eq!(proof.attestations.len(), 2);
+        assert_eq!(proof.attestations[0].txid, second.txid);
+        assert_eq!(proof.merge(&other).unwrap(), 0);
+
+        let mut pending = TimestampProof::new([0x42u8; 32]);
+        pending.set_pending_txid(second.txid.clone());
+        assert_eq!(pending.merge(&proof).unwrap(), 2);
+        assert!(pending.pending_txid.is_none());
+
+        assert!(proof.merge(&TimestampProof::new([0x43u8; 32])).is_err());
+        let blake3 = TimestampProof::new_with_algorithm([0x42u8; 32], HashAlgorithm::Blake3);
+        assert!(proof.merge(&blake3).is_err());
+    }
+
+    #[test]
+    fn test_pending_txid_roundtrip_and_upgrade() {
+        let txid_bytes = [0x11u8; 32];
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        let attestation =
+            ZcashAttestation::new(Network::Testnet, txid_bytes, 100, 1_700_000_000, 0);
+        proof.set_pending_txid(attestation.txid.clone());
+
+        let decoded = TimestampProof::deserialize(&proof.serialize().unwrap()).unwrap();
+        assert_eq!(
+            decoded.pending_txid.as_deref(),
+            Some(attestation.txid.as_str())
+        );
+
+        proof.add_attestation(attestation);
+        assert!(proof.is_confirmed());
+        assert!(proof.pending_txid.is_none());
+        assert!(!proof.serialize().unwrap().contains("pending_txid"));
+    }
+
+    #[test]
+    fn test_add_attestation_ignores_known_txid() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        let attestation =
+            ZcashAttestation::new(Network::Testnet, [0x11u8; 32], 100, 1_700_000_000, 0);
+        proof.add_attestation(attestation.clone());
+
+        let mut upper = attestation;
+        upper.txid = upper.txid.to_uppercase();
+        proof.add_attestation(upper);
+        assert_eq!(proof.attestations.len(), 1);
+    }
+
+    #[test]
+    fn test_proof_metadata_roundtrip() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        assert!(!proof.serialize().unwrap().contains("metadata"));
+
+        proof.set_metadata("source_url", "https://example.com/report.pdf");
+        proof.set_metadata("etag", "\"abc\"");
+
+        let decoded = TimestampProof::deserialize(&proof.serialize().unwrap()).unwrap();
+        assert_eq!(decoded.metadata, proof.metadata);
+        let decoded = TimestampProof::from_compact(&proof.to_compact().unwrap()).unwrap();
+        assert_eq!(decoded.metadata, proof.metadata);
+    }
+
+    #[test]
+    fn test_note_and_filename() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        assert_eq!(proof.note(), None);
+
+        proof
+            .set_note("  Signed lease, unit 4B\nOriginal scan  ")
+            .unwrap();
+        proof.set_filename(std::path::Path::new("/home/alice/docs/lease.pdf"));
+        assert_eq!(proof.note(), Some("Signed lease, unit 4B\nOriginal scan"));
+        assert_eq!(proof.filename(), Some("lease.pdf"));
+
+        let decoded = TimestampProof::from_compact(&proof.to_compact().unwrap()).unwrap();
+        assert_eq!(decoded.note(), proof.note());
+        assert_eq!(decoded.filename(), Some("lease.pdf"));
+    }
+
+    #[test]
+    fn test_note_validation() {
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        assert!(proof.set_note("   ").is_err());
+        assert!(proof.set_note("bell\u{7}").is_err());
+        assert!(proof.set_note(&"é".repeat(MAX_NOTE_CHARS)).is_ok());
+        assert!(proof.set_note(&"é".repeat(MAX_NOTE_CHARS + 1)).is_err());
+        assert_eq!(
+            proof.note().map(|n| n.chars().count()),
+            Some(MAX_NOTE_CHARS)
+        );
+    }
 }
@@ -0,0 +1,367 @@
+//! Registry of pending proofs.
+//!
+//! Proofs written before their transaction is mined (`stamp --no-wait`, a
+//! confirmation timeout, `watch`) are recorded here so they can be listed and
+//! upgraded in one pass later. The registry is a small JSON file; proofs stay
+//! wherever they were saved and are referenced by absolute path.
+//!
+//! Several processes (`stamp`, `watch`, `serve`, the desktop app) share the
+//! registry, so changes go through [`PendingStore::update`], which holds a
+//! `<registry>.lock` file while it reads, changes and rewrites it. Writes
+//! replace the file atomically, so a crash never leaves it truncated.
+//!
+//! [`find_proofs`] lists the proofs saved under a directory, for tools that
+//! browse or re-verify a collection of proofs.
+
+use crate::{Error, Result, TimestampProof};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// How long [`PendingStore::update`] waits for another process's lock
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+/// A lock file older than this was left behind by a crashed process
+const STALE_LOCK_AGE: Duration = Duration::from_secs(60);
+
+/// A pending proof tracked by the registry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingEntry {
+    /// Absolute path of the proof file
+    pub proof_path: PathBuf,
+    /// Hash of the timestamped data (hex string)
+    pub hash: String,
+    /// Broadcast transaction awaiting confirmation (display hex)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub txid: Option<String>,
+    /// When the proof was first tracked (Unix timestamp)
+    pub created_at: i64,
+}
+
+/// Pending proofs recorded in a JSON file
+#[derive(Debug, Clone)]
+pub struct PendingStore {
+    path: PathBuf,
+    entries: Vec<PendingEntry>,
+}
+
+impl PendingStore {
+    /// Load the registry at `path`; a missing file is an empty registry
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(data) => serde_json::from_str(&data)
+                .map_err(|e| Error::PendingRegistry(format!("{}: {e}", path.display())))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// Apply `change` to the registry at `path` under its lock and save it
+    ///
+    /// Use this rather than [`load`](Self::load) and [`save`](Self::save)
+    /// when other processes may update the registry at the same time.
+    pub fn update<T>(path: impl Into<PathBuf>, change: impl FnOnce(&mut Self) -> T) -> Result<T> {
+        let path = path.into();
+        let _lock = RegistryLock::acquire(&path)?;
+        let mut store = Self::load(path)?;
+        let result = change(&mut store);
+        store.save()?;
+        Ok(result)
+    }
+
+    /// Write the registry back to its file
+    ///
+    /// The file is replaced atomically, but entries another process saved
+    /// since [`load`](Self::load) are overwritten; see [`update`](Self::update).
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&self.entries)
+            .map_err(|e| Error::PendingRegistry(format!("serialization failed: {e}")))?;
+        let tmp = sibling(&self.path, &format!(".{}.tmp", std::process::id()));
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, &self.path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
+        })?;
+        Ok(())
+    }
+
+    /// Tracked proofs, oldest first
+    pub fn entries(&self) -> &[PendingEntry] {
+        &self.entries
+    }
+
+    /// Record a pending proof saved at `proof_path`
+    ///
+    /// Re-tracking a path updates its hash and txid but keeps the original
+    /// creation time.
+    pub fn track(&mut self, proof_path: &Path, proof: &TimestampProof) {
+        let proof_path = absolute(proof_path);
+        let created_at = self
+            .entries
+            .iter()
+            .find(|e| e.proof_path == proof_path)
+            .map_or_else(|| Utc::now().timestamp(), |e| e.created_at);
+        self.untrack(&proof_path);
+        self.entries.push(PendingEntry {
+            proof_path,
+            hash: proof.hash.clone(),
+            txid: proof.pending_txid.clone(),
+            created_at,
+        });
+        self.entries.sort_by_key(|e| e.created_at);
+    }
+
+    /// Stop tracking the proof at `proof_path`; returns whether it was tracked
+    pub fn untrack(&mut self, proof_path: &Path) -> bool {
+        let proof_path = absolute(proof_path);
+        let before = self.entries.len();
+        self.entries.retain(|e| e.proof_path != proof_path);
+        self.entries.len() != before
+    }
+}
+
+/// Exclusive lock on a registry, held as a `<registry>.lock` file
+struct RegistryLock {
+    path: PathBuf,
+}
+
+impl RegistryLock {
+    fn acquire(registry: &Path) -> Result<Self> {
+        let path = sibling(registry, ".lock");
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let deadline = Instant::now() + LOCK_TIMEOUT;
+        loop {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let stale = std::fs::metadata(&path)
+                        .and_then(|meta| meta.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok())
+                        .is_some_and(|age| age > STALE_LOCK_AGE);
+                    if stale {
+                        let _ = std::fs::remove_file(&path);
+                    } else if Instant::now() >= deadline {
+                        return Err(Error::PendingRegistry(format!(
+                            "{} is locked by another process (remove {} if none is running)",
+                            registry.display(),
+                            path.display()
+                        )));
+                    } else {
+                        std::thread::sleep(Duration::from_millis(50));
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl Drop for RegistryLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// `path` with `suffix` appended to its file name
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Every `.zots` file under `dir`, recursively, sorted by path
+///
+/// Symlinked directories are not followed, so a link cycle cannot recurse
+/// forever. Entries that cannot be read are skipped (see
+/// [`find_proofs_with`] to report them); only an unreadable `dir` fails.
+pub fn find_proofs(dir: &Path) -> Result<Vec<PathBuf>> {
+    find_proofs_with(dir, |_, _| {})
+}
+
+/// [`find_proofs`], calling `on_error` for every entry skipped because it could not be read
+pub fn find_proofs_with(
+    dir: &Path,
+    mut on_error: impl FnMut(&Path, &std::io::Error),
+) -> Result<Vec<PathBuf>> {
+    let mut proofs = Vec::new();
+    collect_proofs(dir, std::fs::read_dir(dir)?, &mut proofs, &mut on_error);
+    proofs.sort();
+    Ok(proofs)
+}
+
+fn collect_proofs(
+    dir: &Path,
+    entries: std::fs::ReadDir,
+    proofs: &mut Vec<PathBuf>,
+    on_error: &mut dyn FnMut(&Path, &std::io::Error),
+) {
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                on_error(dir, &e);
+                continue;
+            }
+        };
+        let path = entry.path();
+        // Unlike `Path::is_dir`, the entry's own type does not follow symlinks
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(e) => {
+                on_error(&path, &e);
+                continue;
+            }
+        };
+        if file_type.is_dir() {
+            match std::fs::read_dir(&path) {
+                Ok(entries) => collect_proofs(&path, entries, proofs, on_error),
+                Err(e) => on_error(&path, &e),
+            }
+        } else if path.extension().is_some_and(|ext| ext == "zots")
+            && (file_type.is_file() || path.is_file())
+        {
+            proofs.push(path);
+        }
+    }
+}
+
+/// `path` made absolute against the current directory, without touching the filesystem
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_store_roundtrip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let path = dir.join("pending.json");
+
+        let mut store = PendingStore::load(&path).unwrap();
+        assert!(store.entries().is_empty());
+
+        let mut proof = TimestampProof::new([0x42u8; 32]);
+        proof.set_pending_txid("11".repeat(32));
+        store.track(Path::new("a.zots"), &proof);
+        store.track(Path::new("b.zots"), &proof);
+        store.track(Path::new("a.zots"), &proof);
+        assert_eq!(store.entries().len(), 2);
+        store.save().unwrap();
+
+        let mut loaded = PendingStore::load(&path).unwrap();
+        assert_eq!(loaded.entries().len(), 2);
+        assert!(loaded.entries()[0].proof_path.is_absolute());
+        assert_eq!(loaded.entries()[0].txid, proof.pending_txid);
+
+        assert!(loaded.untrack(Path::new("b.zots")));
+        assert!(!loaded.untrack(Path::new("b.zots")));
+        assert_eq!(loaded.entries().len(), 1);
+    }
+
+    #[test]
+    fn test_pending_store_concurrent_updates() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let path = dir.join("pending.json");
+        let proof = TimestampProof::new([0x42u8; 32]);
+
+        std::thread::scope(|scope| {
+            for i in 0..8 {
+                let (path, proof) = (&path, &proof);
+                scope.spawn(move || {
+                    PendingStore::update(path, |store| {
+                        store.track(Path::new(&format!("{i}.zots")), proof);
+                    })
+                    .unwrap();
+                });
+            }
+        });
+
+        assert_eq!(PendingStore::load(&path).unwrap().entries().len(), 8);
+        assert!(!sibling(&path, ".lock").exists());
+    }
+
+    #[test]
+    fn test_pending_store_breaks_stale_lock() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let path = dir.join("pending.json");
+        let lock = std::fs::File::create(sibling(&path, ".lock")).unwrap();
+        lock.set_modified(std::time::SystemTime::now() - 2 * STALE_LOCK_AGE)
+            .unwrap();
+
+        let tracked = PendingStore::update(&path, |store| {
+            store.track(Path::new("a.zots"), &TimestampProof::new([0x42u8; 32]));
+            store.entries().len()
+        })
+        .unwrap();
+        assert_eq!(tracked, 1);
+    }
+
+    #[test]
+    fn test_pending_store_parse_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        let path = dir.join("pending.json");
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            PendingStore::load(&path),
+            Err(Error::PendingRegistry(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_proofs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::create_dir_all(dir.join("nested/deeper")).unwrap();
+        for file in ["b.zots", "a.pdf", "nested/c.zots", "nested/deeper/a.zots"] {
+            std::fs::write(dir.join(file), "{}").unwrap();
+        }
+
+        let proofs = find_proofs(dir).unwrap();
+        assert_eq!(
+            proofs,
+            vec![
+                dir.join("b.zots"),
+                dir.join("nested/c.zots"),
+                dir.join("nested/deeper/a.zots"),
+            ]
+        );
+        assert!(find_proofs(&dir.join("missing")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_proofs_skips_symlinked_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("nested/a.zots"), "{}").unwrap();
+        // A link back to the root would recurse forever if followed
+        std::os::unix::fs::symlink(dir, dir.join("nested/loop")).unwrap();
+        std::os::unix::fs::symlink(dir.join("nested/a.zots"), dir.join("link.zots")).unwrap();
+
+        let mut errors = 0;
+        let proofs = find_proofs_with(dir, |_, _| errors += 1).unwrap();
+        assert_eq!(
+            proofs,
+            vec![dir.join("link.zots"), dir.join("nested/a.zots")]
+        );
+        assert_eq!(errors, 0);
+    }
+}
@@ -0,0 +1,339 @@
+# English messages (fallback for every other locale)
+
+## Shared labels and actions
+
+action-back = Back
+action-browse = Browse
+action-cancel = Cancel
+action-clear = Clear
+action-continue = Continue
+action-copy = Copy
+action-copy-compact = Copy Compact
+action-copy-proof = Copy Proof
+action-export-certificate = Export Certificate
+action-refresh = Refresh
+action-view-explorer = View in Explorer
+label-algorithm = Algorithm
+label-author = Author
+label-balance = Balance
+label-block = Block
+label-block-height = Block Height
+label-error = Error
+label-explorer = Explorer
+label-hash = Hash
+label-license = License
+label-memo = Memo
+label-network = Network
+label-note = Note
+label-status = Status
+label-timestamp = Timestamp
+label-transaction = Transaction
+label-unknown = Unknown
+label-version = Version
+qr-save-png = Save as PNG
+qr-scan-hint = Scan to import the proof
+
+## Navigation
+
+app-tagline = Zcash Timestamping
+view-home = Home
+view-stamp = Stamp
+view-verify = Verify
+view-history = History
+view-wallet = Wallet
+view-settings = Settings
+status-network = { $network }  Block: { $block }
+status-no-wallet = No wallet
+status-drop-verify = Drop the file and/or its .zots proof to verify
+status-drop-stamp = Drop files to stamp them
+status-no-wallet-configured = No wallet configured
+status-synced = Synced
+status-sync-failed = Sync failed
+status-ready-sync-failed = Ready (sync failed)
+status-saving-seed = Saving seed to the OS keychain...
+status-seed-saved = Seed saved, syncing...
+status-seed-save-failed = Failed to save seed
+status-shielding = Shielding transparent funds...
+status-shielded = Shielding { $amount } ZEC in { $txid }
+status-shield-failed = Shielding failed
+status-received = Received { $amount } ZEC
+status-creating-timestamp = Creating timestamp...
+status-stamp-failed = Stamp failed
+status-stamping-batch = Stamping { $count ->
+        [one] 1 file
+       *[other] { $count } files
+    }...
+status-batch-running = A batch is in progress
+status-batch-paused = Batch paused
+status-batch-resumed = Batch resumed
+status-batch-cancelling = Cancelling batch...
+status-batch-cancelled = Batch cancelled
+status-batch-failed = Batch failed
+status-batch-complete = Batch complete
+status-not-a-file = Not a file: { $path }
+status-verify-complete = Verification complete
+status-verify-failed = Verification failed
+status-open-failed = Failed to open { $path }: { $error }
+status-tray = Running in the tray
+status-tray-watching = Running in the tray; pending stamps are still watched
+status-upgrading = Upgrading pending proofs...
+status-upgrade-report = Pending proofs: { $summary }
+status-upgrade-failed = Upgrading pending proofs failed: { $error }
+status-qr-saved = QR code saved to { $path }
+status-qr-save-failed = Could not save QR code: { $error }
+status-certificate-saved = Certificate saved to { $path }
+status-certificate-failed = Could not export certificate: { $error }
+
+## Home
+
+home-title = Welcome to zOpenTimestamps
+home-subtitle = Timestamp files on the Zcash blockchain with privacy-preserving shielded transactions
+home-not-configured = Not configured
+home-quick-actions = Quick Actions
+home-stamp-title = Create Timestamp
+home-stamp-description = Timestamp a file or hash on the Zcash blockchain
+home-verify-title = Verify Proof
+home-verify-description = Verify a timestamp proof against the blockchain
+home-wallet-description = View balance and configure your wallet
+home-testnet-only = Testnet Only
+home-experimental = This is experimental software. Do not use on mainnet with real funds.
+home-about-title = About zOpenTimestamps
+home-about = zOpenTimestamps is a Zcash blockchain timestamping tool inspired by OpenTimestamps. It creates cryptographic proofs that data existed at a specific point in time using Zcash's shielded transactions.
+home-version = Version { $version }
+home-license = MIT License
+
+## Stamp
+
+stamp-title = Create Timestamp
+stamp-description = Timestamp a file or hash on the Zcash blockchain. The proof will be saved as a .zots file. Drop files anywhere in the window to queue them.
+stamp-input-label = File path or hash
+stamp-input-placeholder = Enter file path or 64-char hex hash...
+stamp-algorithm = Hash Algorithm
+stamp-toggle = Toggle
+stamp-broadcast = Transaction Broadcast!
+stamp-confirmed = Timestamp Confirmed!
+stamp-pending-hint = Waiting for block confirmation. The proof file has been saved and is completed in the background once mined.
+stamp-saved-to = Saved to
+stamp-failed = Stamp Failed
+batch-title = Batch ({ $count ->
+        [one] 1 file
+       *[other] { $count } files
+    })
+batch-add-files = Add Files
+batch-empty = Add or drop files to stamp them together in one transaction.
+batch-resume = Resume
+batch-pause = Pause
+batch-start = Stamp { $count ->
+        [one] 1 file
+       *[other] { $count } files
+    } in one transaction
+
+## Progress
+
+phase-syncing = Syncing wallet...
+phase-broadcasting = Broadcasting transaction...
+phase-waiting-confirmation = Waiting for confirmation ({ $confirmations }/{ $target })...
+phase-complete = Timestamp created!
+phase-failed = Operation failed
+queue-queued = Queued
+queue-hashing = Hashing...
+queue-hashed = Hashed
+queue-broadcasting = Broadcasting...
+queue-waiting = Waiting ({ $confirmations }/{ $target })
+queue-confirmed = Confirmed
+queue-pending = Broadcast (pending)
+queue-failed = Failed: { $error }
+queue-cancelled = Cancelled
+interval-off = Off
+interval-minute = Every minute
+interval-hour = Every hour
+interval-minutes = Every { $minutes } minutes
+upgrade-summary = { $upgraded } upgraded, { $pending } still pending
+upgrade-summary-failed = { $upgraded } upgraded, { $pending } still pending, { $failed } failed
+
+## Verify
+
+verify-title = Verify Proof
+verify-description = Verify a timestamp proof against the Zcash blockchain. Optionally provide the original file to check the hash matches. Drop a file and its .zots proof on the window to verify them right away; a proof next to the file (foo.pdf.zots beside foo.pdf) is found automatically.
+verify-file-label = Original file or hash (optional)
+verify-file-placeholder = Enter file path or hash to verify against...
+verify-proof-label = Proof file (.zots)
+verify-proof-placeholder = Select a .zots proof file...
+verify-running = Verifying...
+verify-valid = Proof Valid!
+verify-failed = Verification Failed
+verify-hash-matches = File hash matches proof
+verify-hash-mismatch = File hash does NOT match!
+verify-error-hash-mismatch = Hash does NOT match the provided file/hash!
+verify-error-pending = Proof is pending (no attestations)
+verify-error-no-wallet = Cannot verify on-chain (no wallet)
+
+## History
+
+history-title = Proof History
+history-description = View and manage your timestamp proofs (.zots files under { $dir }, plus pending proofs tracked by zots). New proofs appear automatically.
+history-loading = Loading...
+history-empty = No proofs found
+history-empty-hint = Create a timestamp to see it here
+history-loading-proofs = Loading proofs...
+history-entry-confirmed = { $network } · Block { $block }
+history-entry-pending = Pending confirmation
+history-total = Total
+history-confirmed = Confirmed
+history-pending = Pending
+history-pending-tx = Pending tx
+history-attestations = Attestations ({ $count })
+history-verify-now = Verify now
+history-verified = ✓ Verified on-chain
+history-well-formed = ✓ Proof is well-formed
+history-verify-failed = ✗ Verification failed
+upgrade-auto-off = Auto-upgrade off
+upgrade-auto = Auto-upgrade: { $interval }
+upgrade-last-check =  · last check { $time }: { $summary }
+upgrade-running = Upgrading...
+upgrade-now = Upgrade now
+
+## Wallet
+
+wallet-description = Manage your Zcash testnet wallet for timestamping
+wallet-security-title = Security Warning
+wallet-security = Never enter your mainnet seed phrase here. This app is for testnet only. Your seed is stored in the OS keychain, never in plain text on disk.
+wallet-syncing = Syncing...
+wallet-sync-now = Sync Now
+wallet-configured = Wallet Configured
+wallet-ready = Ready
+wallet-configure-title = Configure Wallet
+wallet-configure = Enter your BIP-39 seed phrase (24 words) to configure the wallet. It is saved in the OS keychain.
+wallet-seed-label = Seed Phrase
+wallet-seed-placeholder = Enter your 24-word seed phrase...
+wallet-saving = Saving...
+wallet-save-seed = Save & Connect
+wallet-run-wizard = Run setup wizard
+wallet-storage-title = ※ Seed storage
+wallet-storage = Seeds saved here or by the setup wizard go to the OS keychain (macOS Keychain, Windows Credential Manager or the Secret Service on Linux) under the active zots profile, which the CLI reads too. ZOTS_SEED still overrides it when set.
+wallet-pool-pending = +{ $amount } pending
+pool-transparent = Transparent
+pool-sapling = Sapling
+pool-orchard = Orchard
+shield-transparent-funds = { $amount } ZEC is in the transparent pool
+shield-hint-unconfirmed = They can be shielded once confirmed.
+shield-hint = Shield them to use them for timestamps.
+shield-hint-watch-only = A watch-only wallet cannot shield them.
+shield-running = Shielding...
+shield-action = Shield Funds
+funding-title = Fund Your Wallet
+funding-description = Each timestamp costs a small transaction fee. Send ZEC to this address to start stamping.
+funding-sync-hint = Sync the wallet to show its receiving address
+funding-copy-address = Copy Address
+funding-faucet = Open Testnet Faucet
+funding-waiting = Waiting for funds...
+funding-stop = Stop
+funding-watch = I've requested funds
+seed-watch-only = Watch-only wallet: no recovery phrase
+seed-title = Recovery phrase
+seed-hide = Hide
+seed-reveal-action = Reveal recovery phrase
+seed-reading = Reading keychain...
+seed-reveal = Reveal
+seed-reveal-title = Reveal recovery phrase?
+seed-reveal-warning = Anyone who sees these 24 words can spend this wallet's funds. Make sure nobody is watching your screen and that it is not being recorded or shared.
+tx-title = Transactions
+tx-description = Recent wallet activity. Transactions carrying a zots memo are your own timestamps.
+tx-empty = No transactions yet
+tx-sync-hint = Sync the wallet to load its transactions
+tx-timestamp = timestamp
+tx-sent = sent
+tx-received = received
+tx-block = Block { $height }
+tx-pending = Pending
+
+## Network
+
+network-testnet = Testnet
+network-mainnet-unsupported = Mainnet is not supported yet: the wallet only speaks testnet
+server-test = Test connection
+server-testing = Testing...
+server-connected = ✓ Connected: { $version } at block { $block }
+server-wrong-chain = This server follows the '{ $chain }' chain, not { $network }
+
+## Setup wizard
+
+onboarding-welcome = Prove that a file existed at a point in time by anchoring its hash in the Zcash blockchain. Let's set up the wallet that pays for your timestamps.
+onboarding-choice-title = Set up your wallet
+onboarding-choice = Create a new wallet or import an existing 24-word seed phrase.
+onboarding-create = Create a new wallet
+onboarding-import = Import a seed phrase
+onboarding-phrase-title = Your recovery phrase
+onboarding-phrase = Write these 24 words down in order and keep them offline. Anyone with them controls the wallet.
+onboarding-backup-title = Confirm backup
+onboarding-backup = Have you securely stored your recovery phrase?
+onboarding-backup-confirm = I understand that losing my recovery phrase means losing access to my funds.
+onboarding-import-hint = Enter your 24-word seed phrase and, if you know it, the block height at which the wallet was created. Lower heights sync slower.
+onboarding-seed-placeholder = 24-word seed phrase
+onboarding-birthday-placeholder = Birthday height (optional)
+onboarding-network-title = Choose a network
+onboarding-network = Testnet coins are free from a faucet and are right for trying zots out.
+onboarding-server-title = Choose a lightwalletd server
+onboarding-server = The wallet syncs and broadcasts through this server.
+onboarding-saving-title = Saving your wallet
+onboarding-saving = Storing the seed in the OS keychain and writing the zots config profile...
+onboarding-skip = Skip
+onboarding-get-started = Get started
+onboarding-finish = Finish
+
+## Settings
+
+settings-description = Configure application settings
+settings-explorer-title = Block Explorer
+settings-explorer = URL for viewing Zcash transactions
+settings-network-title = Wallet Network
+settings-network = Network, lightwalletd server and birthday height of the wallet; saved to the active zots config profile
+settings-lightwalletd = Lightwalletd server
+settings-birthday = Birthday height
+settings-network-switch = Switching to { $network } uses that network's wallet data directory
+settings-proofs-dir-title = Proofs Directory
+settings-proofs-dir = Where new proofs are saved; History lists every .zots file under it
+settings-appearance-title = Appearance
+settings-appearance = Color theme; high contrast uses pure black and white with bright accents
+settings-language-title = Language
+settings-language = Language of the app; the system default follows ZOTS_LANG and the OS locale
+settings-upgrades-title = Pending Proof Upgrades
+settings-upgrades = How often pending proofs are checked and completed once their transaction is mined
+settings-save = Save Settings
+settings-reset = Reset to Defaults
+settings-saved = Settings saved!
+settings-env-title = Environment Variables
+settings-env-seed = Seed phrase, overriding the OS keychain
+settings-env-lightwalletd = Override lightwalletd server
+settings-env-explorer = Override block explorer URL
+settings-resync-title = Wallet settings changed
+settings-resync = Resync so balances and heights come from the new server and network
+settings-resync-now = Resync now
+settings-resync-later = Later
+language-system = System default
+theme-dark = Dark (cypherpunk)
+theme-light = Light
+theme-high-contrast = High contrast
+
+## Errors
+
+error-enter-stamp-input = Please enter a file path or hash
+error-select-proof = Please select a proof file
+error-proof-not-found = Proof file not found: { $path }
+error-invalid-seed = Invalid seed: { $errors }
+error-not-a-height = '{ $input }' is not a block height
+error-network-unsupported = { $network } is not supported yet
+error-invalid-settings = Invalid wallet settings: { $errors }
+
+## Dialogs, notifications and tray
+
+dialog-pick-file = Select file to timestamp
+dialog-pick-files = Select files to timestamp
+dialog-pick-proofs-dir = Select proofs directory
+dialog-pick-proof = Select proof file
+dialog-export-certificate = Export certificate
+dialog-save-qr = Save QR code
+notify-confirmed-title = Timestamp confirmed
+notify-confirmed = { $name } is anchored in block { $block }
+tray-show = Show zOpenTimestamps
+tray-quit = Quit
@@ -0,0 +1,339 @@
+# Mensajes en español
+
+## Etiquetas y acciones comunes
+
+action-back = Atrás
+action-browse = Examinar
+action-cancel = Cancelar
+action-clear = Vaciar
+action-continue = Continuar
+action-copy = Copiar
+action-copy-compact = Copiar compacta
+action-copy-proof = Copiar prueba
+action-export-certificate = Exportar certificado
+action-refresh = Actualizar
+action-view-explorer = Ver en el explorador
+label-algorithm = Algoritmo
+label-author = Autor
+label-balance = Saldo
+label-block = Bloque
+label-block-height = Altura de bloque
+label-error = Error
+label-explorer = Explorador
+label-hash = Hash
+label-license = Licencia
+label-memo = Memo
+label-network = Red
+label-note = Nota
+label-status = Estado
+label-timestamp = Sello de tiempo
+label-transaction = Transacción
+label-unknown = Desconocido
+label-version = Versión
+qr-save-png = Guardar como PNG
+qr-scan-hint = Escanea para importar la prueba
+
+## Navegación
+
+app-tagline = Sellado de tiempo en Zcash
+view-home = Inicio
+view-stamp = Sellar
+view-verify = Verificar
+view-history = Historial
+view-wallet = Billetera
+view-settings = Ajustes
+status-network = { $network }  Bloque: { $block }
+status-no-wallet = Sin billetera
+status-drop-verify = Suelta el archivo y/o su prueba .zots para verificar
+status-drop-stamp = Suelta archivos para sellarlos
+status-no-wallet-configured = No hay ninguna cartera configurada
+status-synced = Sincronizada
+status-sync-failed = La sincronización falló
+status-ready-sync-failed = Listo (la sincronización falló)
+status-saving-seed = Guardando la semilla en el llavero del sistema...
+status-seed-saved = Semilla guardada, sincronizando...
+status-seed-save-failed = No se pudo guardar la semilla
+status-shielding = Blindando los fondos transparentes...
+status-shielded = Blindando { $amount } ZEC en { $txid }
+status-shield-failed = El blindaje falló
+status-received = Recibidos { $amount } ZEC
+status-creating-timestamp = Creando el sello de tiempo...
+status-stamp-failed = El sellado falló
+status-stamping-batch = Sellando { $count ->
+        [one] 1 archivo
+       *[other] { $count } archivos
+    }...
+status-batch-running = Hay un lote en curso
+status-batch-paused = Lote en pausa
+status-batch-resumed = Lote reanudado
+status-batch-cancelling = Cancelando el lote...
+status-batch-cancelled = Lote cancelado
+status-batch-failed = El lote falló
+status-batch-complete = Lote completado
+status-not-a-file = No es un archivo: { $path }
+status-verify-complete = Verificación completada
+status-verify-failed = La verificación falló
+status-open-failed = No se pudo abrir { $path }: { $error }
+status-tray = Ejecutándose en la bandeja del sistema
+status-tray-watching = Ejecutándose en la bandeja del sistema; los sellos pendientes se siguen vigilando
+status-upgrading = Actualizando las pruebas pendientes...
+status-upgrade-report = Pruebas pendientes: { $summary }
+status-upgrade-failed = La actualización de las pruebas pendientes falló: { $error }
+status-qr-saved = Código QR guardado en { $path }
+status-qr-save-failed = No se pudo guardar el código QR: { $error }
+status-certificate-saved = Certificado guardado en { $path }
+status-certificate-failed = No se pudo exportar el certificado: { $error }
+
+## Inicio
+
+home-title = Bienvenido a zOpenTimestamps
+home-subtitle = Sella archivos en la blockchain de Zcash con transacciones blindadas que preservan la privacidad
+home-not-configured = Sin configurar
+home-quick-actions = Acciones rápidas
+home-stamp-title = Crear sello de tiempo
+home-stamp-description = Sella un archivo o hash en la blockchain de Zcash
+home-verify-title = Verificar prueba
+home-verify-description = Verifica una prueba de sello contra la blockchain
+home-wallet-description = Consulta el saldo y configura tu billetera
+home-testnet-only = Solo testnet
+home-experimental = Este software es experimental. No lo uses en mainnet con fondos reales.
+home-about-title = Acerca de zOpenTimestamps
+home-about = zOpenTimestamps es una herramienta de sellado de tiempo en la blockchain de Zcash inspirada en OpenTimestamps. Crea pruebas criptográficas de que unos datos existían en un momento concreto usando las transacciones blindadas de Zcash.
+home-version = Versión { $version }
+home-license = Licencia MIT
+
+## Sellar
+
+stamp-title = Crear sello de tiempo
+stamp-description = Sella un archivo o hash en la blockchain de Zcash. La prueba se guardará como archivo .zots. Suelta archivos en cualquier parte de la ventana para añadirlos a la cola.
+stamp-input-label = Ruta de archivo o hash
+stamp-input-placeholder = Introduce una ruta o un hash hexadecimal de 64 caracteres...
+stamp-algorithm = Algoritmo de hash
+stamp-toggle = Cambiar
+stamp-broadcast = ¡Transacción difundida!
+stamp-confirmed = ¡Sello confirmado!
+stamp-pending-hint = Esperando la confirmación del bloque. El archivo de prueba se ha guardado y se completará en segundo plano cuando se mine.
+stamp-saved-to = Guardado en
+stamp-failed = Error al sellar
+batch-title = Lote ({ $count ->
+        [one] 1 archivo
+       *[other] { $count } archivos
+    })
+batch-add-files = Añadir archivos
+batch-empty = Añade o suelta archivos para sellarlos juntos en una sola transacción.
+batch-resume = Reanudar
+batch-pause = Pausar
+batch-start = Sellar { $count ->
+        [one] 1 archivo
+       *[other] { $count } archivos
+    } en una transacción
+
+## Progreso
+
+phase-syncing = Sincronizando la billetera...
+phase-broadcasting = Difundiendo la transacción...
+phase-waiting-confirmation = Esperando confirmación ({ $confirmations }/{ $target })...
+phase-complete = ¡Sello creado!
+phase-failed = La operación falló
+queue-queued = En cola
+queue-hashing = Calculando hash...
+queue-hashed = Hash calculado
+queue-broadcasting = Difundiendo...
+queue-waiting = Esperando ({ $confirmations }/{ $target })
+queue-confirmed = Confirmado
+queue-pending = Difundido (pendiente)
+queue-failed = Falló: { $error }
+queue-cancelled = Cancelado
+interval-off = Desactivada
+interval-minute = Cada minuto
+interval-hour = Cada hora
+interval-minutes = Cada { $minutes } minutos
+upgrade-summary = { $upgraded } actualizadas, { $pending } aún pendientes
+upgrade-summary-failed = { $upgraded } actualizadas, { $pending } aún pendientes, { $failed } fallidas
+
+## Verificar
+
+verify-title = Verificar prueba
+verify-description = Verifica una prueba de sello contra la blockchain de Zcash. Opcionalmente, indica el archivo original para comprobar que el hash coincide. Suelta un archivo y su prueba .zots en la ventana para verificarlos al momento; la prueba junto al archivo (foo.pdf.zots al lado de foo.pdf) se encuentra automáticamente.
+verify-file-label = Archivo original o hash (opcional)
+verify-file-placeholder = Introduce la ruta o el hash a comprobar...
+verify-proof-label = Archivo de prueba (.zots)
+verify-proof-placeholder = Selecciona un archivo de prueba .zots...
+verify-running = Verificando...
+verify-valid = ¡Prueba válida!
+verify-failed = La verificación falló
+verify-hash-matches = El hash del archivo coincide con la prueba
+verify-hash-mismatch = ¡El hash del archivo NO coincide!
+verify-error-hash-mismatch = ¡El hash NO coincide con el archivo o hash indicado!
+verify-error-pending = La prueba está pendiente (sin atestaciones)
+verify-error-no-wallet = No se puede verificar en la cadena (no hay cartera)
+
+## Historial
+
+history-title = Historial de pruebas
+history-description = Consulta y gestiona tus pruebas de sello (archivos .zots en { $dir }, más las pruebas pendientes que sigue zots). Las pruebas nuevas aparecen automáticamente.
+history-loading = Cargando...
+history-empty = No se encontraron pruebas
+history-empty-hint = Crea un sello para verlo aquí
+history-loading-proofs = Cargando pruebas...
+history-entry-confirmed = { $network } · Bloque { $block }
+history-entry-pending = Pendiente de confirmación
+history-total = Total
+history-confirmed = Confirmadas
+history-pending = Pendientes
+history-pending-tx = Tx pendiente
+history-attestations = Atestaciones ({ $count })
+history-verify-now = Verificar ahora
+history-verified = ✓ Verificada en la cadena
+history-well-formed = ✓ La prueba está bien formada
+history-verify-failed = ✗ La verificación falló
+upgrade-auto-off = Actualización automática desactivada
+upgrade-auto = Actualización automática: { $interval }
+upgrade-last-check =  · última comprobación { $time }: { $summary }
+upgrade-running = Actualizando...
+upgrade-now = Actualizar ahora
+
+## Billetera
+
+wallet-description = Gestiona tu billetera de testnet de Zcash para sellar
+wallet-security-title = Advertencia de seguridad
+wallet-security = Nunca introduzcas aquí tu frase semilla de mainnet. Esta aplicación es solo para testnet. Tu semilla se guarda en el llavero del sistema, nunca en texto plano en el disco.
+wallet-syncing = Sincronizando...
+wallet-sync-now = Sincronizar ahora
+wallet-configured = Billetera configurada
+wallet-ready = Lista
+wallet-configure-title = Configurar billetera
+wallet-configure = Introduce tu frase semilla BIP-39 (24 palabras) para configurar la billetera. Se guarda en el llavero del sistema.
+wallet-seed-label = Frase semilla
+wallet-seed-placeholder = Introduce tu frase semilla de 24 palabras...
+wallet-saving = Guardando...
+wallet-save-seed = Guardar y conectar
+wallet-run-wizard = Abrir el asistente de configuración
+wallet-storage-title = ※ Almacenamiento de la semilla
+wallet-storage = Las semillas guardadas aquí o con el asistente van al llavero del sistema (Llavero de macOS, Administrador de credenciales de Windows o Secret Service en Linux) bajo el perfil activo de zots, que también lee la CLI. ZOTS_SEED sigue teniendo prioridad cuando está definida.
+wallet-pool-pending = +{ $amount } pendiente
+pool-transparent = Transparente
+pool-sapling = Sapling
+pool-orchard = Orchard
+shield-transparent-funds = Hay { $amount } ZEC en el pool transparente
+shield-hint-unconfirmed = Se podrán blindar cuando se confirmen.
+shield-hint = Blíndalos para usarlos en sellos.
+shield-hint-watch-only = Una billetera de solo lectura no puede blindarlos.
+shield-running = Blindando...
+shield-action = Blindar fondos
+funding-title = Añade fondos a tu billetera
+funding-description = Cada sello cuesta una pequeña comisión de transacción. Envía ZEC a esta dirección para empezar a sellar.
+funding-sync-hint = Sincroniza la billetera para ver su dirección de recepción
+funding-copy-address = Copiar dirección
+funding-faucet = Abrir el faucet de testnet
+funding-waiting = Esperando fondos...
+funding-stop = Detener
+funding-watch = Ya he pedido fondos
+seed-watch-only = Billetera de solo lectura: sin frase de recuperación
+seed-title = Frase de recuperación
+seed-hide = Ocultar
+seed-reveal-action = Mostrar la frase de recuperación
+seed-reading = Leyendo el llavero...
+seed-reveal = Mostrar
+seed-reveal-title = ¿Mostrar la frase de recuperación?
+seed-reveal-warning = Cualquiera que vea estas 24 palabras puede gastar los fondos de esta billetera. Asegúrate de que nadie esté mirando tu pantalla y de que no se esté grabando ni compartiendo.
+tx-title = Transacciones
+tx-description = Actividad reciente de la billetera. Las transacciones con un memo de zots son tus propios sellos.
+tx-empty = Todavía no hay transacciones
+tx-sync-hint = Sincroniza la billetera para cargar sus transacciones
+tx-timestamp = sello
+tx-sent = enviada
+tx-received = recibida
+tx-block = Bloque { $height }
+tx-pending = Pendiente
+
+## Red y servidor
+
+network-testnet = Testnet
+network-mainnet-unsupported = Mainnet aún no está disponible: la billetera solo funciona en testnet
+server-test = Probar conexión
+server-testing = Probando...
+server-connected = ✓ Conectado: { $version } en el bloque { $block }
+server-wrong-chain = Este servidor sigue la cadena '{ $chain }', no { $network }
+
+## Asistente de configuración
+
+onboarding-welcome = Demuestra que un archivo existía en un momento dado anclando su hash en la blockchain de Zcash. Configuremos la billetera que paga tus sellos.
+onboarding-choice-title = Configura tu billetera
+onboarding-choice = Crea una billetera nueva o importa una frase semilla de 24 palabras.
+onboarding-create = Crear una billetera nueva
+onboarding-import = Importar una frase semilla
+onboarding-phrase-title = Tu frase de recuperación
+onboarding-phrase = Anota estas 24 palabras en orden y guárdalas sin conexión. Quien las tenga controla la billetera.
+onboarding-backup-title = Confirmar copia de seguridad
+onboarding-backup = ¿Has guardado tu frase de recuperación de forma segura?
+onboarding-backup-confirm = Entiendo que perder mi frase de recuperación significa perder el acceso a mis fondos.
+onboarding-import-hint = Introduce tu frase semilla de 24 palabras y, si la conoces, la altura de bloque en la que se creó la billetera. Las alturas más bajas sincronizan más despacio.
+onboarding-seed-placeholder = Frase semilla de 24 palabras
+onboarding-birthday-placeholder = Altura de creación (opcional)
+onboarding-network-title = Elige una red
+onboarding-network = Las monedas de testnet son gratis en un faucet y sirven para probar zots.
+onboarding-server-title = Elige un servidor lightwalletd
+onboarding-server = La billetera sincroniza y difunde a través de este servidor.
+onboarding-saving-title = Guardando tu billetera
+onboarding-saving = Guardando la semilla en el llavero del sistema y escribiendo el perfil de configuración de zots...
+onboarding-skip = Omitir
+onboarding-get-started = Empezar
+onboarding-finish = Terminar
+
+## Ajustes
+
+settings-description = Configura la aplicación
+settings-explorer-title = Explorador de bloques
+settings-explorer = URL para ver transacciones de Zcash
+settings-network-title = Red de la billetera
+settings-network = Red, servidor lightwalletd y altura de creación de la billetera; se guardan en el perfil de configuración activo de zots
+settings-lightwalletd = Servidor lightwalletd
+settings-birthday = Altura de creación
+settings-network-switch = Cambiar a { $network } usa el directorio de datos de la billetera de esa red
+settings-proofs-dir-title = Directorio de pruebas
+settings-proofs-dir = Dónde se guardan las pruebas nuevas; el historial lista todos los archivos .zots que contiene
+settings-appearance-title = Apariencia
+settings-appearance = Tema de color; el de alto contraste usa negro y blanco puros con acentos brillantes
+settings-language-title = Idioma
+settings-language = Idioma de la aplicación; el predeterminado del sistema sigue ZOTS_LANG y la configuración regional del sistema
+settings-upgrades-title = Actualización de pruebas pendientes
+settings-upgrades = Con qué frecuencia se comprueban las pruebas pendientes y se completan cuando su transacción se mina
+settings-save = Guardar ajustes
+settings-reset = Restablecer valores predeterminados
+settings-saved = ¡Ajustes guardados!
+settings-env-title = Variables de entorno
+settings-env-seed = Frase semilla; tiene prioridad sobre el llavero del sistema
+settings-env-lightwalletd = Sustituye el servidor lightwalletd
+settings-env-explorer = Sustituye la URL del explorador de bloques
+settings-resync-title = Los ajustes de la billetera cambiaron
+settings-resync = Vuelve a sincronizar para que saldos y alturas vengan del nuevo servidor y red
+settings-resync-now = Sincronizar ahora
+settings-resync-later = Más tarde
+language-system = Predeterminado del sistema
+theme-dark = Oscuro (cypherpunk)
+theme-light = Claro
+theme-high-contrast = Alto contraste
+
+## Errores
+
+error-enter-stamp-input = Introduce la ruta de un archivo o un hash
+error-select-proof = Selecciona un archivo de prueba
+error-proof-not-found = Archivo de prueba no encontrado: { $path }
+error-invalid-seed = Semilla no válida: { $errors }
+error-not-a-height = '{ $input }' no es una altura de bloque
+error-network-unsupported = { $network } todavía no es compatible
+error-invalid-settings = Ajustes de la cartera no válidos: { $errors }
+
+## Diálogos, notificaciones y bandeja
+
+dialog-pick-file = Selecciona el archivo que quieres sellar
+dialog-pick-files = Selecciona los archivos que quieres sellar
+dialog-pick-proofs-dir = Selecciona el directorio de pruebas
+dialog-pick-proof = Selecciona el archivo de prueba
+dialog-export-certificate = Exportar certificado
+dialog-save-qr = Guardar código QR
+notify-confirmed-title = Sello de tiempo confirmado
+notify-confirmed = { $name } está anclado en el bloque { $block }
+tray-show = Mostrar zOpenTimestamps
+tray-quit = Salir
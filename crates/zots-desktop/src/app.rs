@@ -1,32 +1,72 @@
 //! Main application state and update logic
 
-use crate::message::{HistoryEntry, Message, StampPhase, StampResult, VerifyResult, View};
-use crate::theme;
+use crate::i18n::{self, Language, t};
+use crate::message::{
+    HistoryEntry, Message, Onboarding, OnboardingStep, ProofDetail, QueueItem, QueuePhase,
+    SeedReveal, StampPhase, StampResult, UpgradeInterval, UpgradeReport, VerifyResult, View,
+    WalletSnapshot,
+};
+use crate::theme::{self, ThemeChoice};
 use crate::views;
 use anyhow::Result;
+use iced::futures::SinkExt;
+use iced::futures::channel::mpsc;
+use iced::widget::image::Handle;
 use iced::widget::{Space, button, column, container, horizontal_space, row, text};
-use iced::{Element, Font, Length, Subscription, Task};
-use std::path::PathBuf;
+use iced::{Element, Event, Font, Length, Subscription, Task, event, window};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
-use zots_core::HashAlgorithm;
-use zots_zcash::ZcashConfig;
+use zots_core::{HashAlgorithm, Network};
+use zots_zcash::{
+    BalanceBreakdown, ConfigField, DEFAULT_BIRTHDAY_HEIGHT, DEFAULT_LIGHTWALLETD_URL, SeedSource,
+    ZcashConfig, ZcashConfigBuilder, default_data_dir,
+};
+
+/// Transactions listed in the wallet view
+const RECENT_TRANSACTIONS: usize = 20;
+
+/// How often the balance is refreshed while waiting for funds
+const FUNDS_POLL_INTERVAL: Duration = Duration::from_secs(20);
 
 /// Main application state
 pub struct ZotsApp {
     // Navigation
     pub current_view: View,
 
+    /// First-run wizard, shown instead of the main window while set
+    pub onboarding: Option<Onboarding>,
+    /// The wizard was completed or skipped once; it no longer opens by itself
+    pub onboarding_done: bool,
+
     // Wallet state
     pub config: Option<ZcashConfig>,
     pub seed_input: String,
+    /// The seed is being written to the OS keychain
+    pub seed_saving: bool,
+    pub seed_reveal: SeedReveal,
     pub block_height: u64,
     pub balance: u64,
     pub wallet_syncing: bool,
     pub wallet_error: Option<String>,
+    pub balance_breakdown: BalanceBreakdown,
+    pub transactions: Vec<zots_zcash::HistoryEntry>,
+    /// A shielding transaction is being built
+    pub shielding: bool,
+    /// Receiving address and its QR code, known after the first sync
+    pub wallet_address: Option<String>,
+    pub address_qr: Option<Handle>,
+    /// Balance when the user started waiting for funds; polled until it grows
+    pub funding_watch: Option<u64>,
 
     // Stamp state
     pub stamp_input: String,
     pub stamp_file: Option<PathBuf>,
+    /// Files stamped together in one transaction
+    pub stamp_queue: Vec<QueueItem>,
+    /// Pause/cancel flags of the running batch, if any
+    pub batch: Option<BatchControl>,
     pub hash_algorithm: HashAlgorithm,
     pub stamp_phase: StampPhase,
     pub stamp_result: Option<StampResult>,
@@ -44,30 +84,99 @@ pub struct ZotsApp {
     // History state
     pub history: Vec<HistoryEntry>,
     pub history_loading: bool,
+    /// History entry opened in the detail view
+    pub proof_detail: Option<ProofDetail>,
 
     // Settings state
     pub explorer_url: String,
+    /// Wallet network settings, applied to `config` on save
+    pub network: Network,
     pub lightwalletd_url: String,
+    pub birthday_input: String,
+    /// Where proofs are saved and History looks for them (applied on save)
+    pub proofs_dir: PathBuf,
+    pub proofs_dir_input: String,
+    pub theme: ThemeChoice,
+    pub language: Language,
     pub settings_saved: bool,
+    pub settings_error: Option<String>,
+    /// The wallet settings changed since the last sync
+    pub resync_prompt: bool,
+    /// How often pending proofs are upgraded in the background
+    pub upgrade_interval: UpgradeInterval,
+    /// A manual upgrade pass is running
+    pub upgrading: bool,
+    pub last_upgrade: Option<UpgradeReport>,
 
     // UI state
     pub spinner_frame: usize,
     pub copied_feedback: bool,
     pub status_message: String,
+    /// Files are being dragged over the window
+    pub files_hovered: bool,
+    /// A tray icon is shown, so closing the window only hides it
+    pub tray: bool,
+}
+
+/// Pause and cancel flags shared with a running batch stamp
+#[derive(Clone, Default)]
+pub struct BatchControl {
+    paused: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl BatchControl {
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Wait while paused; fail once cancelled
+    async fn checkpoint(&self) -> Result<()> {
+        while self.is_paused() && !self.is_cancelled() {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+        if self.is_cancelled() {
+            anyhow::bail!("Batch cancelled");
+        }
+        Ok(())
+    }
+
+    /// Resolve once the batch is cancelled
+    async fn cancelled(&self) {
+        while !self.is_cancelled() {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    }
 }
 
 impl Default for ZotsApp {
     fn default() -> Self {
         Self {
             current_view: View::Home,
+            onboarding: None,
+            onboarding_done: false,
             config: None,
             seed_input: String::new(),
+            seed_saving: false,
+            seed_reveal: SeedReveal::Hidden,
             block_height: 0,
             balance: 0,
             wallet_syncing: false,
             wallet_error: None,
+            balance_breakdown: BalanceBreakdown::default(),
+            transactions: Vec::new(),
+            shielding: false,
+            wallet_address: None,
+            address_qr: None,
+            funding_watch: None,
             stamp_input: String::new(),
             stamp_file: None,
+            stamp_queue: Vec::new(),
+            batch: None,
             hash_algorithm: HashAlgorithm::Sha256,
             stamp_phase: StampPhase::Idle,
             stamp_result: None,
@@ -81,45 +190,75 @@ impl Default for ZotsApp {
             verifying: false,
             history: Vec::new(),
             history_loading: false,
+            proof_detail: None,
             explorer_url: "https://blockexplorer.one/zcash/testnet".to_string(),
-            lightwalletd_url: "https://zcash.mysideoftheweb.com:19067".to_string(),
+            network: Network::Testnet,
+            lightwalletd_url: DEFAULT_LIGHTWALLETD_URL.to_string(),
+            birthday_input: DEFAULT_BIRTHDAY_HEIGHT.to_string(),
+            proofs_dir: default_proofs_dir(),
+            proofs_dir_input: default_proofs_dir().display().to_string(),
+            theme: ThemeChoice::default(),
+            language: Language::SYSTEM,
             settings_saved: false,
+            settings_error: None,
+            resync_prompt: false,
+            upgrade_interval: UpgradeInterval::default(),
+            upgrading: false,
+            last_upgrade: None,
+            tray: false,
             spinner_frame: 0,
             copied_feedback: false,
-            status_message: "Ready".to_string(),
+            status_message: t!("wallet-ready"),
+            files_hovered: false,
         }
     }
 }
 
 impl ZotsApp {
-    fn new() -> (Self, Task<Message>) {
+    fn new(open_proof: Option<PathBuf>) -> (Self, Task<Message>) {
         let mut app = Self::default();
+        app.tray = crate::tray::install();
+        app.load_settings();
+        i18n::set_language(app.language);
 
         // Try to load config from environment
-        if let Ok(config) = ZcashConfig::from_env() {
-            app.config = Some(config.clone());
-            app.status_message = "Syncing wallet...".to_string();
-            app.wallet_syncing = true;
-
-            // Start initial sync
-            let task = Task::perform(initial_sync(config), |result| match result {
-                Ok((height, balance)) => Message::InitialSyncComplete {
-                    block_height: height,
-                    balance,
-                },
-                Err(_) => Message::InitialSyncFailed,
-            });
-
-            // Load settings
-            app.load_settings();
+        let mut task = Task::none();
+        let mut needs_setup = true;
+        if let Ok(config) = ZcashConfig::load(None) {
+            needs_setup = config.seed_phrase.is_none();
+            if needs_setup {
+                // Keep the server settings, but there is no wallet to sync yet
+                app.show_network_settings(&config);
+                app.status_message = t!("status-no-wallet-configured");
+            } else {
+                task = app.start_initial_sync(config);
+            }
+        } else {
+            app.status_message = t!("status-no-wallet-configured");
+        }
 
-            return (app, task);
+        // A proof opened from the file manager skips the setup wizard
+        match open_proof {
+            Some(path) => task = Task::batch([task, app.open_proof(path)]),
+            None if needs_setup && !app.onboarding_done => {
+                app.onboarding = Some(Onboarding::default());
+            }
+            None => {}
         }
 
-        app.status_message = "No wallet configured".to_string();
-        app.load_settings();
+        (app, task)
+    }
+
+    fn start_initial_sync(&mut self, config: ZcashConfig) -> Task<Message> {
+        self.show_network_settings(&config);
+        self.config = Some(config.clone());
+        self.status_message = t!("phase-syncing");
+        self.wallet_syncing = true;
 
-        (app, Task::none())
+        Task::perform(initial_sync(config), |result| match result {
+            Ok(snapshot) => Message::InitialSyncComplete(snapshot),
+            Err(_) => Message::InitialSyncFailed,
+        })
     }
 
     fn title(&self) -> String {
@@ -127,16 +266,71 @@ impl ZotsApp {
     }
 
     fn theme(&self) -> iced::Theme {
-        theme::dark_theme()
+        self.theme.theme()
     }
 
     fn subscription(&self) -> Subscription<Message> {
         // Tick for spinner animation during async operations
-        if self.is_busy() {
+        let tick = if self.is_busy() {
             iced::time::every(Duration::from_millis(100)).map(|_| Message::Tick)
         } else {
             Subscription::none()
-        }
+        };
+
+        // Balance polled while waiting for funds
+        let funding = if self.funding_watch.is_some() {
+            iced::time::every(FUNDS_POLL_INTERVAL).map(|_| Message::PollBalance)
+        } else {
+            Subscription::none()
+        };
+
+        // Files dropped anywhere on the window
+        let file_drop = event::listen_with(|event, _status, _window| match event {
+            Event::Window(window::Event::FileDropped(path)) => Some(Message::FileDropped(path)),
+            Event::Window(window::Event::FileHovered(_)) => Some(Message::FilesHovered),
+            Event::Window(window::Event::FilesHoveredLeft) => Some(Message::FilesHoveredLeft),
+            _ => None,
+        });
+
+        // Proofs appearing or changing on disk
+        let proofs_watcher = if self.proofs_dir.is_dir() {
+            Subscription::run_with_id(
+                ("proofs-watcher", self.proofs_dir.clone()),
+                watch_proofs(self.proofs_dir.clone()),
+            )
+        } else {
+            Subscription::none()
+        };
+
+        // Pending proofs upgraded in the background
+        let upgrades = match (&self.config, self.upgrade_interval.duration()) {
+            (Some(config), Some(interval)) => Subscription::run_with_id(
+                (
+                    "pending-upgrades",
+                    config.pending_store_path(),
+                    self.proofs_dir.clone(),
+                    interval,
+                ),
+                schedule_upgrades(config.clone(), self.proofs_dir.clone(), interval),
+            ),
+            _ => Subscription::none(),
+        };
+
+        let tray = if self.tray {
+            Subscription::run(crate::tray::events)
+        } else {
+            Subscription::none()
+        };
+
+        Subscription::batch([
+            tick,
+            funding,
+            file_drop,
+            proofs_watcher,
+            upgrades,
+            window::close_requests().map(Message::WindowCloseRequested),
+            tray,
+        ])
     }
 
     fn update(&mut self, message: Message) -> Task<Message> {
@@ -157,69 +351,210 @@ impl ZotsApp {
                 Task::none()
             }
             Message::SaveSeed => {
-                if let Ok(config) = ZcashConfig::from_seed(&self.seed_input) {
-                    self.config = Some(config.clone());
-                    self.seed_input.clear();
-                    self.status_message = "Seed saved, syncing...".to_string();
-                    self.wallet_syncing = true;
-                    return Task::perform(sync_wallet(config), |result| match result {
-                        Ok((height, balance)) => Message::WalletSynced {
-                            block_height: height,
-                            balance,
-                        },
-                        Err(e) => Message::WalletSyncFailed(e.to_string()),
-                    });
-                } else {
-                    self.wallet_error = Some("Invalid seed phrase".to_string());
+                let built = ZcashConfigBuilder::new()
+                    .seed(SeedSource::Phrase(self.seed_input.clone()))
+                    .build();
+                match built {
+                    Ok(_) => {
+                        self.seed_saving = true;
+                        self.wallet_error = None;
+                        self.status_message = t!("status-saving-seed");
+                        let seed_phrase = std::mem::take(&mut self.seed_input);
+                        return Task::perform(save_seed(seed_phrase), |result| {
+                            Message::SeedSaved(result.map_err(|e| e.to_string()))
+                        });
+                    }
+                    Err(errors) => {
+                        let messages: Vec<String> =
+                            errors.0.iter().map(|e| e.message.clone()).collect();
+                        self.wallet_error =
+                            Some(t!("error-invalid-seed", errors = messages.join("; ")));
+                    }
+                }
+                Task::none()
+            }
+            Message::SeedSaved(result) => {
+                self.seed_saving = false;
+                match result {
+                    Ok(config) => {
+                        self.show_network_settings(&config);
+                        self.config = Some(config.clone());
+                        self.status_message = t!("status-seed-saved");
+                        self.wallet_syncing = true;
+                        return sync_task(config);
+                    }
+                    Err(e) => {
+                        self.status_message = t!("status-seed-save-failed");
+                        self.wallet_error = Some(e);
+                    }
+                }
+                Task::none()
+            }
+            Message::RevealSeed => {
+                self.seed_reveal = SeedReveal::Confirming;
+                Task::none()
+            }
+            Message::ConfirmRevealSeed => {
+                let Some(config) = &self.config else {
+                    return Task::none();
+                };
+                self.seed_reveal = SeedReveal::Loading;
+                Task::perform(stored_seed(config.seed_phrase.clone()), |result| {
+                    Message::SeedRevealed(result.map_err(|e| e.to_string()))
+                })
+            }
+            Message::SeedRevealed(result) => {
+                // Hidden again before the keychain answered
+                if self.seed_reveal != SeedReveal::Loading {
+                    return Task::none();
+                }
+                match result {
+                    Ok(seed_phrase) => self.seed_reveal = SeedReveal::Shown(seed_phrase),
+                    Err(e) => {
+                        self.seed_reveal = SeedReveal::Hidden;
+                        self.wallet_error = Some(e);
+                    }
                 }
                 Task::none()
             }
+            Message::HideSeed => {
+                self.seed_reveal = SeedReveal::Hidden;
+                Task::none()
+            }
             Message::SyncWallet => {
                 if let Some(config) = &self.config {
+                    self.resync_prompt = false;
                     self.wallet_syncing = true;
                     self.wallet_error = None;
-                    self.status_message = "Syncing wallet...".to_string();
-                    return Task::perform(sync_wallet(config.clone()), |result| match result {
-                        Ok((height, balance)) => Message::WalletSynced {
-                            block_height: height,
-                            balance,
-                        },
-                        Err(e) => Message::WalletSyncFailed(e.to_string()),
-                    });
+                    self.status_message = t!("phase-syncing");
+                    return sync_task(config.clone());
                 }
                 Task::none()
             }
-            Message::WalletSynced {
-                block_height,
-                balance,
-            } => {
-                self.block_height = block_height;
-                self.balance = balance;
+            Message::WalletSynced(snapshot) => {
                 self.wallet_syncing = false;
-                self.status_message = "Synced".to_string();
+                self.status_message = t!("status-synced");
+                self.apply_snapshot(snapshot);
                 Task::none()
             }
             Message::WalletSyncFailed(error) => {
                 self.wallet_error = Some(error);
                 self.wallet_syncing = false;
-                self.status_message = "Sync failed".to_string();
+                self.status_message = t!("status-sync-failed");
                 Task::none()
             }
-            Message::InitialSyncComplete {
-                block_height,
-                balance,
-            } => {
-                self.block_height = block_height;
-                self.balance = balance;
+            Message::InitialSyncComplete(snapshot) => {
                 self.wallet_syncing = false;
-                self.status_message = "Ready".to_string();
+                self.status_message = t!("wallet-ready");
+                self.apply_snapshot(snapshot);
                 Task::none()
             }
             Message::InitialSyncFailed => {
                 self.wallet_syncing = false;
-                self.status_message = "Ready (sync failed)".to_string();
+                self.status_message = t!("status-ready-sync-failed");
+                Task::none()
+            }
+
+            Message::WatchForFunds => {
+                self.funding_watch = Some(self.balance);
+                self.status_message = t!("funding-waiting");
+                Task::none()
+            }
+            Message::StopWatchingForFunds => {
+                self.funding_watch = None;
+                Task::none()
+            }
+            Message::PollBalance => match &self.config {
+                Some(config) if !self.wallet_syncing && self.funding_watch.is_some() => {
+                    self.wallet_syncing = true;
+                    sync_task(config.clone())
+                }
+                _ => Task::none(),
+            },
+
+            Message::ShieldFunds => {
+                let Some(config) = &self.config else {
+                    return Task::none();
+                };
+                if self.shielding || self.wallet_syncing {
+                    return Task::none();
+                }
+                self.shielding = true;
+                self.wallet_error = None;
+                self.status_message = t!("status-shielding");
+                Task::perform(shield_funds(config.clone()), |result| {
+                    Message::FundsShielded(result.map_err(|e| e.to_string()))
+                })
+            }
+            Message::FundsShielded(result) => {
+                self.shielding = false;
+                match result {
+                    Ok((txid, amount)) => {
+                        let zec = amount as f64 / 100_000_000.0;
+                        self.status_message =
+                            t!("status-shielded", amount = format!("{zec:.8}"), txid = txid);
+                        // Show the shielded value as pending
+                        if let Some(config) = &self.config {
+                            self.wallet_syncing = true;
+                            return sync_task(config.clone());
+                        }
+                    }
+                    Err(e) => {
+                        self.status_message = t!("status-shield-failed");
+                        self.wallet_error = Some(e);
+                    }
+                }
+                Task::none()
+            }
+
+            // First-run wizard
+            Message::StartOnboarding => {
+                self.onboarding = Some(Onboarding::default());
+                Task::none()
+            }
+            Message::SkipOnboarding => {
+                self.onboarding = None;
+                self.onboarding_done = true;
+                self.save_settings();
+                Task::none()
+            }
+            Message::OnboardingFinished(Ok(config)) => {
+                self.onboarding = None;
+                self.onboarding_done = true;
+                self.save_settings();
+                self.current_view = View::Wallet;
+                self.start_initial_sync(config)
+            }
+            Message::OnboardingFinished(Err(error)) => {
+                if let Some(onboarding) = &mut self.onboarding {
+                    onboarding.step = OnboardingStep::Server;
+                    onboarding.error = Some(error);
+                }
+                Task::none()
+            }
+            Message::OnboardingServerTested(result) => {
+                if let Some(onboarding) = &mut self.onboarding {
+                    onboarding.testing_server = false;
+                    match result {
+                        Ok(info) => onboarding.server_info = Some(info),
+                        Err(error) => onboarding.error = Some(error),
+                    }
+                }
                 Task::none()
             }
+            message @ (Message::OnboardingBack
+            | Message::OnboardingNext
+            | Message::OnboardingCreateSeed
+            | Message::OnboardingImportSeed
+            | Message::OnboardingSeedInputChanged(_)
+            | Message::OnboardingBirthdayChanged(_)
+            | Message::OnboardingBackupConfirmed(_)
+            | Message::OnboardingNetworkSelected(_)
+            | Message::OnboardingServerChanged(_)
+            | Message::OnboardingTestServer) => match &mut self.onboarding {
+                Some(onboarding) => update_onboarding(onboarding, message),
+                None => Task::none(),
+            },
 
             // Stamp
             Message::StampInputChanged(input) => {
@@ -243,27 +578,45 @@ impl ZotsApp {
             }
             Message::StartStamp => {
                 if self.stamp_input.is_empty() {
-                    self.stamp_error = Some("Please enter a file path or hash".to_string());
+                    self.stamp_error = Some(t!("error-enter-stamp-input"));
                     return Task::none();
                 }
                 if self.config.is_none() {
-                    self.stamp_error = Some("No wallet configured".to_string());
+                    self.stamp_error = Some(t!("status-no-wallet-configured"));
                     return Task::none();
                 }
 
                 self.stamp_phase = StampPhase::Syncing;
                 self.stamp_error = None;
                 self.stamp_result = None;
-                self.status_message = "Creating timestamp...".to_string();
+                self.status_message = t!("status-creating-timestamp");
 
                 let config = self.config.clone().unwrap();
                 let input = self.stamp_input.clone();
                 let algorithm = self.hash_algorithm;
-
-                Task::perform(run_stamp(config, input, algorithm), |result| match result {
-                    Ok(stamp_result) => Message::StampComplete(stamp_result),
-                    Err(e) => Message::StampFailed(e.to_string()),
-                })
+                let proofs_dir = self.proofs_dir.clone();
+
+                Task::run(
+                    iced::stream::channel(
+                        16,
+                        move |mut output: mpsc::Sender<Message>| async move {
+                            let message = match run_stamp(
+                                config,
+                                input,
+                                algorithm,
+                                proofs_dir,
+                                output.clone(),
+                            )
+                            .await
+                            {
+                                Ok(stamp_result) => Message::StampComplete(stamp_result),
+                                Err(e) => Message::StampFailed(e.to_string()),
+                            };
+                            let _ = output.send(message).await;
+                        },
+                    ),
+                    |message| message,
+                )
             }
             Message::StampProgress(phase) => {
                 self.stamp_phase = phase;
@@ -272,13 +625,136 @@ impl ZotsApp {
             Message::StampComplete(result) => {
                 self.stamp_result = Some(result);
                 self.stamp_phase = StampPhase::Complete;
-                self.status_message = "Timestamp created!".to_string();
+                self.status_message = t!("phase-complete");
                 Task::none()
             }
             Message::StampFailed(error) => {
                 self.stamp_error = Some(error);
                 self.stamp_phase = StampPhase::Failed;
-                self.status_message = "Stamp failed".to_string();
+                self.status_message = t!("status-stamp-failed");
+                Task::none()
+            }
+
+            // Batch stamping queue
+            Message::AddFilesToQueue => Task::perform(pick_files(), Message::FilesAddedToQueue),
+            Message::FilesAddedToQueue(paths) => {
+                for path in paths {
+                    self.queue_file(path);
+                }
+                Task::none()
+            }
+            Message::RemoveQueuedFile(index) => {
+                if self.batch.is_none() && index < self.stamp_queue.len() {
+                    self.stamp_queue.remove(index);
+                }
+                Task::none()
+            }
+            Message::ClearStampQueue => {
+                if self.batch.is_none() {
+                    self.stamp_queue.clear();
+                }
+                Task::none()
+            }
+            Message::StartBatch => {
+                if self.batch.is_some() {
+                    return Task::none();
+                }
+                let Some(config) = self.config.clone() else {
+                    self.stamp_error = Some(t!("status-no-wallet-configured"));
+                    return Task::none();
+                };
+                let files: Vec<(usize, PathBuf)> = self
+                    .stamp_queue
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(_, item)| item.phase.needs_stamp())
+                    .map(|(index, item)| {
+                        item.phase = QueuePhase::Queued;
+                        (index, item.path.clone())
+                    })
+                    .collect();
+                if files.is_empty() {
+                    return Task::none();
+                }
+
+                let control = BatchControl::default();
+                self.batch = Some(control.clone());
+                self.stamp_error = None;
+                self.status_message = t!("status-stamping-batch", count = files.len());
+                let algorithm = self.hash_algorithm;
+                let proofs_dir = self.proofs_dir.clone();
+
+                Task::run(
+                    iced::stream::channel(
+                        64,
+                        move |mut output: mpsc::Sender<Message>| async move {
+                            let error = run_batch_stamp(
+                                config,
+                                files,
+                                algorithm,
+                                proofs_dir,
+                                control,
+                                output.clone(),
+                            )
+                            .await
+                            .err()
+                            .map(|e| e.to_string());
+                            let _ = output.send(Message::BatchFinished(error)).await;
+                        },
+                    ),
+                    |message| message,
+                )
+            }
+            Message::PauseBatch => {
+                if let Some(control) = &self.batch {
+                    control.paused.store(true, Ordering::Relaxed);
+                    self.status_message = t!("status-batch-paused");
+                }
+                Task::none()
+            }
+            Message::ResumeBatch => {
+                if let Some(control) = &self.batch {
+                    control.paused.store(false, Ordering::Relaxed);
+                    self.status_message = t!("status-batch-resumed");
+                }
+                Task::none()
+            }
+            Message::CancelBatch => {
+                if let Some(control) = &self.batch {
+                    control.cancelled.store(true, Ordering::Relaxed);
+                    self.status_message = t!("status-batch-cancelling");
+                }
+                Task::none()
+            }
+            Message::BatchItemProgress(index, phase) => {
+                if let Some(item) = self.stamp_queue.get_mut(index) {
+                    item.phase = phase;
+                }
+                Task::none()
+            }
+            Message::BatchFinished(error) => {
+                let cancelled = self
+                    .batch
+                    .take()
+                    .is_some_and(|control| control.is_cancelled());
+                // Files the batch never got to
+                for item in &mut self.stamp_queue {
+                    if !matches!(item.phase, QueuePhase::Done { .. } | QueuePhase::Failed(_)) {
+                        item.phase = match &error {
+                            Some(_) if cancelled => QueuePhase::Cancelled,
+                            Some(e) => QueuePhase::Failed(e.clone()),
+                            None => item.phase.clone(),
+                        };
+                    }
+                }
+                self.status_message = match error {
+                    Some(_) if cancelled => t!("status-batch-cancelled"),
+                    Some(e) => {
+                        self.stamp_error = Some(e);
+                        t!("status-batch-failed")
+                    }
+                    None => t!("status-batch-complete"),
+                };
                 Task::none()
             }
 
@@ -311,14 +787,14 @@ impl ZotsApp {
             }
             Message::StartVerify => {
                 if self.verify_proof_input.is_empty() {
-                    self.verify_error = Some("Please select a proof file".to_string());
+                    self.verify_error = Some(t!("error-select-proof"));
                     return Task::none();
                 }
 
                 self.verifying = true;
                 self.verify_error = None;
                 self.verify_result = None;
-                self.status_message = "Verifying...".to_string();
+                self.status_message = t!("verify-running");
 
                 let file_input = self.verify_file_input.clone();
                 let proof_path = PathBuf::from(&self.verify_proof_input);
@@ -335,20 +811,24 @@ impl ZotsApp {
             Message::VerifyComplete(result) => {
                 self.verify_result = Some(result);
                 self.verifying = false;
-                self.status_message = "Verification complete".to_string();
+                self.status_message = t!("status-verify-complete");
                 Task::none()
             }
             Message::VerifyFailed(error) => {
                 self.verify_error = Some(error);
                 self.verifying = false;
-                self.status_message = "Verification failed".to_string();
+                self.status_message = t!("status-verify-failed");
                 Task::none()
             }
 
             // History
             Message::LoadHistory => {
                 self.history_loading = true;
-                Task::perform(load_history(), Message::HistoryLoaded)
+                let pending_store = self.config.as_ref().map(ZcashConfig::pending_store_path);
+                Task::perform(
+                    load_history(self.proofs_dir.clone(), pending_store),
+                    Message::HistoryLoaded,
+                )
             }
             Message::HistoryLoaded(entries) => {
                 self.history = entries;
@@ -363,8 +843,87 @@ impl ZotsApp {
             }),
             Message::ProofDeleted(path) => {
                 self.history.retain(|e| e.path != path);
+                if self.proof_detail.as_ref().is_some_and(|d| d.path == path) {
+                    self.proof_detail = None;
+                }
+                Task::none()
+            }
+            Message::OpenProofDetail(path) => {
+                match zots_core::TimestampProof::load(&path) {
+                    Ok(proof) => {
+                        self.proof_detail = Some(ProofDetail {
+                            compact: proof.to_compact().unwrap_or_default(),
+                            warnings: proof.lint(),
+                            path,
+                            proof,
+                            verifying: false,
+                            verify_result: None,
+                            verify_error: None,
+                        });
+                    }
+                    Err(e) => {
+                        self.status_message = t!(
+                            "status-open-failed",
+                            path = path.display().to_string(),
+                            error = e.to_string()
+                        );
+                    }
+                }
+                Task::none()
+            }
+            Message::CloseProofDetail => {
+                self.proof_detail = None;
+                Task::none()
+            }
+            Message::VerifyProofDetail => {
+                let Some(detail) = &mut self.proof_detail else {
+                    return Task::none();
+                };
+                if detail.verifying {
+                    return Task::none();
+                }
+                detail.verifying = true;
+                detail.verify_result = None;
+                detail.verify_error = None;
+                self.status_message = t!("verify-running");
+
+                let path = detail.path.clone();
+                Task::perform(
+                    run_verify(self.config.clone(), String::new(), path.clone()),
+                    move |result| match result {
+                        Ok(verify_result) => {
+                            Message::ProofDetailVerified(path.clone(), verify_result)
+                        }
+                        Err(e) => Message::ProofDetailVerifyFailed(path.clone(), e.to_string()),
+                    },
+                )
+            }
+            Message::ProofDetailVerified(path, result) => {
+                self.status_message = t!("status-verify-complete");
+                if let Some(detail) = &mut self.proof_detail
+                    && detail.path == path
+                {
+                    detail.verifying = false;
+                    detail.verify_result = Some(result);
+                }
+                Task::none()
+            }
+            Message::ProofDetailVerifyFailed(path, error) => {
+                self.status_message = t!("status-verify-failed");
+                if let Some(detail) = &mut self.proof_detail
+                    && detail.path == path
+                {
+                    detail.verifying = false;
+                    detail.verify_error = Some(error);
+                }
                 Task::none()
             }
+            Message::ProofsChanged => {
+                if self.history_loading {
+                    return Task::none();
+                }
+                Task::done(Message::LoadHistory)
+            }
 
             // Settings
             Message::ExplorerUrlChanged(url) => {
@@ -377,17 +936,164 @@ impl ZotsApp {
                 self.settings_saved = false;
                 Task::none()
             }
+            Message::NetworkChanged(network) => {
+                // Follow the network unless the user typed their own values
+                if is_default_lightwalletd(self.lightwalletd_url.trim()) {
+                    self.lightwalletd_url = default_lightwalletd(network).to_string();
+                }
+                if self.birthday_input.trim() == default_birthday(self.network).to_string() {
+                    self.birthday_input = default_birthday(network).to_string();
+                }
+                self.network = network;
+                self.settings_saved = false;
+                Task::none()
+            }
+            Message::BirthdayHeightChanged(height) => {
+                self.birthday_input = height;
+                self.settings_saved = false;
+                Task::none()
+            }
+            Message::ProofsDirChanged(dir) => {
+                self.proofs_dir_input = dir;
+                self.settings_saved = false;
+                Task::none()
+            }
+            Message::SelectProofsDir => Task::perform(pick_folder(), Message::ProofsDirSelected),
+            Message::ProofsDirSelected(dir) => {
+                if let Some(dir) = dir {
+                    self.proofs_dir_input = dir.display().to_string();
+                    self.settings_saved = false;
+                }
+                Task::none()
+            }
+            Message::ThemeChanged(choice) => {
+                self.theme = choice;
+                self.settings_saved = false;
+                Task::none()
+            }
+            Message::LanguageChanged(language) => {
+                self.language = language;
+                i18n::set_language(language);
+                self.settings_saved = false;
+                Task::none()
+            }
             Message::SaveSettings => {
+                self.proofs_dir = PathBuf::from(self.proofs_dir_input.trim());
                 self.save_settings();
+                if let Err(e) = self.apply_network_settings() {
+                    self.settings_saved = false;
+                    self.settings_error = Some(format!("{e:#}"));
+                    return Task::none();
+                }
+                self.settings_error = None;
                 self.settings_saved = true;
-                Task::none()
+                Task::done(Message::LoadHistory)
             }
             Message::ResetSettings => {
                 self.explorer_url = "https://blockexplorer.one/zcash/testnet".to_string();
-                self.lightwalletd_url = "https://zcash.mysideoftheweb.com:19067".to_string();
+                self.network = Network::Testnet;
+                self.lightwalletd_url = DEFAULT_LIGHTWALLETD_URL.to_string();
+                self.birthday_input = DEFAULT_BIRTHDAY_HEIGHT.to_string();
+                self.proofs_dir_input = default_proofs_dir().display().to_string();
+                self.upgrade_interval = UpgradeInterval::default();
+                self.theme = ThemeChoice::default();
+                self.language = Language::SYSTEM;
+                i18n::set_language(self.language);
+                self.settings_saved = false;
+                Task::none()
+            }
+            Message::DismissResyncPrompt => {
+                self.resync_prompt = false;
+                Task::none()
+            }
+
+            // Window file drag-and-drop
+            Message::FileDropped(path) => {
+                self.files_hovered = false;
+                self.drop_file(path)
+            }
+            Message::FilesHovered => {
+                self.files_hovered = true;
+                Task::none()
+            }
+            Message::FilesHoveredLeft => {
+                self.files_hovered = false;
+                Task::none()
+            }
+
+            // Tray
+            Message::WindowCloseRequested(id) => {
+                if !self.tray {
+                    return iced::exit();
+                }
+                self.status_message = if self.upgrade_interval == UpgradeInterval::Off {
+                    t!("status-tray")
+                } else {
+                    t!("status-tray-watching")
+                };
+                window::change_mode(id, window::Mode::Hidden)
+            }
+            Message::ShowWindow => window::get_latest().and_then(|id| {
+                window::change_mode(id, window::Mode::Windowed).chain(window::gain_focus(id))
+            }),
+            Message::Quit => iced::exit(),
+
+            // Pending proof upgrades
+            Message::UpgradeIntervalChanged(interval) => {
+                self.upgrade_interval = interval;
                 self.settings_saved = false;
                 Task::none()
             }
+            Message::UpgradePendingNow => {
+                let Some(config) = self.config.clone() else {
+                    self.status_message = t!("status-no-wallet-configured");
+                    return Task::none();
+                };
+                if self.upgrading {
+                    return Task::none();
+                }
+                self.upgrading = true;
+                self.status_message = t!("status-upgrading");
+                let proofs_dir = self.proofs_dir.clone();
+                Task::perform(
+                    async move {
+                        // Wait for a scheduled pass in flight rather than race it
+                        let _pass = UPGRADE_PASS.lock().await;
+                        upgrade_pending(&config, &proofs_dir).await
+                    },
+                    |result| match result {
+                        Ok(report) => Message::PendingUpgraded {
+                            report,
+                            manual: true,
+                        },
+                        Err(e) => Message::PendingUpgradeFailed {
+                            error: e.to_string(),
+                            manual: true,
+                        },
+                    },
+                )
+            }
+            Message::PendingUpgraded { report, manual } => {
+                if manual {
+                    self.upgrading = false;
+                }
+                if manual || !report.upgraded.is_empty() || !report.failed.is_empty() {
+                    self.status_message = t!("status-upgrade-report", summary = report.summary());
+                }
+                let reload = !report.upgraded.is_empty();
+                self.last_upgrade = Some(report);
+                if reload && self.current_view == View::History && !self.history_loading {
+                    return Task::done(Message::LoadHistory);
+                }
+                Task::none()
+            }
+            Message::PendingUpgradeFailed { error, manual } => {
+                if manual {
+                    self.upgrading = false;
+                }
+                self.status_message = t!("status-upgrade-failed", error = error);
+                Task::none()
+            }
 
             // UI
             Message::Tick => {
@@ -411,6 +1117,34 @@ impl ZotsApp {
                 let _ = open::that(&url);
                 Task::none()
             }
+            Message::SaveQrCode(compact) => Task::perform(save_qr(compact), |result| {
+                Message::QrCodeSaved(result.map_err(|e| e.to_string()))
+            }),
+            Message::QrCodeSaved(result) => {
+                self.status_message = match result {
+                    Ok(Some(path)) => t!("status-qr-saved", path = path.display().to_string()),
+                    Ok(None) => return Task::none(),
+                    Err(e) => t!("status-qr-save-failed", error = e),
+                };
+                Task::none()
+            }
+            Message::ExportCertificate(proof_path) => {
+                let explorer_url = self.config.as_ref().and_then(|c| c.explorer_url.clone());
+                Task::perform(export_certificate(proof_path, explorer_url), |result| {
+                    Message::CertificateExported(result.map_err(|e| e.to_string()))
+                })
+            }
+            Message::CertificateExported(result) => {
+                self.status_message = match result {
+                    Ok(Some(path)) => t!(
+                        "status-certificate-saved",
+                        path = path.display().to_string()
+                    ),
+                    Ok(None) => return Task::none(),
+                    Err(e) => t!("status-certificate-failed", error = e),
+                };
+                Task::none()
+            }
             Message::DismissResult => {
                 self.copied_feedback = false;
                 Task::none()
@@ -419,6 +1153,10 @@ impl ZotsApp {
     }
 
     fn view(&self) -> Element<Message> {
+        if let Some(onboarding) = &self.onboarding {
+            return views::onboarding::view(self, onboarding);
+        }
+
         let sidebar = self.sidebar();
         let content = match self.current_view {
             View::Home => views::home::view(self),
@@ -474,7 +1212,7 @@ impl ZotsApp {
 
         let logo = column![
             text("zOpenTimestamps").size(16).font(Font::DEFAULT),
-            text("Zcash Timestamping")
+            text(t!("app-tagline"))
                 .size(11)
                 .style(theme::text_style::muted()),
         ]
@@ -500,13 +1238,13 @@ impl ZotsApp {
             text("●").style(theme::text_style::accent())
         };
 
-        let network = text(format!(
-            "{}  Block: {}",
-            self.config
+        let network = text(t!(
+            "status-network",
+            network = self
+                .config
                 .as_ref()
-                .map(|c| c.network.name())
-                .unwrap_or("No wallet"),
-            self.block_height
+                .map_or_else(|| t!("status-no-wallet"), |c| c.network.name().to_string()),
+            block = self.block_height
         ))
         .size(12)
         .style(theme::text_style::muted());
@@ -516,9 +1254,18 @@ impl ZotsApp {
             .size(12)
             .style(theme::text_style::accent());
 
-        let status = text(&self.status_message)
-            .size(12)
-            .style(theme::text_style::muted());
+        let status = if self.files_hovered {
+            let hint = if self.current_view == View::Verify {
+                t!("status-drop-verify")
+            } else {
+                t!("status-drop-stamp")
+            };
+            text(hint).size(12).style(theme::text_style::accent())
+        } else {
+            text(&self.status_message)
+                .size(12)
+                .style(theme::text_style::muted())
+        };
 
         container(
             row![
@@ -539,7 +1286,120 @@ impl ZotsApp {
     }
 
     fn is_busy(&self) -> bool {
-        self.wallet_syncing || self.stamp_phase.is_busy() || self.verifying || self.history_loading
+        self.wallet_syncing
+            || self.stamp_phase.is_busy()
+            || self.batch.is_some()
+            || self.verifying
+            || self.history_loading
+            || self.proof_detail.as_ref().is_some_and(|d| d.verifying)
+            || self.upgrading
+            || self.seed_saving
+            || self.shielding
+    }
+
+    /// Route a file dropped on the window
+    ///
+    /// On the Verify view a `.zots` file fills the proof field and anything
+    /// else the file field. The other field is filled from a sibling on disk
+    /// (`foo.pdf` next to `foo.pdf.zots`), and verification starts as soon
+    /// as both are set. Elsewhere the file is queued for stamping.
+    fn drop_file(&mut self, path: PathBuf) -> Task<Message> {
+        if self.current_view == View::Verify {
+            if self.verifying {
+                return Task::none();
+            }
+            // A drop after a finished verification starts a new pair
+            if self.verify_result.is_some() || self.verify_error.is_some() {
+                self.verify_result = None;
+                self.verify_error = None;
+                self.verify_file = None;
+                self.verify_file_input.clear();
+                self.verify_proof = None;
+                self.verify_proof_input.clear();
+            }
+
+            if path.extension().is_some_and(|ext| ext == "zots") {
+                if self.verify_file_input.is_empty()
+                    && let Some(document) = document_for_proof(&path)
+                {
+                    self.set_verify_file(document);
+                }
+                self.set_verify_proof(path);
+            } else {
+                if self.verify_proof_input.is_empty()
+                    && let Some(proof) = proof_for_document(&path, &self.proofs_dir)
+                {
+                    self.set_verify_proof(proof);
+                }
+                self.set_verify_file(path);
+            }
+
+            if !self.verify_file_input.is_empty() && !self.verify_proof_input.is_empty() {
+                return Task::done(Message::StartVerify);
+            }
+            return Task::none();
+        }
+
+        if self.current_view != View::Stamp {
+            self.current_view = View::Stamp;
+            self.clear_results();
+        }
+        self.queue_file(path);
+        Task::none()
+    }
+
+    /// Open a proof passed at launch (e.g. by double-clicking a `.zots` file)
+    ///
+    /// Shows it on the Verify view, paired with its document when that sits
+    /// next to it, and starts verifying right away.
+    fn open_proof(&mut self, path: PathBuf) -> Task<Message> {
+        self.current_view = View::Verify;
+        self.clear_results();
+        self.verify_file = None;
+        self.verify_file_input.clear();
+        if let Some(document) = document_for_proof(&path) {
+            self.set_verify_file(document);
+        }
+        let exists = path.is_file();
+        self.set_verify_proof(path);
+        if !exists {
+            self.verify_error = Some(t!(
+                "error-proof-not-found",
+                path = self.verify_proof_input.as_str()
+            ));
+            return Task::none();
+        }
+        Task::done(Message::StartVerify)
+    }
+
+    fn set_verify_file(&mut self, path: PathBuf) {
+        self.verify_file_input = path.display().to_string();
+        self.verify_file = Some(path);
+    }
+
+    fn set_verify_proof(&mut self, path: PathBuf) {
+        self.verify_proof_input = path.display().to_string();
+        self.verify_proof = Some(path);
+    }
+
+    /// Add a file to the batch stamping queue
+    fn queue_file(&mut self, path: PathBuf) {
+        if self.batch.is_some() {
+            self.status_message = t!("status-batch-running");
+            return;
+        }
+        if !path.is_file() {
+            self.status_message = t!("status-not-a-file", path = path.display().to_string());
+            return;
+        }
+        if self.stamp_queue.iter().any(|item| item.path == path) {
+            return;
+        }
+        self.stamp_queue.push(QueueItem {
+            path,
+            phase: QueuePhase::Queued,
+        });
+        self.status_message = format!("{} file(s) queued", self.stamp_queue.len());
     }
 
     fn clear_results(&mut self) {
@@ -548,6 +1408,96 @@ impl ZotsApp {
         self.stamp_phase = StampPhase::Idle;
         self.verify_error = None;
         self.verify_result = None;
+        self.proof_detail = None;
+        self.seed_reveal = SeedReveal::Hidden;
+    }
+
+    fn apply_snapshot(&mut self, snapshot: WalletSnapshot) {
+        self.block_height = snapshot.block_height;
+        self.balance = snapshot.balance;
+        self.balance_breakdown = snapshot.breakdown;
+        self.transactions = snapshot.transactions;
+        if snapshot.address != self.wallet_address {
+            self.address_qr = snapshot
+                .address
+                .as_deref()
+                .and_then(|address| crate::qr::handle(address).ok());
+            self.wallet_address = snapshot.address;
+        }
+        if let Some(start) = self.funding_watch
+            && snapshot.balance > start
+        {
+            self.funding_watch = None;
+            let received = (snapshot.balance - start) as f64 / 100_000_000.0;
+            self.status_message = t!("status-received", amount = format!("{received:.8}"));
+        }
+    }
+
+    /// Fill the wallet section of Settings from `config`
+    fn show_network_settings(&mut self, config: &ZcashConfig) {
+        self.network = config.network;
+        self.lightwalletd_url = config.lightwalletd_url.clone();
+        self.birthday_input = config.birthday_height.to_string();
+    }
+
+    /// Validate the wallet section of Settings, apply it to the running
+    /// config and write it to the active config profile
+    ///
+    /// Switching networks also switches to that network's data directory,
+    /// since a wallet database only holds one chain.
+    fn apply_network_settings(&mut self) -> Result<()> {
+        // The wallet is built on testnet consensus parameters only
+        if self.network != Network::Testnet {
+            anyhow::bail!(t!(
+                "error-network-unsupported",
+                network = self.network.to_string()
+            ));
+        }
+        let birthday_input = self.birthday_input.trim();
+        let birthday_height: u64 = birthday_input
+            .parse()
+            .map_err(|_| anyhow::anyhow!(t!("error-not-a-height", input = birthday_input)))?;
+        let lightwalletd_url = self.lightwalletd_url.trim().to_string();
+
+        let errors: Vec<String> = ZcashConfigBuilder::new()
+            .network(self.network)
+            .lightwalletd(lightwalletd_url.clone())
+            .birthday_height(birthday_height)
+            .validate()
+            .into_iter()
+            .filter(|e| e.field != ConfigField::Seed)
+            .map(|e| e.to_string())
+            .collect();
+        if !errors.is_empty() {
+            anyhow::bail!(t!("error-invalid-settings", errors = errors.join("; ")));
+        }
+
+        let network_changed = self.config.as_ref().map(|c| c.network) != Some(self.network);
+        let data_dir = network_changed.then(|| network_data_dir(self.network));
+        save_network_profile(
+            self.network,
+            &lightwalletd_url,
+            birthday_height,
+            data_dir.clone(),
+        )?;
+
+        if let Some(config) = &mut self.config {
+            let changed = network_changed
+                || config.lightwalletd_url != lightwalletd_url
+                || config.birthday_height != birthday_height;
+            if let Some(data_dir) = data_dir {
+                config.data_dir = data_dir;
+                config.lightwalletd_fallbacks.clear();
+                config.explorer_url = None;
+            }
+            config.network = self.network;
+            config.lightwalletd_url = lightwalletd_url;
+            config.birthday_height = birthday_height;
+            if changed {
+                self.resync_prompt = true;
+            }
+        }
+        Ok(())
     }
 
     fn load_settings(&mut self) {
@@ -558,8 +1508,33 @@ impl ZotsApp {
                     if let Some(url) = settings.get("explorer_url").and_then(|v| v.as_str()) {
                         self.explorer_url = url.to_string();
                     }
-                    if let Some(url) = settings.get("lightwalletd_url").and_then(|v| v.as_str()) {
-                        self.lightwalletd_url = url.to_string();
+                    if let Some(dir) = settings.get("proofs_dir").and_then(|v| v.as_str()) {
+                        self.proofs_dir = PathBuf::from(dir);
+                        self.proofs_dir_input = dir.to_string();
+                    }
+                    if let Some(interval) = settings
+                        .get("upgrade_interval_minutes")
+                        .and_then(|v| v.as_u64())
+                        .and_then(UpgradeInterval::from_minutes)
+                    {
+                        self.upgrade_interval = interval;
+                    }
+                    if let Some(choice) = settings
+                        .get("theme")
+                        .and_then(|v| v.as_str())
+                        .and_then(ThemeChoice::from_key)
+                    {
+                        self.theme = choice;
+                    }
+                    if let Some(language) = settings
+                        .get("language")
+                        .and_then(|v| v.as_str())
+                        .and_then(Language::from_code)
+                    {
+                        self.language = language;
+                    }
+                    if let Some(done) = settings.get("onboarding_done").and_then(|v| v.as_bool()) {
+                        self.onboarding_done = done;
                     }
                 }
             }
@@ -573,7 +1548,11 @@ impl ZotsApp {
             let settings_path = zots_dir.join("settings.json");
             let settings = serde_json::json!({
                 "explorer_url": self.explorer_url,
-                "lightwalletd_url": self.lightwalletd_url,
+                "proofs_dir": self.proofs_dir,
+                "upgrade_interval_minutes": self.upgrade_interval.minutes(),
+                "theme": self.theme.key(),
+                "language": self.language.code(),
+                "onboarding_done": self.onboarding_done,
             });
             let _ = std::fs::write(
                 &settings_path,
@@ -588,54 +1567,356 @@ impl ZotsApp {
     }
 }
 
+/// Wizard steps that only change the wizard's own state
+fn update_onboarding(onboarding: &mut Onboarding, message: Message) -> Task<Message> {
+    use bip0039::{Count, English, Mnemonic};
+
+    onboarding.error = None;
+    match message {
+        Message::OnboardingCreateSeed => {
+            let mnemonic = Mnemonic::<English>::generate(Count::Words24);
+            onboarding.seed_phrase = mnemonic.phrase().to_string();
+            onboarding.generated = true;
+            onboarding.backup_confirmed = false;
+            onboarding.step = OnboardingStep::Create;
+        }
+        Message::OnboardingImportSeed => {
+            onboarding.seed_phrase.clear();
+            onboarding.generated = false;
+            onboarding.step = OnboardingStep::Import;
+        }
+        Message::OnboardingSeedInputChanged(input) => onboarding.seed_input = input,
+        Message::OnboardingBirthdayChanged(input) => onboarding.birthday_input = input,
+        Message::OnboardingBackupConfirmed(confirmed) => onboarding.backup_confirmed = confirmed,
+        Message::OnboardingNetworkSelected(network) => {
+            // Follow the network unless the user typed their own server
+            if is_default_lightwalletd(&onboarding.server_input) {
+                onboarding.server_input = default_lightwalletd(network).to_string();
+                onboarding.server_info = None;
+            }
+            onboarding.network = network;
+        }
+        Message::OnboardingServerChanged(url) => {
+            onboarding.server_input = url;
+            onboarding.server_info = None;
+        }
+        Message::OnboardingTestServer => {
+            onboarding.testing_server = true;
+            onboarding.server_info = None;
+            let url = onboarding.server_input.trim().to_string();
+            return Task::perform(
+                async move { zots_zcash::probe_lightwalletd(&url).await },
+                |result| Message::OnboardingServerTested(result.map_err(|e| format!("{e:#}"))),
+            );
+        }
+        Message::OnboardingBack => {
+            onboarding.step = match onboarding.step {
+                OnboardingStep::Choice => OnboardingStep::Welcome,
+                OnboardingStep::Create | OnboardingStep::Import => OnboardingStep::Choice,
+                OnboardingStep::ConfirmBackup => OnboardingStep::Create,
+                OnboardingStep::Network if onboarding.generated => OnboardingStep::ConfirmBackup,
+                OnboardingStep::Network => OnboardingStep::Import,
+                OnboardingStep::Server => OnboardingStep::Network,
+                step => step,
+            };
+        }
+        Message::OnboardingNext => match onboarding.step {
+            OnboardingStep::Welcome => onboarding.step = OnboardingStep::Choice,
+            OnboardingStep::Create => onboarding.step = OnboardingStep::ConfirmBackup,
+            OnboardingStep::ConfirmBackup if onboarding.backup_confirmed => {
+                onboarding.step = OnboardingStep::Network;
+            }
+            OnboardingStep::Import => match check_import(onboarding) {
+                Ok(phrase) => {
+                    onboarding.seed_phrase = phrase;
+                    onboarding.seed_input.clear();
+                    onboarding.step = OnboardingStep::Network;
+                }
+                Err(error) => onboarding.error = Some(error),
+            },
+            OnboardingStep::Network => onboarding.step = OnboardingStep::Server,
+            OnboardingStep::Server => {
+                onboarding.step = OnboardingStep::Finishing;
+                return Task::perform(finish_onboarding(onboarding.clone()), |result| {
+                    Message::OnboardingFinished(result.map_err(|e| format!("{e:#}")))
+                });
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+    Task::none()
+}
+
+/// Validate the imported seed phrase and birthday; returns the normalized phrase
+fn check_import(onboarding: &Onboarding) -> std::result::Result<String, String> {
+    use zots_zcash::ConfigField;
+
+    let phrase = onboarding
+        .seed_input
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ");
+    if let Err(errors) = ZcashConfigBuilder::new()
+        .seed(SeedSource::Phrase(phrase.clone()))
+        .build()
+    {
+        let messages: Vec<String> = errors
+            .for_field(ConfigField::Seed)
+            .map(|e| e.message.clone())
+            .collect();
+        if !messages.is_empty() {
+            return Err(t!("error-invalid-seed", errors = messages.join("; ")));
+        }
+    }
+    let birthday = onboarding.birthday_input.trim();
+    if !birthday.is_empty() && birthday.parse::<u64>().is_err() {
+        return Err(t!("error-not-a-height", input = birthday));
+    }
+    Ok(phrase)
+}
+
+/// Default lightwalletd server of `network`
+fn default_lightwalletd(network: Network) -> &'static str {
+    match network {
+        Network::Testnet => DEFAULT_LIGHTWALLETD_URL,
+        Network::Mainnet => zots_zcash::DEFAULT_MAINNET_LIGHTWALLETD_URL,
+    }
+}
+
+fn is_default_lightwalletd(url: &str) -> bool {
+    [Network::Testnet, Network::Mainnet]
+        .into_iter()
+        .any(|network| url == default_lightwalletd(network))
+}
+
+/// Birthday used when the wallet's creation height is unknown
+fn default_birthday(network: Network) -> u64 {
+    match network {
+        Network::Testnet => DEFAULT_BIRTHDAY_HEIGHT,
+        Network::Mainnet => zots_zcash::MAINNET_SAPLING_ACTIVATION_HEIGHT,
+    }
+}
+
+/// Wallet data directory of `network`; mainnet gets its own subdirectory
+fn network_data_dir(network: Network) -> PathBuf {
+    match network {
+        Network::Testnet => default_data_dir(),
+        Network::Mainnet => default_data_dir().join("mainnet"),
+    }
+}
+
+/// Write the wallet network settings to the active config profile
+///
+/// `data_dir` is only given on a network switch, which also drops the
+/// previous network's explorer; otherwise a custom directory survives.
+fn save_network_profile(
+    network: Network,
+    lightwalletd_url: &str,
+    birthday_height: u64,
+    data_dir: Option<PathBuf>,
+) -> Result<()> {
+    use zots_zcash::ConfigFile;
+
+    let path = ConfigFile::default_path();
+    let mut file = ConfigFile::read(&path)?.unwrap_or_default();
+    let profile_name = file.active_profile_name(None);
+    let profile = file.profiles.entry(profile_name.clone()).or_default();
+    profile.network = Some(network.to_string());
+    profile.lightwalletd = Some(lightwalletd_url.to_string());
+    profile.birthday_height = Some(birthday_height);
+    if data_dir.is_some() {
+        profile.data_dir = data_dir;
+        profile.explorer = None;
+    }
+    if file.default_profile.is_none() {
+        file.default_profile = Some(profile_name);
+    }
+    file.save(&path)
+}
+
+/// Save the wizard's choices as the default config profile
+///
+/// Like `zots init` with the keychain option: the seed goes to the OS
+/// keychain and the profile only records `keychain = true`, so the CLI
+/// picks up the same wallet.
+async fn finish_onboarding(onboarding: Onboarding) -> Result<ZcashConfig> {
+    use zots_zcash::{ConfigFile, ProfileConfig, store_seed};
+
+    let network = onboarding.network;
+    // A fresh seed cannot have received funds before the current tip
+    let birthday_height = match (&onboarding.server_info, onboarding.generated) {
+        (Some(info), true) => info.block_height,
+        _ => onboarding
+            .birthday_input
+            .trim()
+            .parse()
+            .unwrap_or(default_birthday(network)),
+    };
+    let path = ConfigFile::default_path();
+    let mut file = ConfigFile::read(&path)?.unwrap_or_default();
+    let profile_name = unused_profile_name(&file, network)?;
+    // A second wallet must not share the first one's database
+    let data_dir = if profile_name == network.to_string() {
+        network_data_dir(network)
+    } else {
+        network_data_dir(network).join(&profile_name)
+    };
+
+    store_seed(&profile_name, &onboarding.seed_phrase)?;
+    file.profiles.insert(
+        profile_name.clone(),
+        ProfileConfig {
+            birthday_height: Some(birthday_height),
+            lightwalletd: Some(onboarding.server_input.trim().to_string()),
+            network: Some(network.to_string()),
+            data_dir: Some(data_dir),
+            hash_algorithm: Some("sha256".to_string()),
+            keychain: Some(true),
+            ..ProfileConfig::default()
+        },
+    );
+    file.default_profile = Some(profile_name.clone());
+    file.save(&path)?;
+
+    ZcashConfig::load(Some(&profile_name))
+}
+
+/// First profile name for `network` that neither the config file nor the
+/// keychain uses yet
+///
+/// Onboarding can run again once a wallet exists, and must not replace a
+/// seed that may control funds.
+fn unused_profile_name(file: &zots_zcash::ConfigFile, network: Network) -> Result<String> {
+    for n in 1u32.. {
+        let name = match n {
+            1 => network.to_string(),
+            2 => format!("desktop-{network}"),
+            n => format!("desktop-{network}-{n}"),
+        };
+        if !file.profiles.contains_key(&name) && zots_zcash::load_seed(&name)?.is_none() {
+            return Ok(name);
+        }
+    }
+    unreachable!("ran out of profile names")
+}
+
+/// Keep the seed in the OS keychain under the active config profile
+///
+/// The profile is switched to `keychain = true` and any seed it stored in
+/// plain text is dropped, so the CLI reads the same seed.
+async fn save_seed(seed_phrase: String) -> Result<ZcashConfig> {
+    use zots_zcash::{ConfigFile, store_seed};
+
+    let path = ConfigFile::default_path();
+    let mut file = ConfigFile::read(&path)?.unwrap_or_default();
+    let profile_name = file.active_profile_name(None);
+
+    store_seed(&profile_name, &seed_phrase)?;
+    let profile = file.profiles.entry(profile_name.clone()).or_default();
+    profile.keychain = Some(true);
+    profile.seed = None;
+    if file.default_profile.is_none() {
+        file.default_profile = Some(profile_name.clone());
+    }
+    file.save(&path)?;
+
+    ZcashConfig::load(Some(&profile_name))
+}
+
+/// Seed phrase the wallet runs with, else the one in the OS keychain
+///
+/// The configured seed wins so that a `ZOTS_SEED` override is what gets
+/// backed up; a keychain seed was already loaded into it at startup.
+async fn stored_seed(configured: Option<String>) -> Result<String> {
+    use zots_zcash::{ConfigFile, load_seed};
+
+    if let Some(seed_phrase) = configured {
+        return Ok(seed_phrase);
+    }
+    let file = ConfigFile::read(&ConfigFile::default_path())?.unwrap_or_default();
+    let profile_name = file.active_profile_name(None);
+    load_seed(&profile_name)?
+        .ok_or_else(|| anyhow::anyhow!("No seed phrase is stored for profile '{profile_name}'"))
+}
+
 // Async operations
 
-async fn initial_sync(config: ZcashConfig) -> Result<(u64, u64)> {
+async fn initial_sync(config: ZcashConfig) -> Result<WalletSnapshot> {
     sync_wallet(config).await
 }
 
-async fn sync_wallet(config: ZcashConfig) -> Result<(u64, u64)> {
+async fn sync_wallet(config: ZcashConfig) -> Result<WalletSnapshot> {
+    use zots_zcash::ZotsWallet;
+
+    let mut wallet = ZotsWallet::new(config).await?;
+    wallet.init_account().await?;
+    wallet.sync().await?;
+
+    Ok(WalletSnapshot {
+        block_height: wallet.get_block_height().await.unwrap_or(0),
+        balance: wallet.get_balance().unwrap_or(0),
+        breakdown: wallet.get_balance_breakdown().unwrap_or_default(),
+        address: wallet.get_address().ok(),
+        transactions: wallet
+            .get_transaction_history(RECENT_TRANSACTIONS)
+            .unwrap_or_default(),
+    })
+}
+
+/// Move transparent funds to the Orchard pool; returns the txid and amount
+///
+/// Like `zots wallet shield --no-wait`: the transaction is broadcast and
+/// confirms in the background.
+async fn shield_funds(config: ZcashConfig) -> Result<(String, u64)> {
     use zots_zcash::ZotsWallet;
 
     let mut wallet = ZotsWallet::new(config).await?;
+    wallet.ensure_can_spend()?;
     wallet.init_account().await?;
     wallet.sync().await?;
 
-    let block_height = wallet.get_block_height().await.unwrap_or(0);
-    let balance = wallet.get_balance().unwrap_or(0);
+    let preview = wallet.preview_shielding()?;
+    let result = wallet.shield_previewed(&preview).await?;
+    Ok((result.txid, preview.amount))
+}
 
-    Ok((block_height, balance))
+fn sync_task(config: ZcashConfig) -> Task<Message> {
+    Task::perform(sync_wallet(config), |result| match result {
+        Ok(snapshot) => Message::WalletSynced(snapshot),
+        Err(e) => Message::WalletSyncFailed(e.to_string()),
+    })
 }
 
 async fn run_stamp(
     config: ZcashConfig,
     input: String,
     algorithm: HashAlgorithm,
+    proofs_dir: PathBuf,
+    mut progress: mpsc::Sender<Message>,
 ) -> Result<StampResult> {
-    use std::path::Path;
     use zots_core::{
         TimestampProof, ZcashAttestation, hash_file_with, hash_from_hex_with, hash_to_hex,
     };
-    use zots_zcash::ZotsWallet;
+    use zots_zcash::{ConfirmationWaitPolicy, ZotsWallet};
 
     // Compute hash
     let path = Path::new(&input);
     let (hash_bytes, output_path) = if path.exists() {
         let h = hash_file_with(path, algorithm)?;
-        let output = PathBuf::from(format!(
-            "{}.zots",
-            path.file_name().unwrap_or_default().to_string_lossy()
-        ));
+        let output = proof_path_for(&proofs_dir, path, &h, &[]);
         (h, output)
     } else if input.len() >= 40 {
         let h = hash_from_hex_with(&input, algorithm)?;
-        let output = PathBuf::from(format!("{}.zots", &input[..16]));
+        let output = proofs_dir.join(format!("{}.zots", &input[..16]));
         (h, output)
     } else {
         anyhow::bail!("File not found and input is not a valid hash");
     };
 
     let hash_hex = hash_to_hex(&hash_bytes);
+    std::fs::create_dir_all(&proofs_dir)?;
 
     // Create wallet and sync
     let mut wallet = ZotsWallet::new(config.clone()).await?;
@@ -643,6 +1924,9 @@ async fn run_stamp(
     wallet.sync().await?;
 
     // Create and broadcast transaction
+    let _ = progress
+        .send(Message::StampProgress(StampPhase::Broadcasting))
+        .await;
     let tx_result = wallet.create_timestamp_tx(&hash_bytes).await?;
     let txid = tx_result.txid.clone();
 
@@ -650,36 +1934,269 @@ async fn run_stamp(
     let current_height = wallet.get_block_height().await.unwrap_or(0) as u32;
     let block_time = chrono::Utc::now().timestamp() as u32;
 
-    // Create proof (pending - not yet confirmed)
+    // Save the pending proof right away so nothing is lost if the app is
+    // closed while waiting; the confirmation watcher completes it later
     let network = config.network;
     let mut proof = TimestampProof::new_with_algorithm(hash_bytes, algorithm);
-    proof.add_attestation(ZcashAttestation::new(
-        network,
-        tx_result.txid_bytes,
-        current_height,
-        block_time,
-        0,
-    ));
-
-    // Save proof
+    proof.set_pending_txid(txid.clone());
     proof.save(&output_path)?;
+    record_pending(&config, |store| store.track(&output_path, &proof));
+    let explorer_link =
+        ZcashAttestation::new(network, tx_result.txid_bytes, current_height, block_time, 0)
+            .explorer_link();
+
+    // Wait for confirmation, reporting progress to the stamp view
+    let policy = ConfirmationWaitPolicy::default();
+    let _ = progress
+        .send(Message::StampProgress(StampPhase::WaitingConfirmation {
+            txid: txid.clone(),
+            confirmations: 0,
+            target: policy.confirmations,
+        }))
+        .await;
+    let confirmation = wallet
+        .wait_confirmation_with(&txid, &policy, |p| {
+            let _ = progress.try_send(Message::StampProgress(StampPhase::WaitingConfirmation {
+                txid: txid.clone(),
+                confirmations: p.confirmations,
+                target: p.target,
+            }));
+        })
+        .await;
+
+    let (block_height, block_time, pending) = match confirmation {
+        Ok(confirmation) => {
+            let mut confirmed = TimestampProof::new_with_algorithm(hash_bytes, algorithm);
+            confirmed.add_attestation(ZcashAttestation::new(
+                network,
+                tx_result.txid_bytes,
+                confirmation.block_height,
+                confirmation.block_time,
+                0,
+            ));
+            confirmed.save(&output_path)?;
+            record_pending(&config, |store| {
+                store.untrack(&output_path);
+            });
+            proof = confirmed;
+            (confirmation.block_height, confirmation.block_time, false)
+        }
+        Err(e) => {
+            // Keep the pending proof; the confirmation watcher completes it once mined
+            tracing::warn!("Confirmation wait ended without confirmation: {e}");
+            (current_height, block_time, true)
+        }
+    };
 
     let compact = proof.to_compact().unwrap_or_default();
-    let explorer_link = proof.attestations[0].explorer_link();
+    let qr = crate::qr::handle(&compact).ok();
 
     Ok(StampResult {
         hash: hash_hex,
-        pending: true, // Mark as pending until confirmed
+        pending,
         algorithm,
         txid,
-        block_height: current_height,
+        block_height,
         block_time: block_time as u64,
         output_path,
         compact,
         explorer_link,
+        qr,
     })
 }
 
+/// Proof file written for a stamped file: `<name>.zots` in the proofs directory
+///
+/// Files with the same name from different folders must not overwrite each
+/// other's proofs, so a name taken by a proof of another hash (or reserved
+/// for another file of the same batch) gets a `-2`, `-3`, ... suffix.
+fn proof_path_for(
+    proofs_dir: &Path,
+    path: &Path,
+    hash: &[u8; 32],
+    reserved: &[PathBuf],
+) -> PathBuf {
+    proof_candidates(proofs_dir, path)
+        .find(|candidate| {
+            !reserved.contains(candidate)
+                && (!candidate.exists()
+                    || zots_core::TimestampProof::load(candidate)
+                        .and_then(|proof| proof.hash_bytes())
+                        .is_ok_and(|existing| existing == *hash))
+        })
+        .expect("candidate names are unbounded")
+}
+
+/// `<name>.zots`, then `<name>-2.zots`, `<name>-3.zots`, ... in the proofs directory
+fn proof_candidates(proofs_dir: &Path, document: &Path) -> impl Iterator<Item = PathBuf> {
+    let name = document
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    let proofs_dir = proofs_dir.to_path_buf();
+    (1u32..).map(move |n| match n {
+        1 => proofs_dir.join(format!("{name}.zots")),
+        n => proofs_dir.join(format!("{name}-{n}.zots")),
+    })
+}
+
+/// Default proofs directory: `zots` in the user's documents, else the working directory
+fn default_proofs_dir() -> PathBuf {
+    dirs::document_dir()
+        .map(|dir| dir.join("zots"))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Update the pending proof registry shared with the CLI
+///
+/// Failing to update it only logs a warning; the proof files are saved.
+fn record_pending(config: &ZcashConfig, update: impl FnOnce(&mut zots_core::PendingStore)) {
+    let result = zots_core::PendingStore::update(config.pending_store_path(), update);
+    if let Err(e) = result {
+        tracing::warn!("Could not update the pending proof registry: {e}");
+    }
+}
+
+/// Stamp queued files in one transaction
+///
+/// Files are hashed one by one (pausable, and cancellable up to the
+/// broadcast), then every hash goes out in a single batch transaction. Proofs
+/// are saved as pending right after the broadcast and completed once the
+/// transaction confirms; cancelling while waiting leaves them pending.
+async fn run_batch_stamp(
+    config: ZcashConfig,
+    files: Vec<(usize, PathBuf)>,
+    algorithm: HashAlgorithm,
+    proofs_dir: PathBuf,
+    control: BatchControl,
+    mut progress: mpsc::Sender<Message>,
+) -> Result<()> {
+    use zots_core::{TimestampProof, ZcashAttestation, hash_file_with};
+    use zots_zcash::{ConfirmationWaitPolicy, ZotsWallet};
+
+    let mut hashed = Vec::with_capacity(files.len());
+    for (index, path) in files {
+        control.checkpoint().await?;
+        let _ = progress
+            .send(Message::BatchItemProgress(index, QueuePhase::Hashing))
+            .await;
+        let file = path.clone();
+        let phase =
+            match tokio::task::spawn_blocking(move || hash_file_with(&file, algorithm)).await? {
+                Ok(hash) => {
+                    hashed.push((index, path, hash));
+                    QueuePhase::Hashed
+                }
+                Err(e) => QueuePhase::Failed(e.to_string()),
+            };
+        let _ = progress
+            .send(Message::BatchItemProgress(index, phase))
+            .await;
+    }
+    if hashed.is_empty() {
+        anyhow::bail!("No file could be hashed");
+    }
+    // Pick every proof name before paying, so no two files share one
+    let mut reserved = Vec::with_capacity(hashed.len());
+    let hashed: Vec<_> = hashed
+        .into_iter()
+        .map(|(index, path, hash)| {
+            let output_path = proof_path_for(&proofs_dir, &path, &hash, &reserved);
+            reserved.push(output_path.clone());
+            (index, hash, output_path)
+        })
+        .collect();
+
+    let mut wallet = ZotsWallet::new(config.clone()).await?;
+    wallet.init_account().await?;
+    wallet.sync().await?;
+
+    // Last chance to back out: nothing has been spent yet
+    control.checkpoint().await?;
+    for (index, _, _) in &hashed {
+        let _ = progress
+            .send(Message::BatchItemProgress(*index, QueuePhase::Broadcasting))
+            .await;
+    }
+    let hashes: Vec<[u8; 32]> = hashed.iter().map(|(_, hash, _)| *hash).collect();
+    let tx_result = wallet.create_batch_timestamp_tx(&hashes).await?;
+    let txid = tx_result.txid.clone();
+
+    // Save pending proofs right away so nothing is lost if the app is closed
+    std::fs::create_dir_all(&proofs_dir)?;
+    let mut pending = Vec::with_capacity(hashed.len());
+    for (_, hash, output_path) in &hashed {
+        let mut proof = TimestampProof::new_with_algorithm(*hash, algorithm);
+        proof.set_pending_txid(txid.clone());
+        proof.save(output_path)?;
+        pending.push((output_path.clone(), proof));
+    }
+    record_pending(&config, |store| {
+        for (output_path, proof) in &pending {
+            store.track(output_path, proof);
+        }
+    });
+
+    let policy = ConfirmationWaitPolicy::default();
+    let waiting = |confirmations, target| {
+        hashed.iter().map(move |(index, _, _)| {
+            Message::BatchItemProgress(
+                *index,
+                QueuePhase::WaitingConfirmation {
+                    confirmations,
+                    target,
+                },
+            )
+        })
+    };
+    for message in waiting(0, policy.confirmations) {
+        let _ = progress.send(message).await;
+    }
+    let mut reporter = progress.clone();
+    let confirmation = tokio::select! {
+        confirmation = wallet.wait_confirmation_with(&txid, &policy, |p| {
+            for message in waiting(p.confirmations, p.target) {
+                let _ = reporter.try_send(message);
+            }
+        }) => match confirmation {
+            Ok(confirmation) => Some(confirmation),
+            Err(e) => {
+                // Keep the pending proofs; they can be upgraded once the TX is mined
+                tracing::warn!("Confirmation wait ended without confirmation: {e}");
+                None
+            }
+        },
+        () = control.cancelled() => None,
+    };
+
+    for (index, hash, output_path) in &hashed {
+        if let Some(confirmation) = &confirmation {
+            let mut proof = TimestampProof::new_with_algorithm(*hash, algorithm);
+            proof.add_attestation(ZcashAttestation::new(
+                config.network,
+                tx_result.txid_bytes,
+                confirmation.block_height,
+                confirmation.block_time,
+                0,
+            ));
+            proof.save(output_path)?;
+            record_pending(&config, |store| {
+                store.untrack(output_path);
+            });
+        }
+        let _ = progress
+            .send(Message::BatchItemProgress(
+                *index,
+                QueuePhase::Done {
+                    pending: confirmation.is_none(),
+                },
+            ))
+            .await;
+    }
+    Ok(())
+}
+
 async fn run_verify(
     config: Option<ZcashConfig>,
     file_input: String,
@@ -693,6 +2210,7 @@ async fn run_verify(
     let proof_hash_bytes = proof.hash_bytes()?;
     let algorithm = proof.hash_algorithm();
     let compact = proof.to_compact().unwrap_or_default();
+    let qr = crate::qr::handle(&compact).ok();
 
     // Check file/hash match
     let file_hash_matches = if !file_input.is_empty() {
@@ -718,8 +2236,10 @@ async fn run_verify(
             timestamp: String::new(),
             txid: String::new(),
             explorer_link: String::new(),
-            error: Some("Hash does NOT match the provided file/hash!".to_string()),
+            error: Some(t!("verify-error-hash-mismatch")),
             file_hash_matches: Some(false),
+            note: proof.note().map(str::to_string),
+            qr,
         });
     }
 
@@ -734,8 +2254,10 @@ async fn run_verify(
             timestamp: String::new(),
             txid: String::new(),
             explorer_link: String::new(),
-            error: Some("Proof is pending (no attestations)".to_string()),
+            error: Some(t!("verify-error-pending")),
             file_hash_matches,
+            note: proof.note().map(str::to_string),
+            qr,
         });
     }
 
@@ -763,6 +2285,8 @@ async fn run_verify(
             explorer_link: att.explorer_link(),
             error: vr.error,
             file_hash_matches,
+            note: proof.note().map(str::to_string),
+            qr,
         })
     } else {
         // No wallet, just show proof info
@@ -776,55 +2300,262 @@ async fn run_verify(
             timestamp: att.timestamp().to_rfc3339(),
             txid: att.txid_hex().to_string(),
             explorer_link: att.explorer_link(),
-            error: Some("Cannot verify on-chain (no wallet)".to_string()),
+            error: Some(t!("verify-error-no-wallet")),
             file_hash_matches,
+            note: proof.note().map(str::to_string),
+            qr,
         })
     }
 }
 
-async fn load_history() -> Vec<HistoryEntry> {
-    use zots_core::TimestampProof;
+/// Proofs under the proofs directory, plus pending proofs tracked elsewhere
+async fn load_history(proofs_dir: PathBuf, pending_store: Option<PathBuf>) -> Vec<HistoryEntry> {
+    use zots_core::{PendingStore, TimestampProof, find_proofs_with};
+
+    let mut paths = find_proofs_with(&proofs_dir, |path, e| {
+        tracing::warn!("Skipping {}: {e}", path.display());
+    })
+    .unwrap_or_default();
+
+    // Pending proofs saved elsewhere, e.g. by the CLI, are in the shared registry
+    let tracked = pending_store.and_then(|path| PendingStore::load(path).ok());
+    for entry in tracked.iter().flat_map(|store| store.entries()) {
+        let listed = paths
+            .iter()
+            .any(|path| std::path::absolute(path).is_ok_and(|path| path == entry.proof_path));
+        if !listed {
+            paths.push(entry.proof_path.clone());
+        }
+    }
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let proof = TimestampProof::load(path).ok()?;
+            Some(history_entry(path, &proof))
+        })
+        .collect()
+}
+
+fn history_entry(path: &Path, proof: &zots_core::TimestampProof) -> HistoryEntry {
+    let confirmed = !proof.attestations.is_empty();
+    let (network, block_height) = if confirmed {
+        let att = &proof.attestations[0];
+        (Some(att.network.to_string()), Some(att.block_height))
+    } else {
+        (None, None)
+    };
+
+    HistoryEntry {
+        path: path.to_path_buf(),
+        hash: proof.hash.clone(),
+        algorithm: proof.hash_algorithm(),
+        created: std::fs::metadata(path)
+            .ok()
+            .and_then(|m| m.created().ok())
+            .map(|t| {
+                chrono::DateTime::<chrono::Local>::from(t)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|| t!("label-unknown")),
+        confirmed,
+        network,
+        block_height,
+        note: proof.note().map(str::to_string),
+    }
+}
 
-    let mut entries = Vec::new();
+/// Emit [`Message::ProofsChanged`] when `.zots` files change under `dir`
+fn watch_proofs(dir: PathBuf) -> impl iced::futures::Stream<Item = Message> {
+    use notify::{RecursiveMode, Watcher};
+
+    iced::stream::channel(16, move |mut output: mpsc::Sender<Message>| async move {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            if let Ok(event) = event
+                && event
+                    .paths
+                    .iter()
+                    .any(|path| path.extension().is_some_and(|ext| ext == "zots"))
+            {
+                let _ = tx.send(());
+            }
+        });
+        let mut watcher = match watcher {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                tracing::warn!("Cannot watch {}: {e}", dir.display());
+                return;
+            }
+        };
+        if let Err(e) = watcher.watch(&dir, RecursiveMode::Recursive) {
+            tracing::warn!("Cannot watch {}: {e}", dir.display());
+            return;
+        }
 
-    // Look for .zots files in current directory
-    if let Ok(read_dir) = std::fs::read_dir(".") {
-        for entry in read_dir.flatten() {
-            let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) == Some("zots") {
-                if let Ok(proof) = TimestampProof::load(&path) {
-                    let confirmed = !proof.attestations.is_empty();
-                    let (network, block_height) = if confirmed {
-                        let att = &proof.attestations[0];
-                        (Some(att.network.to_string()), Some(att.block_height))
-                    } else {
-                        (None, None)
-                    };
+        while rx.recv().await.is_some() {
+            // Coalesce the burst of events a single save produces
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            while rx.try_recv().is_ok() {}
+            if output.send(Message::ProofsChanged).await.is_err() {
+                break;
+            }
+        }
+    })
+}
 
-                    entries.push(HistoryEntry {
-                        path: path.clone(),
-                        hash: proof.hash.clone(),
-                        algorithm: proof.hash_algorithm(),
-                        created: entry
-                            .metadata()
-                            .ok()
-                            .and_then(|m| m.created().ok())
-                            .map(|t| {
-                                chrono::DateTime::<chrono::Local>::from(t)
-                                    .format("%Y-%m-%d %H:%M")
-                                    .to_string()
-                            })
-                            .unwrap_or_else(|| "Unknown".to_string()),
-                        confirmed,
-                        network,
-                        block_height,
-                    });
+/// Held for the duration of an upgrade pass, so two passes never attach the
+/// same confirmation
+static UPGRADE_PASS: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Upgrade pending proofs every `interval`
+///
+/// Each pass covers the pending registry shared with the CLI and the pending
+/// proofs in the proofs directory, and announces every confirmation with a
+/// desktop notification. Keeps running while the window is hidden in the tray.
+/// A pass is skipped while a manual one is still running.
+fn schedule_upgrades(
+    config: ZcashConfig,
+    proofs_dir: PathBuf,
+    interval: Duration,
+) -> impl iced::futures::Stream<Item = Message> {
+    iced::stream::channel(16, move |mut output: mpsc::Sender<Message>| async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            let Ok(_pass) = UPGRADE_PASS.try_lock() else {
+                continue;
+            };
+            let message = match upgrade_pending(&config, &proofs_dir).await {
+                Ok(report) => {
+                    for (proof_path, block_height) in &report.upgraded {
+                        notify_confirmed(proof_path, *block_height);
+                    }
+                    Message::PendingUpgraded {
+                        report,
+                        manual: false,
+                    }
                 }
+                Err(e) => Message::PendingUpgradeFailed {
+                    error: e.to_string(),
+                    manual: false,
+                },
+            };
+            if output.send(message).await.is_err() {
+                return;
+            }
+        }
+    })
+}
+
+/// Attach the confirmation of every mined pending proof
+///
+/// Candidates are the proofs tracked in the pending registry plus the pending
+/// proofs under `proofs_dir`. Tracked proofs that no longer exist are left for
+/// `zots pending upgrade-all` to report.
+async fn upgrade_pending(config: &ZcashConfig, proofs_dir: &Path) -> Result<UpgradeReport> {
+    use std::collections::{BTreeMap, BTreeSet};
+    use zots_core::{PendingStore, TimestampProof, ZcashAttestation, find_proofs_with};
+    use zots_zcash::ZotsWallet;
+
+    let mut report = UpgradeReport {
+        checked_at: chrono::Local::now(),
+        upgraded: Vec::new(),
+        pending: 0,
+        failed: Vec::new(),
+    };
+
+    let store = PendingStore::load(config.pending_store_path())?;
+    // Tracked proofs with the txid the registry recorded for them
+    let tracked = store
+        .entries()
+        .iter()
+        .map(|entry| Ok((std::path::absolute(&entry.proof_path)?, entry.txid.clone())))
+        .collect::<std::io::Result<BTreeMap<_, _>>>()?;
+    let mut candidates: BTreeSet<PathBuf> = tracked.keys().cloned().collect();
+    if proofs_dir.is_dir() {
+        let found = find_proofs_with(proofs_dir, |path, e| {
+            tracing::warn!("Skipping {}: {e}", path.display());
+        })?;
+        for path in found {
+            candidates.insert(std::path::absolute(path)?);
+        }
+    }
+
+    let mut pending = Vec::new();
+    for path in candidates {
+        let Ok(proof) = TimestampProof::load(&path) else {
+            continue;
+        };
+        if proof.is_confirmed() {
+            if tracked.contains_key(&path) {
+                record_pending(config, |store| {
+                    store.untrack(&path);
+                });
             }
+        } else if let Some(txid) = proof
+            .pending_txid
+            .clone()
+            .or_else(|| tracked.get(&path).cloned().flatten())
+        {
+            pending.push((path, proof, txid));
         }
     }
+    if pending.is_empty() {
+        return Ok(report);
+    }
+
+    let mut wallet = ZotsWallet::new(config.clone()).await?;
+    for (path, mut proof, txid) in pending {
+        let confirmation = match wallet.fetch_confirmation(&txid).await {
+            Ok(Some(confirmation)) => confirmation,
+            Ok(None) => {
+                report.pending += 1;
+                continue;
+            }
+            Err(e) => {
+                report.failed.push((path, e.to_string()));
+                continue;
+            }
+        };
+
+        let attestation = ZcashAttestation {
+            network: config.network,
+            txid: txid.to_lowercase(),
+            block_height: confirmation.block_height,
+            block_time: confirmation.block_time,
+            memo_offset: 0,
+        };
+        if let Err(e) = attestation.txid_bytes() {
+            report.failed.push((path, e.to_string()));
+            continue;
+        }
+        proof.add_attestation(attestation);
+        if let Err(e) = proof.save(&path) {
+            report.failed.push((path, e.to_string()));
+            continue;
+        }
+        record_pending(config, |store| {
+            store.untrack(&path);
+        });
+        report.upgraded.push((path, confirmation.block_height));
+    }
+    Ok(report)
+}
 
-    entries
+fn notify_confirmed(proof_path: &Path, block_height: u32) {
+    let name = proof_path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_else(|| proof_path.display().to_string());
+    let result = notify_rust::Notification::new()
+        .appname("zOpenTimestamps")
+        .summary(&t!("notify-confirmed-title"))
+        .body(&t!("notify-confirmed", name = name, block = block_height))
+        .show();
+    if let Err(e) = result {
+        tracing::warn!("Could not show notification: {e}");
+    }
 }
 
 async fn delete_proof(path: PathBuf) -> Result<PathBuf> {
@@ -834,15 +2565,32 @@ async fn delete_proof(path: PathBuf) -> Result<PathBuf> {
 
 async fn pick_file() -> Option<PathBuf> {
     rfd::AsyncFileDialog::new()
-        .set_title("Select file to timestamp")
+        .set_title(t!("dialog-pick-file"))
         .pick_file()
         .await
         .map(|f| f.path().to_path_buf())
 }
 
+async fn pick_files() -> Vec<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .set_title(t!("dialog-pick-files"))
+        .pick_files()
+        .await
+        .map(|files| files.iter().map(|f| f.path().to_path_buf()).collect())
+        .unwrap_or_default()
+}
+
+async fn pick_folder() -> Option<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .set_title(t!("dialog-pick-proofs-dir"))
+        .pick_folder()
+        .await
+        .map(|f| f.path().to_path_buf())
+}
+
 async fn pick_proof_file() -> Option<PathBuf> {
     rfd::AsyncFileDialog::new()
-        .set_title("Select proof file")
+        .set_title(t!("dialog-pick-proof"))
         .add_filter("zots", &["zots"])
         .add_filter("JSON", &["json"])
         .pick_file()
@@ -850,6 +2598,99 @@ async fn pick_proof_file() -> Option<PathBuf> {
         .map(|f| f.path().to_path_buf())
 }
 
+/// The proof of a document: `foo.pdf.zots` next to `foo.pdf` if it exists,
+/// else the proof this app saved for it in `proofs_dir`
+///
+/// Same-named documents from other folders get `-2`, `-3`, ... proofs there
+/// (see [`proof_path_for`]), so only a proof of the document's hash is used.
+fn proof_for_document(document: &Path, proofs_dir: &Path) -> Option<PathBuf> {
+    let mut name = document.file_name()?.to_os_string();
+    name.push(".zots");
+    let proof = document.with_file_name(name);
+    if proof.is_file() {
+        return Some(proof);
+    }
+    proof_candidates(proofs_dir, document)
+        .take_while(|candidate| candidate.is_file())
+        .find(|candidate| {
+            zots_core::TimestampProof::load(candidate).is_ok_and(|proof| {
+                proof.hash_bytes().is_ok_and(|hash| {
+                    zots_core::hash_file_with(document, proof.hash_algorithm())
+                        .is_ok_and(|document_hash| document_hash == hash)
+                })
+            })
+        })
+}
+
+/// `foo.pdf` next to `foo.pdf.zots`, if it exists
+fn document_for_proof(proof: &Path) -> Option<PathBuf> {
+    let document = proof.with_extension("");
+    document.is_file().then_some(document)
+}
+
+/// Save a proof file as a printable certificate
+///
+/// The format follows the chosen extension: `.html`/`.htm` gives HTML,
+/// anything else PDF.
+async fn export_certificate(
+    proof_path: PathBuf,
+    explorer_url: Option<String>,
+) -> Result<Option<PathBuf>> {
+    use zots_core::{Certificate, TimestampProof};
+
+    let proof = TimestampProof::load(&proof_path)?;
+    let stem = proof_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "proof".to_string());
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_title(t!("dialog-export-certificate"))
+        .set_file_name(format!("{stem}-certificate.pdf"))
+        .add_filter("PDF", &["pdf"])
+        .add_filter("HTML", &["html", "htm"])
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    let path = file.path().to_path_buf();
+
+    let mut certificate = Certificate::new(&proof);
+    // Proofs too large for a QR code still get a certificate
+    if let Ok(qr) = crate::qr::matrix(&proof.to_compact()?) {
+        certificate = certificate.with_qr(qr);
+    }
+    if let Some(base) = explorer_url {
+        certificate = certificate.with_explorer_url(base);
+    }
+    let is_html = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("html") || e.eq_ignore_ascii_case("htm"));
+    if is_html {
+        std::fs::write(&path, certificate.to_html()?)?;
+    } else {
+        std::fs::write(&path, certificate.to_pdf()?)?;
+    }
+    Ok(Some(path))
+}
+
+/// Ask where to save the QR code of a compact proof, then write it as PNG
+async fn save_qr(compact: String) -> Result<Option<PathBuf>> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_title(t!("dialog-save-qr"))
+        .set_file_name("proof-qr.png")
+        .add_filter("PNG", &["png"])
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    let path = file.path().to_path_buf();
+    crate::qr::save_png(&compact, &path)?;
+    Ok(Some(path))
+}
+
 async fn copy_to_clipboard(_text: String) -> Result<()> {
     // Clipboard is handled by iced's clipboard feature
     Ok(())
@@ -863,10 +2704,17 @@ pub fn run() -> iced::Result {
     // Load environment variables
     let _ = dotenvy::dotenv();
 
+    // File managers pass the proof path when a .zots file is opened with us
+    let open_proof = std::env::args_os()
+        .skip(1)
+        .map(PathBuf::from)
+        .find(|path| path.extension().is_some_and(|ext| ext == "zots"));
+
     iced::application(ZotsApp::title, ZotsApp::update, ZotsApp::view)
         .subscription(ZotsApp::subscription)
         .theme(ZotsApp::theme)
         .window_size((1200.0, 800.0))
+        .exit_on_close_request(false)
         .antialiasing(true)
-        .run_with(ZotsApp::new)
+        .run_with(move || ZotsApp::new(open_proof))
 }
@@ -0,0 +1,140 @@
+//! Localized view strings
+//!
+//! Every string the views show is looked up in
+//! `locales/<lang>/zots-desktop.ftl`, compiled into the binary and formatted
+//! by [`zots_core::i18n`]. The language can be switched at runtime from
+//! Settings; "System default" follows `ZOTS_LANG`, then the POSIX locale
+//! variables.
+//!
+//! Adding a translation only needs its `.ftl` file and an entry in `LOCALES`.
+
+use std::fmt;
+use std::sync::{LazyLock, RwLock};
+use zots_core::i18n::{FluentArgs, Locale, Localizer, env_locale, language_code};
+
+/// Supported locales; the first is the fallback
+const LOCALES: &[Locale] = &[
+    Locale {
+        code: "en",
+        name: "English",
+        source: include_str!("../locales/en/zots-desktop.ftl"),
+    },
+    Locale {
+        code: "es",
+        name: "Español",
+        source: include_str!("../locales/es/zots-desktop.ftl"),
+    },
+];
+
+static LOCALIZER: LazyLock<RwLock<Localizer>> =
+    LazyLock::new(|| RwLock::new(Localizer::new(LOCALES)));
+
+/// Language picked in Settings
+///
+/// `Language::SYSTEM` follows the environment; the others name a bundled
+/// locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Language(Option<&'static str>);
+
+impl Language {
+    pub const SYSTEM: Language = Language(None);
+
+    /// The system default followed by every bundled locale
+    pub fn all() -> Vec<Language> {
+        std::iter::once(Language::SYSTEM)
+            .chain(LOCALES.iter().map(|locale| Language(Some(locale.code))))
+            .collect()
+    }
+
+    /// Code stored in settings.json, `None` for the system default
+    pub fn code(self) -> Option<&'static str> {
+        self.0
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        language_code(LOCALES, code).map(|code| Language(Some(code)))
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            None => f.write_str(&tr("language-system", None)),
+            // Native names, so a language can be found without reading the current one
+            Some(code) => {
+                let name = LOCALES
+                    .iter()
+                    .find(|locale| locale.code == code)
+                    .map_or(code, |locale| locale.name);
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+/// Switch the language of every view
+pub fn set_language(language: Language) {
+    let requested = language
+        .code()
+        .map(str::to_string)
+        .or_else(|| std::env::var("ZOTS_LANG").ok())
+        .or_else(env_locale);
+    if let Ok(mut localizer) = LOCALIZER.write() {
+        localizer.select(requested.as_deref());
+    }
+}
+
+/// Format the message `id`, falling back to English and then to the id itself
+pub fn tr(id: &str, args: Option<&FluentArgs>) -> String {
+    match LOCALIZER.read() {
+        Ok(localizer) => localizer.format(id, args),
+        Err(_) => id.to_string(),
+    }
+}
+
+/// Format a localized message: `t!("id")` or `t!("id", name = value, ...)`
+macro_rules! t {
+    ($id:expr) => {
+        $crate::i18n::tr($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = zots_core::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::tr($id, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translations_only_use_english_keys() {
+        let localizer = Localizer::new(LOCALES);
+        for locale in &LOCALES[1..] {
+            assert_eq!(localizer.unknown_ids(locale.code), Vec::<&str>::new());
+        }
+    }
+
+    #[test]
+    fn test_fallback() {
+        let mut localizer = Localizer::new(LOCALES);
+        let english = localizer.format("language-system", None);
+        localizer.select(Some("fr_FR.UTF-8"));
+        assert_eq!(localizer.format("language-system", None), english);
+        localizer.select(Some("es"));
+        assert_ne!(localizer.format("language-system", None), english);
+        assert_eq!(localizer.format("no-such-message", None), "no-such-message");
+    }
+
+    #[test]
+    fn test_language_codes() {
+        assert_eq!(
+            Language::from_code("es_ES.UTF-8").and_then(Language::code),
+            Some("es")
+        );
+        assert!(Language::from_code("fr").is_none());
+        assert_eq!(Language::all().len(), LOCALES.len() + 1);
+    }
+}
@@ -3,8 +3,11 @@
 //! A modern desktop GUI for Zcash blockchain timestamping.
 
 pub mod app;
+pub mod i18n;
 pub mod message;
+pub mod qr;
 pub mod theme;
+pub mod tray;
 pub mod views;
 
 pub use app::run;
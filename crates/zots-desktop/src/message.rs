@@ -1,7 +1,14 @@
 //! Application messages for iced
 
+use crate::i18n::{Language, t};
+use crate::theme::ThemeChoice;
+use iced::widget::image::Handle;
+use iced::window;
+use std::fmt;
 use std::path::PathBuf;
-use zots_core::HashAlgorithm;
+use std::time::Duration;
+use zots_core::{HashAlgorithm, Network, TimestampProof};
+use zots_zcash::{BalanceBreakdown, LightwalletdInfo, ZcashConfig};
 
 /// Main application message type
 #[derive(Debug, Clone)]
@@ -12,11 +19,38 @@ pub enum Message {
     // Wallet operations
     SeedInputChanged(String),
     SaveSeed,
+    SeedSaved(Result<ZcashConfig, String>),
+    RevealSeed,
+    ConfirmRevealSeed,
+    SeedRevealed(Result<String, String>),
+    HideSeed,
     SyncWallet,
-    WalletSynced { block_height: u64, balance: u64 },
+    WalletSynced(WalletSnapshot),
     WalletSyncFailed(String),
-    InitialSyncComplete { block_height: u64, balance: u64 },
+    InitialSyncComplete(WalletSnapshot),
     InitialSyncFailed,
+    WatchForFunds,
+    StopWatchingForFunds,
+    PollBalance,
+    ShieldFunds,
+    /// Shielding transaction broadcast: txid and shielded amount
+    FundsShielded(Result<(String, u64), String>),
+
+    // First-run wizard
+    StartOnboarding,
+    SkipOnboarding,
+    OnboardingBack,
+    OnboardingNext,
+    OnboardingCreateSeed,
+    OnboardingImportSeed,
+    OnboardingSeedInputChanged(String),
+    OnboardingBirthdayChanged(String),
+    OnboardingBackupConfirmed(bool),
+    OnboardingNetworkSelected(Network),
+    OnboardingServerChanged(String),
+    OnboardingTestServer,
+    OnboardingServerTested(Result<LightwalletdInfo, String>),
+    OnboardingFinished(Result<ZcashConfig, String>),
 
     // Stamp operations
     StampInputChanged(String),
@@ -28,6 +62,18 @@ pub enum Message {
     StampComplete(StampResult),
     StampFailed(String),
 
+    // Batch stamping queue
+    AddFilesToQueue,
+    FilesAddedToQueue(Vec<PathBuf>),
+    RemoveQueuedFile(usize),
+    ClearStampQueue,
+    StartBatch,
+    PauseBatch,
+    ResumeBatch,
+    CancelBatch,
+    BatchItemProgress(usize, QueuePhase),
+    BatchFinished(Option<String>),
+
     // Verify operations
     VerifyFileInputChanged(String),
     VerifyProofInputChanged(String),
@@ -44,18 +90,61 @@ pub enum Message {
     HistoryLoaded(Vec<HistoryEntry>),
     DeleteProof(PathBuf),
     ProofDeleted(PathBuf),
+    /// A `.zots` file changed in the proofs directory
+    ProofsChanged,
+    OpenProofDetail(PathBuf),
+    CloseProofDetail,
+    VerifyProofDetail,
+    ProofDetailVerified(PathBuf, VerifyResult),
+    ProofDetailVerifyFailed(PathBuf, String),
 
     // Settings
     ExplorerUrlChanged(String),
     LightwalletdUrlChanged(String),
+    NetworkChanged(Network),
+    BirthdayHeightChanged(String),
+    ProofsDirChanged(String),
+    SelectProofsDir,
+    ProofsDirSelected(Option<PathBuf>),
+    ThemeChanged(ThemeChoice),
+    LanguageChanged(Language),
     SaveSettings,
     ResetSettings,
+    DismissResyncPrompt,
+
+    // Window file drag-and-drop
+    FileDropped(PathBuf),
+    FilesHovered,
+    FilesHoveredLeft,
+
+    // Tray
+    WindowCloseRequested(window::Id),
+    ShowWindow,
+    Quit,
+
+    // Pending proof upgrades
+    UpgradeIntervalChanged(UpgradeInterval),
+    UpgradePendingNow,
+    /// An upgrade pass finished; `manual` when started from the History view
+    PendingUpgraded {
+        report: UpgradeReport,
+        manual: bool,
+    },
+    PendingUpgradeFailed {
+        error: String,
+        manual: bool,
+    },
 
     // UI
     Tick,
     CopyToClipboard(String),
     Copied,
     OpenExplorer(String),
+    SaveQrCode(String),
+    QrCodeSaved(Result<Option<PathBuf>, String>),
+    /// Export the proof file at this path as a printable certificate
+    ExportCertificate(PathBuf),
+    CertificateExported(Result<Option<PathBuf>, String>),
     DismissResult,
 }
 
@@ -72,14 +161,14 @@ pub enum View {
 }
 
 impl View {
-    pub fn title(&self) -> &'static str {
+    pub fn title(&self) -> String {
         match self {
-            View::Home => "Home",
-            View::Stamp => "Stamp",
-            View::Verify => "Verify",
-            View::History => "History",
-            View::Wallet => "Wallet",
-            View::Settings => "Settings",
+            View::Home => t!("view-home"),
+            View::Stamp => t!("view-stamp"),
+            View::Verify => t!("view-verify"),
+            View::History => t!("view-history"),
+            View::Wallet => t!("view-wallet"),
+            View::Settings => t!("view-settings"),
         }
     }
 
@@ -101,7 +190,11 @@ pub enum StampPhase {
     Idle,
     Syncing,
     Broadcasting,
-    WaitingConfirmation { txid: String },
+    WaitingConfirmation {
+        txid: String,
+        confirmations: u32,
+        target: u32,
+    },
     Complete,
     Failed,
 }
@@ -120,16 +213,75 @@ impl StampPhase {
         )
     }
 
-    pub fn message(&self) -> &str {
+    pub fn message(&self) -> String {
+        match self {
+            StampPhase::Idle => t!("wallet-ready"),
+            StampPhase::Syncing => t!("phase-syncing"),
+            StampPhase::Broadcasting => t!("phase-broadcasting"),
+            StampPhase::WaitingConfirmation {
+                confirmations,
+                target,
+                ..
+            } => t!(
+                "phase-waiting-confirmation",
+                confirmations = *confirmations,
+                target = *target
+            ),
+            StampPhase::Complete => t!("phase-complete"),
+            StampPhase::Failed => t!("phase-failed"),
+        }
+    }
+}
+
+/// Phase of one file in the batch stamping queue
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueuePhase {
+    Queued,
+    Hashing,
+    Hashed,
+    Broadcasting,
+    WaitingConfirmation { confirmations: u32, target: u32 },
+    Done { pending: bool },
+    Failed(String),
+    Cancelled,
+}
+
+impl QueuePhase {
+    pub fn label(&self) -> String {
         match self {
-            StampPhase::Idle => "Ready",
-            StampPhase::Syncing => "Syncing wallet...",
-            StampPhase::Broadcasting => "Broadcasting transaction...",
-            StampPhase::WaitingConfirmation { .. } => "Waiting for confirmation...",
-            StampPhase::Complete => "Timestamp created!",
-            StampPhase::Failed => "Operation failed",
+            QueuePhase::Queued => t!("queue-queued"),
+            QueuePhase::Hashing => t!("queue-hashing"),
+            QueuePhase::Hashed => t!("queue-hashed"),
+            QueuePhase::Broadcasting => t!("queue-broadcasting"),
+            QueuePhase::WaitingConfirmation {
+                confirmations,
+                target,
+            } => t!(
+                "queue-waiting",
+                confirmations = *confirmations,
+                target = *target
+            ),
+            QueuePhase::Done { pending: false } => t!("queue-confirmed"),
+            QueuePhase::Done { pending: true } => t!("queue-pending"),
+            QueuePhase::Failed(error) => t!("queue-failed", error = error.as_str()),
+            QueuePhase::Cancelled => t!("queue-cancelled"),
         }
     }
+
+    /// Whether the next batch should (re)stamp this file
+    pub fn needs_stamp(&self) -> bool {
+        matches!(
+            self,
+            QueuePhase::Queued | QueuePhase::Failed(_) | QueuePhase::Cancelled
+        )
+    }
+}
+
+/// File in the batch stamping queue
+#[derive(Debug, Clone)]
+pub struct QueueItem {
+    pub path: PathBuf,
+    pub phase: QueuePhase,
 }
 
 /// Result of a successful stamp operation
@@ -144,6 +296,8 @@ pub struct StampResult {
     pub compact: String,
     pub explorer_link: String,
     pub pending: bool, // True if not yet confirmed
+    /// QR code of `compact`, if it fits in one
+    pub qr: Option<Handle>,
 }
 
 /// Result of a verify operation
@@ -160,6 +314,10 @@ pub struct VerifyResult {
     pub explorer_link: String,
     pub error: Option<String>,
     pub file_hash_matches: Option<bool>,
+    /// Note recorded at stamp time
+    pub note: Option<String>,
+    /// QR code of `compact`, if it fits in one
+    pub qr: Option<Handle>,
 }
 
 /// History entry for past proofs
@@ -172,4 +330,183 @@ pub struct HistoryEntry {
     pub confirmed: bool,
     pub network: Option<String>,
     pub block_height: Option<u32>,
+    /// Note recorded at stamp time
+    pub note: Option<String>,
+}
+
+/// Wallet state read after a sync
+#[derive(Debug, Clone)]
+pub struct WalletSnapshot {
+    pub block_height: u64,
+    pub balance: u64,
+    /// Spendable and pending value per pool
+    pub breakdown: BalanceBreakdown,
+    /// Receiving address, if the account has one
+    pub address: Option<String>,
+    /// Most recent wallet transactions, newest (and pending) first
+    pub transactions: Vec<zots_zcash::HistoryEntry>,
+}
+
+/// Seed phrase reveal flow of the wallet view
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SeedReveal {
+    #[default]
+    Hidden,
+    /// Waiting for the user to confirm nobody else can see the screen
+    Confirming,
+    /// Read back from the OS keychain
+    Loading,
+    Shown(String),
+}
+
+/// Proof opened from History, with the result of re-verifying it
+#[derive(Debug, Clone)]
+pub struct ProofDetail {
+    pub path: PathBuf,
+    pub proof: TimestampProof,
+    pub compact: String,
+    /// Issues found by [`TimestampProof::lint`]
+    pub warnings: Vec<String>,
+    pub verifying: bool,
+    pub verify_result: Option<VerifyResult>,
+    pub verify_error: Option<String>,
+}
+
+/// How often pending proofs are upgraded in the background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UpgradeInterval {
+    Off,
+    #[default]
+    OneMinute,
+    FiveMinutes,
+    FifteenMinutes,
+    Hourly,
+}
+
+impl UpgradeInterval {
+    pub const ALL: [UpgradeInterval; 5] = [
+        UpgradeInterval::Off,
+        UpgradeInterval::OneMinute,
+        UpgradeInterval::FiveMinutes,
+        UpgradeInterval::FifteenMinutes,
+        UpgradeInterval::Hourly,
+    ];
+
+    /// Minutes between passes, as stored in settings.json (0 = off)
+    pub fn minutes(self) -> u64 {
+        match self {
+            UpgradeInterval::Off => 0,
+            UpgradeInterval::OneMinute => 1,
+            UpgradeInterval::FiveMinutes => 5,
+            UpgradeInterval::FifteenMinutes => 15,
+            UpgradeInterval::Hourly => 60,
+        }
+    }
+
+    pub fn from_minutes(minutes: u64) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|interval| interval.minutes() == minutes)
+    }
+
+    pub fn duration(self) -> Option<Duration> {
+        match self {
+            UpgradeInterval::Off => None,
+            interval => Some(Duration::from_secs(interval.minutes() * 60)),
+        }
+    }
+}
+
+impl fmt::Display for UpgradeInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpgradeInterval::Off => f.write_str(&t!("interval-off")),
+            UpgradeInterval::OneMinute => f.write_str(&t!("interval-minute")),
+            UpgradeInterval::Hourly => f.write_str(&t!("interval-hour")),
+            interval => f.write_str(&t!("interval-minutes", minutes = interval.minutes())),
+        }
+    }
+}
+
+/// Outcome of one pass over the pending proofs
+#[derive(Debug, Clone)]
+pub struct UpgradeReport {
+    pub checked_at: chrono::DateTime<chrono::Local>,
+    /// Proofs completed in this pass, with their block heights
+    pub upgraded: Vec<(PathBuf, u32)>,
+    /// Proofs whose transaction is not mined yet
+    pub pending: usize,
+    /// Proofs that could not be checked
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+impl UpgradeReport {
+    pub fn summary(&self) -> String {
+        if self.failed.is_empty() {
+            t!(
+                "upgrade-summary",
+                upgraded = self.upgraded.len(),
+                pending = self.pending
+            )
+        } else {
+            t!(
+                "upgrade-summary-failed",
+                upgraded = self.upgraded.len(),
+                pending = self.pending,
+                failed = self.failed.len()
+            )
+        }
+    }
+}
+
+/// Steps of the first-run wizard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    Welcome,
+    /// Create a new seed or import one
+    Choice,
+    /// Show the generated seed
+    Create,
+    ConfirmBackup,
+    Import,
+    Network,
+    Server,
+    /// Writing the profile and storing the seed
+    Finishing,
+}
+
+/// First-run wizard state
+#[derive(Debug, Clone)]
+pub struct Onboarding {
+    pub step: OnboardingStep,
+    /// Generated or validated imported seed phrase
+    pub seed_phrase: String,
+    pub generated: bool,
+    pub seed_input: String,
+    pub birthday_input: String,
+    pub backup_confirmed: bool,
+    pub network: Network,
+    pub server_input: String,
+    pub testing_server: bool,
+    /// Result of the last connection test of `server_input`
+    pub server_info: Option<LightwalletdInfo>,
+    pub error: Option<String>,
+}
+
+impl Default for Onboarding {
+    fn default() -> Self {
+        Self {
+            step: OnboardingStep::Welcome,
+            seed_phrase: String::new(),
+            generated: false,
+            seed_input: String::new(),
+            birthday_input: String::new(),
+            backup_confirmed: false,
+            network: Network::Testnet,
+            server_input: zots_zcash::DEFAULT_LIGHTWALLETD_URL.to_string(),
+            testing_server: false,
+            server_info: None,
+            error: None,
+        }
+    }
 }
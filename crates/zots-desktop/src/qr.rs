@@ -0,0 +1,62 @@
+//! QR codes of compact proofs
+//!
+//! The same payload the CLI prints with `--qr`, rendered as an image for the
+//! result panes, for saving as PNG and for certificates.
+
+use anyhow::Result;
+use iced::widget::image::Handle;
+use image::{GrayImage, Luma};
+use qrcode::{Color, QrCode};
+use std::path::Path;
+use zots_core::QrMatrix;
+
+/// Pixels per QR module
+const MODULE_PX: u32 = 8;
+
+/// Blank modules around the code, as scanners expect
+const QUIET_ZONE: u32 = 4;
+
+/// Render `data` as a black-on-white QR code
+pub fn render(data: &str) -> Result<GrayImage> {
+    let code = QrCode::new(data.as_bytes())?;
+    let width = code.width() as u32;
+    let colors = code.to_colors();
+    let size = (width + 2 * QUIET_ZONE) * MODULE_PX;
+
+    Ok(GrayImage::from_fn(size, size, |x, y| {
+        let (column, row) = (x / MODULE_PX, y / MODULE_PX);
+        let inside = (QUIET_ZONE..width + QUIET_ZONE).contains(&column)
+            && (QUIET_ZONE..width + QUIET_ZONE).contains(&row);
+        let dark = inside
+            && colors[((row - QUIET_ZONE) * width + (column - QUIET_ZONE)) as usize] == Color::Dark;
+        Luma([if dark { 0 } else { 255 }])
+    }))
+}
+
+/// Module matrix of `data`'s QR code, for certificates
+pub fn matrix(data: &str) -> Result<QrMatrix> {
+    let code = QrCode::new(data.as_bytes())?;
+    let modules = code
+        .to_colors()
+        .into_iter()
+        .map(|color| color == Color::Dark)
+        .collect();
+    QrMatrix::new(code.width(), modules).ok_or_else(|| anyhow::anyhow!("QR code is not square"))
+}
+
+/// QR code of `data` ready for an iced image widget
+pub fn handle(data: &str) -> Result<Handle> {
+    let image = render(data)?;
+    let (width, height) = image.dimensions();
+    let rgba = image
+        .pixels()
+        .flat_map(|Luma([value])| [*value, *value, *value, 255])
+        .collect::<Vec<u8>>();
+    Ok(Handle::from_rgba(width, height, rgba))
+}
+
+/// Save the QR code of `data` as a PNG file
+pub fn save_png(data: &str, path: &Path) -> Result<()> {
+    render(data)?.save_with_format(path, image::ImageFormat::Png)?;
+    Ok(())
+}
@@ -1,9 +1,15 @@
-//! Cypherpunk dark theme for the desktop app
+//! Themes for the desktop app: cypherpunk dark, light and high contrast
 
+use crate::i18n::t;
 use iced::widget::{button, container, text, text_input};
 use iced::{Background, Border, Color, Shadow, Theme, Vector};
+use std::fmt;
 
 /// Custom color palette - cypherpunk aesthetics
+///
+/// These are the dark theme's colors. Status colors (`SUCCESS`, `WARNING`,
+/// `ERROR`) read well on every theme; anything else should come from
+/// [`palette`] so it follows the selected theme.
 pub mod colors {
     use iced::Color;
 
@@ -32,29 +38,172 @@ pub mod colors {
     pub const BORDER_FOCUS: Color = Color::from_rgb(0.388, 0.4, 0.945); // Primary
 }
 
-/// Get the custom dark theme
-pub fn dark_theme() -> Theme {
-    Theme::custom(
-        "Cypherpunk".to_string(),
-        iced::theme::Palette {
-            background: colors::BACKGROUND,
-            text: colors::TEXT,
-            primary: colors::PRIMARY,
-            success: colors::SUCCESS,
-            danger: colors::ERROR,
-        },
-    )
+/// Colors of one theme
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub background: Color,
+    pub surface: Color,
+    pub surface_light: Color,
+    pub primary: Color,
+    pub primary_hover: Color,
+    /// Text on primary, success and danger buttons
+    pub on_primary: Color,
+    pub accent: Color,
+    pub text: Color,
+    pub text_muted: Color,
+    pub text_dim: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub border: Color,
+    pub border_focus: Color,
+}
+
+pub const DARK: Palette = Palette {
+    background: colors::BACKGROUND,
+    surface: colors::SURFACE,
+    surface_light: colors::SURFACE_LIGHT,
+    primary: colors::PRIMARY,
+    primary_hover: colors::PRIMARY_HOVER,
+    on_primary: colors::TEXT,
+    accent: colors::ACCENT,
+    text: colors::TEXT,
+    text_muted: colors::TEXT_MUTED,
+    text_dim: colors::TEXT_DIM,
+    success: colors::SUCCESS,
+    warning: colors::WARNING,
+    error: colors::ERROR,
+    border: colors::BORDER,
+    border_focus: colors::BORDER_FOCUS,
+};
+
+pub const LIGHT: Palette = Palette {
+    background: Color::from_rgb(0.965, 0.969, 0.984), // #f6f7fb
+    surface: Color::from_rgb(1.0, 1.0, 1.0),          // #ffffff
+    surface_light: Color::from_rgb(0.933, 0.941, 0.965), // #eef0f6
+    primary: Color::from_rgb(0.31, 0.275, 0.898),     // #4f46e5 - indigo
+    primary_hover: Color::from_rgb(0.486, 0.227, 0.929), // #7c3aed - violet
+    on_primary: Color::WHITE,
+    accent: Color::from_rgb(0.082, 0.502, 0.239), // #15803d - green
+    text: Color::from_rgb(0.067, 0.094, 0.153),   // #111827
+    text_muted: Color::from_rgb(0.294, 0.333, 0.388), // #4b5563
+    text_dim: Color::from_rgb(0.42, 0.447, 0.502), // #6b7280
+    success: Color::from_rgb(0.082, 0.502, 0.239), // #15803d
+    warning: Color::from_rgb(0.706, 0.325, 0.035), // #b45309
+    error: Color::from_rgb(0.863, 0.149, 0.149),  // #dc2626
+    border: Color::from_rgb(0.82, 0.835, 0.859),  // #d1d5db
+    border_focus: Color::from_rgb(0.31, 0.275, 0.898), // Primary
+};
+
+/// Pure black and white with saturated accents, for low vision
+pub const HIGH_CONTRAST: Palette = Palette {
+    background: Color::BLACK,
+    surface: Color::BLACK,
+    surface_light: Color::from_rgb(0.067, 0.067, 0.067), // #111111
+    primary: Color::from_rgb(1.0, 0.831, 0.0),           // #ffd400 - yellow
+    primary_hover: Color::from_rgb(1.0, 0.941, 0.4),     // #fff066
+    on_primary: Color::BLACK,
+    accent: Color::from_rgb(0.0, 0.898, 1.0), // #00e5ff - cyan
+    text: Color::WHITE,
+    text_muted: Color::from_rgb(0.878, 0.878, 0.878), // #e0e0e0
+    text_dim: Color::from_rgb(0.753, 0.753, 0.753),   // #c0c0c0
+    success: Color::from_rgb(0.0, 0.902, 0.463),      // #00e676
+    warning: Color::from_rgb(1.0, 0.702, 0.0),        // #ffb300
+    error: Color::from_rgb(1.0, 0.322, 0.322),        // #ff5252
+    border: Color::WHITE,
+    border_focus: Color::from_rgb(1.0, 0.831, 0.0), // Primary
+};
+
+/// Theme picked in Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeChoice {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeChoice {
+    pub const ALL: [ThemeChoice; 3] = [
+        ThemeChoice::Dark,
+        ThemeChoice::Light,
+        ThemeChoice::HighContrast,
+    ];
+
+    /// Name stored in settings.json
+    pub fn key(self) -> &'static str {
+        match self {
+            ThemeChoice::Dark => "dark",
+            ThemeChoice::Light => "light",
+            ThemeChoice::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|choice| choice.key() == key)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ThemeChoice::Dark => "Cypherpunk",
+            ThemeChoice::Light => "Light",
+            ThemeChoice::HighContrast => "High Contrast",
+        }
+    }
+
+    pub fn palette(self) -> &'static Palette {
+        match self {
+            ThemeChoice::Dark => &DARK,
+            ThemeChoice::Light => &LIGHT,
+            ThemeChoice::HighContrast => &HIGH_CONTRAST,
+        }
+    }
+
+    pub fn theme(self) -> Theme {
+        let palette = self.palette();
+        Theme::custom(
+            self.name().to_string(),
+            iced::theme::Palette {
+                background: palette.background,
+                text: palette.text,
+                primary: palette.primary,
+                success: palette.success,
+                danger: palette.error,
+            },
+        )
+    }
+}
+
+impl fmt::Display for ThemeChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            ThemeChoice::Dark => t!("theme-dark"),
+            ThemeChoice::Light => t!("theme-light"),
+            ThemeChoice::HighContrast => t!("theme-high-contrast"),
+        })
+    }
+}
+
+/// Palette of the active theme; the widget styles draw from it
+pub fn palette(theme: &Theme) -> &'static Palette {
+    let name = theme.to_string();
+    ThemeChoice::ALL
+        .into_iter()
+        .find(|choice| choice.name() == name)
+        .unwrap_or_default()
+        .palette()
 }
 
 /// Container styles
 pub mod container_style {
     use super::*;
 
-    pub fn surface(_theme: &Theme) -> container::Style {
+    pub fn surface(theme: &Theme) -> container::Style {
+        let p = palette(theme);
         container::Style {
-            background: Some(Background::Color(colors::SURFACE)),
+            background: Some(Background::Color(p.surface)),
             border: Border {
-                color: colors::BORDER,
+                color: p.border,
                 width: 1.0,
                 radius: 8.0.into(),
             },
@@ -62,11 +211,12 @@ pub mod container_style {
         }
     }
 
-    pub fn card(_theme: &Theme) -> container::Style {
+    pub fn card(theme: &Theme) -> container::Style {
+        let p = palette(theme);
         container::Style {
-            background: Some(Background::Color(colors::SURFACE_LIGHT)),
+            background: Some(Background::Color(p.surface_light)),
             border: Border {
-                color: colors::BORDER,
+                color: p.border,
                 width: 1.0,
                 radius: 12.0.into(),
             },
@@ -79,11 +229,20 @@ pub mod container_style {
         }
     }
 
-    pub fn sidebar(_theme: &Theme) -> container::Style {
+    /// Dimmed backdrop behind a modal
+    pub fn backdrop(_theme: &Theme) -> container::Style {
+        container::Style {
+            background: Some(Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.7))),
+            ..Default::default()
+        }
+    }
+
+    pub fn sidebar(theme: &Theme) -> container::Style {
+        let p = palette(theme);
         container::Style {
-            background: Some(Background::Color(colors::SURFACE)),
+            background: Some(Background::Color(p.surface)),
             border: Border {
-                color: colors::BORDER,
+                color: p.border,
                 width: 0.0,
                 radius: 0.0.into(),
             },
@@ -91,11 +250,12 @@ pub mod container_style {
         }
     }
 
-    pub fn header(_theme: &Theme) -> container::Style {
+    pub fn header(theme: &Theme) -> container::Style {
+        let p = palette(theme);
         container::Style {
-            background: Some(Background::Color(colors::SURFACE)),
+            background: Some(Background::Color(p.surface)),
             border: Border {
-                color: colors::BORDER,
+                color: p.border,
                 width: 1.0,
                 radius: 0.0.into(),
             },
@@ -103,11 +263,12 @@ pub mod container_style {
         }
     }
 
-    pub fn status_bar(_theme: &Theme) -> container::Style {
+    pub fn status_bar(theme: &Theme) -> container::Style {
+        let p = palette(theme);
         container::Style {
-            background: Some(Background::Color(colors::SURFACE)),
+            background: Some(Background::Color(p.surface)),
             border: Border {
-                color: colors::BORDER,
+                color: p.border,
                 width: 1.0,
                 radius: 0.0.into(),
             },
@@ -120,51 +281,56 @@ pub mod container_style {
 pub mod button_style {
     use super::*;
 
-    pub fn primary(_theme: &Theme, status: button::Status) -> button::Style {
+    pub fn primary(theme: &Theme, status: button::Status) -> button::Style {
+        let p = palette(theme);
         let background = match status {
-            button::Status::Active => colors::PRIMARY,
-            button::Status::Hovered => colors::PRIMARY_HOVER,
+            button::Status::Active => p.primary,
+            button::Status::Hovered => p.primary_hover,
             button::Status::Pressed => Color {
                 a: 0.8,
-                ..colors::PRIMARY
+                ..p.primary
             },
             button::Status::Disabled => Color {
                 a: 0.3,
-                ..colors::PRIMARY
+                ..p.primary
             },
         };
 
         button::Style {
             background: Some(Background::Color(background)),
-            text_color: colors::TEXT,
+            text_color: p.on_primary,
             border: Border {
                 radius: 8.0.into(),
                 ..Default::default()
             },
             shadow: Shadow {
-                color: Color::from_rgba(0.388, 0.4, 0.945, 0.3),
+                color: Color {
+                    a: 0.3,
+                    ..p.primary
+                },
                 offset: Vector::new(0.0, 2.0),
                 blur_radius: 8.0,
             },
         }
     }
 
-    pub fn secondary(_theme: &Theme, status: button::Status) -> button::Style {
+    pub fn secondary(theme: &Theme, status: button::Status) -> button::Style {
+        let p = palette(theme);
         let background = match status {
-            button::Status::Active => colors::SURFACE_LIGHT,
-            button::Status::Hovered => colors::BORDER,
-            button::Status::Pressed => colors::SURFACE,
+            button::Status::Active => p.surface_light,
+            button::Status::Hovered => p.border,
+            button::Status::Pressed => p.surface,
             button::Status::Disabled => Color {
                 a: 0.3,
-                ..colors::SURFACE_LIGHT
+                ..p.surface_light
             },
         };
 
         button::Style {
             background: Some(Background::Color(background)),
-            text_color: colors::TEXT,
+            text_color: p.text,
             border: Border {
-                color: colors::BORDER,
+                color: p.border,
                 width: 1.0,
                 radius: 8.0.into(),
             },
@@ -172,21 +338,20 @@ pub mod button_style {
         }
     }
 
-    pub fn nav(_theme: &Theme, status: button::Status, active: bool) -> button::Style {
+    pub fn nav(theme: &Theme, status: button::Status, active: bool) -> button::Style {
+        let p = palette(theme);
         let (background, text_color) = if active {
             (
                 Some(Background::Color(Color {
                     a: 0.2,
-                    ..colors::PRIMARY
+                    ..p.primary
                 })),
-                colors::PRIMARY,
+                p.primary,
             )
         } else {
             match status {
-                button::Status::Hovered => {
-                    (Some(Background::Color(colors::SURFACE_LIGHT)), colors::TEXT)
-                }
-                _ => (None, colors::TEXT_MUTED),
+                button::Status::Hovered => (Some(Background::Color(p.surface_light)), p.text),
+                _ => (None, p.text_muted),
             }
         };
 
@@ -201,26 +366,27 @@ pub mod button_style {
         }
     }
 
-    pub fn success(_theme: &Theme, status: button::Status) -> button::Style {
+    pub fn success(theme: &Theme, status: button::Status) -> button::Style {
+        let p = palette(theme);
         let background = match status {
-            button::Status::Active => colors::SUCCESS,
+            button::Status::Active => p.success,
             button::Status::Hovered => Color {
                 a: 0.8,
-                ..colors::SUCCESS
+                ..p.success
             },
             button::Status::Pressed => Color {
                 a: 0.6,
-                ..colors::SUCCESS
+                ..p.success
             },
             button::Status::Disabled => Color {
                 a: 0.3,
-                ..colors::SUCCESS
+                ..p.success
             },
         };
 
         button::Style {
             background: Some(Background::Color(background)),
-            text_color: colors::TEXT,
+            text_color: p.on_primary,
             border: Border {
                 radius: 8.0.into(),
                 ..Default::default()
@@ -229,26 +395,18 @@ pub mod button_style {
         }
     }
 
-    pub fn danger(_theme: &Theme, status: button::Status) -> button::Style {
+    pub fn danger(theme: &Theme, status: button::Status) -> button::Style {
+        let p = palette(theme);
         let background = match status {
-            button::Status::Active => colors::ERROR,
-            button::Status::Hovered => Color {
-                a: 0.8,
-                ..colors::ERROR
-            },
-            button::Status::Pressed => Color {
-                a: 0.6,
-                ..colors::ERROR
-            },
-            button::Status::Disabled => Color {
-                a: 0.3,
-                ..colors::ERROR
-            },
+            button::Status::Active => p.error,
+            button::Status::Hovered => Color { a: 0.8, ..p.error },
+            button::Status::Pressed => Color { a: 0.6, ..p.error },
+            button::Status::Disabled => Color { a: 0.3, ..p.error },
         };
 
         button::Style {
             background: Some(Background::Color(background)),
-            text_color: colors::TEXT,
+            text_color: p.on_primary,
             border: Border {
                 radius: 8.0.into(),
                 ..Default::default()
@@ -262,30 +420,28 @@ pub mod button_style {
 pub mod input_style {
     use super::*;
 
-    pub fn default(_theme: &Theme, status: text_input::Status) -> text_input::Style {
+    pub fn default(theme: &Theme, status: text_input::Status) -> text_input::Style {
+        let p = palette(theme);
         let border_color = match status {
-            text_input::Status::Active => colors::BORDER,
-            text_input::Status::Hovered => colors::TEXT_MUTED,
-            text_input::Status::Focused => colors::PRIMARY,
-            text_input::Status::Disabled => Color {
-                a: 0.3,
-                ..colors::BORDER
-            },
+            text_input::Status::Active => p.border,
+            text_input::Status::Hovered => p.text_muted,
+            text_input::Status::Focused => p.border_focus,
+            text_input::Status::Disabled => Color { a: 0.3, ..p.border },
         };
 
         text_input::Style {
-            background: Background::Color(colors::SURFACE),
+            background: Background::Color(p.surface),
             border: Border {
                 color: border_color,
                 width: 1.0,
                 radius: 8.0.into(),
             },
-            icon: colors::TEXT_MUTED,
-            placeholder: colors::TEXT_DIM,
-            value: colors::TEXT,
+            icon: p.text_muted,
+            placeholder: p.text_dim,
+            value: p.text,
             selection: Color {
                 a: 0.3,
-                ..colors::PRIMARY
+                ..p.primary
             },
         }
     }
@@ -296,44 +452,44 @@ pub mod text_style {
     use super::*;
 
     pub fn muted() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::TEXT_MUTED),
+        move |theme| text::Style {
+            color: Some(palette(theme).text_muted),
         }
     }
 
     pub fn dim() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::TEXT_DIM),
+        move |theme| text::Style {
+            color: Some(palette(theme).text_dim),
         }
     }
 
     pub fn primary() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::PRIMARY),
+        move |theme| text::Style {
+            color: Some(palette(theme).primary),
         }
     }
 
     pub fn accent() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::ACCENT),
+        move |theme| text::Style {
+            color: Some(palette(theme).accent),
         }
     }
 
     pub fn success() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::SUCCESS),
+        move |theme| text::Style {
+            color: Some(palette(theme).success),
         }
     }
 
     pub fn warning() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::WARNING),
+        move |theme| text::Style {
+            color: Some(palette(theme).warning),
         }
     }
 
     pub fn error() -> impl Fn(&Theme) -> text::Style {
-        move |_theme| text::Style {
-            color: Some(colors::ERROR),
+        move |theme| text::Style {
+            color: Some(palette(theme).error),
         }
     }
 }
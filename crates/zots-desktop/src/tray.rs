@@ -0,0 +1,128 @@
+//! System tray icon
+//!
+//! With a tray icon, closing the window hides it instead of quitting, so
+//! broadcast stamps keep being watched until they confirm. The tray menu
+//! brings the window back or quits.
+
+use crate::i18n::t;
+use crate::message::Message;
+use anyhow::Result;
+use iced::futures::SinkExt;
+use iced::futures::channel::mpsc;
+use std::time::Duration;
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent};
+
+const SHOW_ID: &str = "show";
+const QUIT_ID: &str = "quit";
+
+/// Icon size in pixels
+const ICON_SIZE: u32 = 32;
+
+/// Create the tray icon; returns whether the platform has a tray
+///
+/// The icon lives on its own thread running the GTK main loop.
+#[cfg(target_os = "linux")]
+pub fn install() -> bool {
+    let (ready, installed) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        if let Err(e) = gtk::init() {
+            tracing::warn!("No system tray: {e}");
+            let _ = ready.send(false);
+            return;
+        }
+        match build() {
+            Ok(_tray) => {
+                let _ = ready.send(true);
+                gtk::main();
+            }
+            Err(e) => {
+                tracing::warn!("No system tray: {e}");
+                let _ = ready.send(false);
+            }
+        }
+    });
+    installed.recv().unwrap_or(false)
+}
+
+/// Create the tray icon; returns whether the platform has a tray
+///
+/// Must run on the main thread; the icon is kept for the rest of the process.
+#[cfg(not(target_os = "linux"))]
+pub fn install() -> bool {
+    match build() {
+        Ok(tray) => {
+            std::mem::forget(tray);
+            true
+        }
+        Err(e) => {
+            tracing::warn!("No system tray: {e}");
+            false
+        }
+    }
+}
+
+fn build() -> Result<TrayIcon> {
+    let menu = Menu::new();
+    menu.append_items(&[
+        &MenuItem::with_id(SHOW_ID, &t!("tray-show"), true, None),
+        &PredefinedMenuItem::separator(),
+        &MenuItem::with_id(QUIT_ID, &t!("tray-quit"), true, None),
+    ])?;
+
+    Ok(TrayIconBuilder::new()
+        .with_menu(Box::new(menu))
+        .with_tooltip("zOpenTimestamps")
+        .with_icon(icon()?)
+        .build()?)
+}
+
+/// A filled circle in the theme's primary color
+fn icon() -> Result<Icon> {
+    let center = (ICON_SIZE as f32 - 1.0) / 2.0;
+    let radius = ICON_SIZE as f32 / 2.0 - 1.0;
+    let rgba = (0..ICON_SIZE * ICON_SIZE)
+        .flat_map(|i| {
+            let (x, y) = ((i % ICON_SIZE) as f32, (i / ICON_SIZE) as f32);
+            let inside = (x - center).hypot(y - center) <= radius;
+            if inside {
+                [0x63, 0x66, 0xf1, 0xff]
+            } else {
+                [0, 0, 0, 0]
+            }
+        })
+        .collect();
+    Ok(Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE)?)
+}
+
+/// Menu picks and icon clicks, as app messages
+pub fn events() -> impl iced::futures::Stream<Item = Message> {
+    iced::stream::channel(8, |mut output: mpsc::Sender<Message>| async move {
+        loop {
+            let mut messages = Vec::new();
+            while let Ok(event) = MenuEvent::receiver().try_recv() {
+                match event.id.0.as_str() {
+                    SHOW_ID => messages.push(Message::ShowWindow),
+                    QUIT_ID => messages.push(Message::Quit),
+                    _ => {}
+                }
+            }
+            while let Ok(event) = TrayIconEvent::receiver().try_recv() {
+                if let TrayIconEvent::Click {
+                    button: MouseButton::Left,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } = event
+                {
+                    messages.push(Message::ShowWindow);
+                }
+            }
+            for message in messages {
+                if output.send(message).await.is_err() {
+                    return;
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+    })
+}
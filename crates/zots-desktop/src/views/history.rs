@@ -1,23 +1,29 @@
 //! History view - List past proofs
 
 use crate::app::ZotsApp;
-use crate::message::Message;
+use crate::i18n::t;
+use crate::message::{Message, ProofDetail, UpgradeInterval};
 use crate::theme::{self, colors};
-use iced::widget::{Space, button, column, container, horizontal_space, row, scrollable, text};
+use crate::views::modal;
+use iced::widget::{
+    Space, button, column, container, horizontal_space, mouse_area, row, scrollable, stack, text,
+};
 use iced::{Alignment, Color, Element, Length};
 
 pub fn view(app: &ZotsApp) -> Element<Message> {
     let title = row![
         text(">").size(28),
         Space::with_width(12),
-        text("Proof History").size(24),
+        text(t!("history-title")).size(24),
     ]
     .align_y(Alignment::Center);
 
-    let description =
-        text("View and manage your timestamp proofs (.zots files in current directory)")
-            .size(14)
-            .style(theme::text_style::muted());
+    let description = text(t!(
+        "history-description",
+        dir = app.proofs_dir.display().to_string()
+    ))
+    .size(14)
+    .style(theme::text_style::muted());
 
     let refresh_btn = button(
         row![
@@ -29,9 +35,9 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             .size(14),
             Space::with_width(8),
             text(if app.history_loading {
-                "Loading..."
+                t!("history-loading")
             } else {
-                "Refresh"
+                t!("action-refresh")
             })
             .size(14),
         ]
@@ -49,11 +55,11 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             column![
                 text("--").size(48),
                 Space::with_height(16),
-                text("No proofs found")
+                text(t!("history-empty"))
                     .size(16)
                     .style(theme::text_style::muted()),
                 Space::with_height(8),
-                text("Create a timestamp to see it here")
+                text(t!("history-empty-hint"))
                     .size(13)
                     .style(theme::text_style::dim()),
             ]
@@ -67,7 +73,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             column![
                 text(app.spinner()).size(32),
                 Space::with_height(16),
-                text("Loading proofs...")
+                text(t!("history-loading-proofs"))
                     .size(14)
                     .style(theme::text_style::muted()),
             ]
@@ -92,7 +98,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                     .path
                     .file_name()
                     .map(|f| f.to_string_lossy().to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
+                    .unwrap_or_else(|| t!("label-unknown"));
 
                 let hash_display = if entry.hash.len() > 20 {
                     format!(
@@ -105,17 +111,21 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 };
 
                 let details = if entry.confirmed {
-                    format!(
-                        "{} · Block {}",
-                        entry.network.as_deref().unwrap_or("Unknown"),
-                        entry.block_height.unwrap_or(0)
+                    t!(
+                        "history-entry-confirmed",
+                        network = entry.network.clone().unwrap_or_else(|| t!("label-unknown")),
+                        block = entry.block_height.unwrap_or(0)
                     )
                 } else {
-                    "Pending confirmation".to_string()
+                    t!("history-entry-pending")
+                };
+                let details = match &entry.note {
+                    Some(note) => format!("{details} · {note}"),
+                    None => details,
                 };
 
                 let created = entry.created.clone();
-                container(
+                let item = container(
                     row![
                         // Status indicator
                         text(status_icon).size(18).color(status_color),
@@ -144,8 +154,11 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                     .padding(16),
                 )
                 .style(theme::container_style::surface)
-                .width(Length::Fill)
-                .into()
+                .width(Length::Fill);
+
+                mouse_area(item)
+                    .on_press(Message::OpenProofDetail(entry.path.clone()))
+                    .into()
             })
             .collect();
 
@@ -161,28 +174,241 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     let pending_count = app.history.len() - confirmed_count;
 
     let stats = row![
-        stat_badge("Total", app.history.len(), colors::TEXT_MUTED),
+        stat_badge(t!("history-total"), app.history.len(), colors::TEXT_MUTED),
         Space::with_width(16),
-        stat_badge("Confirmed", confirmed_count, colors::SUCCESS),
+        stat_badge(t!("history-confirmed"), confirmed_count, colors::SUCCESS),
         Space::with_width(16),
-        stat_badge("Pending", pending_count, colors::WARNING),
+        stat_badge(t!("history-pending"), pending_count, colors::WARNING),
     ];
 
-    column![
+    let page = column![
         header,
         Space::with_height(8),
         description,
         Space::with_height(16),
-        stats,
+        row![stats, horizontal_space(), upgrade_status(app)].align_y(Alignment::Center),
         Space::with_height(16),
         content,
     ]
     .width(Length::Fill)
-    .height(Length::Fill)
+    .height(Length::Fill);
+
+    match &app.proof_detail {
+        Some(detail) => stack![
+            page,
+            modal(detail_card(app, detail), Message::CloseProofDetail)
+        ]
+        .into(),
+        None => page.into(),
+    }
+}
+
+fn detail_card<'a>(app: &'a ZotsApp, detail: &'a ProofDetail) -> Element<'a, Message> {
+    let proof = &detail.proof;
+    let filename = detail
+        .path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_else(|| t!("label-unknown"));
+
+    let mut content = column![
+        row![
+            text(filename).size(18),
+            horizontal_space(),
+            button(text("x").size(12))
+                .padding([6, 10])
+                .style(theme::button_style::secondary)
+                .on_press(Message::CloseProofDetail),
+        ]
+        .align_y(Alignment::Center),
+        Space::with_height(4),
+        text(detail.path.display().to_string())
+            .size(11)
+            .style(theme::text_style::dim()),
+        Space::with_height(16),
+        detail_row(&t!("label-hash"), proof.hash.clone()),
+        detail_row(
+            &t!("label-algorithm"),
+            proof.hash_algorithm().name().to_string()
+        ),
+    ];
+
+    for (key, value) in &proof.metadata {
+        content = content.push(detail_row(key, value.clone()));
+    }
+    if let Some(txid) = &proof.pending_txid {
+        content = content.push(detail_row(&t!("history-pending-tx"), txid.clone()));
+    }
+
+    // Attestations
+    content = content.push(Space::with_height(16));
+    content = content.push(
+        text(t!("history-attestations", count = proof.attestations.len()))
+            .size(14)
+            .style(theme::text_style::muted()),
+    );
+    for att in &proof.attestations {
+        content = content.push(Space::with_height(8));
+        content = content.push(
+            container(
+                row![
+                    column![
+                        text(t!(
+                            "history-entry-confirmed",
+                            network = att.network.to_string(),
+                            block = att.block_height
+                        ))
+                        .size(13),
+                        Space::with_height(2),
+                        text(att.timestamp().to_rfc3339())
+                            .size(11)
+                            .style(theme::text_style::muted()),
+                        Space::with_height(2),
+                        text(att.txid_hex().to_string())
+                            .size(11)
+                            .style(theme::text_style::accent()),
+                    ]
+                    .width(Length::Fill),
+                    button(text(t!("label-explorer")).size(12))
+                        .padding([6, 12])
+                        .style(theme::button_style::secondary)
+                        .on_press(Message::OpenExplorer(att.explorer_link())),
+                ]
+                .align_y(Alignment::Center)
+                .padding(12),
+            )
+            .style(theme::container_style::surface)
+            .width(Length::Fill),
+        );
+    }
+
+    // Lint warnings
+    if !detail.warnings.is_empty() {
+        content = content.push(Space::with_height(16));
+        for warning in &detail.warnings {
+            content = content.push(text(format!("! {warning}")).size(12).color(colors::WARNING));
+        }
+    }
+
+    // Compact string
+    content = content.push(Space::with_height(16));
+    content = content.push(
+        row![
+            text(&detail.compact)
+                .size(11)
+                .style(theme::text_style::dim())
+                .width(Length::Fill),
+            Space::with_width(12),
+            button(text(t!("action-copy")).size(12))
+                .padding([6, 12])
+                .style(theme::button_style::secondary)
+                .on_press(Message::CopyToClipboard(detail.compact.clone())),
+        ]
+        .align_y(Alignment::Center),
+    );
+
+    // Re-verify
+    let verify_label = if detail.verifying {
+        format!("{} {}", app.spinner(), t!("verify-running"))
+    } else {
+        t!("history-verify-now")
+    };
+    let mut verify_btn = button(text(verify_label).size(13))
+        .padding([10, 16])
+        .style(theme::button_style::primary);
+    if !detail.verifying {
+        verify_btn = verify_btn.on_press(Message::VerifyProofDetail);
+    }
+    content = content.push(Space::with_height(20));
+    content = content.push(row![
+        verify_btn,
+        Space::with_width(12),
+        button(text(t!("action-export-certificate")).size(13))
+            .padding([10, 16])
+            .style(theme::button_style::secondary)
+            .on_press(Message::ExportCertificate(detail.path.clone())),
+    ]);
+
+    if let Some(result) = &detail.verify_result {
+        let (mark, color) = if result.valid && result.error.is_none() {
+            (t!("history-verified"), colors::SUCCESS)
+        } else if result.valid {
+            (t!("history-well-formed"), colors::WARNING)
+        } else {
+            (t!("history-verify-failed"), colors::ERROR)
+        };
+        content = content.push(Space::with_height(12));
+        content = content.push(text(mark).size(14).color(color));
+        if let Some(error) = &result.error {
+            content = content.push(text(error).size(12).style(theme::text_style::muted()));
+        }
+    } else if let Some(error) = &detail.verify_error {
+        content = content.push(Space::with_height(12));
+        content = content.push(text(error).size(12).style(theme::text_style::error()));
+    }
+
+    container(scrollable(content.padding(20)))
+        .style(theme::container_style::card)
+        .max_width(640)
+        .into()
+}
+
+fn detail_row(label: &str, value: String) -> Element<'static, Message> {
+    row![
+        text(format!("{label}:"))
+            .size(13)
+            .style(theme::text_style::muted())
+            .width(100),
+        text(value).size(13).style(theme::text_style::accent()),
+    ]
+    .padding([4, 0])
+    .into()
+}
+
+/// Last background upgrade pass, with a button to run one now
+fn upgrade_status(app: &ZotsApp) -> Element<Message> {
+    let schedule = match app.upgrade_interval {
+        UpgradeInterval::Off => t!("upgrade-auto-off"),
+        interval => t!(
+            "upgrade-auto",
+            interval = interval.to_string().to_lowercase()
+        ),
+    };
+    let last = match &app.last_upgrade {
+        Some(report) => t!(
+            "upgrade-last-check",
+            time = report.checked_at.format("%H:%M").to_string(),
+            summary = report.summary()
+        ),
+        None => String::new(),
+    };
+
+    let mut upgrade_btn = button(
+        text(if app.upgrading {
+            t!("upgrade-running")
+        } else {
+            t!("upgrade-now")
+        })
+        .size(12),
+    )
+    .padding([6, 12])
+    .style(theme::button_style::secondary);
+    if app.config.is_some() && !app.upgrading {
+        upgrade_btn = upgrade_btn.on_press(Message::UpgradePendingNow);
+    }
+
+    row![
+        text(format!("{schedule}{last}"))
+            .size(12)
+            .style(theme::text_style::dim()),
+        Space::with_width(12),
+        upgrade_btn,
+    ]
+    .align_y(Alignment::Center)
     .into()
 }
 
-fn stat_badge<'a>(label: &'a str, count: usize, color: Color) -> Element<'a, Message> {
+fn stat_badge(label: String, count: usize, color: Color) -> Element<'static, Message> {
     container(
         row![
             text(format!("{label}: "))
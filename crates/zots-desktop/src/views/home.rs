@@ -1,63 +1,60 @@
 //! Home view - Dashboard overview
 
 use crate::app::ZotsApp;
+use crate::i18n::t;
 use crate::message::{Message, View};
 use crate::theme::{self, colors};
 use iced::widget::{Space, button, column, container, horizontal_space, row, text};
 use iced::{Alignment, Element, Length};
 
 pub fn view(app: &ZotsApp) -> Element<Message> {
-    let title = text("Welcome to zOpenTimestamps")
+    let title = text(t!("home-title"))
         .size(28)
         .style(theme::text_style::primary());
 
-    let subtitle = text(
-        "Timestamp files on the Zcash blockchain with privacy-preserving shielded transactions",
-    )
-    .size(14)
-    .style(theme::text_style::muted());
+    let subtitle = text(t!("home-subtitle"))
+        .size(14)
+        .style(theme::text_style::muted());
 
     // Quick stats
     let balance_zec = app.balance as f64 / 100_000_000.0;
     let balance_str = format!("{balance_zec:.8} ZEC");
     let block_str = app.block_height.to_string();
-    let network_str = app
-        .config
-        .as_ref()
-        .map(|c| c.network.name())
-        .unwrap_or("Not configured")
-        .to_string();
+    let network_str = app.config.as_ref().map_or_else(
+        || t!("home-not-configured"),
+        |c| c.network.name().to_string(),
+    );
     let stats = container(
         row![
-            stat_card("Balance", balance_str),
+            stat_card(t!("label-balance"), balance_str),
             Space::with_width(16),
-            stat_card("Block Height", block_str),
+            stat_card(t!("label-block-height"), block_str),
             Space::with_width(16),
-            stat_card("Network", network_str),
+            stat_card(t!("label-network"), network_str),
         ]
         .padding([0, 0]),
     );
 
     // Quick actions
-    let actions_title = text("Quick Actions")
+    let actions_title = text(t!("home-quick-actions"))
         .size(18)
         .style(theme::text_style::muted());
 
     let stamp_action = action_card(
-        "Create Timestamp",
-        "Timestamp a file or hash on the Zcash blockchain",
+        t!("home-stamp-title"),
+        t!("home-stamp-description"),
         View::Stamp,
     );
 
     let verify_action = action_card(
-        "Verify Proof",
-        "Verify a timestamp proof against the blockchain",
+        t!("home-verify-title"),
+        t!("home-verify-description"),
         View::Verify,
     );
 
     let wallet_action = action_card(
-        "Wallet",
-        "View balance and configure your wallet",
+        t!("view-wallet"),
+        t!("home-wallet-description"),
         View::Wallet,
     );
 
@@ -75,10 +72,10 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             text("!").size(20),
             Space::with_width(12),
             column![
-                text("Testnet Only")
+                text(t!("home-testnet-only"))
                     .size(14)
                     .style(theme::text_style::warning()),
-                text("This is experimental software. Do not use on mainnet with real funds.")
+                text(t!("home-experimental"))
                     .size(12)
                     .style(theme::text_style::muted()),
             ],
@@ -96,16 +93,20 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     // About section
     let about = container(
         column![
-            text("About zOpenTimestamps").size(16),
+            text(t!("home-about-title")).size(16),
             Space::with_height(8),
-            text("zOpenTimestamps is a Zcash blockchain timestamping tool inspired by OpenTimestamps. It creates cryptographic proofs that data existed at a specific point in time using Zcash's shielded transactions.")
+            text(t!("home-about"))
                 .size(13)
                 .style(theme::text_style::muted()),
             Space::with_height(16),
             row![
-                text("Version 0.1.1").size(12).style(theme::text_style::dim()),
+                text(t!("home-version", version = env!("CARGO_PKG_VERSION")))
+                    .size(12)
+                    .style(theme::text_style::dim()),
                 horizontal_space(),
-                text("MIT License").size(12).style(theme::text_style::dim()),
+                text(t!("home-license"))
+                    .size(12)
+                    .style(theme::text_style::dim()),
             ],
         ]
         .padding(16),
@@ -136,7 +137,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     .into()
 }
 
-fn stat_card(label: &'static str, value: String) -> Element<'static, Message> {
+fn stat_card(label: String, value: String) -> Element<'static, Message> {
     container(
         column![
             text(label).size(12).style(theme::text_style::muted()),
@@ -150,7 +151,7 @@ fn stat_card(label: &'static str, value: String) -> Element<'static, Message> {
     .into()
 }
 
-fn action_card<'a>(title: &'a str, description: &'a str, target: View) -> Element<'a, Message> {
+fn action_card(title: String, description: String, target: View) -> Element<'static, Message> {
     button(
         column![
             text(title).size(16),
@@ -2,7 +2,88 @@
 
 pub mod history;
 pub mod home;
+pub mod onboarding;
 pub mod settings;
 pub mod stamp;
 pub mod verify;
 pub mod wallet;
+
+use crate::i18n::t;
+use crate::message::Message;
+use crate::theme;
+use iced::widget::image::Handle;
+use iced::widget::{
+    Space, button, center, column, container, image, mouse_area, opaque, row, text,
+};
+use iced::{Alignment, Element, Length};
+
+/// Show `content` over a dimmed backdrop; clicking outside sends `on_close`
+pub fn modal(content: Element<Message>, on_close: Message) -> Element<Message> {
+    opaque(
+        mouse_area(
+            center(opaque(content))
+                .padding(24)
+                .style(theme::container_style::backdrop),
+        )
+        .on_press(on_close),
+    )
+}
+
+/// QR code of a compact proof with a button to save it as PNG
+pub fn qr_panel(qr: &Handle, compact: &str) -> Element<'static, Message> {
+    row![
+        image(qr.clone()).width(180).height(180),
+        Space::with_width(16),
+        column![
+            text(t!("qr-scan-hint"))
+                .size(13)
+                .style(theme::text_style::muted()),
+            Space::with_height(12),
+            button(text(t!("qr-save-png")).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::SaveQrCode(compact.to_string())),
+        ],
+    ]
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Seed phrase as a numbered four-column grid with a copy button
+pub fn seed_words(phrase: &str) -> Element<'_, Message> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let rows: Vec<Element<Message>> = words
+        .chunks(4)
+        .enumerate()
+        .map(|(row_index, chunk)| {
+            let cells: Vec<Element<Message>> = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    row![
+                        text(format!("{:>2}.", row_index * 4 + i + 1))
+                            .size(12)
+                            .style(theme::text_style::dim()),
+                        Space::with_width(6),
+                        text(*word).size(14),
+                    ]
+                    .width(Length::FillPortion(1))
+                    .into()
+                })
+                .collect();
+            row(cells).into()
+        })
+        .collect();
+
+    column![
+        container(column(rows).spacing(8).padding(16))
+            .style(theme::container_style::surface)
+            .width(Length::Fill),
+        Space::with_height(12),
+        button(text(t!("action-copy")).size(13))
+            .padding([8, 16])
+            .style(theme::button_style::secondary)
+            .on_press(Message::CopyToClipboard(phrase.to_string())),
+    ]
+    .into()
+}
@@ -0,0 +1,230 @@
+//! Onboarding view - First-run wallet setup
+
+use crate::app::ZotsApp;
+use crate::i18n::t;
+use crate::message::{Message, Onboarding, OnboardingStep};
+use crate::theme::{self, colors};
+use crate::views;
+use iced::widget::{
+    Space, button, center, checkbox, column, container, horizontal_space, radio, row, text,
+    text_input,
+};
+use iced::{Alignment, Element, Length};
+use zots_core::Network;
+
+pub fn view<'a>(app: &'a ZotsApp, onboarding: &'a Onboarding) -> Element<'a, Message> {
+    let (title, subtitle, body): (String, String, Element<Message>) = match onboarding.step {
+        OnboardingStep::Welcome => (
+            t!("home-title"),
+            t!("onboarding-welcome"),
+            Space::with_height(0).into(),
+        ),
+        OnboardingStep::Choice => (
+            t!("onboarding-choice-title"),
+            t!("onboarding-choice"),
+            column![
+                button(text(t!("onboarding-create")).size(14))
+                    .padding([12, 20])
+                    .width(Length::Fill)
+                    .style(theme::button_style::primary)
+                    .on_press(Message::OnboardingCreateSeed),
+                Space::with_height(12),
+                button(text(t!("onboarding-import")).size(14))
+                    .padding([12, 20])
+                    .width(Length::Fill)
+                    .style(theme::button_style::secondary)
+                    .on_press(Message::OnboardingImportSeed),
+            ]
+            .into(),
+        ),
+        OnboardingStep::Create => (
+            t!("onboarding-phrase-title"),
+            t!("onboarding-phrase"),
+            views::seed_words(&onboarding.seed_phrase),
+        ),
+        OnboardingStep::ConfirmBackup => (
+            t!("onboarding-backup-title"),
+            t!("onboarding-backup"),
+            checkbox(t!("onboarding-backup-confirm"), onboarding.backup_confirmed)
+                .on_toggle(Message::OnboardingBackupConfirmed)
+                .text_size(13)
+                .into(),
+        ),
+        OnboardingStep::Import => (
+            t!("onboarding-import"),
+            t!("onboarding-import-hint"),
+            column![
+                text_input(&t!("onboarding-seed-placeholder"), &onboarding.seed_input)
+                    .padding(12)
+                    .size(14)
+                    .style(theme::input_style::default)
+                    .on_input(Message::OnboardingSeedInputChanged)
+                    .secure(true),
+                Space::with_height(12),
+                text_input(
+                    &t!("onboarding-birthday-placeholder"),
+                    &onboarding.birthday_input
+                )
+                .padding(12)
+                .size(14)
+                .style(theme::input_style::default)
+                .on_input(Message::OnboardingBirthdayChanged),
+            ]
+            .into(),
+        ),
+        OnboardingStep::Network => (
+            t!("onboarding-network-title"),
+            t!("onboarding-network"),
+            network_choice(onboarding.network),
+        ),
+        OnboardingStep::Server => (
+            t!("onboarding-server-title"),
+            t!("onboarding-server"),
+            server_choice(app, onboarding),
+        ),
+        OnboardingStep::Finishing => (
+            t!("onboarding-saving-title"),
+            t!("onboarding-saving"),
+            text(app.spinner()).size(28).into(),
+        ),
+    };
+
+    let mut content = column![
+        text(title).size(24),
+        Space::with_height(8),
+        text(subtitle).size(14).style(theme::text_style::muted()),
+        Space::with_height(24),
+        body,
+    ];
+
+    if let Some(error) = &onboarding.error {
+        content = content.push(Space::with_height(16));
+        content = content.push(text(error).size(12).color(colors::ERROR));
+    }
+
+    content = content.push(Space::with_height(24));
+    content = content.push(navigation(onboarding));
+
+    center(
+        container(content.padding(32))
+            .style(theme::container_style::card)
+            .max_width(640),
+    )
+    .padding(24)
+    .into()
+}
+
+fn network_choice(selected: Network) -> Element<'static, Message> {
+    // Mainnet is not offered until the wallet follows the configured network
+    column![
+        radio(
+            t!("network-testnet"),
+            Network::Testnet,
+            Some(selected),
+            Message::OnboardingNetworkSelected,
+        )
+        .text_size(14),
+        Space::with_height(12),
+        text(t!("network-mainnet-unsupported"))
+            .size(12)
+            .style(theme::text_style::dim()),
+    ]
+    .into()
+}
+
+fn server_choice<'a>(app: &'a ZotsApp, onboarding: &'a Onboarding) -> Element<'a, Message> {
+    let mut test_btn = button(
+        text(if onboarding.testing_server {
+            format!("{} {}", app.spinner(), t!("server-testing"))
+        } else {
+            t!("server-test")
+        })
+        .size(14),
+    )
+    .padding([12, 16])
+    .style(theme::button_style::secondary);
+    if !onboarding.testing_server {
+        test_btn = test_btn.on_press(Message::OnboardingTestServer);
+    }
+
+    let mut content = column![
+        row![
+            text_input("https://testnet.zec.rocks:443", &onboarding.server_input)
+                .padding(12)
+                .size(14)
+                .style(theme::input_style::default)
+                .on_input(Message::OnboardingServerChanged),
+            Space::with_width(12),
+            test_btn,
+        ]
+        .align_y(Alignment::Center),
+    ];
+
+    if let Some(info) = &onboarding.server_info {
+        content = content.push(Space::with_height(12));
+        content = content.push(
+            text(t!(
+                "server-connected",
+                version = info.version.clone(),
+                block = info.block_height
+            ))
+            .size(12)
+            .color(colors::SUCCESS),
+        );
+        if info.network() != Some(onboarding.network) {
+            content = content.push(
+                text(t!(
+                    "server-wrong-chain",
+                    chain = info.chain_name.clone(),
+                    network = onboarding.network.to_string()
+                ))
+                .size(12)
+                .color(colors::WARNING),
+            );
+        }
+    }
+    content.into()
+}
+
+fn navigation(onboarding: &Onboarding) -> Element<'_, Message> {
+    let step = onboarding.step;
+    if step == OnboardingStep::Finishing {
+        return Space::with_height(0).into();
+    }
+
+    let mut nav = row![].align_y(Alignment::Center);
+    if step == OnboardingStep::Welcome {
+        nav = nav.push(
+            button(text(t!("onboarding-skip")).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::SkipOnboarding),
+        );
+    } else {
+        nav = nav.push(
+            button(text(t!("action-back")).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::OnboardingBack),
+        );
+    }
+    nav = nav.push(horizontal_space());
+
+    // The choice step moves on with its own buttons
+    if step != OnboardingStep::Choice {
+        let label = match step {
+            OnboardingStep::Welcome => t!("onboarding-get-started"),
+            OnboardingStep::Server => t!("onboarding-finish"),
+            _ => t!("action-continue"),
+        };
+        let ready = step != OnboardingStep::ConfirmBackup || onboarding.backup_confirmed;
+        let mut next = button(text(label).size(13))
+            .padding([10, 20])
+            .style(theme::button_style::primary);
+        if ready {
+            next = next.on_press(Message::OnboardingNext);
+        }
+        nav = nav.push(next);
+    }
+    nav.into()
+}
@@ -1,29 +1,33 @@
 //! Settings view - Application settings
 
 use crate::app::ZotsApp;
-use crate::message::Message;
-use crate::theme;
-use iced::widget::{Space, button, column, container, row, text, text_input};
+use crate::i18n::{Language, t};
+use crate::message::{Message, UpgradeInterval};
+use crate::theme::{self, ThemeChoice, colors};
+use iced::widget::{
+    Space, button, column, container, horizontal_space, pick_list, radio, row, text, text_input,
+};
 use iced::{Alignment, Element, Length};
+use zots_core::Network;
 
 pub fn view(app: &ZotsApp) -> Element<Message> {
     let title = row![
         text(">").size(28),
         Space::with_width(12),
-        text("Settings").size(24),
+        text(t!("view-settings")).size(24),
     ]
     .align_y(Alignment::Center);
 
-    let description = text("Configure application settings")
+    let description = text(t!("settings-description"))
         .size(14)
         .style(theme::text_style::muted());
 
     // Explorer URL setting
     let explorer_section = container(
         column![
-            text("Block Explorer").size(16),
+            text(t!("settings-explorer-title")).size(16),
             Space::with_height(8),
-            text("URL for viewing Zcash transactions")
+            text(t!("settings-explorer"))
                 .size(12)
                 .style(theme::text_style::dim()),
             Space::with_height(12),
@@ -38,23 +42,145 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     .style(theme::container_style::card)
     .width(Length::Fill);
 
-    // Lightwalletd URL setting
-    let lightwalletd_section = container(
+    // Wallet network, server and birthday
+    let mut network_col = column![
+        text(t!("settings-network-title")).size(16),
+        Space::with_height(8),
+        text(t!("settings-network"))
+            .size(12)
+            .style(theme::text_style::dim()),
+        Space::with_height(12),
+        radio(
+            t!("network-testnet"),
+            Network::Testnet,
+            Some(app.network),
+            Message::NetworkChanged,
+        )
+        .text_size(14),
+        Space::with_height(8),
+        text(t!("network-mainnet-unsupported"))
+            .size(12)
+            .style(theme::text_style::dim()),
+        Space::with_height(12),
+        text(t!("settings-lightwalletd")).size(13),
+        Space::with_height(8),
+        text_input("https://testnet.zec.rocks:443", &app.lightwalletd_url)
+            .padding(12)
+            .size(14)
+            .style(theme::input_style::default)
+            .on_input(Message::LightwalletdUrlChanged),
+        Space::with_height(12),
+        text(t!("settings-birthday")).size(13),
+        Space::with_height(8),
+        text_input("3717528", &app.birthday_input)
+            .padding(12)
+            .size(14)
+            .style(theme::input_style::default)
+            .on_input(Message::BirthdayHeightChanged),
+    ];
+    if app
+        .config
+        .as_ref()
+        .is_some_and(|c| c.network != app.network)
+    {
+        network_col = network_col.push(Space::with_height(12));
+        network_col = network_col.push(
+            text(t!(
+                "settings-network-switch",
+                network = app.network.to_string()
+            ))
+            .size(12)
+            .style(theme::text_style::warning()),
+        );
+    }
+    let network_section = container(network_col.padding(20))
+        .style(theme::container_style::card)
+        .width(Length::Fill);
+
+    // Proofs directory setting
+    let proofs_dir_section = container(
+        column![
+            text(t!("settings-proofs-dir-title")).size(16),
+            Space::with_height(8),
+            text(t!("settings-proofs-dir"))
+                .size(12)
+                .style(theme::text_style::dim()),
+            Space::with_height(12),
+            row![
+                text_input("~/Documents/zots", &app.proofs_dir_input)
+                    .padding(12)
+                    .size(14)
+                    .style(theme::input_style::default)
+                    .on_input(Message::ProofsDirChanged),
+                Space::with_width(12),
+                button(text(t!("action-browse")).size(14))
+                    .padding([12, 16])
+                    .style(theme::button_style::secondary)
+                    .on_press(Message::SelectProofsDir),
+            ]
+            .align_y(Alignment::Center),
+        ]
+        .padding(20),
+    )
+    .style(theme::container_style::card)
+    .width(Length::Fill);
+
+    // Appearance
+    let theme_section = container(
         column![
-            text("Lightwalletd Server").size(16),
+            text(t!("settings-appearance-title")).size(16),
             Space::with_height(8),
-            text("gRPC endpoint for Zcash light client")
+            text(t!("settings-appearance"))
                 .size(12)
                 .style(theme::text_style::dim()),
             Space::with_height(12),
-            text_input(
-                "https://zcash.mysideoftheweb.com:19067",
-                &app.lightwalletd_url,
+            pick_list(ThemeChoice::ALL, Some(app.theme), Message::ThemeChanged)
+                .padding(12)
+                .text_size(14),
+        ]
+        .padding(20),
+    )
+    .style(theme::container_style::card)
+    .width(Length::Fill);
+
+    // Display language
+    let language_section = container(
+        column![
+            text(t!("settings-language-title")).size(16),
+            Space::with_height(8),
+            text(t!("settings-language"))
+                .size(12)
+                .style(theme::text_style::dim()),
+            Space::with_height(12),
+            pick_list(
+                Language::all(),
+                Some(app.language),
+                Message::LanguageChanged
             )
             .padding(12)
-            .size(14)
-            .style(theme::input_style::default)
-            .on_input(Message::LightwalletdUrlChanged),
+            .text_size(14),
+        ]
+        .padding(20),
+    )
+    .style(theme::container_style::card)
+    .width(Length::Fill);
+
+    // Pending proof upgrade schedule
+    let upgrades_section = container(
+        column![
+            text(t!("settings-upgrades-title")).size(16),
+            Space::with_height(8),
+            text(t!("settings-upgrades"))
+                .size(12)
+                .style(theme::text_style::dim()),
+            Space::with_height(12),
+            pick_list(
+                UpgradeInterval::ALL,
+                Some(app.upgrade_interval),
+                Message::UpgradeIntervalChanged,
+            )
+            .padding(12)
+            .text_size(14),
         ]
         .padding(20),
     )
@@ -66,7 +192,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         row![
             text(">").size(14),
             Space::with_width(8),
-            text("Save Settings").size(14),
+            text(t!("settings-save")).size(14),
         ]
         .align_y(Alignment::Center),
     )
@@ -78,7 +204,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         row![
             text("↩").size(14),
             Space::with_width(8),
-            text("Reset to Defaults").size(14),
+            text(t!("settings-reset")).size(14),
         ]
         .align_y(Alignment::Center),
     )
@@ -91,7 +217,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text("✓").size(14).style(theme::text_style::success()),
                 Space::with_width(8),
-                text("Settings saved!")
+                text(t!("settings-saved"))
                     .size(14)
                     .style(theme::text_style::success()),
             ]
@@ -110,16 +236,26 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     ]
     .align_y(Alignment::Center);
 
+    let mut actions = column![buttons];
+    if let Some(error) = &app.settings_error {
+        actions = actions.push(Space::with_height(12));
+        actions = actions.push(text(error).size(12).color(colors::ERROR));
+    }
+    if app.resync_prompt {
+        actions = actions.push(Space::with_height(16));
+        actions = actions.push(resync_prompt(app));
+    }
+
     // About section
     let about_section = container(
         column![
-            text("About zOpenTimestamps").size(16),
+            text(t!("home-about-title")).size(16),
             Space::with_height(12),
-            info_row("Version", "0.1.1"),
-            info_row("License", "MIT"),
-            info_row("Author", "AbdelStark"),
+            info_row(t!("label-version"), env!("CARGO_PKG_VERSION")),
+            info_row(t!("label-license"), "MIT"),
+            info_row(t!("label-author"), "AbdelStark"),
             Space::with_height(16),
-            text("zOpenTimestamps is a Zcash blockchain timestamping tool inspired by OpenTimestamps. It creates cryptographic proofs that data existed at a specific point in time using Zcash's shielded transactions.")
+            text(t!("home-about"))
                 .size(12)
                 .style(theme::text_style::muted()),
             Space::with_height(16),
@@ -147,11 +283,11 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     // Keyboard shortcuts
     let shortcuts_section = container(
         column![
-            text("Environment Variables").size(16),
+            text(t!("settings-env-title")).size(16),
             Space::with_height(12),
-            env_var_row("ZOTS_SEED", "BIP-39 seed phrase for wallet"),
-            env_var_row("ZOTS_LIGHTWALLETD_URL", "Override lightwalletd server"),
-            env_var_row("ZOTS_EXPLORER_URL", "Override block explorer URL"),
+            env_var_row("ZOTS_SEED", t!("settings-env-seed")),
+            env_var_row("ZOTS_LIGHTWALLETD", t!("settings-env-lightwalletd")),
+            env_var_row("ZOTS_EXPLORER_URL", t!("settings-env-explorer")),
         ]
         .padding(20),
     )
@@ -165,9 +301,17 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         Space::with_height(24),
         explorer_section,
         Space::with_height(16),
-        lightwalletd_section,
+        network_section,
+        Space::with_height(16),
+        proofs_dir_section,
+        Space::with_height(16),
+        upgrades_section,
+        Space::with_height(16),
+        theme_section,
+        Space::with_height(16),
+        language_section,
         Space::with_height(24),
-        buttons,
+        actions,
         Space::with_height(24),
         shortcuts_section,
         Space::with_height(16),
@@ -177,7 +321,45 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     .into()
 }
 
-fn info_row<'a>(label: &'a str, value: &'a str) -> Element<'a, Message> {
+/// Offer to resync after the wallet settings changed
+fn resync_prompt(app: &ZotsApp) -> Element<Message> {
+    let mut resync_btn = button(text(t!("settings-resync-now")).size(13))
+        .padding([8, 16])
+        .style(theme::button_style::primary);
+    if !app.wallet_syncing {
+        resync_btn = resync_btn.on_press(Message::SyncWallet);
+    }
+
+    container(
+        row![
+            column![
+                text(t!("settings-resync-title")).size(14),
+                Space::with_height(4),
+                text(t!("settings-resync"))
+                    .size(12)
+                    .style(theme::text_style::muted()),
+            ],
+            horizontal_space(),
+            button(text(t!("settings-resync-later")).size(13))
+                .padding([8, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::DismissResyncPrompt),
+            Space::with_width(8),
+            resync_btn,
+        ]
+        .align_y(Alignment::Center)
+        .padding(16),
+    )
+    .style(|theme| {
+        let mut style = theme::container_style::card(theme);
+        style.border.color = colors::WARNING;
+        style
+    })
+    .width(Length::Fill)
+    .into()
+}
+
+fn info_row(label: String, value: &str) -> Element<'_, Message> {
     container(
         row![
             text(format!("{label}:"))
@@ -192,7 +374,7 @@ fn info_row<'a>(label: &'a str, value: &'a str) -> Element<'a, Message> {
     .into()
 }
 
-fn env_var_row<'a>(name: &'a str, description: &'a str) -> Element<'a, Message> {
+fn env_var_row(name: &str, description: String) -> Element<'_, Message> {
     container(
         row![
             text(name)
@@ -1,7 +1,9 @@
 //! Stamp view - Create timestamps
 
+use crate::app::BatchControl;
 use crate::app::ZotsApp;
-use crate::message::Message;
+use crate::i18n::t;
+use crate::message::{Message, QueuePhase};
 use crate::theme::{self, colors};
 use iced::widget::{Space, button, column, container, row, text, text_input};
 use iced::{Alignment, Element, Length};
@@ -10,18 +12,18 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     let title = row![
         text(">").size(28),
         Space::with_width(12),
-        text("Create Timestamp").size(24),
+        text(t!("stamp-title")).size(24),
     ]
     .align_y(Alignment::Center);
 
-    let description = text("Timestamp a file or hash on the Zcash blockchain. The proof will be saved as a .zots file.")
+    let description = text(t!("stamp-description"))
         .size(14)
         .style(theme::text_style::muted());
 
     // Input section
-    let input_label = text("File path or hash").size(14);
+    let input_label = text(t!("stamp-input-label")).size(14);
 
-    let file_input = text_input("Enter file path or 64-char hex hash...", &app.stamp_input)
+    let file_input = text_input(&t!("stamp-input-placeholder"), &app.stamp_input)
         .padding(12)
         .size(14)
         .style(theme::input_style::default)
@@ -31,7 +33,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         row![
             text("...").size(14),
             Space::with_width(8),
-            text("Browse").size(14),
+            text(t!("action-browse")).size(14),
         ]
         .align_y(Alignment::Center),
     )
@@ -44,12 +46,12 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         .width(Length::Fill);
 
     // Algorithm selection
-    let algo_label = text("Hash Algorithm").size(14);
+    let algo_label = text(t!("stamp-algorithm")).size(14);
     let algo_value = text(app.hash_algorithm.name())
         .size(14)
         .style(theme::text_style::accent());
 
-    let toggle_btn = button(text("Toggle").size(12))
+    let toggle_btn = button(text(t!("stamp-toggle")).size(12))
         .padding([8, 12])
         .style(theme::button_style::secondary)
         .on_press(Message::ToggleAlgorithm);
@@ -81,7 +83,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text(">").size(16),
                 Space::with_width(12),
-                text("Create Timestamp").size(14),
+                text(t!("stamp-title")).size(14),
             ]
             .align_y(Alignment::Center),
         )
@@ -96,14 +98,9 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         let block_str = result.block_height.to_string();
         let output_str = result.output_path.display().to_string();
         let (icon, title, status_color) = if result.pending {
-            ("⏳", "Transaction Broadcast!", colors::WARNING)
+            ("⏳", t!("stamp-broadcast"), colors::WARNING)
         } else {
-            ("✓", "Timestamp Confirmed!", colors::SUCCESS)
-        };
-        let status_text = if result.pending {
-            "Waiting for block confirmation. The proof file has been saved."
-        } else {
-            ""
+            ("✓", t!("stamp-confirmed"), colors::SUCCESS)
         };
 
         let mut content_col = column![
@@ -117,27 +114,31 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
 
         if result.pending {
             content_col = content_col.push(Space::with_height(8));
-            content_col =
-                content_col.push(text(status_text).size(12).style(theme::text_style::muted()));
+            content_col = content_col.push(
+                text(t!("stamp-pending-hint"))
+                    .size(12)
+                    .style(theme::text_style::muted()),
+            );
         }
 
         content_col = content_col.push(Space::with_height(16));
-        content_col = content_col.push(info_row("Hash", result.hash.clone(), true));
+        content_col = content_col.push(info_row(t!("label-hash"), result.hash.clone(), true));
         content_col = content_col.push(info_row(
-            "Algorithm",
+            t!("label-algorithm"),
             result.algorithm.name().to_string(),
             false,
         ));
-        content_col = content_col.push(info_row("Transaction", result.txid.clone(), true));
-        content_col = content_col.push(info_row("Block", block_str, false));
-        content_col = content_col.push(info_row("Saved to", output_str, false));
+        content_col =
+            content_col.push(info_row(t!("label-transaction"), result.txid.clone(), true));
+        content_col = content_col.push(info_row(t!("label-block"), block_str, false));
+        content_col = content_col.push(info_row(t!("stamp-saved-to"), output_str, false));
         content_col = content_col.push(Space::with_height(16));
         content_col = content_col.push(row![
             button(
                 row![
                     text(">").size(14),
                     Space::with_width(8),
-                    text("Copy Proof").size(13),
+                    text(t!("action-copy-proof")).size(13),
                 ]
                 .align_y(Alignment::Center),
             )
@@ -149,14 +150,32 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text(">").size(14),
                     Space::with_width(8),
-                    text("View in Explorer").size(13),
+                    text(t!("action-view-explorer")).size(13),
                 ]
                 .align_y(Alignment::Center),
             )
             .padding([10, 16])
             .style(theme::button_style::secondary)
             .on_press(Message::OpenExplorer(result.explorer_link.clone())),
+            Space::with_width(12),
+            button(
+                row![
+                    text(">").size(14),
+                    Space::with_width(8),
+                    text(t!("action-export-certificate")).size(13),
+                ]
+                .align_y(Alignment::Center),
+            )
+            .padding([10, 16])
+            .style(theme::button_style::secondary)
+            .on_press(Message::ExportCertificate(result.output_path.clone())),
         ]);
+        if let Some(qr) = &result.qr
+            && !result.compact.is_empty()
+        {
+            content_col = content_col.push(Space::with_height(16));
+            content_col = content_col.push(super::qr_panel(qr, &result.compact));
+        }
 
         let border_color = if result.pending {
             colors::WARNING
@@ -177,7 +196,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text("✗").size(20).style(theme::text_style::error()),
                     Space::with_width(12),
-                    text("Stamp Failed")
+                    text(t!("stamp-failed"))
                         .size(16)
                         .style(theme::text_style::error()),
                 ]
@@ -220,13 +239,126 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         Space::with_height(24),
         content,
         Space::with_height(24),
+        queue_card(app),
+        Space::with_height(24),
         result_section,
     ]
     .width(Length::Fill)
     .into()
 }
 
-fn info_row(label: &'static str, value: String, copyable: bool) -> Element<'static, Message> {
+/// Batch stamping queue: files stamped together in one transaction
+fn queue_card(app: &ZotsApp) -> Element<Message> {
+    let running = app.batch.is_some();
+    let paused = app.batch.as_ref().is_some_and(BatchControl::is_paused);
+    let to_stamp = app
+        .stamp_queue
+        .iter()
+        .filter(|item| item.phase.needs_stamp())
+        .count();
+
+    let header = row![
+        text(t!("batch-title", count = app.stamp_queue.len())).size(14),
+        Space::with_width(Length::Fill),
+        button(text(t!("batch-add-files")).size(12))
+            .padding([6, 12])
+            .style(theme::button_style::secondary)
+            .on_press_maybe((!running).then_some(Message::AddFilesToQueue)),
+        Space::with_width(8),
+        button(text(t!("action-clear")).size(12))
+            .padding([6, 12])
+            .style(theme::button_style::secondary)
+            .on_press_maybe(
+                (!running && !app.stamp_queue.is_empty()).then_some(Message::ClearStampQueue),
+            ),
+    ]
+    .align_y(Alignment::Center);
+
+    let mut content = column![header, Space::with_height(12)];
+
+    if app.stamp_queue.is_empty() {
+        content = content.push(
+            text(t!("batch-empty"))
+                .size(13)
+                .style(theme::text_style::muted()),
+        );
+    } else {
+        let items: Vec<Element<Message>> =
+            app.stamp_queue
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let name = item
+                        .path
+                        .file_name()
+                        .map(|f| f.to_string_lossy().to_string())
+                        .unwrap_or_else(|| item.path.display().to_string());
+                    let phase_color = match item.phase {
+                        QueuePhase::Done { pending: false } => colors::SUCCESS,
+                        QueuePhase::Done { pending: true }
+                        | QueuePhase::WaitingConfirmation { .. } => colors::WARNING,
+                        QueuePhase::Failed(_) => colors::ERROR,
+                        _ => colors::TEXT_MUTED,
+                    };
+                    row![
+                        text(name).size(13).width(Length::FillPortion(2)),
+                        text(item.phase.label())
+                            .size(12)
+                            .color(phase_color)
+                            .width(Length::FillPortion(2)),
+                        button(text("x").size(11))
+                            .padding([4, 8])
+                            .style(theme::button_style::secondary)
+                            .on_press_maybe((!running).then_some(Message::RemoveQueuedFile(index))),
+                    ]
+                    .align_y(Alignment::Center)
+                    .into()
+                })
+                .collect();
+        content = content.push(column(items).spacing(6));
+        content = content.push(Space::with_height(16));
+
+        let controls = if running {
+            row![
+                button(
+                    text(if paused {
+                        t!("batch-resume")
+                    } else {
+                        t!("batch-pause")
+                    })
+                    .size(13)
+                )
+                .padding([10, 16])
+                .style(theme::button_style::secondary)
+                .on_press(if paused {
+                    Message::ResumeBatch
+                } else {
+                    Message::PauseBatch
+                }),
+                Space::with_width(12),
+                button(text(t!("action-cancel")).size(13))
+                    .padding([10, 16])
+                    .style(theme::button_style::secondary)
+                    .on_press(Message::CancelBatch),
+            ]
+        } else {
+            row![
+                button(text(t!("batch-start", count = to_stamp)).size(14))
+                    .padding([12, 20])
+                    .style(theme::button_style::primary)
+                    .on_press_maybe((to_stamp > 0).then_some(Message::StartBatch)),
+            ]
+        };
+        content = content.push(controls.align_y(Alignment::Center));
+    }
+
+    container(content.padding(24))
+        .style(theme::container_style::card)
+        .width(Length::Fill)
+        .into()
+}
+
+fn info_row(label: String, value: String, copyable: bool) -> Element<'static, Message> {
     let display_value = if value.len() > 40 {
         format!("{}...{}", &value[..20], &value[value.len() - 12..])
     } else {
@@ -1,6 +1,7 @@
 //! Verify view - Verify timestamp proofs
 
 use crate::app::ZotsApp;
+use crate::i18n::t;
 use crate::message::Message;
 use crate::theme::{self, colors};
 use iced::widget::{Space, button, column, container, row, text, text_input};
@@ -10,31 +11,28 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     let title = row![
         text(">").size(28),
         Space::with_width(12),
-        text("Verify Proof").size(24),
+        text(t!("verify-title")).size(24),
     ]
     .align_y(Alignment::Center);
 
-    let description = text("Verify a timestamp proof against the Zcash blockchain. Optionally provide the original file to check the hash matches.")
+    let description = text(t!("verify-description"))
         .size(14)
         .style(theme::text_style::muted());
 
     // File/hash input (optional)
-    let file_label = text("Original file or hash (optional)").size(14);
+    let file_label = text(t!("verify-file-label")).size(14);
 
-    let file_input = text_input(
-        "Enter file path or hash to verify against...",
-        &app.verify_file_input,
-    )
-    .padding(12)
-    .size(14)
-    .style(theme::input_style::default)
-    .on_input(Message::VerifyFileInputChanged);
+    let file_input = text_input(&t!("verify-file-placeholder"), &app.verify_file_input)
+        .padding(12)
+        .size(14)
+        .style(theme::input_style::default)
+        .on_input(Message::VerifyFileInputChanged);
 
     let browse_file_btn = button(
         row![
             text("...").size(14),
             Space::with_width(8),
-            text("Browse").size(14),
+            text(t!("action-browse")).size(14),
         ]
         .align_y(Alignment::Center),
     )
@@ -47,9 +45,9 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         .width(Length::Fill);
 
     // Proof file input (required)
-    let proof_label = text("Proof file (.zots)").size(14);
+    let proof_label = text(t!("verify-proof-label")).size(14);
 
-    let proof_input = text_input("Select a .zots proof file...", &app.verify_proof_input)
+    let proof_input = text_input(&t!("verify-proof-placeholder"), &app.verify_proof_input)
         .padding(12)
         .size(14)
         .style(theme::input_style::default)
@@ -59,7 +57,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         row![
             text("...").size(14),
             Space::with_width(8),
-            text("Browse").size(14),
+            text(t!("action-browse")).size(14),
         ]
         .align_y(Alignment::Center),
     )
@@ -77,7 +75,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text(app.spinner()).size(16),
                 Space::with_width(12),
-                text("Verifying...").size(14),
+                text(t!("verify-running")).size(14),
             ]
             .align_y(Alignment::Center),
         )
@@ -89,7 +87,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text("✓").size(16),
                 Space::with_width(12),
-                text("Verify Proof").size(14),
+                text(t!("verify-title")).size(14),
             ]
             .align_y(Alignment::Center),
         )
@@ -102,9 +100,9 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     // Result section
     let result_section = if let Some(result) = &app.verify_result {
         let (icon, title_text, title_color) = if result.valid {
-            ("✓", "Proof Valid!", colors::SUCCESS)
+            ("✓", t!("verify-valid"), colors::SUCCESS)
         } else {
-            ("✗", "Verification Failed", colors::ERROR)
+            ("✗", t!("verify-failed"), colors::ERROR)
         };
 
         let hash_match_indicator = match result.file_hash_matches {
@@ -112,7 +110,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text("✓").size(14).style(theme::text_style::success()),
                     Space::with_width(8),
-                    text("File hash matches proof")
+                    text(t!("verify-hash-matches"))
                         .size(13)
                         .style(theme::text_style::success()),
                 ]
@@ -122,7 +120,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text("✗").size(14).style(theme::text_style::error()),
                     Space::with_width(8),
-                    text("File hash does NOT match!")
+                    text(t!("verify-hash-mismatch"))
                         .size(13)
                         .style(theme::text_style::error()),
                 ]
@@ -161,20 +159,28 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
         }
 
         content_col = content_col.push(Space::with_height(16));
-        content_col = content_col.push(info_row("Hash", result.hash.clone()));
-        content_col = content_col.push(info_row("Algorithm", result.algorithm.name().to_string()));
+        content_col = content_col.push(info_row(t!("label-hash"), result.hash.clone()));
+        content_col = content_col.push(info_row(
+            t!("label-algorithm"),
+            result.algorithm.name().to_string(),
+        ));
+        if let Some(note) = &result.note {
+            content_col = content_col.push(info_row(t!("label-note"), note.clone()));
+        }
 
         if !result.network.is_empty() {
-            content_col = content_col.push(info_row("Network", result.network.clone()));
+            content_col = content_col.push(info_row(t!("label-network"), result.network.clone()));
         }
         if result.block_height > 0 {
-            content_col = content_col.push(info_row("Block", result.block_height.to_string()));
+            content_col =
+                content_col.push(info_row(t!("label-block"), result.block_height.to_string()));
         }
         if !result.timestamp.is_empty() {
-            content_col = content_col.push(info_row("Timestamp", result.timestamp.clone()));
+            content_col =
+                content_col.push(info_row(t!("label-timestamp"), result.timestamp.clone()));
         }
         if !result.txid.is_empty() {
-            content_col = content_col.push(info_row("Transaction", result.txid.clone()));
+            content_col = content_col.push(info_row(t!("label-transaction"), result.txid.clone()));
         }
 
         content_col = content_col.push(Space::with_height(16));
@@ -183,7 +189,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text(">").size(14),
                 Space::with_width(8),
-                text("Copy Compact").size(13),
+                text(t!("action-copy-compact")).size(13),
             ]
             .align_y(Alignment::Center),
         )
@@ -200,7 +206,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                     row![
                         text(">").size(14),
                         Space::with_width(8),
-                        text("View in Explorer").size(13),
+                        text(t!("action-view-explorer")).size(13),
                     ]
                     .align_y(Alignment::Center),
                 )
@@ -212,6 +218,13 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
 
         content_col = content_col.push(buttons_row);
 
+        if let Some(qr) = &result.qr
+            && !result.compact.is_empty()
+        {
+            content_col = content_col.push(Space::with_height(16));
+            content_col = content_col.push(super::qr_panel(qr, &result.compact));
+        }
+
         let border_color = if result.valid {
             theme::colors::SUCCESS
         } else {
@@ -231,7 +244,9 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text("✗").size(20).style(theme::text_style::error()),
                     Space::with_width(12),
-                    text("Error").size(16).style(theme::text_style::error()),
+                    text(t!("label-error"))
+                        .size(16)
+                        .style(theme::text_style::error()),
                 ]
                 .align_y(Alignment::Center),
                 Space::with_height(12),
@@ -280,7 +295,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
     .into()
 }
 
-fn info_row(label: &'static str, value: String) -> Element<'static, Message> {
+fn info_row(label: String, value: String) -> Element<'static, Message> {
     let display_value = if value.len() > 50 {
         format!("{}...{}", &value[..24], &value[value.len() - 12..])
     } else {
@@ -1,20 +1,27 @@
 //! Wallet view - Wallet management
 
 use crate::app::ZotsApp;
-use crate::message::Message;
+use crate::i18n::t;
+use crate::message::{Message, SeedReveal};
 use crate::theme::{self, colors};
-use iced::widget::{Space, button, column, container, horizontal_space, row, text, text_input};
+use crate::views::{self, modal};
+use iced::widget::{
+    Space, button, column, container, horizontal_space, image, row, scrollable, stack, text,
+    text_input,
+};
 use iced::{Alignment, Element, Length};
+use zots_core::{Network, hash_to_hex};
+use zots_zcash::{HistoryEntry, TESTNET_FAUCET_URL, memo_text};
 
 pub fn view(app: &ZotsApp) -> Element<Message> {
     let title = row![
         text(">").size(28),
         Space::with_width(12),
-        text("Wallet").size(24),
+        text(t!("view-wallet")).size(24),
     ]
     .align_y(Alignment::Center);
 
-    let description = text("Manage your Zcash testnet wallet for timestamping")
+    let description = text(t!("wallet-description"))
         .size(14)
         .style(theme::text_style::muted());
 
@@ -24,11 +31,11 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             text("!").size(20),
             Space::with_width(12),
             column![
-                text("Security Warning")
+                text(t!("wallet-security-title"))
                     .size(14)
                     .style(theme::text_style::warning()),
                 Space::with_height(4),
-                text("Never enter your mainnet seed phrase here. This app is for testnet only. Your seed is stored in the ZOTS_SEED environment variable.")
+                text(t!("wallet-security"))
                     .size(12)
                     .style(theme::text_style::muted()),
             ],
@@ -53,7 +60,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text(app.spinner()).size(14),
                     Space::with_width(8),
-                    text("Syncing...").size(14),
+                    text(t!("wallet-syncing")).size(14),
                 ]
                 .align_y(Alignment::Center),
             )
@@ -64,7 +71,7 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
                 row![
                     text(">").size(14),
                     Space::with_width(8),
-                    text("Sync Now").size(14),
+                    text(t!("wallet-sync-now")).size(14),
                 ]
                 .align_y(Alignment::Center),
             )
@@ -77,14 +84,18 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text("✓").size(18).color(colors::SUCCESS),
                 Space::with_width(12),
-                text("Wallet Configured").size(16).color(colors::SUCCESS),
+                text(t!("wallet-configured"))
+                    .size(16)
+                    .color(colors::SUCCESS),
             ]
             .align_y(Alignment::Center),
             Space::with_height(24),
             // Balance card
             container(
                 column![
-                    text("Balance").size(12).style(theme::text_style::muted()),
+                    text(t!("label-balance"))
+                        .size(12)
+                        .style(theme::text_style::muted()),
                     Space::with_height(4),
                     text(format!("{balance_zec:.8} ZEC"))
                         .size(28)
@@ -95,27 +106,30 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             )
             .style(theme::container_style::surface)
             .width(Length::Fill),
+            Space::with_height(12),
+            pool_balances(app),
+            shielding_prompt(app),
             Space::with_height(16),
             // Info rows
             info_row(
-                "Network",
+                t!("label-network"),
                 app.config
                     .as_ref()
-                    .map(|c| c.network.name())
-                    .unwrap_or("Unknown")
-                    .to_string(),
+                    .map_or_else(|| t!("label-unknown"), |c| c.network.name().to_string()),
             ),
-            info_row("Block Height", app.block_height.to_string()),
+            info_row(t!("label-block-height"), app.block_height.to_string()),
             info_row(
-                "Status",
+                t!("label-status"),
                 if app.wallet_syncing {
-                    "Syncing...".to_string()
+                    t!("wallet-syncing")
                 } else {
-                    "Ready".to_string()
+                    t!("wallet-ready")
                 },
             ),
             Space::with_height(20),
             row![sync_btn, horizontal_space(),],
+            Space::with_height(20),
+            recovery_phrase(app),
         ]
         .padding(24);
 
@@ -143,34 +157,52 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             row![
                 text(">").size(18),
                 Space::with_width(12),
-                text("Configure Wallet").size(16),
+                text(t!("wallet-configure-title")).size(16),
             ]
             .align_y(Alignment::Center),
             Space::with_height(16),
-            text("Enter your BIP-39 seed phrase (24 words) to configure the wallet.")
+            text(t!("wallet-configure"))
                 .size(13)
                 .style(theme::text_style::muted()),
             Space::with_height(16),
-            text("Seed Phrase").size(13),
+            text(t!("wallet-seed-label")).size(13),
             Space::with_height(8),
-            text_input("Enter your 24-word seed phrase...", &app.seed_input,)
+            text_input(&t!("wallet-seed-placeholder"), &app.seed_input,)
                 .padding(12)
                 .size(14)
                 .style(theme::input_style::default)
                 .on_input(Message::SeedInputChanged)
                 .secure(true),
             Space::with_height(16),
-            button(
-                row![
-                    text(">").size(14),
-                    Space::with_width(8),
-                    text("Save & Connect").size(14),
-                ]
-                .align_y(Alignment::Center),
-            )
-            .padding([12, 20])
-            .style(theme::button_style::primary)
-            .on_press(Message::SaveSeed),
+            if app.seed_saving {
+                button(
+                    row![
+                        text(app.spinner()).size(14),
+                        Space::with_width(8),
+                        text(t!("wallet-saving")).size(14),
+                    ]
+                    .align_y(Alignment::Center),
+                )
+                .padding([12, 20])
+                .style(theme::button_style::primary)
+            } else {
+                button(
+                    row![
+                        text(">").size(14),
+                        Space::with_width(8),
+                        text(t!("wallet-save-seed")).size(14),
+                    ]
+                    .align_y(Alignment::Center),
+                )
+                .padding([12, 20])
+                .style(theme::button_style::primary)
+                .on_press(Message::SaveSeed)
+            },
+            Space::with_height(12),
+            button(text(t!("wallet-run-wizard")).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::StartOnboarding),
         ]
         .padding(24);
 
@@ -194,46 +226,329 @@ pub fn view(app: &ZotsApp) -> Element<Message> {
             .width(Length::Fill)
     };
 
-    // Environment variable hint
-    let env_hint = container(
+    // Where the seed lives
+    let storage_hint = container(
         column![
-            text("※ Tip: Set ZOTS_SEED environment variable")
+            text(t!("wallet-storage-title"))
                 .size(13)
                 .style(theme::text_style::muted()),
             Space::with_height(8),
-            text("You can set your seed phrase in a .env file or as an environment variable to avoid entering it each time.")
+            text(t!("wallet-storage"))
                 .size(12)
                 .style(theme::text_style::dim()),
-            Space::with_height(8),
-            container(
-                text("export ZOTS_SEED=\"your seed phrase here\"")
-                    .size(12)
-                    .style(theme::text_style::accent()),
-            )
-            .style(theme::container_style::surface)
-            .padding(12),
         ]
         .padding(16),
     )
     .style(theme::container_style::card)
     .width(Length::Fill);
 
+    let page = scrollable(
+        column![
+            title,
+            Space::with_height(8),
+            description,
+            Space::with_height(24),
+            warning,
+            Space::with_height(24),
+            wallet_content,
+            Space::with_height(24),
+            funding_card(app),
+            Space::with_height(24),
+            transactions_card(app),
+            Space::with_height(24),
+            storage_hint,
+        ]
+        .width(Length::Fill),
+    )
+    .height(Length::Fill);
+
+    match &app.seed_reveal {
+        SeedReveal::Confirming | SeedReveal::Loading => {
+            stack![page, modal(reveal_dialog(app), Message::HideSeed)].into()
+        }
+        _ => page.into(),
+    }
+}
+
+/// Spendable value per pool, with value still awaiting confirmations
+fn pool_balances(app: &ZotsApp) -> Element<Message> {
+    let breakdown = &app.balance_breakdown;
+    let pool = |name: String, spendable: u64, pending: u64| {
+        let mut col = column![
+            text(name).size(12).style(theme::text_style::muted()),
+            Space::with_height(4),
+            text(format!("{:.8} ZEC", zec(spendable))).size(14),
+        ];
+        if pending > 0 {
+            col = col.push(
+                text(t!(
+                    "wallet-pool-pending",
+                    amount = format!("{:.8}", zec(pending))
+                ))
+                .size(11)
+                .style(theme::text_style::dim()),
+            );
+        }
+        container(col.padding(12))
+            .style(theme::container_style::surface)
+            .width(Length::FillPortion(1))
+    };
+
+    row![
+        pool(
+            t!("pool-transparent"),
+            breakdown.transparent,
+            breakdown.transparent_pending
+        ),
+        pool(
+            t!("pool-sapling"),
+            breakdown.sapling,
+            breakdown.sapling_pending
+        ),
+        pool(
+            t!("pool-orchard"),
+            breakdown.orchard,
+            breakdown.orchard_pending
+        ),
+    ]
+    .spacing(12)
+    .into()
+}
+
+/// Offer to shield funds sitting in the transparent pool
+///
+/// Timestamps are shielded transactions, so transparent funds cannot pay
+/// for them until they are moved to Orchard.
+fn shielding_prompt(app: &ZotsApp) -> Element<Message> {
+    let breakdown = &app.balance_breakdown;
+    let transparent = breakdown.transparent + breakdown.transparent_pending;
+    if transparent == 0 {
+        return Space::with_height(0).into();
+    }
+    let can_spend = app.config.as_ref().is_some_and(|c| c.seed_phrase.is_some());
+
+    let hint = if breakdown.transparent == 0 {
+        t!("shield-hint-unconfirmed")
+    } else if can_spend {
+        t!("shield-hint")
+    } else {
+        t!("shield-hint-watch-only")
+    };
+    let mut content = row![
+        column![
+            text(t!(
+                "shield-transparent-funds",
+                amount = format!("{:.8}", zec(transparent))
+            ))
+            .size(13),
+            Space::with_height(4),
+            text(hint).size(12).style(theme::text_style::muted()),
+        ]
+        .width(Length::Fill),
+    ]
+    .align_y(Alignment::Center);
+
+    if can_spend {
+        let shield_btn = if app.shielding {
+            button(text(format!("{} {}", app.spinner(), t!("shield-running"))).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::primary)
+        } else {
+            button(text(t!("shield-action")).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::primary)
+                .on_press_maybe(
+                    (breakdown.transparent > 0 && !app.wallet_syncing)
+                        .then_some(Message::ShieldFunds),
+                )
+        };
+        content = content.push(shield_btn);
+    }
+
     column![
-        title,
+        Space::with_height(12),
+        container(content.padding(12))
+            .style(|theme| {
+                let mut style = theme::container_style::surface(theme);
+                style.border.color = colors::WARNING;
+                style.border.width = 1.0;
+                style
+            })
+            .width(Length::Fill),
+    ]
+    .into()
+}
+
+fn zec(zatoshis: u64) -> f64 {
+    zatoshis as f64 / 100_000_000.0
+}
+
+/// Receiving address with its QR code, a faucet link and balance polling
+fn funding_card(app: &ZotsApp) -> Element<Message> {
+    let Some(config) = &app.config else {
+        return Space::with_height(0).into();
+    };
+
+    let header = column![
+        text(t!("funding-title")).size(16),
         Space::with_height(8),
-        description,
-        Space::with_height(24),
-        warning,
-        Space::with_height(24),
-        wallet_content,
-        Space::with_height(24),
-        env_hint,
+        text(t!("funding-description"))
+            .size(12)
+            .style(theme::text_style::muted()),
+        Space::with_height(16),
+    ];
+
+    let Some(address) = &app.wallet_address else {
+        return container(
+            header
+                .push(
+                    text(t!("funding-sync-hint"))
+                        .size(13)
+                        .style(theme::text_style::dim()),
+                )
+                .padding(24),
+        )
+        .style(theme::container_style::card)
+        .width(Length::Fill)
+        .into();
+    };
+
+    let mut actions = row![
+        button(text(t!("funding-copy-address")).size(13))
+            .padding([10, 16])
+            .style(theme::button_style::secondary)
+            .on_press(Message::CopyToClipboard(address.clone())),
+    ]
+    .spacing(12);
+    if config.network == Network::Testnet {
+        actions = actions.push(
+            button(text(t!("funding-faucet")).size(13))
+                .padding([10, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::OpenExplorer(TESTNET_FAUCET_URL.to_string())),
+        );
+    }
+
+    let watch: Element<Message> = if app.funding_watch.is_some() {
+        row![
+            text(format!("{} {}", app.spinner(), t!("funding-waiting")))
+                .size(12)
+                .style(theme::text_style::accent()),
+            Space::with_width(12),
+            button(text(t!("funding-stop")).size(12))
+                .padding([6, 12])
+                .style(theme::button_style::secondary)
+                .on_press(Message::StopWatchingForFunds),
+        ]
+        .align_y(Alignment::Center)
+        .into()
+    } else {
+        button(text(t!("funding-watch")).size(13))
+            .padding([10, 16])
+            .style(theme::button_style::primary)
+            .on_press(Message::WatchForFunds)
+            .into()
+    };
+
+    let details = column![
+        text(address.as_str())
+            .size(12)
+            .font(iced::Font::MONOSPACE)
+            .style(theme::text_style::accent()),
+        Space::with_height(16),
+        actions,
+        Space::with_height(16),
+        watch,
     ]
-    .width(Length::Fill)
+    .width(Length::Fill);
+
+    let body: Element<Message> = match &app.address_qr {
+        Some(qr) => row![
+            image(qr.clone()).width(160).height(160),
+            Space::with_width(20),
+            details,
+        ]
+        .align_y(Alignment::Center)
+        .into(),
+        None => details.into(),
+    };
+
+    container(header.push(body).padding(24))
+        .style(theme::container_style::card)
+        .width(Length::Fill)
+        .into()
+}
+
+/// Reveal button, or the revealed seed with a button to hide it again
+fn recovery_phrase(app: &ZotsApp) -> Element<Message> {
+    let has_seed = app.config.as_ref().is_some_and(|c| c.seed_phrase.is_some());
+    if !has_seed {
+        return text(t!("seed-watch-only"))
+            .size(12)
+            .style(theme::text_style::dim())
+            .into();
+    }
+
+    match &app.seed_reveal {
+        SeedReveal::Shown(seed_phrase) => column![
+            text(t!("seed-title"))
+                .size(13)
+                .style(theme::text_style::muted()),
+            Space::with_height(8),
+            views::seed_words(seed_phrase),
+            Space::with_height(8),
+            button(text(t!("seed-hide")).size(13))
+                .padding([8, 16])
+                .style(theme::button_style::secondary)
+                .on_press(Message::HideSeed),
+        ]
+        .into(),
+        _ => button(text(t!("seed-reveal-action")).size(13))
+            .padding([10, 16])
+            .style(theme::button_style::secondary)
+            .on_press(Message::RevealSeed)
+            .into(),
+    }
+}
+
+fn reveal_dialog(app: &ZotsApp) -> Element<Message> {
+    let reveal_btn = if app.seed_reveal == SeedReveal::Loading {
+        button(text(format!("{} {}", app.spinner(), t!("seed-reading"))).size(13))
+            .padding([10, 16])
+            .style(theme::button_style::primary)
+    } else {
+        button(text(t!("seed-reveal")).size(13))
+            .padding([10, 16])
+            .style(theme::button_style::primary)
+            .on_press(Message::ConfirmRevealSeed)
+    };
+
+    container(
+        column![
+            text(t!("seed-reveal-title")).size(18),
+            Space::with_height(12),
+            text(t!("seed-reveal-warning"))
+                .size(13)
+                .style(theme::text_style::muted()),
+            Space::with_height(24),
+            row![
+                button(text(t!("action-cancel")).size(13))
+                    .padding([10, 16])
+                    .style(theme::button_style::secondary)
+                    .on_press(Message::HideSeed),
+                horizontal_space(),
+                reveal_btn,
+            ]
+            .align_y(Alignment::Center),
+        ]
+        .padding(24),
+    )
+    .style(theme::container_style::card)
+    .max_width(480)
     .into()
 }
 
-fn info_row(label: &'static str, value: String) -> Element<'static, Message> {
+fn info_row(label: String, value: String) -> Element<'static, Message> {
     container(
         row![
             text(format!("{label}:"))
@@ -247,3 +562,143 @@ fn info_row(label: &'static str, value: String) -> Element<'static, Message> {
     )
     .into()
 }
+
+/// Recent wallet transactions with their decrypted memos
+fn transactions_card(app: &ZotsApp) -> Element<Message> {
+    let Some(config) = &app.config else {
+        return Space::with_height(0).into();
+    };
+
+    let mut content = column![
+        text(t!("tx-title")).size(16),
+        Space::with_height(8),
+        text(t!("tx-description"))
+            .size(12)
+            .style(theme::text_style::muted()),
+        Space::with_height(16),
+    ];
+
+    if app.transactions.is_empty() {
+        content = content.push(
+            text(if app.block_height > 0 {
+                t!("tx-empty")
+            } else {
+                t!("tx-sync-hint")
+            })
+            .size(13)
+            .style(theme::text_style::dim()),
+        );
+    } else {
+        let explorer = config
+            .explorer_url
+            .as_deref()
+            .unwrap_or_else(|| config.network.default_explorer_url());
+        let rows = app
+            .transactions
+            .iter()
+            .fold(column![].spacing(8), |col, tx| {
+                col.push(transaction_row(tx, explorer))
+            });
+        content = content.push(rows);
+    }
+
+    container(content.padding(24))
+        .style(theme::container_style::card)
+        .width(Length::Fill)
+        .into()
+}
+
+fn transaction_row<'a>(tx: &'a HistoryEntry, explorer: &str) -> Element<'a, Message> {
+    let hashes = tx.timestamp_hashes();
+    let (label, color) = if !hashes.is_empty() {
+        (t!("tx-timestamp"), colors::PRIMARY)
+    } else if tx.is_sent {
+        (t!("tx-sent"), colors::WARNING)
+    } else {
+        (t!("tx-received"), colors::SUCCESS)
+    };
+    let badge = container(text(label).size(11).color(color))
+        .padding([2, 8])
+        .style(move |theme| {
+            let mut style = theme::container_style::surface(theme);
+            style.border.color = color;
+            style.border.width = 1.0;
+            style.border.radius = 10.0.into();
+            style
+        });
+
+    let when = match (tx.height, tx.block_time) {
+        (Some(height), Some(time)) => {
+            let time = chrono::DateTime::from_timestamp(time as i64, 0)
+                .unwrap_or_default()
+                .with_timezone(&chrono::Local);
+            format!(
+                "{} · {}",
+                t!("tx-block", height = height),
+                time.format("%Y-%m-%d %H:%M")
+            )
+        }
+        (Some(height), None) => t!("tx-block", height = height),
+        (None, _) => t!("tx-pending"),
+    };
+    let sign = if tx.amount < 0 { "-" } else { "+" };
+    let txid_short = if tx.txid.len() > 20 {
+        format!("{}...{}", &tx.txid[..10], &tx.txid[tx.txid.len() - 10..])
+    } else {
+        tx.txid.clone()
+    };
+
+    let amount = text(format!("{}{:.8} ZEC", sign, zec(tx.amount.unsigned_abs()))).size(13);
+    let amount = if tx.amount < 0 {
+        amount
+    } else {
+        amount.color(colors::SUCCESS)
+    };
+
+    let mut details = column![
+        row![
+            badge,
+            Space::with_width(12),
+            text(txid_short).size(12).font(iced::Font::MONOSPACE),
+            horizontal_space(),
+            amount,
+        ]
+        .align_y(Alignment::Center),
+        Space::with_height(4),
+        row![
+            text(when).size(11).style(theme::text_style::dim()),
+            horizontal_space(),
+            button(text(t!("action-view-explorer")).size(11))
+                .padding([4, 10])
+                .style(theme::button_style::secondary)
+                .on_press(Message::OpenExplorer(format!(
+                    "{}/tx/{}",
+                    explorer, tx.txid
+                ))),
+        ]
+        .align_y(Alignment::Center),
+    ];
+
+    for hash in &hashes {
+        details = details.push(
+            text(format!("{} {}", t!("label-hash"), hash_to_hex(hash)))
+                .size(11)
+                .font(iced::Font::MONOSPACE)
+                .style(theme::text_style::accent()),
+        );
+    }
+    if hashes.is_empty() {
+        for memo in tx.memos.iter().filter_map(|memo| memo_text(memo)) {
+            details = details.push(
+                text(format!("{}: {}", t!("label-memo"), memo))
+                    .size(12)
+                    .style(theme::text_style::muted()),
+            );
+        }
+    }
+
+    container(details.spacing(2).padding(12))
+        .style(theme::container_style::surface)
+        .width(Length::Fill)
+        .into()
+}
@@ -0,0 +1,396 @@
+//! Validating builder for [`ZcashConfig`].
+//!
+//! Unlike the environment loaders, the builder checks every field up front
+//! and reports all problems at once, tagged with the field they belong to,
+//! so GUIs can show messages next to the offending input.
+//!
+//! ```rust,ignore
+//! use zots_zcash::{SeedSource, ZcashConfigBuilder};
+//!
+//! let config = ZcashConfigBuilder::new()
+//!     .seed(SeedSource::Env("ZOTS_SEED".into()))
+//!     .lightwalletd("https://testnet.zec.rocks:443")
+//!     .birthday_height(3717528)
+//!     .build()?;
+//! ```
+
+use bip0039::{English, Mnemonic};
+use std::fmt;
+use std::path::PathBuf;
+use zcash_protocol::consensus::{NetworkUpgrade, Parameters, TEST_NETWORK};
+use zots_core::{HashAlgorithm, Network};
+
+use crate::config::{
+    DEFAULT_BIRTHDAY_HEIGHT, DEFAULT_IPFS_GATEWAY, DEFAULT_LIGHTWALLETD_URL,
+    DEFAULT_SYNC_MEMORY_MB, ZcashConfig, default_data_dir,
+};
+use crate::prover::ProverBackend;
+
+/// Where the wallet seed phrase comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeedSource {
+    /// Seed phrase given directly
+    Phrase(String),
+    /// Seed phrase read from the named environment variable
+    Env(String),
+    /// Seed phrase read from a file (surrounding whitespace is ignored)
+    File(PathBuf),
+}
+
+/// Configuration field a validation error refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigField {
+    /// Seed phrase source, its contents or checksum
+    Seed,
+    /// Network to use; the wallet only supports testnet
+    Network,
+    /// A lightwalletd server URL
+    Lightwalletd,
+    /// Wallet birthday height
+    BirthdayHeight,
+    /// Data directory
+    DataDir,
+    /// Remote prover URL
+    Prover,
+}
+
+impl fmt::Display for ConfigField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConfigField::Seed => "seed",
+            ConfigField::Network => "network",
+            ConfigField::Lightwalletd => "lightwalletd",
+            ConfigField::BirthdayHeight => "birthday height",
+            ConfigField::DataDir => "data directory",
+            ConfigField::Prover => "prover",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single field-level validation failure
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{field}: {message}")]
+pub struct ConfigFieldError {
+    /// Field that failed validation
+    pub field: ConfigField,
+    /// Human-readable reason
+    pub message: String,
+}
+
+impl ConfigFieldError {
+    fn new(field: ConfigField, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+/// All validation failures from [`ZcashConfigBuilder::build`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigValidationErrors(pub Vec<ConfigFieldError>);
+
+impl ConfigValidationErrors {
+    /// Errors for a single field
+    pub fn for_field(&self, field: ConfigField) -> impl Iterator<Item = &ConfigFieldError> {
+        self.0.iter().filter(move |e| e.field == field)
+    }
+}
+
+impl fmt::Display for ConfigValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid configuration")?;
+        for error in &self.0 {
+            write!(f, "\n  - {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigValidationErrors {}
+
+/// Builder for [`ZcashConfig`] with eager validation
+#[derive(Debug, Clone, Default)]
+pub struct ZcashConfigBuilder {
+    seed: Option<SeedSource>,
+    network: Option<Network>,
+    lightwalletd: Vec<String>,
+    birthday_height: Option<u64>,
+    data_dir: Option<PathBuf>,
+    prover: ProverBackend,
+    orchard_only: bool,
+    sync_memory_mb: Option<u64>,
+}
+
+impl ZcashConfigBuilder {
+    /// Create an empty builder (testnet defaults apply to unset fields)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the seed phrase source (required)
+    pub fn seed(mut self, source: SeedSource) -> Self {
+        self.seed = Some(source);
+        self
+    }
+
+    /// Set the network (default: testnet; mainnet fails validation)
+    pub fn network(mut self, network: Network) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Add a lightwalletd server; the first one added is the primary
+    pub fn lightwalletd(mut self, url: impl Into<String>) -> Self {
+        self.lightwalletd.push(url.into());
+        self
+    }
+
+    /// Replace the lightwalletd server list
+    pub fn lightwalletd_servers<I, S>(mut self, urls: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.lightwalletd = urls.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the wallet birthday height (default: 3717528)
+    pub fn birthday_height(mut self, height: u64) -> Self {
+        self.birthday_height = Some(height);
+        self
+    }
+
+    /// Set the data directory (default: ~/.zopentimestamps)
+    pub fn data_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.data_dir = Some(path.into());
+        self
+    }
+
+    /// Set the Sapling prover backend (default: bundled)
+    pub fn prover(mut self, prover: ProverBackend) -> Self {
+        self.prover = prover;
+        self
+    }
+
+    /// Create the account with Orchard keys only (default: false)
+    pub fn orchard_only(mut self, orchard_only: bool) -> Self {
+        self.orchard_only = orchard_only;
+        self
+    }
+
+    /// Set the sync block cache memory budget in MiB (default: 256)
+    pub fn sync_memory_mb(mut self, megabytes: u64) -> Self {
+        self.sync_memory_mb = Some(megabytes);
+        self
+    }
+
+    /// Check every field and return all problems found
+    ///
+    /// An empty list means [`Self::build`] will succeed.
+    pub fn validate(&self) -> Vec<ConfigFieldError> {
+        self.check().err().unwrap_or_default()
+    }
+
+    /// Validate every field, returning the seed phrase read on the way
+    ///
+    /// The seed source is read once, so [`Self::build`] uses the phrase that
+    /// was validated even if the file or variable changes afterwards.
+    fn check(&self) -> Result<String, Vec<ConfigFieldError>> {
+        let mut errors = Vec::new();
+        let seed = match self.resolve_seed() {
+            Ok(seed) => Some(seed),
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        };
+        // Keys and transactions are built with testnet parameters only
+        if self.network == Some(Network::Mainnet) {
+            errors.push(ConfigFieldError::new(
+                ConfigField::Network,
+                "mainnet is not supported yet; the wallet only speaks testnet",
+            ));
+        }
+        for url in &self.lightwalletd {
+            if let Err(message) = validate_url(url) {
+                errors.push(ConfigFieldError::new(ConfigField::Lightwalletd, message));
+            }
+        }
+        if let Err(message) = self.validate_birthday() {
+            errors.push(ConfigFieldError::new(ConfigField::BirthdayHeight, message));
+        }
+        if let Some(dir) = &self.data_dir
+            && dir.exists()
+            && !dir.is_dir()
+        {
+            errors.push(ConfigFieldError::new(
+                ConfigField::DataDir,
+                format!("{} exists and is not a directory", dir.display()),
+            ));
+        }
+        if let ProverBackend::Remote(url) = &self.prover
+            && let Err(message) = validate_url(url)
+        {
+            errors.push(ConfigFieldError::new(ConfigField::Prover, message));
+        }
+        match seed {
+            Some(seed) if errors.is_empty() => Ok(seed),
+            _ => Err(errors),
+        }
+    }
+
+    /// Validate and build the configuration
+    pub fn build(self) -> Result<ZcashConfig, ConfigValidationErrors> {
+        let seed_phrase = self.check().map_err(ConfigValidationErrors)?;
+
+        let mut servers = self.lightwalletd.into_iter();
+        let lightwalletd_url = servers
+            .next()
+            .unwrap_or_else(|| DEFAULT_LIGHTWALLETD_URL.to_string());
+
+        Ok(ZcashConfig {
+            seed_phrase: Some(seed_phrase),
+            birthday_height: self.birthday_height.unwrap_or(DEFAULT_BIRTHDAY_HEIGHT),
+            lightwalletd_url,
+            lightwalletd_fallbacks: servers.collect(),
+            data_dir: self.data_dir.unwrap_or_else(default_data_dir),
+            network: self.network.unwrap_or(Network::Testnet),
+            prover: self.prover,
+            orchard_only: self.orchard_only,
+            sync_memory_mb: self.sync_memory_mb.unwrap_or(DEFAULT_SYNC_MEMORY_MB),
+            hash_algorithm: HashAlgorithm::default(),
+            explorer_url: None,
+            ipfs_gateway: DEFAULT_IPFS_GATEWAY.to_string(),
+            keychain: false,
+            log_file: None,
+        })
+    }
+
+    /// Read the seed phrase from its source and check the BIP-39 checksum
+    fn resolve_seed(&self) -> Result<String, ConfigFieldError> {
+        let seed_error = |message: String| ConfigFieldError::new(ConfigField::Seed, message);
+        let phrase = match &self.seed {
+            None => return Err(seed_error("seed phrase is required".to_string())),
+            Some(SeedSource::Phrase(phrase)) => phrase.clone(),
+            Some(SeedSource::Env(var)) => std::env::var(var)
+                .map_err(|_| seed_error(format!("environment variable {var} is not set")))?,
+            Some(SeedSource::File(path)) => std::fs::read_to_string(path)
+                .map_err(|e| seed_error(format!("cannot read {}: {e}", path.display())))?,
+        };
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        if words.len() != 24 {
+            return Err(seed_error(format!(
+                "seed phrase must be 24 words, got {}",
+                words.len()
+            )));
+        }
+        let phrase = words.join(" ");
+        Mnemonic::<English>::from_phrase(&phrase)
+            .map_err(|e| seed_error(format!("invalid seed phrase: {e:?}")))?;
+        Ok(phrase)
+    }
+
+    /// Birthday must be at or after testnet Sapling activation
+    fn validate_birthday(&self) -> Result<(), String> {
+        let Some(height) = self.birthday_height else {
+            return Ok(());
+        };
+        let sapling = TEST_NETWORK
+            .activation_height(NetworkUpgrade::Sapling)
+            .map(u32::from)
+            .unwrap_or(0);
+        if height < u64::from(sapling) {
+            return Err(format!(
+                "{height} is before Sapling activation ({sapling}); wallets cannot be older"
+            ));
+        }
+        if height > u64::from(u32::MAX) {
+            return Err(format!("{height} is not a valid block height"));
+        }
+        Ok(())
+    }
+}
+
+/// Check that a lightwalletd URL is an absolute http(s) URI with a host
+fn validate_url(url: &str) -> Result<(), String> {
+    let uri: tonic::transport::Uri = url
+        .parse()
+        .map_err(|e| format!("invalid URL '{url}': {e}"))?;
+    match uri.scheme_str() {
+        Some("http") | Some("https") => {}
+        _ => return Err(format!("'{url}' must start with http:// or https://")),
+    }
+    if uri.host().is_none_or(str::is_empty) {
+        return Err(format!("'{url}' has no host"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_SEED: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon abandon abandon abandon abandon art";
+
+    #[test]
+    fn test_build_valid_config() {
+        let config = ZcashConfigBuilder::new()
+            .seed(SeedSource::Phrase(TEST_SEED.to_string()))
+            .lightwalletd("https://a.example:443")
+            .lightwalletd("http://127.0.0.1:9067")
+            .birthday_height(3_000_000)
+            .build()
+            .unwrap();
+        assert_eq!(config.lightwalletd_url, "https://a.example:443");
+        assert_eq!(config.lightwalletd_fallbacks, vec!["http://127.0.0.1:9067"]);
+        assert_eq!(config.birthday_height, 3_000_000);
+        assert_eq!(config.network, Network::Testnet);
+    }
+
+    #[test]
+    fn test_reports_every_invalid_field() {
+        let errors = ZcashConfigBuilder::new()
+            .seed(SeedSource::Phrase("not a seed".to_string()))
+            .lightwalletd("testnet.zec.rocks:443")
+            .birthday_height(1)
+            .build()
+            .unwrap_err();
+        assert_eq!(errors.0.len(), 3);
+        assert_eq!(errors.for_field(ConfigField::Seed).count(), 1);
+        assert_eq!(errors.for_field(ConfigField::Lightwalletd).count(), 1);
+        assert_eq!(errors.for_field(ConfigField::BirthdayHeight).count(), 1);
+    }
+
+    #[test]
+    fn test_rejects_bad_checksum() {
+        let bad = TEST_SEED.replace(" art", " abandon");
+        let errors = ZcashConfigBuilder::new()
+            .seed(SeedSource::Phrase(bad))
+            .validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, ConfigField::Seed);
+    }
+
+    #[test]
+    fn test_rejects_mainnet() {
+        let errors = ZcashConfigBuilder::new()
+            .seed(SeedSource::Phrase(TEST_SEED.to_string()))
+            .network(Network::Mainnet)
+            .build()
+            .unwrap_err();
+        assert_eq!(errors.0.len(), 1);
+        assert_eq!(errors.for_field(ConfigField::Network).count(), 1);
+    }
+
+    #[test]
+    fn test_missing_seed() {
+        let errors = ZcashConfigBuilder::new().validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, ConfigField::Seed);
+    }
+}
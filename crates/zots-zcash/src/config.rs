@@ -1,16 +1,47 @@
 //! Configuration for Zcash wallet operations.
 //!
-//! Configuration is loaded from environment variables or a `.env` file.
+//! Configuration is loaded from environment variables or a `.env` file,
+//! optionally layered over a named profile in `~/.config/zots/config.toml`
+//! (see [`ZcashConfig::load`]). Precedence is env > file > defaults.
 //!
 //! ## Environment Variables
 //!
 //! | Variable | Required | Default | Description |
 //! |----------|----------|---------|-------------|
-//! | `ZOTS_SEED` | Yes | - | 24-word BIP-39 seed phrase |
+//! | `ZOTS_SEED` | To spend | - | 24-word BIP-39 seed phrase (omit for a watch-only wallet) |
 //! | `ZOTS_BIRTHDAY_HEIGHT` | No | 3717528 | Wallet birthday for faster sync |
-//! | `ZOTS_LIGHTWALLETD` | No | testnet.zec.rocks:443 | Lightwalletd server URL |
+//! | `ZOTS_LIGHTWALLETD` | No | testnet.zec.rocks:443 | Lightwalletd server URL (`http://` has no TLS) |
 //! | `ZOTS_NETWORK` | No | testnet | Network: "testnet" or "mainnet" |
 //! | `ZOTS_DATA_DIR` | No | ~/.zopentimestamps | Data directory path |
+//! | `ZOTS_PROVER` | No | bundled | Sapling prover: "bundled", "local" or a prover service URL |
+//! | `ZOTS_ORCHARD_ONLY` | No | false | Create the account without Sapling (faster sync) |
+//! | `ZOTS_SYNC_MEMORY_MB` | No | 256 | Memory budget for the sync block cache |
+//! | `ZOTS_HASH_ALGORITHM` | No | sha256 | Default hash algorithm for new stamps |
+//! | `ZOTS_ZCASH_EXPLORER` | No | blockexplorer.one | Block explorer base URL for links |
+//! | `ZOTS_IPFS_GATEWAY` | No | https://ipfs.io | IPFS HTTP gateway for fetching `ipfs://` content |
+//! | `ZOTS_KEYCHAIN` | No | false | Read the seed from the OS keychain (set up by `zots init`) |
+//! | `ZOTS_PROFILE` | No | - | Profile to use from the config file |
+//! | `ZOTS_CONFIG` | No | ~/.config/zots/config.toml | Config file path |
+//!
+//! ## Config File
+//!
+//! `zots config init/get/set/show` manages this file from the CLI.
+//!
+//! ```toml
+//! default_profile = "testnet"
+//!
+//! [profiles.testnet]
+//! lightwalletd = "https://testnet.zec.rocks:443"
+//! birthday_height = 3717528
+//!
+//! [profiles.regtest]
+//! lightwalletd = "http://127.0.0.1:9067"
+//! birthday_height = 1
+//! data_dir = "/tmp/zots-regtest"
+//!
+//! [profiles.pi]
+//! prover = "https://prover.lan:8443"
+//! ```
 //!
 //! ## Security Warning
 //!
@@ -18,81 +49,508 @@
 //! - Use environment variables or a `.env` file (add to .gitignore)
 //! - Only use testnet - mainnet is not recommended
 
-use std::path::PathBuf;
-use zots_core::Network;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use zots_core::{HashAlgorithm, Network};
+
+use crate::prover::ProverBackend;
+
+/// Default wallet birthday (recent testnet block)
+pub const DEFAULT_BIRTHDAY_HEIGHT: u64 = 3717528;
+
+/// Default lightwalletd server
+pub const DEFAULT_LIGHTWALLETD_URL: &str = "https://testnet.zec.rocks:443";
+
+/// Default lightwalletd server for mainnet profiles
+pub const DEFAULT_MAINNET_LIGHTWALLETD_URL: &str = "https://zec.rocks:443";
+
+/// Faucet handing out testnet ZEC to fund new wallets
+pub const TESTNET_FAUCET_URL: &str = "https://testnet.zecfaucet.com/";
+
+/// Sapling activation height on mainnet, the earliest useful birthday
+pub const MAINNET_SAPLING_ACTIVATION_HEIGHT: u64 = 419_200;
+
+/// Sapling activation height on testnet, the earliest useful birthday
+pub const TESTNET_SAPLING_ACTIVATION_HEIGHT: u64 = 280_000;
+
+/// Default memory budget for the sync block cache (MiB)
+pub(crate) const DEFAULT_SYNC_MEMORY_MB: u64 = 256;
+
+/// Default IPFS HTTP gateway
+pub(crate) const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io";
+
+/// Profile keys and the environment variables that override them
+pub const CONFIG_KEYS: &[(&str, &str)] = &[
+    ("seed", "ZOTS_SEED"),
+    ("birthday_height", "ZOTS_BIRTHDAY_HEIGHT"),
+    ("lightwalletd", "ZOTS_LIGHTWALLETD"),
+    ("network", "ZOTS_NETWORK"),
+    ("data_dir", "ZOTS_DATA_DIR"),
+    ("prover", "ZOTS_PROVER"),
+    ("orchard_only", "ZOTS_ORCHARD_ONLY"),
+    ("sync_memory_mb", "ZOTS_SYNC_MEMORY_MB"),
+    ("hash_algorithm", "ZOTS_HASH_ALGORITHM"),
+    ("explorer", "ZOTS_ZCASH_EXPLORER"),
+    ("ipfs_gateway", "ZOTS_IPFS_GATEWAY"),
+    ("keychain", "ZOTS_KEYCHAIN"),
+    ("log_file", "ZOTS_LOG_FILE"),
+];
+
+/// A named profile in the config file
+///
+/// Every field is optional; unset fields fall back to environment
+/// variables (which always win) and then to built-in defaults.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    /// BIP-39 seed phrase (prefer `ZOTS_SEED` over storing it on disk)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<String>,
+    /// Wallet birthday height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthday_height: Option<u64>,
+    /// Lightwalletd server URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lightwalletd: Option<String>,
+    /// Network name ("testnet" or "mainnet")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// Directory for wallet data storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_dir: Option<PathBuf>,
+    /// Sapling prover ("bundled", "local" or a prover service URL)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prover: Option<String>,
+    /// Create the account without Sapling receivers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orchard_only: Option<bool>,
+    /// Memory budget for the sync block cache in MiB
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_memory_mb: Option<u64>,
+    /// Default hash algorithm for new stamps ("sha256" or "blake3")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_algorithm: Option<String>,
+    /// Block explorer base URL used for attestation links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explorer: Option<String>,
+    /// IPFS HTTP gateway used to fetch `ipfs://` content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipfs_gateway: Option<String>,
+    /// Read the seed from the OS keychain (stored there by `zots init`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keychain: Option<bool>,
+    /// Also write logs to this file, rotated daily
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<PathBuf>,
+}
+
+impl ProfileConfig {
+    /// Value of `key` as written in the file, if set
+    pub fn get(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(match key {
+            "seed" => self.seed.clone(),
+            "birthday_height" => self.birthday_height.map(|v| v.to_string()),
+            "lightwalletd" => self.lightwalletd.clone(),
+            "network" => self.network.clone(),
+            "data_dir" => self.data_dir.as_ref().map(|p| p.display().to_string()),
+            "prover" => self.prover.clone(),
+            "orchard_only" => self.orchard_only.map(|v| v.to_string()),
+            "sync_memory_mb" => self.sync_memory_mb.map(|v| v.to_string()),
+            "hash_algorithm" => self.hash_algorithm.clone(),
+            "explorer" => self.explorer.clone(),
+            "ipfs_gateway" => self.ipfs_gateway.clone(),
+            "keychain" => self.keychain.map(|v| v.to_string()),
+            "log_file" => self.log_file.as_ref().map(|p| p.display().to_string()),
+            _ => return Err(unknown_key(key)),
+        })
+    }
+
+    /// Validate and store `value` under `key`
+    pub fn set(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+        let invalid = |e: &dyn std::fmt::Display| anyhow::anyhow!("Invalid {key}: {e}");
+        match key {
+            "seed" => self.seed = Some(value.to_string()),
+            "birthday_height" => {
+                self.birthday_height = Some(value.parse().map_err(|e| invalid(&e))?)
+            }
+            "lightwalletd" => self.lightwalletd = Some(value.to_string()),
+            "network" => {
+                parse_network(value).ok_or_else(|| invalid(&"expected testnet or mainnet"))?;
+                self.network = Some(value.to_lowercase());
+            }
+            "data_dir" => self.data_dir = Some(PathBuf::from(value)),
+            "prover" => {
+                ProverBackend::parse(value).map_err(|e| invalid(&e))?;
+                self.prover = Some(value.to_string());
+            }
+            "orchard_only" => {
+                self.orchard_only =
+                    Some(parse_bool(value).ok_or_else(|| invalid(&"expected true or false"))?)
+            }
+            "sync_memory_mb" => self.sync_memory_mb = Some(value.parse().map_err(|e| invalid(&e))?),
+            "hash_algorithm" => {
+                parse_hash_algorithm(value).ok_or_else(|| invalid(&"expected sha256 or blake3"))?;
+                self.hash_algorithm = Some(value.to_lowercase());
+            }
+            "explorer" => self.explorer = Some(value.trim_end_matches('/').to_string()),
+            "ipfs_gateway" => self.ipfs_gateway = Some(value.trim_end_matches('/').to_string()),
+            "keychain" => {
+                self.keychain =
+                    Some(parse_bool(value).ok_or_else(|| invalid(&"expected true or false"))?)
+            }
+            "log_file" => self.log_file = Some(PathBuf::from(value)),
+            _ => return Err(unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+/// Contents of `config.toml`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Profile used when none is requested explicitly
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    /// Named profiles (e.g. testnet, mainnet, regtest, ci)
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+}
+
+impl ConfigFile {
+    /// Starter file written by `zots config init`: one testnet profile with the defaults
+    pub fn starter() -> Self {
+        let profile = ProfileConfig {
+            birthday_height: Some(DEFAULT_BIRTHDAY_HEIGHT),
+            lightwalletd: Some(DEFAULT_LIGHTWALLETD_URL.to_string()),
+            network: Some("testnet".to_string()),
+            data_dir: Some(default_data_dir()),
+            hash_algorithm: Some("sha256".to_string()),
+            ..ProfileConfig::default()
+        };
+        Self {
+            default_profile: Some("testnet".to_string()),
+            profiles: BTreeMap::from([("testnet".to_string(), profile)]),
+        }
+    }
+
+    /// Profile edited by `zots config set`: `requested`, else `ZOTS_PROFILE`,
+    /// else the file's default profile, else "default"
+    pub fn active_profile_name(&self, requested: Option<&str>) -> String {
+        requested
+            .map(str::to_string)
+            .or_else(|| std::env::var("ZOTS_PROFILE").ok())
+            .or_else(|| self.default_profile.clone())
+            .unwrap_or_else(|| "default".to_string())
+    }
+
+    /// Serialize to TOML
+    pub fn to_toml(&self) -> anyhow::Result<String> {
+        toml::to_string_pretty(self).map_err(|e| anyhow::anyhow!("Failed to encode config: {e}"))
+    }
+
+    /// Write the config file, creating its directory if needed
+    ///
+    /// Profiles may hold a seed phrase, so the file is readable only by the
+    /// current user.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_private_file(path, &self.to_toml()?)
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {e}", path.display()))
+    }
+
+    /// Default config file location (`~/.config/zots/config.toml`), overridable with `ZOTS_CONFIG`
+    pub fn default_path() -> PathBuf {
+        std::env::var("ZOTS_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| {
+                dirs::config_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join("zots")
+                    .join("config.toml")
+            })
+    }
+
+    /// Parse config file contents
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        toml::from_str(contents).map_err(|e| anyhow::anyhow!("Invalid config file: {e}"))
+    }
+
+    /// Read a config file, returning `None` if it does not exist
+    pub fn read(path: &Path) -> anyhow::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents)
+                .map(Some)
+                .map_err(|e| anyhow::anyhow!("{}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(anyhow::anyhow!("Failed to read {}: {e}", path.display())),
+        }
+    }
+}
 
 /// Configuration for Zcash wallet and network operations
 #[derive(Debug, Clone)]
 pub struct ZcashConfig {
     /// BIP-39 seed phrase (24 words)
-    pub seed_phrase: String,
+    ///
+    /// `None` for a watch-only wallet, which syncs an imported viewing key
+    /// and cannot create transactions.
+    pub seed_phrase: Option<String>,
     /// Wallet birthday height for faster sync
     pub birthday_height: u64,
     /// Lightwalletd server URL
     pub lightwalletd_url: String,
+    /// Additional lightwalletd servers tried in order if the primary is unreachable
+    pub lightwalletd_fallbacks: Vec<String>,
     /// Directory for wallet data storage
     pub data_dir: PathBuf,
     /// Network (mainnet or testnet)
     pub network: Network,
+    /// Where Sapling proofs are generated
+    pub prover: ProverBackend,
+    /// Create the account with Orchard keys only (no Sapling scanning)
+    ///
+    /// Only applies when the account is first initialized; an existing
+    /// wallet database keeps the keys it was created with.
+    pub orchard_only: bool,
+    /// Memory budget for the sync block cache in MiB
+    ///
+    /// Sync batch sizes are chosen so that cached blocks stay within this
+    /// budget, which matters on low-RAM machines.
+    pub sync_memory_mb: u64,
+    /// Hash algorithm used for new stamps when none is given explicitly
+    pub hash_algorithm: HashAlgorithm,
+    /// Block explorer base URL for attestation links (`None` = network default)
+    pub explorer_url: Option<String>,
+    /// IPFS HTTP gateway base URL (content is fetched from `<gateway>/ipfs/<cid>`)
+    pub ipfs_gateway: String,
+    /// Read the seed phrase from the OS keychain when no seed is configured
+    pub keychain: bool,
+    /// Rotating log file written in addition to stderr
+    pub log_file: Option<PathBuf>,
 }
 
 impl ZcashConfig {
     /// Load configuration from environment variables
     ///
-    /// Required:
-    /// - `ZOTS_SEED`: 24-word BIP-39 seed phrase
+    /// Optional:
+    /// - `ZOTS_SEED`: 24-word BIP-39 seed phrase (required to stamp or send)
     ///
     /// Optional (with defaults):
     /// - `ZOTS_BIRTHDAY_HEIGHT`: Wallet birthday (default: 3717528)
     /// - `ZOTS_LIGHTWALLETD`: Server URL (default: https://testnet.zec.rocks:443)
     /// - `ZOTS_NETWORK`: Network type (default: testnet)
     /// - `ZOTS_DATA_DIR`: Data directory (default: ~/.zopentimestamps)
+    /// - `ZOTS_PROVER`: Sapling prover (default: bundled)
+    /// - `ZOTS_ORCHARD_ONLY`: Orchard-only account (default: false)
+    /// - `ZOTS_SYNC_MEMORY_MB`: Sync block cache budget (default: 256)
+    /// - `ZOTS_HASH_ALGORITHM`: Default stamp hash algorithm (default: sha256)
+    /// - `ZOTS_ZCASH_EXPLORER`: Block explorer base URL (default: per network)
+    /// - `ZOTS_IPFS_GATEWAY`: IPFS HTTP gateway (default: https://ipfs.io)
+    /// - `ZOTS_KEYCHAIN`: Read the seed from the OS keychain (default: false)
+    /// - `ZOTS_LOG_FILE`: Rotating log file (default: none)
     pub fn from_env() -> anyhow::Result<Self> {
         dotenvy::dotenv().ok();
+        Self::resolve(None, None, |key| std::env::var(key).ok())
+    }
+
+    /// Load configuration from the config file and environment
+    ///
+    /// The profile is chosen from `profile`, then `ZOTS_PROFILE`, then the
+    /// file's `default_profile`. Each setting is taken from the environment
+    /// if set, otherwise from the profile, otherwise from the defaults. A
+    /// missing config file is not an error unless a profile was requested.
+    ///
+    /// With `keychain` enabled and no seed set, the seed is read from the OS
+    /// keychain entry of the active profile.
+    pub fn load(profile: Option<&str>) -> anyhow::Result<Self> {
+        dotenvy::dotenv().ok();
+        let file = ConfigFile::read(&ConfigFile::default_path())?;
+        let mut config = Self::resolve(profile, file.as_ref(), |key| std::env::var(key).ok())?;
+        if config.seed_phrase.is_none() && config.keychain {
+            let profile_name = file.unwrap_or_default().active_profile_name(profile);
+            config.seed_phrase = crate::keychain::load_seed(&profile_name)?;
+        }
+        Ok(config)
+    }
+
+    /// Log file from `ZOTS_LOG_FILE` or the active profile
+    ///
+    /// Read before logging starts, so unlike [`ZcashConfig::load`] it never
+    /// touches the keychain, and an unreadable config file only means the
+    /// file setting is ignored.
+    pub fn log_file_setting() -> Option<PathBuf> {
+        let file = ConfigFile::read(&ConfigFile::default_path()).ok().flatten();
+        Self::resolve(None, file.as_ref(), |key| std::env::var(key).ok())
+            .ok()
+            .and_then(|config| config.log_file)
+    }
 
-        let seed_phrase = std::env::var("ZOTS_SEED").map_err(|_| {
-            anyhow::anyhow!("ZOTS_SEED environment variable not set. Set your 24-word seed phrase.")
-        })?;
+    /// Merge environment, config file profile and defaults
+    fn resolve(
+        profile: Option<&str>,
+        file: Option<&ConfigFile>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> anyhow::Result<Self> {
+        let profile_name = profile
+            .map(str::to_string)
+            .or_else(|| env("ZOTS_PROFILE"))
+            .or_else(|| file.and_then(|f| f.default_profile.clone()));
+        let profile = match &profile_name {
+            Some(name) => {
+                let file = file.ok_or_else(|| {
+                    anyhow::anyhow!("Profile '{name}' requested but no config file was found")
+                })?;
+                file.profiles
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("Profile '{name}' not found in config file"))?
+            }
+            None => ProfileConfig::default(),
+        };
 
-        let birthday_height = std::env::var("ZOTS_BIRTHDAY_HEIGHT")
-            .unwrap_or_else(|_| "3717528".to_string())
-            .parse()
-            .map_err(|e| anyhow::anyhow!("Invalid ZOTS_BIRTHDAY_HEIGHT: {e}"))?;
+        // Without a seed the wallet runs watch-only from an imported viewing key
+        let seed_phrase = env("ZOTS_SEED").or(profile.seed);
 
-        let lightwalletd_url = std::env::var("ZOTS_LIGHTWALLETD")
-            .unwrap_or_else(|_| "https://testnet.zec.rocks:443".to_string());
+        let birthday_height = match env("ZOTS_BIRTHDAY_HEIGHT") {
+            Some(value) => value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid ZOTS_BIRTHDAY_HEIGHT: {e}"))?,
+            None => profile.birthday_height.unwrap_or(DEFAULT_BIRTHDAY_HEIGHT),
+        };
 
-        let data_dir = std::env::var("ZOTS_DATA_DIR")
+        let lightwalletd_url = env("ZOTS_LIGHTWALLETD")
+            .or(profile.lightwalletd)
+            .unwrap_or_else(|| DEFAULT_LIGHTWALLETD_URL.to_string());
+
+        let data_dir = env("ZOTS_DATA_DIR")
             .map(PathBuf::from)
-            .unwrap_or_else(|_| {
-                dirs::home_dir()
-                    .unwrap_or_else(|| PathBuf::from("."))
-                    .join(".zopentimestamps")
-            });
-
-        let network = match std::env::var("ZOTS_NETWORK")
-            .unwrap_or_else(|_| "testnet".to_string())
-            .to_lowercase()
-            .as_str()
-        {
-            "mainnet" | "main" => Network::Mainnet,
-            _ => Network::Testnet,
+            .or(profile.data_dir)
+            .unwrap_or_else(default_data_dir);
+
+        let network = match env("ZOTS_NETWORK").or(profile.network) {
+            Some(value) => parse_network(&value).ok_or_else(|| {
+                anyhow::anyhow!("Invalid ZOTS_NETWORK: expected testnet or mainnet")
+            })?,
+            None => Network::Testnet,
+        };
+
+        let prover = match env("ZOTS_PROVER").or(profile.prover) {
+            Some(value) => ProverBackend::parse(&value)
+                .map_err(|e| anyhow::anyhow!("Invalid ZOTS_PROVER: {e}"))?,
+            None => ProverBackend::default(),
+        };
+
+        let orchard_only = match env("ZOTS_ORCHARD_ONLY") {
+            Some(value) => parse_bool(&value)
+                .ok_or_else(|| anyhow::anyhow!("Invalid ZOTS_ORCHARD_ONLY: {value}"))?,
+            None => profile.orchard_only.unwrap_or(false),
+        };
+
+        let sync_memory_mb = match env("ZOTS_SYNC_MEMORY_MB") {
+            Some(value) => value
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid ZOTS_SYNC_MEMORY_MB: {e}"))?,
+            None => profile.sync_memory_mb.unwrap_or(DEFAULT_SYNC_MEMORY_MB),
+        };
+
+        let hash_algorithm = match env("ZOTS_HASH_ALGORITHM").or(profile.hash_algorithm) {
+            Some(value) => parse_hash_algorithm(&value)
+                .ok_or_else(|| anyhow::anyhow!("Invalid ZOTS_HASH_ALGORITHM: {value}"))?,
+            None => HashAlgorithm::default(),
         };
 
+        let explorer_url = env("ZOTS_ZCASH_EXPLORER")
+            .or(profile.explorer)
+            .map(|url| url.trim_end_matches('/').to_string());
+
+        let ipfs_gateway = env("ZOTS_IPFS_GATEWAY")
+            .or(profile.ipfs_gateway)
+            .map_or_else(
+                || DEFAULT_IPFS_GATEWAY.to_string(),
+                |url| url.trim_end_matches('/').to_string(),
+            );
+
+        let keychain = match env("ZOTS_KEYCHAIN") {
+            Some(value) => parse_bool(&value)
+                .ok_or_else(|| anyhow::anyhow!("Invalid ZOTS_KEYCHAIN: {value}"))?,
+            None => profile.keychain.unwrap_or(false),
+        };
+
+        let log_file = env("ZOTS_LOG_FILE").map(PathBuf::from).or(profile.log_file);
+
         Ok(Self {
             seed_phrase,
             birthday_height,
             lightwalletd_url,
+            lightwalletd_fallbacks: Vec::new(),
             data_dir,
             network,
+            prover,
+            orchard_only,
+            sync_memory_mb,
+            hash_algorithm,
+            explorer_url,
+            ipfs_gateway,
+            keychain,
+            log_file,
         })
     }
 
+    /// Effective value of every setting, keyed like [`CONFIG_KEYS`]
+    ///
+    /// The seed phrase is redacted.
+    pub fn settings(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "seed",
+                if self.seed_phrase.is_some() {
+                    "<set>".to_string()
+                } else {
+                    "<unset: watch-only>".to_string()
+                },
+            ),
+            ("birthday_height", self.birthday_height.to_string()),
+            ("lightwalletd", self.lightwalletd_url.clone()),
+            ("network", self.network.to_string()),
+            ("data_dir", self.data_dir.display().to_string()),
+            ("prover", self.prover.to_string()),
+            ("orchard_only", self.orchard_only.to_string()),
+            ("sync_memory_mb", self.sync_memory_mb.to_string()),
+            (
+                "hash_algorithm",
+                hash_algorithm_key(self.hash_algorithm).to_string(),
+            ),
+            (
+                "explorer",
+                self.explorer_url
+                    .clone()
+                    .unwrap_or_else(|| self.network.default_explorer_url().to_string()),
+            ),
+            ("ipfs_gateway", self.ipfs_gateway.clone()),
+            ("keychain", self.keychain.to_string()),
+            (
+                "log_file",
+                self.log_file
+                    .as_ref()
+                    .map_or_else(|| "<unset>".to_string(), |p| p.display().to_string()),
+            ),
+        ]
+    }
+
     /// Get the path to the wallet database file
     pub fn wallet_db_path(&self) -> PathBuf {
         self.data_dir.join("wallet.db")
     }
 
+    /// Get the path to the registry of pending proofs
+    pub fn pending_store_path(&self) -> PathBuf {
+        self.data_dir.join("pending.json")
+    }
+
     /// Get the path to the data directory, creating it if needed
     pub fn ensure_data_dir(&self) -> anyhow::Result<PathBuf> {
         std::fs::create_dir_all(&self.data_dir)?;
@@ -119,16 +577,319 @@ impl ZcashConfig {
             anyhow::bail!("Seed phrase must be 24 words, got {}", words.len());
         }
 
-        let data_dir = dirs::home_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join(".zopentimestamps");
-
         Ok(Self {
-            seed_phrase: seed_phrase.to_string(),
-            birthday_height: birthday_height.unwrap_or(3717528),
-            lightwalletd_url: "https://testnet.zec.rocks:443".to_string(),
-            data_dir,
+            seed_phrase: Some(seed_phrase.to_string()),
+            birthday_height: birthday_height.unwrap_or(DEFAULT_BIRTHDAY_HEIGHT),
+            lightwalletd_url: DEFAULT_LIGHTWALLETD_URL.to_string(),
+            lightwalletd_fallbacks: Vec::new(),
+            data_dir: default_data_dir(),
             network: Network::Testnet,
+            prover: ProverBackend::default(),
+            orchard_only: false,
+            sync_memory_mb: DEFAULT_SYNC_MEMORY_MB,
+            hash_algorithm: HashAlgorithm::default(),
+            explorer_url: None,
+            ipfs_gateway: DEFAULT_IPFS_GATEWAY.to_string(),
+            keychain: false,
+            log_file: None,
+        })
+    }
+}
+
+/// Parse a boolean environment value ("1"/"0", "true"/"false", "yes"/"no")
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" | "" => Some(false),
+        _ => None,
+    }
+}
+
+/// Parse a network name ("testnet"/"test" or "mainnet"/"main")
+fn parse_network(value: &str) -> Option<Network> {
+    match value.trim().to_lowercase().as_str() {
+        "mainnet" | "main" => Some(Network::Mainnet),
+        "testnet" | "test" => Some(Network::Testnet),
+        _ => None,
+    }
+}
+
+/// Parse a hash algorithm name ("sha256" or "blake3")
+fn parse_hash_algorithm(value: &str) -> Option<HashAlgorithm> {
+    match value.trim().to_lowercase().as_str() {
+        "sha256" | "sha-256" => Some(HashAlgorithm::Sha256),
+        "blake3" => Some(HashAlgorithm::Blake3),
+        _ => None,
+    }
+}
+
+/// Config file spelling of a hash algorithm
+fn hash_algorithm_key(algorithm: HashAlgorithm) -> &'static str {
+    match algorithm {
+        HashAlgorithm::Sha256 => "sha256",
+        HashAlgorithm::Blake3 => "blake3",
+    }
+}
+
+fn unknown_key(key: &str) -> anyhow::Error {
+    let keys: Vec<_> = CONFIG_KEYS.iter().map(|(key, _)| *key).collect();
+    anyhow::anyhow!(
+        "Unknown config key '{key}' (expected one of: {})",
+        keys.join(", ")
+    )
+}
+
+/// Write `contents` to a file readable only by the current user
+///
+/// The contents go to a fresh `0600` temp file next to `path` that then
+/// replaces it, so an existing file with looser permissions never holds the
+/// new contents.
+pub fn write_private_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}.tmp", std::process::id()));
+    let tmp = path.with_file_name(name);
+    // Left behind by a crashed write from a process with the same id
+    let _ = std::fs::remove_file(&tmp);
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let written = options.open(&tmp).and_then(|mut file| {
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()
+    });
+    written
+        .and_then(|()| std::fs::rename(&tmp, path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&tmp);
         })
+}
+
+/// Default data directory (`~/.zopentimestamps`)
+pub fn default_data_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".zopentimestamps")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const SAMPLE: &str = r#"
+default_profile = "testnet"
+
+[profiles.testnet]
+seed = "file seed"
+birthday_height = 100
+lightwalletd = "https://file.example:443"
+
+[profiles.regtest]
+lightwalletd = "http://127.0.0.1:9067"
+data_dir = "/tmp/zots-regtest"
+orchard_only = true
+"#;
+
+    fn env_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
+    #[test]
+    fn test_defaults_without_file() {
+        let config =
+            ZcashConfig::resolve(None, None, env_from(&[("ZOTS_SEED", "env seed")])).unwrap();
+        assert_eq!(config.seed_phrase.as_deref(), Some("env seed"));
+        assert_eq!(config.birthday_height, DEFAULT_BIRTHDAY_HEIGHT);
+        assert_eq!(config.lightwalletd_url, DEFAULT_LIGHTWALLETD_URL);
+        assert_eq!(config.network, Network::Testnet);
+        assert_eq!(config.sync_memory_mb, DEFAULT_SYNC_MEMORY_MB);
+    }
+
+    #[test]
+    fn test_default_profile_from_file() {
+        let file = ConfigFile::parse(SAMPLE).unwrap();
+        let config = ZcashConfig::resolve(None, Some(&file), env_from(&[])).unwrap();
+        assert_eq!(config.seed_phrase.as_deref(), Some("file seed"));
+        assert_eq!(config.birthday_height, 100);
+        assert_eq!(config.lightwalletd_url, "https://file.example:443");
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let file = ConfigFile::parse(SAMPLE).unwrap();
+        let env = env_from(&[
+            ("ZOTS_PROFILE", "regtest"),
+            ("ZOTS_SEED", "env seed"),
+            ("ZOTS_LIGHTWALLETD", "http://env:9067"),
+        ]);
+        let config = ZcashConfig::resolve(None, Some(&file), env).unwrap();
+        assert_eq!(config.seed_phrase.as_deref(), Some("env seed"));
+        assert_eq!(config.lightwalletd_url, "http://env:9067");
+        assert_eq!(config.data_dir, PathBuf::from("/tmp/zots-regtest"));
+        assert_eq!(config.birthday_height, DEFAULT_BIRTHDAY_HEIGHT);
+        assert!(config.orchard_only);
+    }
+
+    #[test]
+    fn test_orchard_only_env() {
+        let env = env_from(&[("ZOTS_SEED", "env seed"), ("ZOTS_ORCHARD_ONLY", "1")]);
+        assert!(ZcashConfig::resolve(None, None, env).unwrap().orchard_only);
+        let env = env_from(&[("ZOTS_SEED", "env seed"), ("ZOTS_ORCHARD_ONLY", "maybe")]);
+        assert!(ZcashConfig::resolve(None, None, env).is_err());
+    }
+
+    #[test]
+    fn test_seed_is_optional_for_watch_only() {
+        let file = ConfigFile::parse(SAMPLE).unwrap();
+        let env = env_from(&[("ZOTS_PROFILE", "regtest")]);
+        let config = ZcashConfig::resolve(None, Some(&file), env).unwrap();
+        assert!(config.seed_phrase.is_none());
+    }
+
+    #[test]
+    fn test_unknown_profile_is_error() {
+        let file = ConfigFile::parse(SAMPLE).unwrap();
+        let env = env_from(&[("ZOTS_SEED", "env seed")]);
+        assert!(ZcashConfig::resolve(Some("mainnet"), Some(&file), env).is_err());
+        let env = env_from(&[("ZOTS_SEED", "env seed")]);
+        assert!(ZcashConfig::resolve(Some("ci"), None, env).is_err());
+    }
+
+    #[test]
+    fn test_hash_algorithm_and_explorer() {
+        let env = env_from(&[
+            ("ZOTS_HASH_ALGORITHM", "blake3"),
+            ("ZOTS_ZCASH_EXPLORER", "https://explorer.example/"),
+        ]);
+        let config = ZcashConfig::resolve(None, None, env).unwrap();
+        assert_eq!(config.hash_algorithm, HashAlgorithm::Blake3);
+        assert_eq!(
+            config.explorer_url.as_deref(),
+            Some("https://explorer.example")
+        );
+
+        let env = env_from(&[("ZOTS_HASH_ALGORITHM", "md5")]);
+        assert!(ZcashConfig::resolve(None, None, env).is_err());
+    }
+
+    #[test]
+    fn test_network() {
+        let env = env_from(&[("ZOTS_NETWORK", "Mainnet")]);
+        let config = ZcashConfig::resolve(None, None, env).unwrap();
+        assert_eq!(config.network, Network::Mainnet);
+
+        let env = env_from(&[("ZOTS_NETWORK", "mainet")]);
+        let err = ZcashConfig::resolve(None, None, env).unwrap_err();
+        assert!(err.to_string().contains("ZOTS_NETWORK"));
+
+        let file = ConfigFile::parse("[profiles.typo]\nnetwork = \"tesnet\"\n").unwrap();
+        let env = env_from(&[("ZOTS_PROFILE", "typo")]);
+        assert!(ZcashConfig::resolve(None, Some(&file), env).is_err());
+    }
+
+    #[test]
+    fn test_ipfs_gateway() {
+        let config = ZcashConfig::resolve(None, None, env_from(&[])).unwrap();
+        assert_eq!(config.ipfs_gateway, DEFAULT_IPFS_GATEWAY);
+
+        let env = env_from(&[("ZOTS_IPFS_GATEWAY", "http://127.0.0.1:8080/")]);
+        let config = ZcashConfig::resolve(None, None, env).unwrap();
+        assert_eq!(config.ipfs_gateway, "http://127.0.0.1:8080");
+    }
+
+    #[test]
+    fn test_keychain_flag() {
+        let file = ConfigFile::parse("[profiles.testnet]\nkeychain = true\n").unwrap();
+        let env = env_from(&[("ZOTS_PROFILE", "testnet")]);
+        let config = ZcashConfig::resolve(None, Some(&file), env).unwrap();
+        assert!(config.keychain);
+        assert!(config.seed_phrase.is_none());
+
+        let env = env_from(&[("ZOTS_PROFILE", "testnet"), ("ZOTS_KEYCHAIN", "0")]);
+        assert!(
+            !ZcashConfig::resolve(None, Some(&file), env)
+                .unwrap()
+                .keychain
+        );
+        let env = env_from(&[("ZOTS_KEYCHAIN", "sometimes")]);
+        assert!(ZcashConfig::resolve(None, None, env).is_err());
+    }
+
+    #[test]
+    fn test_log_file() {
+        let file =
+            ConfigFile::parse("[profiles.testnet]\nlog_file = \"/var/log/zots.log\"\n").unwrap();
+        let env = env_from(&[("ZOTS_PROFILE", "testnet")]);
+        let config = ZcashConfig::resolve(None, Some(&file), env).unwrap();
+        assert_eq!(config.log_file, Some(PathBuf::from("/var/log/zots.log")));
+
+        let env = env_from(&[
+            ("ZOTS_PROFILE", "testnet"),
+            ("ZOTS_LOG_FILE", "/tmp/zots.log"),
+        ]);
+        let config = ZcashConfig::resolve(None, Some(&file), env).unwrap();
+        assert_eq!(config.log_file, Some(PathBuf::from("/tmp/zots.log")));
+
+        let config = ZcashConfig::resolve(None, None, env_from(&[])).unwrap();
+        assert!(config.log_file.is_none());
+    }
+
+    #[test]
+    fn test_profile_set_get_roundtrip() {
+        let mut file = ConfigFile::starter();
+        let profile = file.profiles.get_mut("testnet").unwrap();
+        profile.set("hash_algorithm", "BLAKE3").unwrap();
+        profile.set("orchard_only", "yes").unwrap();
+        assert!(profile.set("network", "regtest").is_err());
+        assert!(profile.set("birthday_height", "soon").is_err());
+        assert!(profile.set("lightwalletd_url", "x").is_err());
+
+        let reparsed = ConfigFile::parse(&file.to_toml().unwrap()).unwrap();
+        let profile = &reparsed.profiles["testnet"];
+        assert_eq!(
+            profile.get("hash_algorithm").unwrap().as_deref(),
+            Some("blake3")
+        );
+        assert_eq!(
+            profile.get("orchard_only").unwrap().as_deref(),
+            Some("true")
+        );
+        assert_eq!(profile.get("seed").unwrap(), None);
+        assert_eq!(reparsed.default_profile.as_deref(), Some("testnet"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        // An existing world-readable file is tightened too
+        std::fs::write(&path, "").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        ConfigFile::starter().save(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(ConfigFile::read(&path).unwrap().is_some());
+        // The temp file was renamed into place
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_keys() {
+        assert!(ConfigFile::parse("[profiles.testnet]\nlightwalletd_url = \"x\"\n").is_err());
     }
 }
@@ -0,0 +1,45 @@
+//! Seed phrase storage in the OS keychain.
+//!
+//! `zots init` can keep the seed in the platform credential store (macOS
+//! Keychain, Windows Credential Manager, Secret Service on Linux) instead of
+//! the config file. Profiles with `keychain = true` read it from there when
+//! neither `ZOTS_SEED` nor the profile sets a seed.
+//!
+//! Entries live under the `zots` service, one per profile name.
+
+/// Keychain service name of zots entries
+pub const KEYCHAIN_SERVICE: &str = "zots";
+
+fn entry(profile: &str) -> anyhow::Result<keyring::Entry> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, profile)
+        .map_err(|e| anyhow::anyhow!("OS keychain unavailable: {e}"))
+}
+
+/// Store the seed phrase of `profile`, replacing any previous one
+pub fn store_seed(profile: &str, seed_phrase: &str) -> anyhow::Result<()> {
+    entry(profile)?
+        .set_password(seed_phrase)
+        .map_err(|e| anyhow::anyhow!("Failed to store the seed in the OS keychain: {e}"))
+}
+
+/// Seed phrase stored for `profile`, if any
+pub fn load_seed(profile: &str) -> anyhow::Result<Option<String>> {
+    match entry(profile)?.get_password() {
+        Ok(seed_phrase) => Ok(Some(seed_phrase)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(anyhow::anyhow!(
+            "Failed to read the seed from the OS keychain: {e}"
+        )),
+    }
+}
+
+/// Remove the seed phrase of `profile`; returns whether one was stored
+pub fn delete_seed(profile: &str) -> anyhow::Result<bool> {
+    match entry(profile)?.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(anyhow::anyhow!(
+            "Failed to delete the seed from the OS keychain: {e}"
+        )),
+    }
+}
@@ -11,6 +11,8 @@
 //! - **Transactions**: Self-send transactions with memo-encoded timestamps
 //! - **Sync**: Compact block scanning via lightwalletd gRPC
 //! - **Verification**: Fetch and decrypt transactions to verify timestamps
+//! - **Batching**: [`StampQueue`] stamps many hashes in one transaction
+//! - **Proving**: Bundled, local or delegated remote Sapling [`Prover`]
 //!
 //! ## Example
 //!
@@ -52,10 +54,20 @@
 //! Currently supports Zcash testnet via lightwalletd servers.
 //! Mainnet support is intentionally disabled for safety.
 
+pub mod builder;
 pub mod config;
+pub mod keychain;
 pub mod memo;
+pub mod prover;
+pub mod queue;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod wallet;
 
+pub use builder::*;
 pub use config::*;
+pub use keychain::*;
 pub use memo::*;
+pub use prover::*;
+pub use queue::*;
 pub use wallet::*;
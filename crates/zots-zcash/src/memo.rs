@@ -16,21 +16,48 @@
 //! - **Hash**: The 32-byte hash being timestamped
 //! - **Padding**: Zero-padded to 512 bytes total
 
+use std::ops::Range;
+
 use tracing::debug;
 use zots_core::proof::ZOTS_MAGIC;
 
+/// Size of a Zcash memo field in bytes
+pub const MEMO_SIZE: usize = 512;
+
+/// Byte layout of a timestamp memo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoLayout {
+    /// Byte range holding the ZOTS magic header
+    pub magic: Range<usize>,
+    /// Byte range holding the hash digest
+    pub hash: Range<usize>,
+    /// Byte range of zero padding
+    pub padding: Range<usize>,
+}
+
+/// Get the byte layout used by [`create_timestamp_memo`]
+pub fn timestamp_memo_layout() -> MemoLayout {
+    let hash_start = ZOTS_MAGIC.len();
+    let hash_end = hash_start + 32;
+    MemoLayout {
+        magic: 0..hash_start,
+        hash: hash_start..hash_end,
+        padding: hash_end..MEMO_SIZE,
+    }
+}
+
 /// Create a memo field containing timestamp data
 ///
 /// Format: ZOTS_MAGIC (8 bytes) + hash (32 bytes) = 40 bytes
 /// Padded to 512 bytes for Zcash memo field
 pub fn create_timestamp_memo(hash: &[u8; 32]) -> Vec<u8> {
     debug!("Creating timestamp memo with 32-byte hash digest");
-    let mut data = Vec::with_capacity(512);
+    let mut data = Vec::with_capacity(MEMO_SIZE);
     data.extend_from_slice(&ZOTS_MAGIC);
     data.extend_from_slice(hash);
 
     // Pad to 512 bytes (Zcash memo field size)
-    data.resize(512, 0);
+    data.resize(MEMO_SIZE, 0);
 
     data
 }
@@ -55,10 +82,31 @@ pub fn parse_timestamp_memo(memo: &[u8]) -> Option<[u8; 32]> {
     Some(hash)
 }
 
+/// Decode a ZIP-302 text memo, without trailing padding
+///
+/// Returns None for binary memos (first byte above 0xF4) and for memos that
+/// are not valid UTF-8.
+pub fn memo_text(memo: &[u8]) -> Option<String> {
+    if memo.first().is_none_or(|b| *b > 0xF4) {
+        return None;
+    }
+    let end = memo.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    String::from_utf8(memo[..end].to_vec()).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_memo_text() {
+        let mut memo = b"hello".to_vec();
+        memo.resize(MEMO_SIZE, 0);
+        assert_eq!(memo_text(&memo).as_deref(), Some("hello"));
+        assert_eq!(memo_text(&create_timestamp_memo(&[0xAB; 32])), None);
+        assert_eq!(memo_text(&[0xF6]), None);
+    }
+
     #[test]
     fn test_create_memo() {
         let hash = [0xAB; 32];
@@ -69,6 +117,18 @@ mod tests {
         assert_eq!(&memo[8..40], &hash);
     }
 
+    #[test]
+    fn test_memo_layout_matches_encoding() {
+        let hash = [0xEF; 32];
+        let memo = create_timestamp_memo(&hash);
+        let layout = timestamp_memo_layout();
+
+        assert_eq!(&memo[layout.magic.clone()], &ZOTS_MAGIC);
+        assert_eq!(&memo[layout.hash.clone()], &hash);
+        assert!(memo[layout.padding.clone()].iter().all(|b| *b == 0));
+        assert_eq!(layout.padding.end, memo.len());
+    }
+
     #[test]
     fn test_parse_memo_roundtrip() {
         let hash = [0xCD; 32];
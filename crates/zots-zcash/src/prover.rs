@@ -0,0 +1,504 @@
+//! Sapling proving backends
+//!
+//! Building a shielded transaction needs Groth16 proofs for every Sapling
+//! spend and output. The bundled local prover is CPU and memory hungry on
+//! small devices, so [`Prover`] can instead delegate proof generation to a
+//! trusted machine running a gRPC service (see `proto/prover.proto`).
+//!
+//! The backend is selected by [`ProverBackend`] in
+//! [`ZcashConfig`](crate::ZcashConfig):
+//!
+//! | Backend | Source |
+//! |---------|--------|
+//! | `Bundled` | Parameters compiled into the binary (default) |
+//! | `Local` | Parameters in the default Zcash params directory |
+//! | `Remote` | Proofs created by a trusted prover service |
+//!
+//! ## Security Warning
+//!
+//! A remote prover receives the proof generation key and note openings for
+//! every spend. It can link your transactions, so only point it at a machine
+//! you control.
+
+use prost::Message;
+use rand_core::RngCore;
+use sapling_crypto::bundle::GrothProofBytes;
+use sapling_crypto::circuit::{Output, Spend};
+use sapling_crypto::keys::EphemeralSecretKey;
+use sapling_crypto::prover::{OutputProver, SpendProver};
+use sapling_crypto::value::{NoteValue, ValueCommitTrapdoor};
+use sapling_crypto::{Diversifier, MerklePath, PaymentAddress, ProofGenerationKey, Rseed};
+use std::sync::Arc;
+use std::time::Duration;
+use tonic::transport::{Channel, ClientTlsConfig};
+use tracing::{debug, info};
+use zcash_proofs::prover::LocalTxProver;
+
+/// Where Sapling proofs are generated
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ProverBackend {
+    /// Parameters bundled into the binary
+    #[default]
+    Bundled,
+    /// Parameters loaded from the default Zcash params directory
+    Local,
+    /// Delegate proving to a trusted prover service at this URL
+    Remote(String),
+}
+
+impl ProverBackend {
+    /// Parse a backend name: `bundled`, `local`, or an `http(s)://` prover URL
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value.trim() {
+            "" | "bundled" => Ok(Self::Bundled),
+            "local" => Ok(Self::Local),
+            url if url.starts_with("http://") || url.starts_with("https://") => {
+                Ok(Self::Remote(url.to_string()))
+            }
+            other => Err(anyhow::anyhow!(
+                "Unknown prover '{other}'. Use 'bundled', 'local' or a prover service URL."
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ProverBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bundled => f.write_str("bundled"),
+            Self::Local => f.write_str("local"),
+            Self::Remote(url) => f.write_str(url),
+        }
+    }
+}
+
+/// gRPC messages for the delegated prover service (`zots.prover.v1.Prover`)
+mod proto {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct AuthPathElement {
+        #[prost(bytes = "vec", tag = "1")]
+        pub node: Vec<u8>,
+        #[prost(bool, tag = "2")]
+        pub is_right: bool,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SpendCircuit {
+        #[prost(uint64, tag = "1")]
+        pub value: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub rcv: Vec<u8>,
+        #[prost(bytes = "vec", tag = "3")]
+        pub ak: Vec<u8>,
+        #[prost(bytes = "vec", tag = "4")]
+        pub nsk: Vec<u8>,
+        #[prost(bytes = "vec", tag = "5")]
+        pub payment_address: Vec<u8>,
+        #[prost(bytes = "vec", tag = "6")]
+        pub rcm: Vec<u8>,
+        #[prost(bytes = "vec", tag = "7")]
+        pub ar: Vec<u8>,
+        #[prost(message, repeated, tag = "8")]
+        pub auth_path: Vec<AuthPathElement>,
+        #[prost(bytes = "vec", tag = "9")]
+        pub anchor: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct OutputCircuit {
+        #[prost(uint64, tag = "1")]
+        pub value: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub rcv: Vec<u8>,
+        #[prost(bytes = "vec", tag = "3")]
+        pub payment_address: Vec<u8>,
+        #[prost(bytes = "vec", tag = "4")]
+        pub rcm: Vec<u8>,
+        #[prost(bytes = "vec", tag = "5")]
+        pub esk: Vec<u8>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ProofResponse {
+        #[prost(bytes = "vec", tag = "1")]
+        pub proof: Vec<u8>,
+    }
+}
+
+/// Longest a single remote proof may take, connection included
+const REMOTE_PROOF_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A remote proving failure, carried out of the infallible proving traits
+struct ProvingFailed(anyhow::Error);
+
+/// Unwrap a remote proof, or abandon the transaction build
+///
+/// The proving traits cannot return errors, so a failed proof unwinds (without
+/// running the panic hook) out of the builder. Proofs are created before the
+/// wallet stores anything, so the abandoned transaction leaves no trace.
+fn proof_or_abandon(proof: anyhow::Result<GrothProofBytes>) -> GrothProofBytes {
+    proof.unwrap_or_else(|e| std::panic::resume_unwind(Box::new(ProvingFailed(e))))
+}
+
+/// Run a transaction build, turning a failed remote proof back into an error
+///
+/// Other panics keep unwinding.
+pub(crate) fn catch_proving_failure<T>(build: impl FnOnce() -> T) -> anyhow::Result<T> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(build)).map_err(|payload| {
+        match payload.downcast::<ProvingFailed>() {
+            Ok(failed) => failed.0,
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    })
+}
+
+/// Runtime the synchronous proving callbacks hand their requests to
+#[derive(Debug)]
+struct ProverRuntime(Option<tokio::runtime::Runtime>);
+
+impl Drop for ProverRuntime {
+    fn drop(&mut self) {
+        // The prover is usually dropped inside the caller's async context,
+        // where blocking on the runtime's shutdown is not allowed
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// Client for a delegated Sapling prover service
+#[derive(Debug, Clone)]
+pub struct RemoteProver {
+    url: String,
+    runtime: Arc<ProverRuntime>,
+    /// Connection reused by every proof, opened on the prover runtime
+    channel: Arc<tokio::sync::OnceCell<Channel>>,
+    /// Deadline for each proof request
+    timeout: Duration,
+}
+
+impl RemoteProver {
+    /// Create a client for the prover service at `url`
+    pub fn new(url: impl Into<String>) -> anyhow::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("zots-prover")
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            url: url.into(),
+            runtime: Arc::new(ProverRuntime(Some(runtime))),
+            channel: Arc::new(tokio::sync::OnceCell::new()),
+            timeout: REMOTE_PROOF_TIMEOUT,
+        })
+    }
+
+    /// Check that the service is reachable before building a transaction
+    pub async fn check(&self) -> anyhow::Result<()> {
+        self.connect().await.map(|_| ())
+    }
+
+    async fn connect(&self) -> anyhow::Result<tonic::transport::Channel> {
+        let mut endpoint = tonic::transport::Endpoint::from_shared(self.url.clone())?;
+        if self.url.starts_with("https://") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?;
+        }
+        endpoint
+            .connect()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to connect to prover {}: {e}", self.url))
+    }
+
+    async fn call<M: Message + Send + Sync + 'static>(
+        &self,
+        method: &'static str,
+        request: M,
+    ) -> anyhow::Result<GrothProofBytes> {
+        let channel = self
+            .channel
+            .get_or_try_init(|| self.connect())
+            .await?
+            .clone();
+        let mut grpc = tonic::client::Grpc::new(channel);
+        grpc.ready()
+            .await
+            .map_err(|e| anyhow::anyhow!("Prover not ready: {e}"))?;
+        let path = tonic::codegen::http::uri::PathAndQuery::from_static(method);
+        let codec = tonic_prost::ProstCodec::<M, proto::ProofResponse>::default();
+        let response = grpc
+            .unary(tonic::Request::new(request), path, codec)
+            .await
+            .map_err(|e| anyhow::anyhow!("Remote proving failed: {e}"))?
+            .into_inner();
+        response
+            .proof
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Prover returned a malformed proof"))
+    }
+
+    /// Run a request to completion from synchronous prover callbacks
+    ///
+    /// The proving traits are synchronous and may be called from inside an
+    /// async runtime, so the call runs on the prover's own runtime while the
+    /// calling thread waits for it. The wallet builds transactions under
+    /// `block_in_place`, so that wait does not hold up other async tasks.
+    fn blocking_call<M: Message + Send + Sync + 'static>(
+        &self,
+        method: &'static str,
+        request: M,
+    ) -> anyhow::Result<GrothProofBytes> {
+        let runtime = self
+            .runtime
+            .0
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Remote prover is shut down"))?;
+        let (tx, rx) = std::sync::mpsc::channel();
+        let this = self.clone();
+        runtime.spawn(async move {
+            let result = tokio::time::timeout(this.timeout, this.call(method, request))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(anyhow::anyhow!(
+                        "Remote proving timed out after {:?}",
+                        this.timeout
+                    ))
+                });
+            let _ = tx.send(result);
+        });
+        rx.recv()
+            .map_err(|_| anyhow::anyhow!("Remote prover task was dropped"))?
+    }
+
+    fn prove_spend(&self, circuit: Spend) -> anyhow::Result<GrothProofBytes> {
+        let missing = || anyhow::anyhow!("Incomplete spend circuit");
+        let opening = circuit.value_commitment_opening.ok_or_else(missing)?;
+        let key = circuit.proof_generation_key.ok_or_else(missing)?;
+        let request = proto::SpendCircuit {
+            value: opening.value.inner(),
+            rcv: opening.randomness.to_bytes().to_vec(),
+            ak: key.ak.to_bytes().to_vec(),
+            nsk: key.nsk.to_bytes().to_vec(),
+            payment_address: circuit
+                .payment_address
+                .ok_or_else(missing)?
+                .to_bytes()
+                .to_vec(),
+            rcm: circuit
+                .commitment_randomness
+                .ok_or_else(missing)?
+                .to_bytes()
+                .to_vec(),
+            ar: circuit.ar.ok_or_else(missing)?.to_bytes().to_vec(),
+            auth_path: circuit
+                .auth_path
+                .into_iter()
+                .map(|elem| {
+                    let (node, is_right) = elem.ok_or_else(missing)?;
+                    Ok(proto::AuthPathElement {
+                        node: node.to_bytes().to_vec(),
+                        is_right,
+                    })
+                })
+                .collect::<anyhow::Result<_>>()?,
+            anchor: circuit.anchor.ok_or_else(missing)?.to_bytes().to_vec(),
+        };
+        debug!("Delegating Sapling spend proof to {}", self.url);
+        self.blocking_call("/zots.prover.v1.Prover/ProveSpend", request)
+    }
+
+    fn prove_output(&self, circuit: Output) -> anyhow::Result<GrothProofBytes> {
+        let missing = || anyhow::anyhow!("Incomplete output circuit");
+        let opening = circuit.value_commitment_opening.ok_or_else(missing)?;
+        let request = proto::OutputCircuit {
+            value: opening.value.inner(),
+            rcv: opening.randomness.to_bytes().to_vec(),
+            payment_address: circuit
+                .payment_address
+                .ok_or_else(missing)?
+                .to_bytes()
+                .to_vec(),
+            rcm: circuit
+                .commitment_randomness
+                .ok_or_else(missing)?
+                .to_bytes()
+                .to_vec(),
+            esk: circuit.esk.ok_or_else(missing)?.to_bytes().to_vec(),
+        };
+        debug!("Delegating Sapling output proof to {}", self.url);
+        self.blocking_call("/zots.prover.v1.Prover/ProveOutput", request)
+    }
+}
+
+/// Sapling prover used when building transactions
+///
+/// Implements the Sapling proving traits so it can be passed straight to
+/// transaction creation. The proving traits are infallible, so a failed
+/// remote proof abandons the build, which the wallet runs under
+/// `catch_proving_failure`. Call [`Prover::check`] first so an unreachable
+/// prover is reported before any work is done.
+pub enum Prover {
+    /// Groth16 parameters loaded in this process (bundled or from disk)
+    Local(Box<LocalTxProver>),
+    /// Proofs requested from a delegated prover service
+    Remote(RemoteProver),
+}
+
+impl Prover {
+    /// Load the prover for `backend`
+    pub async fn load(backend: &ProverBackend) -> anyhow::Result<Self> {
+        match backend {
+            ProverBackend::Bundled => Ok(Self::Local(Box::new(LocalTxProver::bundled()))),
+            ProverBackend::Local => LocalTxProver::with_default_location()
+                .map(|p| Self::Local(Box::new(p)))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Sapling parameters not found in the default params directory")
+                }),
+            ProverBackend::Remote(url) => {
+                let remote = RemoteProver::new(url.clone())?;
+                remote.check().await?;
+                info!("Using remote Sapling prover at {}", url);
+                Ok(Self::Remote(remote))
+            }
+        }
+    }
+
+    /// Check that proofs can be created right before a build
+    pub async fn check(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Local(_) => Ok(()),
+            Self::Remote(remote) => remote.check().await,
+        }
+    }
+}
+
+impl SpendProver for Prover {
+    type Proof = GrothProofBytes;
+
+    fn prepare_circuit(
+        proof_generation_key: ProofGenerationKey,
+        diversifier: Diversifier,
+        rseed: Rseed,
+        value: NoteValue,
+        alpha: jubjub::Fr,
+        rcv: ValueCommitTrapdoor,
+        anchor: bls12_381::Scalar,
+        merkle_path: MerklePath,
+    ) -> Option<Spend> {
+        <LocalTxProver as SpendProver>::prepare_circuit(
+            proof_generation_key,
+            diversifier,
+            rseed,
+            value,
+            alpha,
+            rcv,
+            anchor,
+            merkle_path,
+        )
+    }
+
+    fn create_proof<R: RngCore>(&self, circuit: Spend, rng: &mut R) -> Self::Proof {
+        match self {
+            Prover::Local(prover) => {
+                let proof = SpendProver::create_proof(prover.as_ref(), circuit, rng);
+                <LocalTxProver as SpendProver>::encode_proof(proof)
+            }
+            Prover::Remote(remote) => proof_or_abandon(remote.prove_spend(circuit)),
+        }
+    }
+
+    fn encode_proof(proof: Self::Proof) -> GrothProofBytes {
+        proof
+    }
+}
+
+impl OutputProver for Prover {
+    type Proof = GrothProofBytes;
+
+    fn prepare_circuit(
+        esk: &EphemeralSecretKey,
+        payment_address: PaymentAddress,
+        rcm: jubjub::Fr,
+        value: NoteValue,
+        rcv: ValueCommitTrapdoor,
+    ) -> Output {
+        <LocalTxProver as OutputProver>::prepare_circuit(esk, payment_address, rcm, value, rcv)
+    }
+
+    fn create_proof<R: RngCore>(&self, circuit: Output, rng: &mut R) -> Self::Proof {
+        match self {
+            Prover::Local(prover) => {
+                let proof = OutputProver::create_proof(prover.as_ref(), circuit, rng);
+                <LocalTxProver as OutputProver>::encode_proof(proof)
+            }
+            Prover::Remote(remote) => proof_or_abandon(remote.prove_output(circuit)),
+        }
+    }
+
+    fn encode_proof(proof: Self::Proof) -> GrothProofBytes {
+        proof
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_backend() {
+        assert_eq!(
+            ProverBackend::parse("bundled").unwrap(),
+            ProverBackend::Bundled
+        );
+        assert_eq!(ProverBackend::parse("").unwrap(), ProverBackend::Bundled);
+        assert_eq!(ProverBackend::parse("local").unwrap(), ProverBackend::Local);
+        assert_eq!(
+            ProverBackend::parse("https://prover.lan:8443").unwrap(),
+            ProverBackend::Remote("https://prover.lan:8443".to_string())
+        );
+        assert!(ProverBackend::parse("gpu").is_err());
+    }
+
+    #[test]
+    fn test_failed_proof_abandons_build() {
+        let result = catch_proving_failure(|| {
+            let first = proof_or_abandon(Err(anyhow::anyhow!("prover down")));
+            panic!("build continued with proof {first:?}");
+        });
+        assert!(result.unwrap_err().to_string().contains("prover down"));
+
+        let proof = catch_proving_failure(|| proof_or_abandon(Ok([1; 192]))).unwrap();
+        assert_eq!(proof, [1; 192]);
+    }
+
+    #[test]
+    fn test_unreachable_prover_fails() {
+        // Nothing listens on the discard port
+        let mut remote = RemoteProver::new("http://127.0.0.1:9").unwrap();
+        remote.timeout = Duration::from_secs(5);
+        let result = remote.blocking_call(
+            "/zots.prover.v1.Prover/ProveOutput",
+            proto::OutputCircuit::default(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_slow_prover_times_out() {
+        // Accepts connections through the backlog but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let mut remote = RemoteProver::new(url).unwrap();
+        remote.timeout = Duration::from_millis(300);
+
+        let started = std::time::Instant::now();
+        let err = remote
+            .blocking_call(
+                "/zots.prover.v1.Prover/ProveOutput",
+                proto::OutputCircuit::default(),
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("timed out"), "{err}");
+        assert!(started.elapsed() < Duration::from_secs(10));
+        drop(listener);
+    }
+}
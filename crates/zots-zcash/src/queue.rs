@@ -0,0 +1,472 @@
+//! Batched stamping queue
+//!
+//! [`StampQueue`] owns a [`ZotsWallet`] on a background task and collects
+//! hashes from any number of submitters. Pending hashes are flushed into a
+//! single batched transaction (one memo output per hash) when the batch is
+//! full or the oldest hash has waited long enough. Each submission returns a
+//! [`PendingStamp`] future that resolves to the hash's attestation once the
+//! batch transaction is confirmed; [`PendingStamp::broadcast`] reports the
+//! transaction as soon as it is sent.
+//!
+//! A batch whose transaction cannot be built (e.g. too many outputs for the
+//! wallet's funds) is retried in halves, so only the submitters that still
+//! cannot be stamped see the error.
+//!
+//! Confirmations are awaited on their own tasks through a
+//! [`ConfirmationWatcher`](crate::ConfirmationWatcher), so the worker goes
+//! straight back to collecting and flushing hashes after each broadcast.
+//!
+//! ```rust,ignore
+//! use zots_zcash::{StampQueue, StampQueueConfig, ZotsWallet};
+//!
+//! let queue = StampQueue::spawn(wallet, StampQueueConfig::default());
+//! let a = queue.submit([1u8; 32]);
+//! let b = queue.submit([2u8; 32]);
+//! queue.flush();
+//! let (a, b) = (a.await?, b.await?);
+//! assert_eq!(a.txid, b.txid);
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+use zots_core::ZcashAttestation;
+
+use crate::wallet::{ConfirmationWaitPolicy, ZotsWallet, is_network_error};
+
+/// Flush thresholds for a [`StampQueue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StampQueueConfig {
+    /// Flush as soon as this many hashes are pending
+    pub max_batch: usize,
+    /// Flush once the oldest pending hash has waited this long
+    pub max_delay: Duration,
+    /// How to wait for each batch transaction to confirm
+    pub wait_policy: ConfirmationWaitPolicy,
+}
+
+impl Default for StampQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_batch: 32,
+            max_delay: Duration::from_secs(60),
+            wait_policy: ConfirmationWaitPolicy::default(),
+        }
+    }
+}
+
+type StampOutcome = Result<ZcashAttestation, String>;
+
+/// Channels resolving one submitter
+struct Waiter {
+    /// Taken when the txid is sent
+    broadcast: Option<oneshot::Sender<String>>,
+    done: oneshot::Sender<StampOutcome>,
+}
+
+impl Waiter {
+    fn new() -> (Self, PendingStamp) {
+        let (broadcast, broadcast_rx) = oneshot::channel();
+        let (done, rx) = oneshot::channel();
+        let waiter = Waiter {
+            broadcast: Some(broadcast),
+            done,
+        };
+        let pending = PendingStamp {
+            broadcast: Some(broadcast_rx),
+            rx,
+        };
+        (waiter, pending)
+    }
+}
+
+enum QueueCommand {
+    Submit([u8; 32], Waiter),
+    Flush,
+}
+
+/// Attestation for a submitted hash, available once its batch confirms
+pub struct PendingStamp {
+    broadcast: Option<oneshot::Receiver<String>>,
+    rx: oneshot::Receiver<StampOutcome>,
+}
+
+impl PendingStamp {
+    /// Wait until the hash's batch transaction is broadcast and return its txid
+    ///
+    /// Returns `None` if the batch failed before broadcasting, or when called
+    /// again after the txid was already returned.
+    pub async fn broadcast(&mut self) -> Option<String> {
+        self.broadcast.take()?.await.ok()
+    }
+}
+
+impl Future for PendingStamp {
+    type Output = anyhow::Result<ZcashAttestation>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx)
+            .poll(cx)
+            .map(|outcome| match outcome {
+                Ok(Ok(attestation)) => Ok(attestation),
+                Ok(Err(e)) => Err(anyhow::anyhow!(e)),
+                Err(_) => Err(anyhow::anyhow!(
+                    "Stamp queue stopped before the hash was stamped"
+                )),
+            })
+    }
+}
+
+/// Accumulates hashes and stamps them in batched transactions
+pub struct StampQueue {
+    tx: mpsc::UnboundedSender<QueueCommand>,
+    worker: JoinHandle<ZotsWallet>,
+}
+
+impl StampQueue {
+    /// Start the queue worker, taking ownership of the wallet
+    ///
+    /// The wallet should already be initialized and synced.
+    pub fn spawn(wallet: ZotsWallet, config: StampQueueConfig) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let worker = tokio::spawn(run_queue(wallet, config, rx));
+        Self { tx, worker }
+    }
+
+    /// Queue a hash for stamping
+    pub fn submit(&self, hash: [u8; 32]) -> PendingStamp {
+        let (waiter, pending) = Waiter::new();
+        // If the worker is gone the senders are dropped and the future reports it
+        let _ = self.tx.send(QueueCommand::Submit(hash, waiter));
+        pending
+    }
+
+    /// Flush pending hashes now instead of waiting for a threshold
+    pub fn flush(&self) {
+        let _ = self.tx.send(QueueCommand::Flush);
+    }
+
+    /// Stamp anything still pending, wait for every batch to confirm, stop the
+    /// worker and return the wallet
+    pub async fn shutdown(self) -> anyhow::Result<ZotsWallet> {
+        drop(self.tx);
+        self.worker
+            .await
+            .map_err(|e| anyhow::anyhow!("Stamp queue worker failed: {e}"))
+    }
+}
+
+/// Confirmation of a broadcast batch, awaited on its own task
+type Confirmation = Pin<Box<dyn Future<Output = anyhow::Result<ZcashAttestation>> + Send>>;
+
+/// Builds and broadcasts the queue's batch transactions
+trait BatchStamper: Send + 'static {
+    /// Broadcast one transaction carrying `hashes`
+    ///
+    /// Returns the txid and a future that resolves once the transaction is
+    /// confirmed according to `policy`.
+    fn broadcast(
+        &mut self,
+        hashes: &[[u8; 32]],
+        policy: &ConfirmationWaitPolicy,
+    ) -> impl Future<Output = anyhow::Result<(String, Confirmation)>> + Send;
+}
+
+impl BatchStamper for ZotsWallet {
+    async fn broadcast(
+        &mut self,
+        hashes: &[[u8; 32]],
+        policy: &ConfirmationWaitPolicy,
+    ) -> anyhow::Result<(String, Confirmation)> {
+        self.sync().await?;
+        let tx = self.create_batch_timestamp_tx(hashes).await?;
+        debug!("Batch transaction {} broadcast", tx.txid);
+
+        let network = self.config().network;
+        let mut watcher = self.confirmation_watcher();
+        let (txid, txid_bytes, policy) = (tx.txid.clone(), tx.txid_bytes, *policy);
+        let confirmation = async move {
+            let confirmation = watcher.wait(&txid, &policy).await?;
+            Ok::<_, anyhow::Error>(ZcashAttestation::new(
+                network,
+                txid_bytes,
+                confirmation.block_height,
+                confirmation.block_time,
+                0,
+            ))
+        };
+        Ok((tx.txid, Box::pin(confirmation)))
+    }
+}
+
+async fn run_queue<S: BatchStamper>(
+    mut stamper: S,
+    config: StampQueueConfig,
+    mut rx: mpsc::UnboundedReceiver<QueueCommand>,
+) -> S {
+    let max_batch = config.max_batch.max(1);
+    let mut pending: Vec<([u8; 32], Waiter)> = Vec::new();
+    let mut deadline: Option<Instant> = None;
+    let mut confirmations = JoinSet::new();
+
+    loop {
+        // Drop the handles of batches that have already resolved
+        while confirmations.try_join_next().is_some() {}
+
+        let command = match deadline {
+            Some(deadline) => match tokio::time::timeout_at(deadline, rx.recv()).await {
+                Ok(command) => command,
+                Err(_) => Some(QueueCommand::Flush),
+            },
+            None => rx.recv().await,
+        };
+
+        let flush_now = match command {
+            Some(QueueCommand::Submit(hash, waiter)) => {
+                pending.push((hash, waiter));
+                deadline.get_or_insert_with(|| Instant::now() + config.max_delay);
+                pending.len() >= max_batch
+            }
+            Some(QueueCommand::Flush) => true,
+            None => {
+                // All handles dropped: stamp what is left, let every
+                // broadcast batch resolve and stop
+                if !pending.is_empty() {
+                    stamp_batch(
+                        &mut stamper,
+                        &config.wait_policy,
+                        pending,
+                        &mut confirmations,
+                    )
+                    .await;
+                }
+                confirmations.join_all().await;
+                return stamper;
+            }
+        };
+
+        // Batches are flushed as soon as they reach `max_batch`, so the
+        // pending list never exceeds it
+        if flush_now && !pending.is_empty() {
+            let batch = std::mem::take(&mut pending);
+            stamp_batch(&mut stamper, &config.wait_policy, batch, &mut confirmations).await;
+            deadline = None;
+        }
+    }
+}
+
+/// Broadcast one batch and hand each transaction's confirmation to a task
+///
+/// A failure before the broadcast splits the batch in halves that are
+/// stamped separately, down to single hashes, so one hash that cannot be
+/// stamped fails only its own submitters. A broadcast batch is resolved by
+/// its confirmation task; lightwalletd being unreachable fails the whole
+/// batch at once.
+async fn stamp_batch<S: BatchStamper>(
+    stamper: &mut S,
+    policy: &ConfirmationWaitPolicy,
+    batch: Vec<([u8; 32], Waiter)>,
+    confirmations: &mut JoinSet<()>,
+) {
+    info!("Flushing stamp queue batch of {} hashes", batch.len());
+
+    let mut batches = vec![batch];
+    while let Some(mut batch) = batches.pop() {
+        let hashes: Vec<[u8; 32]> = batch.iter().map(|(hash, _)| *hash).collect();
+        match stamper.broadcast(&hashes, policy).await {
+            Ok((txid, confirmation)) => {
+                let waiters: Vec<Waiter> = batch
+                    .into_iter()
+                    .map(|(_, mut waiter)| {
+                        if let Some(sender) = waiter.broadcast.take() {
+                            let _ = sender.send(txid.clone());
+                        }
+                        waiter
+                    })
+                    .collect();
+                confirmations.spawn(async move {
+                    let outcome = confirmation.await.map_err(|e| {
+                        warn!("Batch {txid} was not confirmed: {e}");
+                        e.to_string()
+                    });
+                    for waiter in waiters {
+                        let _ = waiter.done.send(outcome.clone());
+                    }
+                });
+            }
+            Err(e) if batch.len() > 1 && !is_network_error(&e) => {
+                warn!(
+                    "Batch of {} hashes failed before broadcast, retrying in halves: {e}",
+                    batch.len()
+                );
+                let second = batch.split_off(batch.len() / 2);
+                batches.push(second);
+                batches.push(batch);
+            }
+            Err(e) => {
+                warn!("Batch stamp failed: {e}");
+                let outcome = Err(e.to_string());
+                for (_, waiter) in batch {
+                    let _ = waiter.done.send(outcome.clone());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use zots_core::Network;
+
+    /// Broadcasts by recording each batch; batches containing `poison` fail
+    #[derive(Clone, Default)]
+    struct StubStamper {
+        batches: Arc<Mutex<Vec<Vec<[u8; 32]>>>>,
+        poison: Option<[u8; 32]>,
+        /// Attestations resolve once this has a permit
+        confirm: Option<Arc<tokio::sync::Semaphore>>,
+    }
+
+    impl StubStamper {
+        fn batches(&self) -> Vec<Vec<[u8; 32]>> {
+            self.batches.lock().unwrap().clone()
+        }
+    }
+
+    impl BatchStamper for StubStamper {
+        async fn broadcast(
+            &mut self,
+            hashes: &[[u8; 32]],
+            _policy: &ConfirmationWaitPolicy,
+        ) -> anyhow::Result<(String, Confirmation)> {
+            if self.poison.is_some_and(|poison| hashes.contains(&poison)) {
+                anyhow::bail!("Insufficient funds");
+            }
+            let n = {
+                let mut batches = self.batches.lock().unwrap();
+                batches.push(hashes.to_vec());
+                batches.len() as u8
+            };
+            let confirm = self.confirm.clone();
+            let confirmation = async move {
+                if let Some(confirm) = confirm {
+                    let _ = confirm.acquire().await;
+                }
+                Ok::<_, anyhow::Error>(ZcashAttestation::new(
+                    Network::Testnet,
+                    [n; 32],
+                    1000 + u32::from(n),
+                    1_700_000_000,
+                    0,
+                ))
+            };
+            Ok((format!("tx{n}"), Box::pin(confirmation)))
+        }
+    }
+
+    fn start(
+        stamper: StubStamper,
+        max_batch: usize,
+        max_delay: Duration,
+    ) -> (mpsc::UnboundedSender<QueueCommand>, JoinHandle<StubStamper>) {
+        let config = StampQueueConfig {
+            max_batch,
+            max_delay,
+            ..StampQueueConfig::default()
+        };
+        let (tx, rx) = mpsc::unbounded_channel();
+        (tx, tokio::spawn(run_queue(stamper, config, rx)))
+    }
+
+    fn submit(tx: &mpsc::UnboundedSender<QueueCommand>, hash: [u8; 32]) -> PendingStamp {
+        let (waiter, pending) = Waiter::new();
+        tx.send(QueueCommand::Submit(hash, waiter)).unwrap();
+        pending
+    }
+
+    #[tokio::test]
+    async fn test_flushes_when_batch_is_full() {
+        let stamper = StubStamper::default();
+        let (tx, worker) = start(stamper.clone(), 2, Duration::from_secs(3600));
+
+        let a = submit(&tx, [1; 32]);
+        let b = submit(&tx, [2; 32]);
+        let c = submit(&tx, [3; 32]);
+        let (a, b) = (a.await.unwrap(), b.await.unwrap());
+        assert_eq!(a.txid, b.txid);
+        assert_eq!(stamper.batches(), vec![vec![[1; 32], [2; 32]]]);
+
+        // The third hash waits for the next flush
+        drop(tx);
+        let c = c.await.unwrap();
+        assert_ne!(c.txid, a.txid);
+        assert_eq!(worker.await.unwrap().batches().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_flushes_after_max_delay() {
+        let stamper = StubStamper::default();
+        let (tx, _worker) = start(stamper.clone(), 32, Duration::from_millis(300));
+
+        let pending = submit(&tx, [1; 32]);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(stamper.batches().is_empty());
+
+        pending.await.unwrap();
+        assert_eq!(stamper.batches(), vec![vec![[1; 32]]]);
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_before_confirmation() {
+        let confirm = Arc::new(tokio::sync::Semaphore::new(0));
+        let stamper = StubStamper {
+            confirm: Some(confirm.clone()),
+            ..StubStamper::default()
+        };
+        let (tx, _worker) = start(stamper.clone(), 1, Duration::from_secs(3600));
+
+        let mut first = submit(&tx, [1; 32]);
+        assert_eq!(first.broadcast().await.as_deref(), Some("tx1"));
+        assert_eq!(first.broadcast().await, None);
+
+        // An unconfirmed batch does not hold up the next one
+        let mut second = submit(&tx, [2; 32]);
+        assert_eq!(second.broadcast().await.as_deref(), Some("tx2"));
+
+        confirm.add_permits(1);
+        assert_eq!(first.await.unwrap().block_height, 1001);
+        assert_eq!(second.await.unwrap().block_height, 1002);
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_is_retried_in_halves() {
+        let stamper = StubStamper {
+            poison: Some([3; 32]),
+            ..StubStamper::default()
+        };
+        let (tx, _worker) = start(stamper.clone(), 4, Duration::from_secs(3600));
+
+        let pending: Vec<_> = (1..=4).map(|i| submit(&tx, [i; 32])).collect();
+        let mut outcomes = Vec::new();
+        for mut stamp in pending {
+            let broadcast = stamp.broadcast().await;
+            outcomes.push((broadcast.is_some(), stamp.await.is_ok()));
+        }
+
+        assert_eq!(
+            outcomes,
+            vec![(true, true), (true, true), (false, false), (true, true)]
+        );
+        assert_eq!(
+            stamper.batches(),
+            vec![vec![[1; 32], [2; 32]], vec![[4; 32]]]
+        );
+    }
+}
@@ -0,0 +1,523 @@
+//! In-process lightwalletd mock for integration tests
+//!
+//! [`MockLightwalletd`] serves the `CompactTxStreamer` gRPC service from
+//! canned chain data on a local port, so stamping and verification can be
+//! exercised without a live network. Enable the `testing` feature to use it
+//! from other crates.
+//!
+//! | RPC | Behaviour |
+//! |-----|-----------|
+//! | `GetLatestBlock` | Highest canned block (or the tip set with [`MockLightwalletd::set_tip`]) |
+//! | `GetBlock` | Canned compact block at the requested height |
+//! | `GetBlockRange` | Canned compact blocks in the range, either direction |
+//! | `GetTreeState` | Canned tree state, or empty trees after the canned block at that height |
+//! | `GetTransaction` | Canned or broadcast transactions by txid |
+//! | `SendTransaction` | Captures the raw transaction and accepts it |
+//! | `GetSubtreeRoots`, `GetAddressUtxosStream` | Empty streams |
+//! | `GetLightdInfo` | Testnet server info at the current tip |
+//!
+//! Other RPCs return `UNIMPLEMENTED`.
+//!
+//! ```rust,no_run
+//! use zots_zcash::testing::MockLightwalletd;
+//! use zots_zcash::{ZcashConfig, ZotsWallet};
+//!
+//! # async fn example(seed: &str) -> anyhow::Result<()> {
+//! let mock = MockLightwalletd::new();
+//! mock.set_tip(3_717_600);
+//! let server = mock.clone().serve().await?;
+//!
+//! let mut config = ZcashConfig::from_seed(seed)?;
+//! config.lightwalletd_url = server.url().to_string();
+//! let mut wallet = ZotsWallet::new(config).await?;
+//! wallet.init_account().await?;
+//! // ... stamp, then inspect what was sent
+//! assert_eq!(mock.broadcasts().len(), 1);
+//! server.shutdown().await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tonic::Status;
+use tonic::body::Body;
+use tonic::codegen::{BoxFuture, BoxStream, Service, http, tokio_stream};
+use tonic::server::NamedService;
+use tonic::transport::Server;
+use tonic::transport::server::TcpIncoming;
+use tracing::debug;
+use zcash_client_backend::proto::compact_formats::CompactBlock;
+use zcash_client_backend::proto::service::{
+    BlockId, BlockRange, ChainSpec, Empty, GetAddressUtxosArg, GetAddressUtxosReply,
+    GetSubtreeRootsArg, LightdInfo, RawTransaction, SendResponse, SubtreeRoot, TreeState, TxFilter,
+};
+use zcash_primitives::transaction::Transaction;
+use zcash_protocol::consensus::BranchId;
+
+const SERVICE_NAME: &str = "cash.z.wallet.sdk.rpc.CompactTxStreamer";
+
+#[derive(Debug, Default)]
+struct MockChain {
+    tip: Option<u64>,
+    blocks: BTreeMap<u64, CompactBlock>,
+    tree_states: BTreeMap<u64, TreeState>,
+    /// Raw transactions keyed by txid in internal byte order
+    transactions: HashMap<[u8; 32], RawTransaction>,
+    broadcasts: Vec<Vec<u8>>,
+}
+
+impl MockChain {
+    fn tip_height(&self) -> u64 {
+        let highest = self.blocks.keys().next_back().copied().unwrap_or(0);
+        self.tip.unwrap_or(0).max(highest)
+    }
+}
+
+/// Canned chain data served over the lightwalletd gRPC interface
+///
+/// Clones share the same chain, so a test can keep a handle to add blocks
+/// and inspect broadcasts while the server is running.
+#[derive(Debug, Clone, Default)]
+pub struct MockLightwalletd {
+    chain: Arc<Mutex<MockChain>>,
+}
+
+impl MockLightwalletd {
+    /// Create a mock with an empty chain
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn chain(&self) -> std::sync::MutexGuard<'_, MockChain> {
+        self.chain.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Report `height` as the chain tip even if no block is canned there
+    pub fn set_tip(&self, height: u64) {
+        self.chain().tip = Some(height);
+    }
+
+    /// Current chain tip height
+    pub fn tip_height(&self) -> u64 {
+        self.chain().tip_height()
+    }
+
+    /// Add a compact block served by `GetBlockRange`
+    pub fn add_block(&self, block: CompactBlock) {
+        self.chain().blocks.insert(block.height, block);
+    }
+
+    /// Set the tree state returned for `state.height`
+    pub fn set_tree_state(&self, state: TreeState) {
+        self.chain().tree_states.insert(state.height, state);
+    }
+
+    /// Add a raw transaction served by `GetTransaction`
+    ///
+    /// `txid` is in internal byte order; `height` 0 means unmined.
+    pub fn add_transaction(&self, txid: [u8; 32], data: Vec<u8>, height: u64) {
+        self.chain()
+            .transactions
+            .insert(txid, RawTransaction { data, height });
+    }
+
+    /// Raw transactions received through `SendTransaction`, oldest first
+    pub fn broadcasts(&self) -> Vec<Vec<u8>> {
+        self.chain().broadcasts.clone()
+    }
+
+    /// Mark a broadcast (or canned) transaction as mined at `height`
+    pub fn mine_transaction(&self, txid: [u8; 32], height: u64) -> bool {
+        match self.chain().transactions.get_mut(&txid) {
+            Some(tx) => {
+                tx.height = height;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Serve the mock on an ephemeral localhost port
+    pub async fn serve(self) -> anyhow::Result<MockServerHandle> {
+        let incoming = TcpIncoming::bind(SocketAddr::from(([127, 0, 0, 1], 0)))?;
+        let addr = incoming.local_addr()?;
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        let task = tokio::spawn(
+            Server::builder()
+                .add_service(MockService { mock: self })
+                .serve_with_incoming_shutdown(incoming, async move {
+                    let _ = shutdown_rx.await;
+                }),
+        );
+        debug!("Mock lightwalletd listening on {}", addr);
+
+        Ok(MockServerHandle {
+            url: format!("http://{addr}"),
+            shutdown: shutdown_tx,
+            task,
+        })
+    }
+
+    fn latest_block(&self) -> BlockId {
+        let chain = self.chain();
+        let height = chain.tip_height();
+        BlockId {
+            height,
+            hash: chain
+                .blocks
+                .get(&height)
+                .map(|b| b.hash.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn block(&self, id: BlockId) -> Result<CompactBlock, Status> {
+        self.chain()
+            .blocks
+            .get(&id.height)
+            .cloned()
+            .ok_or_else(|| Status::not_found(format!("block {} not found", id.height)))
+    }
+
+    fn block_range(&self, range: BlockRange) -> Result<Vec<CompactBlock>, Status> {
+        let (Some(start), Some(end)) = (range.start, range.end) else {
+            return Err(Status::invalid_argument("block range needs start and end"));
+        };
+        let chain = self.chain();
+        let (low, high) = (start.height.min(end.height), start.height.max(end.height));
+        let mut blocks: Vec<CompactBlock> = chain
+            .blocks
+            .range(low..=high)
+            .map(|(_, b)| b.clone())
+            .collect();
+        if start.height > end.height {
+            blocks.reverse();
+        }
+        Ok(blocks)
+    }
+
+    fn tree_state(&self, id: BlockId) -> TreeState {
+        let chain = self.chain();
+        if let Some(state) = chain.tree_states.get(&id.height) {
+            return state.clone();
+        }
+        // Empty trees, anchored to the canned block so scanning can follow on
+        let block = chain.blocks.get(&id.height);
+        let hash = block.map_or_else(|| vec![0; 32], |b| b.hash.clone());
+        TreeState {
+            network: "test".to_string(),
+            height: id.height,
+            // Tree states carry the hash in display (byte-reversed) order
+            hash: hash.iter().rev().map(|b| format!("{b:02x}")).collect(),
+            time: block.map_or(0, |b| b.time),
+            ..Default::default()
+        }
+    }
+
+    fn transaction(&self, filter: TxFilter) -> Result<RawTransaction, Status> {
+        let txid: [u8; 32] = filter
+            .hash
+            .try_into()
+            .map_err(|_| Status::invalid_argument("txid must be 32 bytes"))?;
+        self.chain()
+            .transactions
+            .get(&txid)
+            .cloned()
+            .ok_or_else(|| Status::not_found("transaction not found"))
+    }
+
+    fn send_transaction(&self, raw: RawTransaction) -> SendResponse {
+        let mut chain = self.chain();
+        chain.broadcasts.push(raw.data.clone());
+        // Index parseable transactions so they can be fetched back by txid
+        match Transaction::read(&raw.data[..], BranchId::Nu6) {
+            Ok(tx) => {
+                let txid = *tx.txid().as_ref();
+                chain.transactions.insert(
+                    txid,
+                    RawTransaction {
+                        data: raw.data,
+                        height: 0,
+                    },
+                );
+                SendResponse {
+                    error_code: 0,
+                    error_message: txid.iter().rev().map(|b| format!("{b:02x}")).collect(),
+                }
+            }
+            Err(_) => SendResponse {
+                error_code: 0,
+                error_message: String::new(),
+            },
+        }
+    }
+
+    fn lightd_info(&self) -> LightdInfo {
+        LightdInfo {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            vendor: "zots mock".to_string(),
+            taddr_support: true,
+            chain_name: "test".to_string(),
+            sapling_activation_height: 280_000,
+            block_height: self.tip_height(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A running [`MockLightwalletd`] server
+pub struct MockServerHandle {
+    url: String,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<Result<(), tonic::transport::Error>>,
+}
+
+impl MockServerHandle {
+    /// URL to use as `ZcashConfig::lightwalletd_url`
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Stop the server and wait for it to exit
+    pub async fn shutdown(self) {
+        let _ = self.shutdown.send(());
+        let _ = self.task.await;
+    }
+}
+
+/// Routes gRPC requests to the mock handlers
+#[derive(Clone)]
+struct MockService {
+    mock: MockLightwalletd,
+}
+
+impl NamedService for MockService {
+    const NAME: &'static str = SERVICE_NAME;
+}
+
+impl Service<http::Request<Body>> for MockService {
+    type Response = http::Response<Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<Body>) -> Self::Future {
+        let mock = self.mock.clone();
+        let method = req
+            .uri()
+            .path()
+            .strip_prefix(&format!("/{SERVICE_NAME}/"))
+            .unwrap_or_default()
+            .to_string();
+        debug!("Mock lightwalletd call: {}", method);
+
+        match method.as_str() {
+            "GetLatestBlock" => unary(req, move |_: ChainSpec| Ok(mock.latest_block())),
+            "GetBlock" => unary(req, move |id: BlockId| mock.block(id)),
+            "GetBlockRange" => streaming(req, move |range: BlockRange| mock.block_range(range)),
+            "GetTreeState" => unary(req, move |id: BlockId| Ok(mock.tree_state(id))),
+            "GetTransaction" => unary(req, move |filter: TxFilter| mock.transaction(filter)),
+            "SendTransaction" => unary(req, move |raw: RawTransaction| {
+                Ok(mock.send_transaction(raw))
+            }),
+            "GetSubtreeRoots" => {
+                streaming(req, |_: GetSubtreeRootsArg| Ok(Vec::<SubtreeRoot>::new()))
+            }
+            "GetAddressUtxosStream" => streaming(req, |_: GetAddressUtxosArg| {
+                Ok(Vec::<GetAddressUtxosReply>::new())
+            }),
+            "GetLightdInfo" => unary(req, move |_: Empty| Ok(mock.lightd_info())),
+            _ => Box::pin(async move {
+                Ok(Status::unimplemented(format!("mock does not implement {method}")).into_http())
+            }),
+        }
+    }
+}
+
+/// One-shot handler adapted to a tonic unary or server-streaming service
+struct Handler<F>(Option<F>);
+
+impl<F> Handler<F> {
+    fn take(&mut self) -> F {
+        self.0.take().expect("gRPC handler called twice")
+    }
+}
+
+impl<Req, Resp, F> Service<tonic::Request<Req>> for Handler<F>
+where
+    F: FnOnce(Req) -> Result<Resp, Status>,
+{
+    type Response = tonic::Response<Resp>;
+    type Error = Status;
+    type Future = std::future::Ready<Result<Self::Response, Status>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Status>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: tonic::Request<Req>) -> Self::Future {
+        std::future::ready((self.take())(request.into_inner()).map(tonic::Response::new))
+    }
+}
+
+fn unary<Req, Resp, F>(
+    req: http::Request<Body>,
+    handler: F,
+) -> BoxFuture<http::Response<Body>, Infallible>
+where
+    Req: prost::Message + Default + Send + 'static,
+    Resp: prost::Message + Send + 'static,
+    F: FnOnce(Req) -> Result<Resp, Status> + Send + 'static,
+{
+    Box::pin(async move {
+        let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::<Resp, Req>::default());
+        Ok(grpc.unary(Handler(Some(handler)), req).await)
+    })
+}
+
+fn streaming<Req, Resp, F>(
+    req: http::Request<Body>,
+    handler: F,
+) -> BoxFuture<http::Response<Body>, Infallible>
+where
+    Req: prost::Message + Default + Send + 'static,
+    Resp: prost::Message + Send + 'static,
+    F: FnOnce(Req) -> Result<Vec<Resp>, Status> + Send + 'static,
+{
+    let handler = move |request: Req| {
+        handler(request)
+            .map(|items| Box::pin(tokio_stream::iter(items.into_iter().map(Ok))) as BoxStream<Resp>)
+    };
+    Box::pin(async move {
+        let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::<Resp, Req>::default());
+        Ok(grpc.server_streaming(Handler(Some(handler)), req).await)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ZcashConfig, ZotsWallet};
+    use zcash_client_backend::proto::compact_formats::ChainMetadata;
+    use zcash_client_backend::proto::service::compact_tx_streamer_client::CompactTxStreamerClient;
+
+    const TEST_SEED: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon abandon abandon abandon abandon art";
+
+    fn block(height: u64) -> CompactBlock {
+        CompactBlock {
+            height,
+            hash: vec![height as u8; 32],
+            ..Default::default()
+        }
+    }
+
+    /// Empty block linked to `block(height - 1)`, with empty note trees
+    fn linked_block(height: u64) -> CompactBlock {
+        CompactBlock {
+            prev_hash: vec![(height - 1) as u8; 32],
+            time: 1_700_000_000 + height as u32,
+            chain_metadata: Some(ChainMetadata {
+                sapling_commitment_tree_size: 0,
+                orchard_commitment_tree_size: 0,
+            }),
+            ..block(height)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_serves_canned_chain() {
+        let mock = MockLightwalletd::new();
+        for height in 100..105 {
+            mock.add_block(block(height));
+        }
+        let server = mock.clone().serve().await.unwrap();
+        let mut client = CompactTxStreamerClient::connect(server.url().to_string())
+            .await
+            .unwrap();
+
+        let tip = client
+            .get_latest_block(ChainSpec::default())
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(tip.height, 104);
+
+        let range = BlockRange {
+            start: Some(BlockId {
+                height: 101,
+                ..Default::default()
+            }),
+            end: Some(BlockId {
+                height: 103,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut stream = client.get_block_range(range).await.unwrap().into_inner();
+        let mut heights = Vec::new();
+        while let Some(block) = stream.message().await.unwrap() {
+            heights.push(block.height);
+        }
+        assert_eq!(heights, vec![101, 102, 103]);
+
+        let response = client
+            .send_transaction(RawTransaction {
+                data: vec![1, 2, 3],
+                height: 0,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.error_code, 0);
+        assert_eq!(mock.broadcasts(), vec![vec![1, 2, 3]]);
+
+        server.shutdown().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_wallet_against_mock() {
+        let birthday = 3_717_600;
+        let mock = MockLightwalletd::new();
+        for height in birthday - 1..birthday + 5 {
+            mock.add_block(linked_block(height));
+        }
+        let server = mock.clone().serve().await.unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = ZcashConfig::from_seed_with_birthday(TEST_SEED, Some(birthday)).unwrap();
+        config.lightwalletd_url = server.url().to_string();
+        config.data_dir = dir.path().to_path_buf();
+
+        let mut wallet = ZotsWallet::new(config).await.unwrap();
+        wallet.init_account().await.unwrap();
+        wallet.sync().await.unwrap();
+        assert_eq!(wallet.get_block_height().await.unwrap(), birthday + 4);
+        assert_eq!(wallet.get_balance().unwrap(), 0);
+
+        // Unmined until the mock mines it
+        let txid = [7u8; 32];
+        let display: String = txid.iter().rev().map(|b| format!("{b:02x}")).collect();
+        mock.add_transaction(txid, vec![1, 2, 3], 0);
+        assert!(wallet.fetch_confirmation(&display).await.unwrap().is_none());
+
+        assert!(mock.mine_transaction(txid, birthday + 2));
+        let confirmation = wallet.fetch_confirmation(&display).await.unwrap().unwrap();
+        assert_eq!(u64::from(confirmation.block_height), birthday + 2);
+        assert_eq!(confirmation.block_time, linked_block(birthday + 2).time);
+
+        drop(wallet);
+        server.shutdown().await;
+    }
+}
@@ -3,18 +3,26 @@
 //! Provides wallet initialization, sync, and transaction creation
 //! for timestamping operations on the Zcash blockchain.
 
-use std::collections::HashMap;
+use anyhow::Context;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::time::Duration;
 
 use bip0039::{English, Mnemonic};
+use prost::Message;
 use rand_core::OsRng;
+use sapling_crypto::prover::{OutputProver, SpendProver};
 use tonic::transport::{Channel, ClientTlsConfig};
 use tracing::{debug, info, warn};
+use zcash_address::unified::{self, Encoding};
 use zcash_client_backend::data_api::wallet::input_selection::GreedyInputSelector;
 use zcash_client_backend::data_api::wallet::{
     ConfirmationsPolicy, SpendingKeys, create_proposed_transactions, propose_shielding,
-    propose_standard_transfer_to_address,
+    propose_standard_transfer_to_address, propose_transfer,
+};
+use zcash_client_backend::data_api::{
+    Account, AccountBirthday, AccountPurpose, Balance, WalletRead, WalletWrite,
 };
-use zcash_client_backend::data_api::{AccountBirthday, AccountPurpose, WalletRead, WalletWrite};
 use zcash_client_backend::decrypt_transaction;
 use zcash_client_backend::fees::standard::SingleOutputChangeStrategy;
 use zcash_client_backend::fees::{DustOutputPolicy, StandardFeeRule};
@@ -23,7 +31,7 @@ use zcash_client_backend::proto::service::{
     self, ChainSpec, RawTransaction, TxFilter, compact_tx_streamer_client::CompactTxStreamerClient,
 };
 use zcash_client_backend::sync::run as sync_run;
-use zcash_client_backend::wallet::OvkPolicy;
+use zcash_client_backend::wallet::{Note, OvkPolicy};
 use zcash_client_memory::MemBlockCache;
 use zcash_client_sqlite::WalletDb;
 use zcash_client_sqlite::error::SqliteClientError;
@@ -31,18 +39,40 @@ use zcash_client_sqlite::util::SystemClock;
 use zcash_client_sqlite::wallet::init::init_wallet_db;
 use zcash_keys::keys::UnifiedFullViewingKey;
 use zcash_primitives::transaction::Transaction;
-use zcash_proofs::prover::LocalTxProver;
 use zcash_protocol::ShieldedProtocol;
 use zcash_protocol::consensus::{BlockHeight, BranchId, TEST_NETWORK};
 use zcash_protocol::memo::MemoBytes;
 use zcash_protocol::value::Zatoshis;
 use zip32::AccountId;
+use zip321::{Payment, TransactionRequest};
 
 use crate::config::ZcashConfig;
-use crate::memo::{create_timestamp_memo, parse_timestamp_memo};
+use crate::memo::{
+    MemoLayout, create_timestamp_memo, memo_text, parse_timestamp_memo, timestamp_memo_layout,
+};
+use crate::prover::{Prover, catch_proving_failure};
 
+/// Largest number of blocks downloaded per sync batch
 const SYNC_BATCH_SIZE: u32 = 1000;
 
+/// Smallest sync batch, used when blocks are large relative to the memory budget
+const MIN_SYNC_BATCH_SIZE: u32 = 10;
+
+/// Number of blocks at the chain tip sampled to estimate compact block size
+const SYNC_SAMPLE_BLOCKS: u64 = 10;
+
+/// Cached blocks are held both encoded and decoded while scanning
+const BLOCK_MEMORY_OVERHEAD: u64 = 3;
+
+/// How often sync progress is sampled from the wallet database
+const SYNC_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Blocks below the scanned tip that each memo sync queries again, in case of a reorg
+const MEMO_REORG_MARGIN: u64 = 10;
+
+/// Amount sent to self to carry the timestamp memo (0.0001 ZEC)
+const TIMESTAMP_DUST_AMOUNT: u64 = 10000;
+
 /// Result of creating a timestamp transaction
 pub struct TimestampTxResult {
     /// Transaction ID as string
@@ -51,6 +81,61 @@ pub struct TimestampTxResult {
     pub txid_bytes: [u8; 32],
 }
 
+/// Input selected by a transaction proposal
+#[derive(Debug, Clone)]
+pub struct ProposedInput {
+    /// Pool the input is spent from ("orchard", "sapling" or "transparent")
+    pub pool: &'static str,
+    /// Input value in zatoshis
+    pub value: u64,
+}
+
+/// Result of a dry-run timestamp transaction
+///
+/// Describes the transaction that `create_timestamp_tx` would build, without
+/// proving, signing or broadcasting anything.
+#[derive(Debug, Clone)]
+pub struct DryRunResult {
+    /// Fee the transaction would pay in zatoshis
+    pub fee: u64,
+    /// Memo outputs in the transaction (identical hashes share one)
+    pub outputs: usize,
+    /// Amount sent to self to carry the memos, in zatoshis
+    pub amount: u64,
+    /// Change returned to the wallet in zatoshis
+    pub change: u64,
+    /// Inputs selected to fund the transaction
+    pub inputs: Vec<ProposedInput>,
+    /// Address receiving the self-send
+    pub recipient: String,
+    /// Memo payload that would be attached (512 bytes; the first output's for a batch)
+    pub memo: Vec<u8>,
+    /// Byte layout of the memo payload
+    pub memo_layout: MemoLayout,
+}
+
+/// Transparent funds that `shield_transparent_funds` would move to Orchard
+///
+/// Holds the proposal it describes, so [`ZotsWallet::shield_previewed`]
+/// broadcasts exactly the transaction that was shown.
+#[derive(Debug, Clone)]
+pub struct ShieldPreview {
+    /// Total transparent value being shielded in zatoshis
+    pub amount: u64,
+    /// Fee the shielding transaction would pay in zatoshis
+    pub fee: u64,
+    proposal: ZotsShieldingProposal,
+}
+
+/// A send that `send_to_address` would make
+#[derive(Debug, Clone, Copy)]
+pub struct SendPreview {
+    /// Amount sent to the recipient in zatoshis
+    pub amount: u64,
+    /// Fee the transaction would pay in zatoshis
+    pub fee: u64,
+}
+
 /// Result of sending a transaction
 pub struct SendResult {
     /// Transaction ID as string
@@ -67,8 +152,188 @@ pub struct ConfirmationResult {
     pub block_time: u32,
 }
 
+/// Policy controlling how [`ZotsWallet::wait_confirmation_with`] polls for a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmationWaitPolicy {
+    /// Delay between sync/poll rounds
+    pub poll_interval: Duration,
+    /// Give up once the chain has advanced this many blocks without reaching the target
+    pub max_blocks: u32,
+    /// Give up after this long regardless, in case the chain tip stalls
+    pub max_wait: Duration,
+    /// Number of confirmations required (1 = mined in a block)
+    pub confirmations: u32,
+}
+
+impl Default for ConfirmationWaitPolicy {
+    fn default() -> Self {
+        Self {
+            // Zcash block time is ~75 seconds
+            poll_interval: Duration::from_secs(30),
+            max_blocks: 10,
+            // Well beyond the ~12 minutes ten blocks normally take
+            max_wait: Duration::from_secs(30 * 60),
+            confirmations: 1,
+        }
+    }
+}
+
+/// Waits for transactions to confirm using only lightwalletd
+///
+/// Obtained from [`ZotsWallet::confirmation_watcher`]. Unlike
+/// [`ZotsWallet::wait_confirmation_with`] it reads the mined height from
+/// lightwalletd instead of syncing the wallet, so any number of watchers can
+/// run alongside the wallet.
+#[derive(Debug, Clone)]
+pub struct ConfirmationWatcher {
+    client: CompactTxStreamerClient<Channel>,
+}
+
+impl ConfirmationWatcher {
+    /// Look up whether a transaction has been mined, without waiting
+    ///
+    /// Returns `None` while the transaction is in the mempool or only mined
+    /// on a fork.
+    pub async fn fetch(&mut self, txid: &str) -> anyhow::Result<Option<ConfirmationResult>> {
+        let tx_id = parse_txid(txid)?;
+        let tx_filter = TxFilter {
+            block: None,
+            index: 0,
+            hash: tx_id.as_ref().to_vec(),
+        };
+        let raw_tx = self
+            .client
+            .get_transaction(tx_filter)
+            .await
+            .with_context(|| format!("Failed to fetch transaction {txid}"))?
+            .into_inner();
+
+        // 0 = mempool, u64::MAX = mined on a non-main-chain fork
+        if raw_tx.height == 0 || raw_tx.height == u64::MAX {
+            return Ok(None);
+        }
+
+        Ok(Some(ConfirmationResult {
+            block_height: raw_tx.height as u32,
+            block_time: self.block_time(raw_tx.height).await?,
+        }))
+    }
+
+    /// Wait for `txid` to reach the confirmations required by `policy`
+    ///
+    /// Polls every `poll_interval` until the chain has advanced `max_blocks`
+    /// or `max_wait` has passed. A transaction lightwalletd does not know yet
+    /// (still propagating) counts as unconfirmed.
+    pub async fn wait(
+        &mut self,
+        txid: &str,
+        policy: &ConfirmationWaitPolicy,
+    ) -> anyhow::Result<ConfirmationResult> {
+        let target = u64::from(policy.confirmations.max(1));
+        let deadline = tokio::time::Instant::now() + policy.max_wait;
+        let start_height = self.tip_height().await?;
+
+        loop {
+            let current_height = self.tip_height().await?;
+            let confirmation = match self.fetch(txid).await {
+                Ok(confirmation) => confirmation,
+                Err(e) if is_network_error(&e) => return Err(e),
+                Err(e) => {
+                    debug!("Transaction {txid} not found yet: {e:#}");
+                    None
+                }
+            };
+            if let Some(confirmation) = confirmation
+                && current_height.saturating_sub(u64::from(confirmation.block_height)) + 1 >= target
+            {
+                return Ok(confirmation);
+            }
+
+            if current_height.saturating_sub(start_height) >= u64::from(policy.max_blocks) {
+                return Err(anyhow::anyhow!(
+                    "Transaction {txid} not confirmed within {} blocks",
+                    policy.max_blocks
+                ));
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(anyhow::anyhow!(
+                    "Transaction {txid} not confirmed within {}s",
+                    policy.max_wait.as_secs()
+                ));
+            }
+
+            tokio::time::sleep(policy.poll_interval.min(deadline - now)).await;
+        }
+    }
+
+    async fn tip_height(&mut self) -> anyhow::Result<u64> {
+        let response = self
+            .client
+            .get_latest_block(ChainSpec::default())
+            .await?
+            .into_inner();
+        Ok(response.height)
+    }
+
+    /// Time of the block at `height`, from its compact block
+    async fn block_time(&mut self, height: u64) -> anyhow::Result<u32> {
+        let block = self
+            .client
+            .get_block(service::BlockId {
+                height,
+                ..Default::default()
+            })
+            .await
+            .with_context(|| format!("Failed to fetch block {height}"))?
+            .into_inner();
+        Ok(block.time)
+    }
+}
+
+/// Progress event emitted on every poll while waiting for confirmation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmationProgress {
+    /// Chain tip when waiting started
+    pub start_height: u64,
+    /// Chain tip observed by this poll
+    pub current_height: u64,
+    /// Height the transaction was mined at, once the wallet has seen it
+    pub mined_height: Option<u64>,
+    /// Confirmations observed so far
+    pub confirmations: u32,
+    /// Confirmations required by the policy
+    pub target: u32,
+}
+
+/// Progress event emitted periodically while the wallet syncs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// Highest block scanned when the sync started
+    pub start_height: u64,
+    /// Highest block scanned so far
+    pub scanned_height: u64,
+    /// Chain tip the sync is catching up to
+    pub tip_height: u64,
+}
+
+impl SyncProgress {
+    /// Blocks scanned since the sync started
+    pub fn scanned_blocks(&self) -> u64 {
+        self.scanned_height.saturating_sub(self.start_height)
+    }
+
+    /// Blocks this sync has to scan in total
+    pub fn total_blocks(&self) -> u64 {
+        self.tip_height.saturating_sub(self.start_height)
+    }
+}
+
 /// Balance breakdown by shielded pool
-#[derive(Default)]
+///
+/// The pool fields hold spendable value; the `_pending` fields hold value
+/// (including change) that is not spendable yet for lack of confirmations.
+#[derive(Debug, Clone, Default)]
 pub struct BalanceBreakdown {
     /// Transparent pool balance in zatoshis
     pub transparent: u64,
@@ -76,6 +341,24 @@ pub struct BalanceBreakdown {
     pub sapling: u64,
     /// Orchard pool balance in zatoshis
     pub orchard: u64,
+    /// Transparent value awaiting confirmations, in zatoshis
+    pub transparent_pending: u64,
+    /// Sapling value awaiting confirmations, in zatoshis
+    pub sapling_pending: u64,
+    /// Orchard value awaiting confirmations, in zatoshis
+    pub orchard_pending: u64,
+}
+
+impl BalanceBreakdown {
+    /// Spendable value across all pools
+    pub fn spendable(&self) -> u64 {
+        self.transparent + self.sapling + self.orchard
+    }
+
+    /// Value awaiting confirmations across all pools
+    pub fn pending(&self) -> u64 {
+        self.transparent_pending + self.sapling_pending + self.orchard_pending
+    }
 }
 
 /// Result of verifying a timestamp transaction
@@ -103,30 +386,102 @@ pub struct TransactionRecord {
     pub memo: Option<String>,
 }
 
+/// Wallet transaction with the memos the wallet could decrypt
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    /// Transaction ID as hex string
+    pub txid: String,
+    /// Height the transaction was mined at (`None` while pending)
+    pub height: Option<u32>,
+    /// Block timestamp (Unix timestamp) once mined
+    pub block_time: Option<u32>,
+    /// Change in wallet balance in zatoshis (negative for sent)
+    pub amount: i64,
+    /// Whether the wallet sent outputs to other parties
+    pub is_sent: bool,
+    /// Non-empty memos attached to the transaction's outputs
+    pub memos: Vec<Vec<u8>>,
+}
+
+impl HistoryEntry {
+    /// Hashes carried by zots timestamp memos, in output order
+    pub fn timestamp_hashes(&self) -> Vec<[u8; 32]> {
+        self.memos
+            .iter()
+            .filter_map(|memo| parse_timestamp_memo(memo))
+            .collect()
+    }
+
+    /// Whether this is a zots timestamp transaction
+    pub fn is_timestamp(&self) -> bool {
+        !self.timestamp_hashes().is_empty()
+    }
+}
+
+/// Memo received from another party, detected during sync
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncomingMemo {
+    /// Transaction ID as hex string
+    pub txid: String,
+    /// Pool the output was received in ("sapling" or "orchard")
+    pub pool: &'static str,
+    /// Output (or Orchard action) index within the transaction
+    pub output_index: u32,
+    /// Wallet address the output was sent to, if known
+    pub address: Option<String>,
+    /// Output value in zatoshis
+    pub value: u64,
+    /// Raw memo bytes
+    pub memo: Vec<u8>,
+    /// Height the transaction was mined at
+    pub height: u64,
+}
+
+impl IncomingMemo {
+    /// Hash carried by the memo, if it uses the zots timestamp format
+    pub fn timestamp_hash(&self) -> Option<[u8; 32]> {
+        parse_timestamp_memo(&self.memo)
+    }
+
+    /// Memo as UTF-8 text (ZIP-302 text memo), without trailing padding
+    pub fn text(&self) -> Option<String> {
+        memo_text(&self.memo)
+    }
+}
+
 type ZotsWalletDb =
     WalletDb<rusqlite::Connection, zcash_protocol::consensus::TestNetwork, SystemClock, OsRng>;
 
+type ZotsProposal =
+    zcash_client_backend::proposal::Proposal<StandardFeeRule, zcash_client_sqlite::ReceivedNoteId>;
+
+/// Shielding proposals spend only transparent UTXOs, so they carry no note references
+type ZotsShieldingProposal = zcash_client_backend::proposal::Proposal<StandardFeeRule, Infallible>;
+
+/// Total ZIP-317 fee over all steps of a proposal
+fn proposal_fee<N>(proposal: &zcash_client_backend::proposal::Proposal<StandardFeeRule, N>) -> u64 {
+    proposal
+        .steps()
+        .iter()
+        .map(|step| u64::from(step.balance().fee_required()))
+        .sum()
+}
+
 /// Helper to build and sign transaction with proper type annotations
-fn build_and_sign_transaction(
+fn build_and_sign_transaction<P: SpendProver + OutputProver, N: std::fmt::Debug>(
     db: &mut ZotsWalletDb,
     params: &zcash_protocol::consensus::TestNetwork,
-    prover: &LocalTxProver,
+    prover: &P,
     spending_keys: &SpendingKeys,
-    proposal: &zcash_client_backend::proposal::Proposal<
-        StandardFeeRule,
-        zcash_client_sqlite::ReceivedNoteId,
-    >,
+    proposal: &zcash_client_backend::proposal::Proposal<StandardFeeRule, N>,
 ) -> anyhow::Result<::nonempty::NonEmpty<zcash_protocol::TxId>> {
     create_proposed_transactions::<
         ZotsWalletDb,
         zcash_protocol::consensus::TestNetwork,
         zcash_client_backend::data_api::wallet::input_selection::GreedyInputSelectorError,
         StandardFeeRule,
-        zcash_client_backend::fees::ChangeError<
-            SqliteClientError,
-            zcash_client_sqlite::ReceivedNoteId,
-        >,
-        zcash_client_sqlite::ReceivedNoteId,
+        zcash_client_backend::fees::ChangeError<SqliteClientError, N>,
+        N,
     >(
         db,
         params,
@@ -139,12 +494,287 @@ fn build_and_sign_transaction(
     .map_err(|e| anyhow::anyhow!("Failed to create transaction: {e:?}"))
 }
 
+/// Run blocking work (proving, waiting on the remote prover) from async code
+///
+/// On a multi-threaded runtime the worker hands its other tasks to the rest
+/// of the pool first. A current-thread runtime has nowhere to move them, so
+/// the work runs inline there.
+fn run_blocking<T>(work: impl FnOnce() -> T) -> T {
+    use tokio::runtime::{Handle, RuntimeFlavor};
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(work)
+        }
+        _ => work(),
+    }
+}
+
+/// Number of blocks per sync batch that fits in `budget_bytes`
+fn batch_size_for_budget(budget_bytes: u64, avg_block_bytes: u64) -> u32 {
+    let per_block = avg_block_bytes.max(1).saturating_mul(BLOCK_MEMORY_OVERHEAD);
+    (budget_bytes / per_block).clamp(MIN_SYNC_BATCH_SIZE as u64, SYNC_BATCH_SIZE as u64) as u32
+}
+
+/// Error returned when a watch-only wallet is asked to sign a transaction
+fn watch_only_error() -> anyhow::Error {
+    anyhow::anyhow!(
+        "This wallet is watch-only (configured with a viewing key, no ZOTS_SEED). \
+         It can verify proofs and show balances, but creating timestamps or sending \
+         funds requires the seed phrase."
+    )
+}
+
+/// Restrict a full viewing key to its Orchard component
+///
+/// Accounts imported with this key have no Sapling receivers, so sync skips
+/// Sapling trial decryption entirely.
+fn orchard_only_ufvk(ufvk: &UnifiedFullViewingKey) -> anyhow::Result<UnifiedFullViewingKey> {
+    let orchard = ufvk
+        .orchard()
+        .ok_or_else(|| anyhow::anyhow!("Viewing key has no Orchard component"))?;
+    let items = vec![unified::Fvk::Orchard(orchard.to_bytes())];
+    let encoded = unified::Ufvk::try_from_items(items)
+        .map_err(|e| anyhow::anyhow!("Failed to build Orchard-only viewing key: {e}"))?;
+    UnifiedFullViewingKey::parse(&encoded)
+        .map_err(|e| anyhow::anyhow!("Failed to build Orchard-only viewing key: {e}"))
+}
+
+/// Server details reported by lightwalletd
+#[derive(Debug, Clone)]
+pub struct LightwalletdInfo {
+    /// Chain name ("main", "test" or "regtest")
+    pub chain_name: String,
+    /// Current chain tip height
+    pub block_height: u64,
+    /// Server vendor and version
+    pub version: String,
+}
+
+impl LightwalletdInfo {
+    /// Network the server follows, if it is mainnet or testnet
+    pub fn network(&self) -> Option<zots_core::Network> {
+        match self.chain_name.as_str() {
+            "main" => Some(zots_core::Network::Mainnet),
+            "test" => Some(zots_core::Network::Testnet),
+            _ => None,
+        }
+    }
+}
+
+/// Check that a lightwalletd server is reachable and report what it serves
+///
+/// Needs no wallet, so setup flows can test a server before writing config.
+pub async fn probe_lightwalletd(url: &str) -> anyhow::Result<LightwalletdInfo> {
+    let channel = connect_lightwalletd(url).await?;
+    let info = CompactTxStreamerClient::new(channel)
+        .get_lightd_info(service::Empty {})
+        .await?
+        .into_inner();
+    Ok(LightwalletdInfo {
+        chain_name: info.chain_name,
+        block_height: info.block_height,
+        version: format!("{} {}", info.vendor, info.version)
+            .trim()
+            .to_string(),
+    })
+}
+
+/// Run SQLite's integrity check on a wallet database
+///
+/// The file is opened read-only, so a corrupt database is never modified.
+pub fn check_wallet_db(path: &std::path::Path) -> anyhow::Result<()> {
+    let conn =
+        rusqlite::Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let result: String = conn.query_row("PRAGMA integrity_check", [], |row| row.get(0))?;
+    if result != "ok" {
+        return Err(anyhow::anyhow!("Integrity check failed: {result}"));
+    }
+    Ok(())
+}
+
+/// Open a channel to a lightwalletd server (TLS for `https://` URLs)
+///
+/// Plain `http://` is meant for a local node; it is still allowed for
+/// other hosts, but with a warning since the traffic is unencrypted.
+async fn connect_lightwalletd(url: &str) -> anyhow::Result<Channel> {
+    let mut endpoint = tonic::transport::Endpoint::from_shared(url.to_string())?;
+    if url.starts_with("https://") {
+        endpoint = endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?;
+    } else if !endpoint.uri().host().is_some_and(is_loopback_host) {
+        warn!(
+            "Connecting to lightwalletd at {} without TLS; use an https:// URL unless the network is trusted",
+            url
+        );
+    }
+    let channel = endpoint.connect().await?;
+    Ok(channel)
+}
+
+/// Whether a URI host is this machine (`localhost` or a loopback address)
+fn is_loopback_host(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Fee, change and selected inputs of a timestamp proposal
+fn summarize_proposal(
+    proposal: &ZotsProposal,
+    outputs: usize,
+    recipient: String,
+    memo: Vec<u8>,
+) -> DryRunResult {
+    let mut fee = 0u64;
+    let mut change = 0u64;
+    let mut inputs = Vec::new();
+    for step in proposal.steps().iter() {
+        let balance = step.balance();
+        fee += u64::from(balance.fee_required());
+        change += balance
+            .proposed_change()
+            .iter()
+            .map(|c| u64::from(c.value()))
+            .sum::<u64>();
+
+        for input in step.transparent_inputs() {
+            inputs.push(ProposedInput {
+                pool: "transparent",
+                value: u64::from(input.value()),
+            });
+        }
+        if let Some(shielded) = step.shielded_inputs() {
+            for note in shielded.notes().iter() {
+                let pool = match note.note() {
+                    Note::Sapling(_) => "sapling",
+                    Note::Orchard(_) => "orchard",
+                };
+                inputs.push(ProposedInput {
+                    pool,
+                    value: u64::from(note.note().value()),
+                });
+            }
+        }
+    }
+    debug!(
+        fee,
+        change,
+        inputs = inputs.len(),
+        "Dry-run proposal summary"
+    );
+
+    DryRunResult {
+        fee,
+        outputs,
+        amount: TIMESTAMP_DUST_AMOUNT * outputs as u64,
+        change,
+        inputs,
+        recipient,
+        memo,
+        memo_layout: timestamp_memo_layout(),
+    }
+}
+
+/// Highest block scanned into a wallet database, if any
+fn max_scanned_height(db: &ZotsWalletDb) -> Option<u64> {
+    db.block_max_scanned()
+        .ok()
+        .flatten()
+        .map(|meta| u64::from(u32::from(meta.block_height())))
+}
+
+/// Whether an error comes from reaching lightwalletd
+///
+/// Lets front-ends tell connectivity problems apart from invalid proofs or
+/// wallet errors.
+pub fn is_network_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause.is::<tonic::transport::Error>()
+            || cause.downcast_ref::<tonic::Status>().is_some_and(|status| {
+                matches!(
+                    status.code(),
+                    tonic::Code::Unavailable | tonic::Code::DeadlineExceeded
+                )
+            })
+    })
+}
+
+/// Format raw transaction ID bytes from the wallet DB as display (byte-reversed) hex
+fn txid_to_display(txid_bytes: &[u8]) -> String {
+    let mut txid_arr = [0u8; 32];
+    if txid_bytes.len() == 32 {
+        txid_arr.copy_from_slice(txid_bytes);
+        txid_arr.reverse(); // Reverse for display format
+    }
+    // Manual hex encoding
+    txid_arr.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Parse a display-format (byte-reversed hex) transaction ID
+fn parse_txid(txid: &str) -> anyhow::Result<zcash_protocol::TxId> {
+    if txid.len() != 64 || !txid.is_ascii() {
+        return Err(anyhow::anyhow!("Invalid txid: {txid}"));
+    }
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&txid[i * 2..i * 2 + 2], 16)
+            .map_err(|_| anyhow::anyhow!("Invalid txid: {txid}"))?;
+    }
+    bytes.reverse(); // Display format is byte-reversed
+    Ok(zcash_protocol::TxId::from_bytes(bytes))
+}
+
 /// Zcash wallet for timestamping operations
 pub struct ZotsWallet {
     config: ZcashConfig,
     db: ZotsWalletDb,
     client: CompactTxStreamerClient<Channel>,
-    seed: [u8; 64],
+    /// BIP-39 seed; `None` for a watch-only wallet backed by an imported viewing key
+    seed: Option<[u8; 64]>,
+    /// Progress of `sync_incoming_memos` through the chain
+    memos: MemoTracker,
+}
+
+/// Incoming memos reported by `sync_incoming_memos` so far
+#[derive(Debug, Default)]
+struct MemoTracker {
+    /// Lowest height queried by the next sync; `None` before the first call
+    cursor: Option<u64>,
+    /// Memos reported at or above the cursor, keyed by (txid, pool, output index)
+    seen: HashMap<(String, &'static str, u32), u64>,
+}
+
+impl MemoTracker {
+    /// Height to query from, just above `scanned_tip` on the first call
+    fn cursor(&mut self, scanned_tip: Option<u64>) -> u64 {
+        *self
+            .cursor
+            .get_or_insert_with(|| scanned_tip.map_or(0, |h| h + 1))
+    }
+
+    /// Drop memos already reported, then move the cursor up to `scanned_tip`
+    /// minus the reorg margin and forget memos below it
+    fn advance(&mut self, memos: Vec<IncomingMemo>, scanned_tip: Option<u64>) -> Vec<IncomingMemo> {
+        let fresh = memos
+            .into_iter()
+            .filter(|m| {
+                self.seen
+                    .insert((m.txid.clone(), m.pool, m.output_index), m.height)
+                    .is_none()
+            })
+            .collect();
+        if let Some(tip) = scanned_tip {
+            let cursor = self
+                .cursor
+                .unwrap_or(0)
+                .max(tip.saturating_sub(MEMO_REORG_MARGIN));
+            self.cursor = Some(cursor);
+            self.seen.retain(|_, height| *height >= cursor);
+        }
+        fresh
+    }
 }
 
 impl ZotsWallet {
@@ -152,32 +782,49 @@ impl ZotsWallet {
     ///
     /// Initializes the wallet database and connects to lightwalletd.
     pub async fn new(config: ZcashConfig) -> anyhow::Result<Self> {
+        // Keys, addresses and transactions are all built with testnet consensus parameters
+        if config.network != zots_core::Network::Testnet {
+            return Err(anyhow::anyhow!(
+                "{} is not supported yet; the wallet only speaks testnet",
+                config.network
+            ));
+        }
+
         // Create data directory
         config.ensure_data_dir()?;
 
-        // Parse seed phrase
-        let mnemonic = Mnemonic::<English>::from_phrase(&config.seed_phrase)
-            .map_err(|e| anyhow::anyhow!("Invalid seed phrase: {e:?}"))?;
-        let seed = mnemonic.to_seed("");
+        // Parse seed phrase (absent for watch-only wallets)
+        let seed = match &config.seed_phrase {
+            Some(phrase) => {
+                let mnemonic = Mnemonic::<English>::from_phrase(phrase)
+                    .map_err(|e| anyhow::anyhow!("Invalid seed phrase: {e:?}"))?;
+                Some(mnemonic.to_seed(""))
+            }
+            None => None,
+        };
 
         // Initialize wallet database
         let db_path = config.wallet_db_path();
         let mut db = WalletDb::for_path(&db_path, TEST_NETWORK, SystemClock, OsRng)?;
         init_wallet_db(&mut db, None)?;
 
-        // Connect to lightwalletd with TLS
-        let tls_config = ClientTlsConfig::new().with_native_roots();
-        let channel = tonic::transport::Endpoint::from_shared(config.lightwalletd_url.clone())?
-            .tls_config(tls_config)?
-            .connect()
-            .await?;
-        let client = CompactTxStreamerClient::new(channel);
+        // Connect to lightwalletd, trying fallback servers in order
+        let mut channel = Err(anyhow::anyhow!("No lightwalletd server configured"));
+        for url in std::iter::once(&config.lightwalletd_url).chain(&config.lightwalletd_fallbacks) {
+            channel = connect_lightwalletd(url).await;
+            match &channel {
+                Ok(_) => break,
+                Err(e) => warn!("Failed to connect to lightwalletd {}: {}", url, e),
+            }
+        }
+        let client = CompactTxStreamerClient::new(channel?);
 
         Ok(Self {
             config,
             db,
             client,
             seed,
+            memos: MemoTracker::default(),
         })
     }
 
@@ -186,27 +833,19 @@ impl ZotsWallet {
     /// Creates the account from seed and imports it into the wallet database.
     pub async fn init_account(&mut self) -> anyhow::Result<()> {
         // Check if account already exists
-        let accounts = self.db.get_account_ids()?;
-        if !accounts.is_empty() {
+        if let Some(ufvk) = self.stored_ufvk()? {
+            self.warn_orchard_only_mismatch(&ufvk);
             return Ok(());
         }
+        if self.seed.is_none() {
+            return Err(anyhow::anyhow!(
+                "No wallet account found. Set ZOTS_SEED, or import a viewing key with \
+                 `zots wallet import --ufvk <key>` for a watch-only wallet."
+            ));
+        }
 
-        // Create unified spending key from seed
-        let account_id = AccountId::ZERO;
-        let usk = UnifiedSpendingKey::from_seed(&TEST_NETWORK, &self.seed, account_id)
-            .map_err(|e| anyhow::anyhow!("Failed to derive spending key: {e:?}"))?;
-        let ufvk = usk.to_unified_full_viewing_key();
-
-        // Get birthday tree state from lightwalletd
-        let birthday_height = self.config.birthday_height;
-        let request = service::BlockId {
-            height: birthday_height.saturating_sub(1),
-            ..Default::default()
-        };
-        let treestate = self.client.get_tree_state(request).await?.into_inner();
-
-        let birthday = AccountBirthday::from_treestate(treestate, None)
-            .map_err(|_| anyhow::anyhow!("Failed to create birthday from tree state"))?;
+        let ufvk = self.account_ufvk()?;
+        let birthday = self.account_birthday().await?;
 
         // Import account into wallet
         self.db.import_account_ufvk(
@@ -220,6 +859,143 @@ impl ZotsWallet {
         Ok(())
     }
 
+    /// Import a unified full viewing key as a watch-only account
+    ///
+    /// The wallet can then sync, show balances and verify timestamps without
+    /// a seed, but refuses to create transactions. Only valid for an empty
+    /// wallet database with no `ZOTS_SEED` configured.
+    pub async fn import_viewing_key(&mut self, ufvk: &str) -> anyhow::Result<()> {
+        if self.seed.is_some() {
+            return Err(anyhow::anyhow!(
+                "ZOTS_SEED is set; unset it to use a watch-only wallet"
+            ));
+        }
+        if !self.db.get_account_ids()?.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Wallet database already has an account; use an empty ZOTS_DATA_DIR"
+            ));
+        }
+
+        let ufvk = UnifiedFullViewingKey::decode(&TEST_NETWORK, ufvk.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid viewing key: {e}"))?;
+        let birthday = self.account_birthday().await?;
+
+        self.db.import_account_ufvk(
+            "zots-watch-only",
+            &ufvk,
+            &birthday,
+            AccountPurpose::ViewOnly,
+            None,
+        )?;
+        info!("Imported watch-only account");
+
+        Ok(())
+    }
+
+    /// Whether the wallet only has a viewing key and cannot spend
+    pub fn is_watch_only(&self) -> bool {
+        self.seed.is_none()
+    }
+
+    /// Fail early with a helpful message if the wallet cannot sign transactions
+    pub fn ensure_can_spend(&self) -> anyhow::Result<()> {
+        if self.seed.is_none() {
+            return Err(watch_only_error());
+        }
+        Ok(())
+    }
+
+    /// Birthday of a new account, from the tree state at the configured height
+    async fn account_birthday(&mut self) -> anyhow::Result<AccountBirthday> {
+        let request = service::BlockId {
+            height: self.config.birthday_height.saturating_sub(1),
+            ..Default::default()
+        };
+        let treestate = self.client.get_tree_state(request).await?.into_inner();
+
+        AccountBirthday::from_treestate(treestate, None)
+            .map_err(|_| anyhow::anyhow!("Failed to create birthday from tree state"))
+    }
+
+    /// Spending key derived from the seed
+    fn spending_key(&self) -> anyhow::Result<UnifiedSpendingKey> {
+        let seed = self.seed.as_ref().ok_or_else(watch_only_error)?;
+        UnifiedSpendingKey::from_seed(&TEST_NETWORK, seed, AccountId::ZERO)
+            .map_err(|e| anyhow::anyhow!("Failed to derive spending key: {e:?}"))
+    }
+
+    /// Full viewing key of the wallet
+    ///
+    /// Derived from the seed when there is one, otherwise read from the
+    /// imported watch-only account.
+    fn viewing_key(&self) -> anyhow::Result<UnifiedFullViewingKey> {
+        if self.seed.is_some() {
+            return Ok(self.spending_key()?.to_unified_full_viewing_key());
+        }
+        self.stored_ufvk()?
+            .ok_or_else(|| anyhow::anyhow!("No account found - run init_account first"))
+    }
+
+    /// Viewing key of the account in the wallet database, if one exists
+    fn stored_ufvk(&self) -> anyhow::Result<Option<UnifiedFullViewingKey>> {
+        let Some(account_id) = self.db.get_account_ids()?.first().copied() else {
+            return Ok(None);
+        };
+        self.db
+            .get_account(account_id)?
+            .and_then(|account| account.ufvk().cloned())
+            .map(Some)
+            .ok_or_else(|| anyhow::anyhow!("Account has no full viewing key"))
+    }
+
+    /// Warn when `orchard_only` no longer matches the existing account
+    ///
+    /// The account keeps the pools it was created with; the flag only
+    /// applies to new wallets.
+    fn warn_orchard_only_mismatch(&self, ufvk: &UnifiedFullViewingKey) {
+        let orchard_only = ufvk.sapling().is_none();
+        if orchard_only != self.config.orchard_only {
+            warn!(
+                "orchard_only is {} but the wallet account was created {}; \
+                 using the account as it is",
+                self.config.orchard_only,
+                if orchard_only {
+                    "Orchard-only"
+                } else {
+                    "with a Sapling key"
+                }
+            );
+        }
+    }
+
+    /// Viewing key the account is created with (Orchard-only if configured)
+    fn account_ufvk(&self) -> anyhow::Result<UnifiedFullViewingKey> {
+        let ufvk = self.viewing_key()?;
+        if self.config.orchard_only {
+            orchard_only_ufvk(&ufvk)
+        } else {
+            Ok(ufvk)
+        }
+    }
+
+    /// Export the unified full viewing key (UFVK) of the wallet account
+    ///
+    /// This is the key the account was created with, which only differs from
+    /// the configured one if `orchard_only` changed since. The key can see
+    /// every incoming and outgoing transaction and memo but cannot spend, so
+    /// it is suitable for provisioning verification infrastructure without
+    /// handing out the seed.
+    pub fn export_viewing_key(&self) -> anyhow::Result<String> {
+        let ufvk = match self.stored_ufvk()? {
+            Some(ufvk) => {
+                self.warn_orchard_only_mismatch(&ufvk);
+                ufvk
+            }
+            None => self.account_ufvk()?,
+        };
+        Ok(ufvk.encode(&TEST_NETWORK))
+    }
+
     /// Reset and reinitialize wallet with a new birthday height
     ///
     /// This is useful if the birthday height was set too high and transactions were missed.
@@ -236,23 +1012,107 @@ impl ZotsWallet {
     ///
     /// Downloads compact blocks and scans for transactions belonging to this wallet.
     pub async fn sync(&mut self) -> anyhow::Result<()> {
-        // Use in-memory block cache for sync
+        self.sync_with_progress(|_| {}).await
+    }
+
+    /// Sync wallet with the blockchain, reporting [`SyncProgress`] as blocks are scanned
+    ///
+    /// Progress is sampled through a second connection to the wallet
+    /// database, so events arrive every few hundred milliseconds rather than
+    /// per block. A final event at the tip is sent once the sync completes.
+    pub async fn sync_with_progress<F>(&mut self, mut on_progress: F) -> anyhow::Result<()>
+    where
+        F: FnMut(&SyncProgress),
+    {
+        // Use in-memory block cache for sync, sized to the memory budget
         let db_cache = MemBlockCache::new();
+        let batch_size = self.sync_batch_size().await;
+        debug!("Syncing with batch size {}", batch_size);
+
+        let tip_height = self.get_block_height().await?;
+        let start_height = max_scanned_height(&self.db).unwrap_or(tip_height);
+        let monitor = WalletDb::for_path(
+            self.config.wallet_db_path(),
+            TEST_NETWORK,
+            SystemClock,
+            OsRng,
+        )?;
 
         // Run the sync - downloads blocks and scans for our transactions
-        sync_run(
+        let sync = sync_run(
             &mut self.client,
             &TEST_NETWORK,
             &db_cache,
             &mut self.db,
-            SYNC_BATCH_SIZE,
-        )
-        .await
-        .map_err(|e| anyhow::anyhow!("Sync failed: {e:?}"))?;
+            batch_size,
+        );
+        tokio::pin!(sync);
+        let mut ticker = tokio::time::interval(SYNC_PROGRESS_INTERVAL);
+        loop {
+            tokio::select! {
+                result = &mut sync => {
+                    result.map_err(|e| anyhow::anyhow!("Sync failed: {e:?}"))?;
+                    break;
+                }
+                _ = ticker.tick() => {
+                    // The sync may hold a write lock; skip samples that fail
+                    if let Some(scanned_height) = max_scanned_height(&monitor) {
+                        on_progress(&SyncProgress {
+                            start_height,
+                            scanned_height: scanned_height.min(tip_height),
+                            tip_height,
+                        });
+                    }
+                }
+            }
+        }
 
+        on_progress(&SyncProgress {
+            start_height,
+            scanned_height: tip_height,
+            tip_height,
+        });
         Ok(())
     }
 
+    /// Pick a sync batch size that keeps the block cache within the memory budget
+    ///
+    /// Block sizes vary a lot over the chain's history, so the average size
+    /// is re-sampled at the tip before every sync.
+    async fn sync_batch_size(&mut self) -> u32 {
+        let budget = self.config.sync_memory_mb.saturating_mul(1024 * 1024);
+        match self.sample_block_size().await {
+            Ok(avg_block_bytes) => batch_size_for_budget(budget, avg_block_bytes),
+            Err(e) => {
+                warn!("Could not sample block sizes ({e}), using default sync batch");
+                SYNC_BATCH_SIZE
+            }
+        }
+    }
+
+    /// Average encoded size of the most recent compact blocks
+    async fn sample_block_size(&mut self) -> anyhow::Result<u64> {
+        let tip = self.get_block_height().await?;
+        let range = service::BlockRange {
+            start: Some(service::BlockId {
+                height: tip.saturating_sub(SYNC_SAMPLE_BLOCKS - 1),
+                ..Default::default()
+            }),
+            end: Some(service::BlockId {
+                height: tip,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut blocks = self.client.get_block_range(range).await?.into_inner();
+        let (mut total, mut count) = (0u64, 0u64);
+        while let Some(block) = blocks.message().await? {
+            total += block.encoded_len() as u64;
+            count += 1;
+        }
+        Ok(total.checked_div(count).unwrap_or(0))
+    }
+
     /// Get current block height from lightwalletd
     pub async fn get_block_height(&mut self) -> anyhow::Result<u64> {
         let response = self
@@ -285,11 +1145,18 @@ impl ZotsWallet {
         match summary {
             Some(s) => {
                 let mut breakdown = BalanceBreakdown::default();
+                let pending = |pool: &Balance| {
+                    u64::from(pool.change_pending_confirmation())
+                        + u64::from(pool.value_pending_spendability())
+                };
                 for balance in s.account_balances().values() {
                     breakdown.transparent +=
                         u64::from(balance.unshielded_balance().spendable_value());
                     breakdown.sapling += u64::from(balance.sapling_balance().spendable_value());
                     breakdown.orchard += u64::from(balance.orchard_balance().spendable_value());
+                    breakdown.transparent_pending += pending(balance.unshielded_balance());
+                    breakdown.sapling_pending += pending(balance.sapling_balance());
+                    breakdown.orchard_pending += pending(balance.orchard_balance());
                 }
                 Ok(breakdown)
             }
@@ -328,10 +1195,20 @@ impl ZotsWallet {
             .first()
             .ok_or_else(|| anyhow::anyhow!("No account found - run init_account first"))?;
 
-        // Request a new unified address with Orchard and Sapling receivers (no transparent)
+        // Request a new unified address with Orchard and, if the account has
+        // a Sapling key, Sapling receivers (no transparent)
+        let ufvk = self
+            .stored_ufvk()?
+            .ok_or_else(|| anyhow::anyhow!("No account found - run init_account first"))?;
+        self.warn_orchard_only_mismatch(&ufvk);
+        let sapling = if ufvk.sapling().is_some() {
+            ReceiverRequirement::Require
+        } else {
+            ReceiverRequirement::Omit
+        };
         let request = UnifiedAddressRequest::unsafe_custom(
             ReceiverRequirement::Require,
-            ReceiverRequirement::Require,
+            sapling,
             ReceiverRequirement::Omit,
         );
         let (address, _diversifier_index) = self
@@ -357,16 +1234,20 @@ impl ZotsWallet {
             .collect())
     }
 
-    /// Shield transparent funds to Orchard
-    ///
-    /// Moves funds from transparent pool to shielded Orchard pool.
-    #[allow(dead_code)]
-    pub async fn shield_transparent_funds(&mut self) -> anyhow::Result<String> {
+    /// Propose moving every transparent UTXO of the account to Orchard
+    fn propose_shielding_tx(&mut self) -> anyhow::Result<ZotsShieldingProposal> {
         let accounts = self.db.get_account_ids()?;
         let account_id = accounts
             .first()
             .ok_or_else(|| anyhow::anyhow!("No account found"))?;
 
+        let from_addrs: Vec<_> = self
+            .db
+            .get_transparent_receivers(*account_id, true, true)?
+            .into_keys()
+            .collect();
+        debug!("Shielding from {} transparent receivers", from_addrs.len());
+
         // Create change strategy for shielding
         let dust_policy = DustOutputPolicy::default();
         let change_strategy = SingleOutputChangeStrategy::new(
@@ -378,43 +1259,67 @@ impl ZotsWallet {
 
         let input_selector = GreedyInputSelector::<ZotsWalletDb>::new();
 
-        // Propose shielding
-        let _proposal = propose_shielding::<_, _, _, _, SqliteClientError>(
+        propose_shielding::<_, _, _, _, SqliteClientError>(
             &mut self.db,
             &TEST_NETWORK,
             &input_selector,
             &change_strategy,
             Zatoshis::ZERO,
-            &[],
+            &from_addrs,
             *account_id,
             ConfirmationsPolicy::MIN,
         )
-        .map_err(|e| anyhow::anyhow!("Failed to propose shielding: {e:?}"))?;
+        .map_err(|e| anyhow::anyhow!("Failed to propose shielding: {e:?}"))
+    }
 
-        // TODO: Implement full transaction building with zk-SNARK provers
-        Err(anyhow::anyhow!(
-            "Shielding requires zk-SNARK provers (not yet integrated).\n\
-            Please use Zingo or zcashd to shield your funds."
-        ))
+    /// Report the amount and fee of shielding, without building anything
+    ///
+    /// Fails if there are no spendable transparent funds.
+    pub fn preview_shielding(&mut self) -> anyhow::Result<ShieldPreview> {
+        let proposal = self.propose_shielding_tx()?;
+
+        let amount = proposal
+            .steps()
+            .iter()
+            .flat_map(|step| step.transparent_inputs())
+            .map(|input| u64::from(input.value()))
+            .sum::<u64>();
+        if amount == 0 {
+            return Err(anyhow::anyhow!("No transparent funds to shield"));
+        }
+
+        Ok(ShieldPreview {
+            amount,
+            fee: proposal_fee(&proposal),
+            proposal,
+        })
     }
 
-    /// Create and broadcast a timestamp transaction
+    /// Shield transparent funds to Orchard
     ///
-    /// Creates a shielded transaction with the file hash in the memo field,
-    /// then broadcasts it to the Zcash network.
-    pub async fn create_timestamp_tx(
+    /// Moves funds from transparent pool to shielded Orchard pool so they can
+    /// pay for timestamp transactions.
+    pub async fn shield_transparent_funds(&mut self) -> anyhow::Result<SendResult> {
+        let preview = self.preview_shielding()?;
+        self.shield_previewed(&preview).await
+    }
+
+    /// Broadcast the shielding transaction shown by [`Self::preview_shielding`]
+    pub async fn shield_previewed(
         &mut self,
-        hash: &[u8; 32],
-    ) -> anyhow::Result<TimestampTxResult> {
-        let accounts = self.db.get_account_ids()?;
-        let account_id = accounts
-            .first()
-            .ok_or_else(|| anyhow::anyhow!("No account found"))?;
-        info!(
-            "Creating timestamp transaction for account {:?}",
-            account_id
-        );
+        preview: &ShieldPreview,
+    ) -> anyhow::Result<SendResult> {
+        let result = self.build_and_broadcast(&preview.proposal).await?;
+        info!("Shielding transaction broadcast with txid {}", result.txid);
+
+        Ok(SendResult {
+            txid: result.txid,
+            fee: preview.fee,
+        })
+    }
 
+    /// Check that the wallet has enough shielded funds to send a timestamp memo
+    fn ensure_timestamp_funds(&self) -> anyhow::Result<()> {
         // Check balance - iterate over all account balances (consistent with get_balance_breakdown)
         let summary = self.db.get_wallet_summary(ConfirmationsPolicy::MIN)?;
         let (transparent_balance, orchard_balance, sapling_balance) = match &summary {
@@ -432,84 +1337,244 @@ impl ZotsWallet {
             None => (0, 0, 0),
         };
 
-        let total_shielded = orchard_balance + sapling_balance;
-        let min_required = 20000u64; // ZIP-317 minimum fee
-        debug!(
-            transparent_balance,
-            orchard_balance, sapling_balance, total_shielded, "Wallet balance snapshot (zatoshis)"
+        let total_shielded = orchard_balance + sapling_balance;
+        let min_required = 20000u64; // ZIP-317 minimum fee
+        debug!(
+            transparent_balance,
+            orchard_balance, sapling_balance, total_shielded, "Wallet balance snapshot (zatoshis)"
+        );
+
+        // Need shielded funds to send memo
+        if total_shielded < min_required {
+            warn!("Insufficient shielded funds for timestamp transaction");
+            if transparent_balance >= min_required {
+                return Err(anyhow::anyhow!(
+                    "Your funds are in the transparent pool.\n\
+                    Run 'zots wallet shield' to move them to Orchard, then try again."
+                ));
+            }
+            return Err(anyhow::anyhow!(
+                "Insufficient funds. Run 'zots wallet address' and fund your wallet."
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check balances and propose a self-send carrying the timestamp memo
+    ///
+    /// Shared by [`Self::create_timestamp_tx`] and
+    /// [`Self::create_timestamp_tx_dry_run`]; nothing is written to the
+    /// wallet or the network.
+    fn propose_timestamp(
+        &mut self,
+        hash: &[u8; 32],
+    ) -> anyhow::Result<(ZotsProposal, zcash_keys::address::Address, Vec<u8>)> {
+        let accounts = self.db.get_account_ids()?;
+        let account_id = accounts
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("No account found"))?;
+        debug!("Proposing timestamp self-send for account {:?}", account_id);
+
+        self.ensure_timestamp_funds()?;
+
+        // Get the wallet's own address to send to self
+        let addresses = self.db.list_addresses(*account_id)?;
+        let address = addresses
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("No address found"))?
+            .address()
+            .clone();
+        debug!("Using internal address {:?} for self-send", address);
+
+        // Create memo with timestamp data
+        debug!("Creating timestamp memo payload");
+        let memo_data = create_timestamp_memo(hash);
+        let memo = MemoBytes::from_bytes(&memo_data)
+            .map_err(|_| anyhow::anyhow!("Failed to create memo"))?;
+
+        // Create proposal for self-send with memo
+        // Send dust amount (just to carry the memo)
+        let dust_amount = Zatoshis::from_u64(TIMESTAMP_DUST_AMOUNT).unwrap();
+
+        let proposal = propose_standard_transfer_to_address::<_, _, SqliteClientError>(
+            &mut self.db,
+            &TEST_NETWORK,
+            StandardFeeRule::Zip317,
+            *account_id,
+            ConfirmationsPolicy::MIN,
+            &address,
+            dust_amount,
+            Some(memo),
+            None, // no change memo
+            ShieldedProtocol::Orchard,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to create transaction proposal: {e:?}"))?;
+        debug!("Proposal created for self-send with memo");
+
+        Ok((proposal, address, memo_data))
+    }
+
+    /// Dry-run a timestamp transaction
+    ///
+    /// Runs the same balance checks and proposal step as
+    /// [`Self::create_timestamp_tx`] and reports the fee, selected inputs and
+    /// memo layout. No proof is generated and nothing is broadcast.
+    pub fn create_timestamp_tx_dry_run(&mut self, hash: &[u8; 32]) -> anyhow::Result<DryRunResult> {
+        let (proposal, address, memo) = self.propose_timestamp(hash)?;
+        let recipient = address.to_zcash_address(&TEST_NETWORK).to_string();
+        Ok(summarize_proposal(&proposal, 1, recipient, memo))
+    }
+
+    /// Dry-run a batch timestamp transaction
+    ///
+    /// Like [`Self::create_timestamp_tx_dry_run`] for the transaction
+    /// [`Self::create_batch_timestamp_tx`] would build; `memo` holds the
+    /// payload of the first output.
+    pub fn create_batch_timestamp_tx_dry_run(
+        &mut self,
+        hashes: &[[u8; 32]],
+    ) -> anyhow::Result<DryRunResult> {
+        let (proposal, recipient) = self.propose_batch_timestamp(hashes)?;
+        let memo = create_timestamp_memo(&hashes[0]);
+        Ok(summarize_proposal(
+            &proposal,
+            hashes.len(),
+            recipient.to_string(),
+            memo,
+        ))
+    }
+
+    /// Create and broadcast a timestamp transaction
+    ///
+    /// Creates a shielded transaction with the file hash in the memo field,
+    /// then broadcasts it to the Zcash network.
+    pub async fn create_timestamp_tx(
+        &mut self,
+        hash: &[u8; 32],
+    ) -> anyhow::Result<TimestampTxResult> {
+        let (proposal, _address, _memo) = self.propose_timestamp(hash)?;
+        info!("Creating timestamp transaction");
+        self.build_and_broadcast(&proposal).await
+    }
+
+    /// Create and broadcast a single transaction timestamping several hashes
+    ///
+    /// Each hash gets its own self-send output carrying a standard timestamp
+    /// memo, so every hash verifies independently against the same txid.
+    pub async fn create_batch_timestamp_tx(
+        &mut self,
+        hashes: &[[u8; 32]],
+    ) -> anyhow::Result<TimestampTxResult> {
+        let (proposal, _recipient) = self.propose_batch_timestamp(hashes)?;
+        info!(
+            "Creating batch timestamp transaction for {} hashes",
+            hashes.len()
         );
+        self.build_and_broadcast(&proposal).await
+    }
 
-        // Need shielded funds to send memo
-        if total_shielded < min_required {
-            warn!("Insufficient shielded funds for timestamp transaction");
-            if transparent_balance >= min_required {
-                return Err(anyhow::anyhow!(
-                    "Your funds are in the transparent pool.\n\
-                    Shield them first using Zingo or zcashd, then try again."
-                ));
-            }
-            return Err(anyhow::anyhow!(
-                "Insufficient funds. Run 'zots wallet address' and fund your wallet."
-            ));
+    /// Propose a self-send with one memo output per hash
+    fn propose_batch_timestamp(
+        &mut self,
+        hashes: &[[u8; 32]],
+    ) -> anyhow::Result<(ZotsProposal, zcash_address::ZcashAddress)> {
+        if hashes.is_empty() {
+            return Err(anyhow::anyhow!("No hashes to timestamp"));
         }
+        let accounts = self.db.get_account_ids()?;
+        let account_id = accounts
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("No account found"))?;
+        debug!(
+            "Proposing batch timestamp self-send for {} hashes",
+            hashes.len()
+        );
+
+        self.ensure_timestamp_funds()?;
 
-        // Get the wallet's own address to send to self
         let addresses = self.db.list_addresses(*account_id)?;
-        let address = addresses
+        let recipient = addresses
             .first()
             .ok_or_else(|| anyhow::anyhow!("No address found"))?
             .address()
-            .clone();
-        debug!("Using internal address {:?} for self-send", address);
-
-        // Create memo with timestamp data
-        debug!("Creating timestamp memo payload");
-        let memo_data = create_timestamp_memo(hash);
-        let memo = MemoBytes::from_bytes(&memo_data)
-            .map_err(|_| anyhow::anyhow!("Failed to create memo"))?;
+            .to_zcash_address(&TEST_NETWORK);
 
-        // Derive spending key
-        debug!("Deriving unified spending key for transaction");
-        let usk = UnifiedSpendingKey::from_seed(&TEST_NETWORK, &self.seed, AccountId::ZERO)
-            .map_err(|e| anyhow::anyhow!("Failed to derive spending key: {e:?}"))?;
+        let dust_amount = Zatoshis::from_u64(TIMESTAMP_DUST_AMOUNT).unwrap();
+        let payments = hashes
+            .iter()
+            .map(|hash| {
+                let memo = MemoBytes::from_bytes(&create_timestamp_memo(hash))
+                    .map_err(|_| anyhow::anyhow!("Failed to create memo"))?;
+                Payment::new(
+                    recipient.clone(),
+                    dust_amount,
+                    Some(memo),
+                    None,
+                    None,
+                    vec![],
+                )
+                .ok_or_else(|| anyhow::anyhow!("Recipient cannot receive memos"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let request = TransactionRequest::new(payments)
+            .map_err(|e| anyhow::anyhow!("Invalid transaction request: {e:?}"))?;
 
-        // Create proposal for self-send with memo
-        // Send dust amount (just to carry the memo)
-        let dust_amount = Zatoshis::from_u64(10000).unwrap(); // 0.0001 ZEC
+        let input_selector = GreedyInputSelector::<ZotsWalletDb>::new();
+        let change_strategy = SingleOutputChangeStrategy::new(
+            StandardFeeRule::Zip317,
+            None,
+            ShieldedProtocol::Orchard,
+            DustOutputPolicy::default(),
+        );
 
-        let proposal = propose_standard_transfer_to_address::<_, _, SqliteClientError>(
+        let proposal = propose_transfer::<_, _, _, _, SqliteClientError>(
             &mut self.db,
             &TEST_NETWORK,
-            StandardFeeRule::Zip317,
             *account_id,
+            &input_selector,
+            &change_strategy,
+            request,
             ConfirmationsPolicy::MIN,
-            &address,
-            dust_amount,
-            Some(memo),
-            None, // no change memo
-            ShieldedProtocol::Orchard,
         )
         .map_err(|e| anyhow::anyhow!("Failed to create transaction proposal: {e:?}"))?;
-        debug!("Proposal created for self-send with memo");
+        debug!("Batch proposal created with {} memo outputs", hashes.len());
+
+        Ok((proposal, recipient))
+    }
+
+    /// Prove, sign and broadcast the transaction described by `proposal`
+    async fn build_and_broadcast<N: std::fmt::Debug>(
+        &mut self,
+        proposal: &zcash_client_backend::proposal::Proposal<StandardFeeRule, N>,
+    ) -> anyhow::Result<TimestampTxResult> {
+        // Derive spending key
+        debug!("Deriving unified spending key for transaction");
+        let usk = self.spending_key()?;
 
-        // Load bundled Sapling prover (includes proving parameters)
-        let prover = LocalTxProver::bundled();
+        // Load the configured Sapling prover
+        let prover = Prover::load(&self.config.prover).await?;
         let spending_keys = SpendingKeys::from_unified_spending_key(usk);
         debug!("Loaded proving parameters and spending keys");
 
-        // Build the transaction using helper to handle complex type inference
-        let txids = build_and_sign_transaction(
-            &mut self.db,
-            &TEST_NETWORK,
-            &prover,
-            &spending_keys,
-            &proposal,
-        )?;
+        // A remote prover that went away since loading fails here, before
+        // anything is written to the wallet
+        prover.check().await?;
+
+        // Build the transaction using helper to handle complex type inference.
+        // A failed remote proof abandons the build before anything is stored.
+        let db = &mut self.db;
+        let txids = run_blocking(|| {
+            catch_proving_failure(|| {
+                build_and_sign_transaction(db, &TEST_NETWORK, &prover, &spending_keys, proposal)
+            })
+        })
+        .map_err(|e| e.context("Proving failed; nothing was stored or broadcast"))??;
         debug!("Transaction built and signed");
 
         // NonEmpty guarantees at least one element
         let txid = *txids.first();
-        info!("Timestamp transaction built with txid {}", txid);
+        info!("Transaction built with txid {}", txid);
 
         // Get the transaction from the database
         let tx = self
@@ -532,7 +1597,7 @@ impl ZotsWallet {
             .client
             .send_transaction(raw_tx)
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to broadcast transaction: {e:?}"))?;
+            .context("Failed to broadcast transaction")?;
 
         let send_response = response.into_inner();
         // error_code 0 means success, error_message may contain txid on success
@@ -556,24 +1621,17 @@ impl ZotsWallet {
         })
     }
 
-    /// Send ZEC to an address
-    ///
-    /// Creates and broadcasts a shielded transaction to the specified address.
-    /// Optionally includes a memo.
-    pub async fn send_to_address(
+    /// Propose a transfer of `amount_zatoshi` to `to_address`
+    fn propose_send(
         &mut self,
         to_address: &str,
         amount_zatoshi: u64,
-        memo: Option<Vec<u8>>,
-    ) -> anyhow::Result<SendResult> {
+        memo: Option<&[u8]>,
+    ) -> anyhow::Result<ZotsProposal> {
         let accounts = self.db.get_account_ids()?;
         let account_id = accounts
             .first()
             .ok_or_else(|| anyhow::anyhow!("No account found"))?;
-        info!(
-            "Sending {} zatoshis to {} from account {:?}",
-            amount_zatoshi, to_address, account_id
-        );
 
         // Check balance - iterate over all account balances (consistent with get_balance_breakdown)
         let summary = self.db.get_wallet_summary(ConfirmationsPolicy::MIN)?;
@@ -614,21 +1672,15 @@ impl ZotsWallet {
 
         // Create memo if provided
         let memo_bytes = if let Some(data) = memo {
-            MemoBytes::from_bytes(&data).map_err(|_| anyhow::anyhow!("Invalid memo"))?
+            MemoBytes::from_bytes(data).map_err(|_| anyhow::anyhow!("Invalid memo"))?
         } else {
             MemoBytes::empty()
         };
 
-        // Derive spending key
-        debug!("Deriving unified spending key for transaction");
-        let usk = UnifiedSpendingKey::from_seed(&TEST_NETWORK, &self.seed, AccountId::ZERO)
-            .map_err(|e| anyhow::anyhow!("Failed to derive spending key: {e:?}"))?;
-
-        // Create proposal
         let send_amount =
             Zatoshis::from_u64(amount_zatoshi).map_err(|_| anyhow::anyhow!("Invalid amount"))?;
 
-        let proposal = propose_standard_transfer_to_address::<_, _, SqliteClientError>(
+        propose_standard_transfer_to_address::<_, _, SqliteClientError>(
             &mut self.db,
             &TEST_NETWORK,
             StandardFeeRule::Zip317,
@@ -640,101 +1692,190 @@ impl ZotsWallet {
             None,
             ShieldedProtocol::Orchard,
         )
-        .map_err(|e| anyhow::anyhow!("Failed to create transaction proposal: {e:?}"))?;
-
-        // Estimate fee (ZIP-317 standard fee)
-        let fee = 10000u64; // 0.0001 ZEC - standard minimum fee
-
-        // Load prover and build transaction
-        let prover = LocalTxProver::bundled();
-        let spending_keys = SpendingKeys::from_unified_spending_key(usk);
-        debug!("Building and signing transaction");
-
-        let txids = build_and_sign_transaction(
-            &mut self.db,
-            &TEST_NETWORK,
-            &prover,
-            &spending_keys,
-            &proposal,
-        )?;
-
-        let txid = *txids.first();
-        info!("Transaction built with txid {}", txid);
-
-        // Get the transaction and broadcast
-        let tx = self
-            .db
-            .get_transaction(txid)?
-            .ok_or_else(|| anyhow::anyhow!("Transaction not found in database"))?;
+        .map_err(|e| anyhow::anyhow!("Failed to create transaction proposal: {e:?}"))
+    }
 
-        let mut tx_bytes = Vec::new();
-        tx.write(&mut tx_bytes)
-            .map_err(|e| anyhow::anyhow!("Failed to serialize transaction: {e:?}"))?;
+    /// Report the amount and ZIP-317 fee of a send, without building anything
+    pub fn preview_send(
+        &mut self,
+        to_address: &str,
+        amount_zatoshi: u64,
+        memo: Option<&[u8]>,
+    ) -> anyhow::Result<SendPreview> {
+        let proposal = self.propose_send(to_address, amount_zatoshi, memo)?;
+        Ok(SendPreview {
+            amount: amount_zatoshi,
+            fee: proposal_fee(&proposal),
+        })
+    }
 
-        let raw_tx = RawTransaction {
-            data: tx_bytes,
-            height: 0,
-        };
+    /// Send ZEC to an address
+    ///
+    /// Creates and broadcasts a shielded transaction to the specified address.
+    /// Optionally includes a memo.
+    pub async fn send_to_address(
+        &mut self,
+        to_address: &str,
+        amount_zatoshi: u64,
+        memo: Option<Vec<u8>>,
+    ) -> anyhow::Result<SendResult> {
+        self.send_with_fee_limit(to_address, amount_zatoshi, memo, None)
+            .await
+    }
 
-        let response = self
-            .client
-            .send_transaction(raw_tx)
+    /// Send the transaction shown by [`Self::preview_send`]
+    ///
+    /// The transaction is rebuilt against the current wallet state; if it
+    /// would now pay more than the previewed fee nothing is broadcast.
+    pub async fn send_previewed(
+        &mut self,
+        to_address: &str,
+        memo: Option<Vec<u8>>,
+        preview: &SendPreview,
+    ) -> anyhow::Result<SendResult> {
+        self.send_with_fee_limit(to_address, preview.amount, memo, Some(preview.fee))
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to broadcast transaction: {e:?}"))?;
+    }
 
-        let send_response = response.into_inner();
-        if send_response.error_code != 0 {
-            return Err(anyhow::anyhow!(
-                "Transaction rejected (code {}): {}",
-                send_response.error_code,
-                send_response.error_message
-            ));
+    async fn send_with_fee_limit(
+        &mut self,
+        to_address: &str,
+        amount_zatoshi: u64,
+        memo: Option<Vec<u8>>,
+        max_fee: Option<u64>,
+    ) -> anyhow::Result<SendResult> {
+        info!("Sending {} zatoshis to {}", amount_zatoshi, to_address);
+        let proposal = self.propose_send(to_address, amount_zatoshi, memo.as_deref())?;
+        let fee = proposal_fee(&proposal);
+        if let Some(max_fee) = max_fee
+            && fee > max_fee
+        {
+            anyhow::bail!(
+                "Fee rose from {max_fee} to {fee} zatoshis since the preview; nothing was sent"
+            );
         }
 
-        info!("Transaction {} broadcast successfully", txid);
+        let result = self.build_and_broadcast(&proposal).await?;
         Ok(SendResult {
-            txid: txid.to_string(),
+            txid: result.txid,
             fee,
         })
     }
 
     /// Wait for transaction confirmation.
     ///
-    /// Polls the lightwalletd tip until the height advances, then returns the
-    /// observed height plus a wall-clock timestamp for UX purposes. This is a
-    /// lightweight heuristic rather than a consensus-proof of inclusion.
+    /// Convenience wrapper around [`Self::wait_confirmation_with`] using the
+    /// default policy with the given block limit and no progress reporting.
     pub async fn wait_confirmation(
         &mut self,
         txid: &str,
         max_blocks: u32,
     ) -> anyhow::Result<ConfirmationResult> {
+        let policy = ConfirmationWaitPolicy {
+            max_blocks,
+            ..ConfirmationWaitPolicy::default()
+        };
+        self.wait_confirmation_with(txid, &policy, |_| {}).await
+    }
+
+    /// Wait for transaction confirmation according to `policy`.
+    ///
+    /// Syncs the wallet every `poll_interval` and reports a
+    /// [`ConfirmationProgress`] event after each round, until the chain has
+    /// advanced `max_blocks` or `max_wait` has passed. Confirmations are
+    /// counted from the height the wallet recorded for the transaction, and
+    /// the returned block time is the time of the block it was mined in.
+    pub async fn wait_confirmation_with<F>(
+        &mut self,
+        txid: &str,
+        policy: &ConfirmationWaitPolicy,
+        mut on_progress: F,
+    ) -> anyhow::Result<ConfirmationResult>
+    where
+        F: FnMut(&ConfirmationProgress),
+    {
+        let tx_id = parse_txid(txid)?;
+        let target = policy.confirmations.max(1);
+        let deadline = tokio::time::Instant::now() + policy.max_wait;
         let start_height = self.get_block_height().await?;
         info!(
-            "Waiting for confirmation of txid {} starting at height {}",
-            txid, start_height
+            "Waiting for {} confirmation(s) of txid {} starting at height {}",
+            target, txid, start_height
         );
 
-        for _ in 0..max_blocks {
+        loop {
             self.sync().await?;
             let current_height = self.get_block_height().await?;
-            debug!(current_height, start_height, "Synced height while waiting");
+            let mined_height = self
+                .db
+                .get_tx_height(tx_id)?
+                .map(|h| u64::from(u32::from(h)));
+            let confirmations = mined_height
+                .map(|h| (current_height.saturating_sub(h) + 1) as u32)
+                .unwrap_or(0);
+            debug!(
+                current_height,
+                start_height,
+                ?mined_height,
+                confirmations,
+                "Synced height while waiting"
+            );
+
+            on_progress(&ConfirmationProgress {
+                start_height,
+                current_height,
+                mined_height,
+                confirmations,
+                target,
+            });
 
-            if current_height > start_height {
-                // Use wall-clock time here; lightwalletd does not return block metadata
-                let block_time = chrono::Utc::now().timestamp() as u32;
+            if let Some(mined_height) = mined_height
+                && confirmations >= target
+            {
                 return Ok(ConfirmationResult {
-                    block_height: current_height as u32,
-                    block_time,
+                    block_height: mined_height as u32,
+                    block_time: self.confirmation_watcher().block_time(mined_height).await?,
                 });
             }
 
-            // Wait before next check (Zcash block time ~75 seconds)
-            tokio::time::sleep(tokio::time::Duration::from_secs(30)).await;
+            if current_height.saturating_sub(start_height) >= u64::from(policy.max_blocks) {
+                return Err(anyhow::anyhow!(
+                    "Transaction {txid} not confirmed within {} blocks",
+                    policy.max_blocks
+                ));
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(anyhow::anyhow!(
+                    "Transaction {txid} not confirmed within {}s",
+                    policy.max_wait.as_secs()
+                ));
+            }
+
+            tokio::time::sleep(policy.poll_interval.min(deadline - now)).await;
         }
+    }
 
-        Err(anyhow::anyhow!(
-            "Transaction {txid} not confirmed within {max_blocks} blocks"
-        ))
+    /// Look up whether a transaction has been mined, without waiting
+    ///
+    /// Queries lightwalletd directly, so it works for any txid (not only the
+    /// wallet's own). Returns `None` while the transaction is in the mempool
+    /// or only mined on a fork.
+    pub async fn fetch_confirmation(
+        &mut self,
+        txid: &str,
+    ) -> anyhow::Result<Option<ConfirmationResult>> {
+        self.confirmation_watcher().fetch(txid).await
+    }
+
+    /// Confirmation lookups on this wallet's lightwalletd connection
+    ///
+    /// The watcher does not touch the wallet database, so it can wait for a
+    /// transaction on another task while the wallet keeps working.
+    pub fn confirmation_watcher(&self) -> ConfirmationWatcher {
+        ConfirmationWatcher {
+            client: self.client.clone(),
+        }
     }
 
     /// Get the wallet configuration
@@ -789,13 +1930,7 @@ impl ZotsWallet {
         let mut transactions = Vec::new();
         for (txid_bytes, balance_delta, block_time, sent_note_count, is_shielding) in rows.flatten()
         {
-            let mut txid_arr = [0u8; 32];
-            if txid_bytes.len() == 32 {
-                txid_arr.copy_from_slice(&txid_bytes);
-                txid_arr.reverse(); // Reverse for display format
-            }
-            // Manual hex encoding
-            let txid: String = txid_arr.iter().map(|b| format!("{b:02x}")).collect();
+            let txid = txid_to_display(&txid_bytes);
 
             let timestamp = block_time.map(|t| t as u64).unwrap_or(0);
 
@@ -817,6 +1952,177 @@ impl ZotsWallet {
         Ok(transactions)
     }
 
+    /// Get the wallet's transaction history with decrypted memos
+    ///
+    /// Pending transactions come first, then mined ones from newest to
+    /// oldest. Memos of self-sends appear once even though the wallet both
+    /// sent and received them.
+    pub fn get_transaction_history(&self, limit: usize) -> anyhow::Result<Vec<HistoryEntry>> {
+        use rusqlite::Connection;
+
+        let db_path = self.config.wallet_db_path();
+        let conn =
+            Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+        let mut stmt = conn.prepare(
+            "SELECT
+                txid,
+                mined_height,
+                block_time,
+                account_balance_delta,
+                sent_note_count,
+                is_shielding
+            FROM v_transactions
+            ORDER BY mined_height IS NULL DESC, mined_height DESC
+            LIMIT ?",
+        )?;
+        let rows = stmt.query_map([limit.min(i64::MAX as usize) as i64], |row| {
+            let txid_bytes: Vec<u8> = row.get(0)?;
+            let height: Option<u32> = row.get(1)?;
+            let block_time: Option<u32> = row.get(2)?;
+            let balance_delta: i64 = row.get(3)?;
+            let sent_note_count: i64 = row.get(4)?;
+            let is_shielding: bool = row.get(5)?;
+            Ok((
+                txid_bytes,
+                height,
+                block_time,
+                balance_delta,
+                sent_note_count > 0 && !is_shielding,
+            ))
+        })?;
+
+        let mut memo_stmt = conn.prepare(
+            "SELECT DISTINCT output_pool, output_index, memo
+            FROM v_tx_outputs
+            WHERE txid = ? AND memo IS NOT NULL
+            ORDER BY output_pool, output_index",
+        )?;
+
+        let mut history = Vec::new();
+        for (txid_bytes, height, block_time, amount, is_sent) in rows.flatten() {
+            let mut seen = HashSet::new();
+            let mut memos = Vec::new();
+            let memo_rows = memo_stmt.query_map([&txid_bytes], |row| {
+                let pool: i64 = row.get(0)?;
+                let output_index: u32 = row.get(1)?;
+                let memo: Vec<u8> = row.get(2)?;
+                Ok((pool, output_index, memo))
+            })?;
+            for (pool, output_index, memo) in memo_rows.flatten() {
+                // 0xF6 marks an empty memo (ZIP-302)
+                if memo.first().is_none_or(|b| *b == 0xF6) || !seen.insert((pool, output_index)) {
+                    continue;
+                }
+                memos.push(memo);
+            }
+
+            history.push(HistoryEntry {
+                txid: txid_to_display(&txid_bytes),
+                height,
+                block_time,
+                amount,
+                is_sent,
+                memos,
+            });
+        }
+
+        Ok(history)
+    }
+
+    /// Get memos received from other parties at or above `min_height`
+    ///
+    /// Only non-change outputs from external senders with a non-empty memo
+    /// are returned, ordered by height. Our own timestamp self-sends are
+    /// excluded.
+    pub fn get_incoming_memos(&self, min_height: u64) -> anyhow::Result<Vec<IncomingMemo>> {
+        use rusqlite::Connection;
+
+        let db_path = self.config.wallet_db_path();
+        let conn =
+            Connection::open_with_flags(&db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+        let mut stmt = conn.prepare(
+            "SELECT
+                o.txid,
+                o.output_pool,
+                o.output_index,
+                o.to_address,
+                o.value,
+                o.memo,
+                t.mined_height
+            FROM v_tx_outputs o
+            JOIN transactions t ON t.txid = o.txid
+            WHERE o.to_account_uuid IS NOT NULL
+              AND o.from_account_uuid IS NULL
+              AND o.is_change = 0
+              AND o.memo IS NOT NULL
+              AND t.mined_height IS NOT NULL
+              AND t.mined_height >= ?
+            ORDER BY t.mined_height, o.txid, o.output_index",
+        )?;
+
+        let rows = stmt.query_map([min_height as i64], |row| {
+            let txid_bytes: Vec<u8> = row.get(0)?;
+            let pool: i64 = row.get(1)?;
+            let output_index: u32 = row.get(2)?;
+            let address: Option<String> = row.get(3)?;
+            let value: i64 = row.get(4)?;
+            let memo: Vec<u8> = row.get(5)?;
+            let height: u32 = row.get(6)?;
+            Ok((txid_bytes, pool, output_index, address, value, memo, height))
+        })?;
+
+        let mut memos = Vec::new();
+        for (txid_bytes, pool, output_index, address, value, memo, height) in rows.flatten() {
+            // 0xF6 marks an empty memo (ZIP-302)
+            if memo.first().is_none_or(|b| *b == 0xF6) {
+                continue;
+            }
+            let pool = match pool {
+                2 => "sapling",
+                3 => "orchard",
+                _ => continue,
+            };
+            memos.push(IncomingMemo {
+                txid: txid_to_display(&txid_bytes),
+                pool,
+                output_index,
+                address,
+                value: value.max(0) as u64,
+                memo,
+                height: u64::from(height),
+            });
+        }
+
+        Ok(memos)
+    }
+
+    /// Sync the wallet and return incoming memos detected since the last call
+    ///
+    /// The first call records the wallet's scanned tip and only reports memos
+    /// mined above it, so historical memos are not replayed. Later calls only
+    /// query the last few blocks below the previous tip, so each call costs
+    /// about the same however long the wallet runs. Each memo is reported
+    /// once per wallet instance.
+    pub async fn sync_incoming_memos(&mut self) -> anyhow::Result<Vec<IncomingMemo>> {
+        let cursor = self.memos.cursor(self.scanned_height()?);
+
+        self.sync().await?;
+
+        let incoming = self.get_incoming_memos(cursor)?;
+        let memos = self.memos.advance(incoming, self.scanned_height()?);
+        if !memos.is_empty() {
+            info!("Detected {} new incoming memo(s)", memos.len());
+        }
+        Ok(memos)
+    }
+
+    /// Highest block the wallet has scanned, if any
+    fn scanned_height(&self) -> anyhow::Result<Option<u64>> {
+        Ok(self.db.chain_height()?.map(|h| u64::from(u32::from(h))))
+    }
+
     /// Verify a timestamp transaction by fetching it from the blockchain
     /// and checking that the memo contains the expected hash.
     ///
@@ -850,7 +2156,7 @@ impl ZotsWallet {
             .client
             .get_transaction(tx_filter)
             .await
-            .map_err(|e| anyhow::anyhow!("Failed to fetch transaction: {e:?}"))?;
+            .context("Failed to fetch transaction")?;
 
         let raw_tx = response.into_inner();
         debug!("Fetched raw transaction bytes: {}", raw_tx.data.len());
@@ -868,9 +2174,7 @@ impl ZotsWallet {
         debug!("Transaction parsed; scanning outputs for memo");
 
         // Get the viewing key for decryption
-        let usk = UnifiedSpendingKey::from_seed(&TEST_NETWORK, &self.seed, AccountId::ZERO)
-            .map_err(|e| anyhow::anyhow!("Failed to derive spending key: {e:?}"))?;
-        let ufvk = usk.to_unified_full_viewing_key();
+        let ufvk = self.viewing_key()?;
 
         // Create a map of viewing keys for decrypt_transaction
         let mut ufvks: HashMap<u32, UnifiedFullViewingKey> = HashMap::new();
@@ -939,3 +2243,46 @@ impl ZotsWallet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memo(txid: &str, height: u64) -> IncomingMemo {
+        IncomingMemo {
+            txid: txid.to_string(),
+            pool: "orchard",
+            output_index: 0,
+            address: None,
+            value: 1000,
+            memo: b"hello".to_vec(),
+            height,
+        }
+    }
+
+    #[test]
+    fn test_memo_tracker_advances_cursor() {
+        let mut tracker = MemoTracker::default();
+
+        // First call starts just above the scanned tip
+        assert_eq!(tracker.cursor(Some(100)), 101);
+        let fresh = tracker.advance(vec![memo("aa", 105), memo("bb", 110)], Some(110));
+        assert_eq!(fresh.len(), 2);
+        // The cursor never moves below the starting point
+        assert_eq!(tracker.cursor, Some(101));
+
+        // The second call re-reads the overlap but reports only the new memo
+        let cursor = tracker.cursor(Some(110));
+        assert_eq!(cursor, 101);
+        let fresh = tracker.advance(
+            vec![memo("aa", 105), memo("bb", 110), memo("cc", 130)],
+            Some(130),
+        );
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].txid, "cc");
+
+        // The cursor follows the tip and memos below it are forgotten
+        assert_eq!(tracker.cursor(Some(130)), 130 - MEMO_REORG_MARGIN);
+        assert_eq!(tracker.seen.len(), 1);
+    }
+}